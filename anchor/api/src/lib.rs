@@ -0,0 +1,38 @@
+//! Read-only HTTP API over the database written by `academic-indexer`.
+//!
+//! Responses use the JSON shapes of `src/types/index.ts`, so the frontend can
+//! switch from the mock routes without changing its types. List endpoints take
+//! `limit` and `offset` and report the unpaged count in `X-Total-Count`.
+
+pub mod db;
+mod error;
+pub mod models;
+mod query;
+mod routes;
+
+use axum::http::{HeaderName, Method};
+use axum::routing::get;
+use axum::Router;
+use tower_http::cors::{Any, CorsLayer};
+
+pub use db::Database;
+pub use error::ApiError;
+pub use query::{DEFAULT_LIMIT, MAX_LIMIT, TOTAL_COUNT};
+
+pub fn router(db: Database) -> Router {
+    let cors = CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods([Method::GET])
+        .expose_headers([HeaderName::from_static(TOTAL_COUNT)]);
+
+    Router::new()
+        .route("/courses", get(routes::courses::list))
+        .route("/courses/{id}", get(routes::courses::get))
+        .route("/enrollments", get(routes::enrollments::list))
+        .route("/certificates", get(routes::certificates::list))
+        .route("/certificates/{mint}", get(routes::certificates::get))
+        .route("/transactions", get(routes::transactions::list))
+        .route("/metrics/dashboard", get(routes::metrics::dashboard))
+        .layer(cors)
+        .with_state(db)
+}
@@ -0,0 +1,38 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use academic_api::{router, Database};
+use anyhow::{Context as _, Result};
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "academic-api", version, about = "Serve indexed academic_chain data over HTTP")]
+struct Args {
+    /// SQLite database written by academic-indexer
+    #[arg(long, default_value = "academic_chain.db", env = "ACADEMIC_DB")]
+    db: PathBuf,
+
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:8080", env = "ACADEMIC_API_BIND")]
+    bind: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
+
+    let db = Database::open(&args.db)
+        .with_context(|| format!("failed to open {}; has academic-indexer run yet?", args.db.display()))?;
+    let listener = tokio::net::TcpListener::bind(args.bind)
+        .await
+        .with_context(|| format!("failed to bind {}", args.bind))?;
+    log::info!("listening on http://{}", args.bind);
+
+    axum::serve(listener, router(db))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
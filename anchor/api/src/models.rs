@@ -0,0 +1,217 @@
+//! Response bodies, mirroring `src/types/index.ts`.
+//!
+//! Fields the program does not store (descriptions, ratings, thumbnails, ...)
+//! are returned empty so clients can rely on every field being present. A few
+//! on-chain fields the frontend types lack are added alongside.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    #[default]
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Course {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub instructor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructor_avatar: Option<String>,
+    pub required_credits: u64,
+    pub duration: String,
+    pub difficulty: Difficulty,
+    pub category: String,
+    pub tags: Vec<String>,
+    pub thumbnail: String,
+    pub enrolled_count: u64,
+    pub rating: f64,
+    pub is_active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerequisites: Option<Vec<String>>,
+    pub learning_outcomes: Vec<String>,
+    pub created_at: String,
+    pub address: String,
+    pub credit_hours: u8,
+    pub max_attempts: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrollmentStatus {
+    Enrolled,
+    InProgress,
+    Completed,
+    Dropped,
+}
+
+impl EnrollmentStatus {
+    /// On-chain `Incomplete` (grading deadline missed) is reported as dropped
+    pub fn from_indexed(status: &str) -> Self {
+        match status {
+            "completed" => Self::Completed,
+            "incomplete" => Self::Dropped,
+            _ => Self::Enrolled,
+        }
+    }
+
+    /// The indexer's status column value; `InProgress` has none
+    pub fn indexed(self) -> &'static str {
+        match self {
+            Self::Enrolled => "enrolled",
+            Self::InProgress => "in_progress",
+            Self::Completed => "completed",
+            Self::Dropped => "incomplete",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseEnrollment {
+    pub id: String,
+    pub student_id: String,
+    pub course_id: String,
+    pub enrolled_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    pub status: EnrollmentStatus,
+    pub progress: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<u8>,
+    pub credits_paid: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_id: Option<String>,
+    pub term_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letter_grade: Option<String>,
+    pub attempt: u8,
+    /// Enrollment at another institution this completion was transferred from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transferred_from: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CertificateKind {
+    Course,
+    Graduation,
+}
+
+impl CertificateKind {
+    pub fn indexed(self) -> &'static str {
+        match self {
+            Self::Course => "course",
+            Self::Graduation => "graduation",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Certificate {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: CertificateKind,
+    pub student_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_id: Option<String>,
+    pub title: String,
+    pub issue_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<u8>,
+    pub mint_address: String,
+    pub metadata_uri: String,
+    /// The minting transaction is finalized
+    pub is_verified: bool,
+    pub issuer: String,
+    pub blockchain: String,
+    pub transaction_hash: String,
+    /// Graduation GPA, x100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpa: Option<u16>,
+    /// Hex SHA-256 of the metadata JSON, recorded on the enrollment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_hash: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionType {
+    CreditPurchase,
+    CourseRegistration,
+    CertificateMint,
+}
+
+impl TransactionType {
+    pub fn indexed(self) -> &'static str {
+        match self {
+            Self::CreditPurchase => "credit_purchase",
+            Self::CourseRegistration => "course_registration",
+            Self::CertificateMint => "certificate_mint",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionStatus {
+    Pending,
+    Confirmed,
+    Failed,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: TransactionType,
+    pub amount: u64,
+    pub status: TransactionStatus,
+    pub hash: String,
+    pub timestamp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_address: Option<String>,
+    /// Transaction fee in SOL
+    pub gas_fee: f64,
+    pub details: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardMetrics {
+    pub total_students: u64,
+    pub total_courses: u64,
+    pub certificates_issued: u64,
+    /// Credits purchased minus credits spent on registrations, only reported
+    /// for a single institution since each one has its own credit mint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_credits_in_circulation: Option<u64>,
+    /// Credits in circulation per institution and credit mint
+    pub credits_in_circulation: Vec<CreditsInCirculation>,
+    /// Distinct signers over the last 30 days
+    pub monthly_active_users: u64,
+    /// Mean grade of completed enrollments, 0-100
+    pub average_grade: f64,
+    /// Completed enrollments as a percentage of all enrollments
+    pub completion_rate: f64,
+    /// Credit purchases since the start of the current UTC month, in SOL
+    pub revenue_this_month: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditsInCirculation {
+    pub institution: String,
+    pub credit_mint: String,
+    pub credits: u64,
+}
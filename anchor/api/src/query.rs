@@ -0,0 +1,122 @@
+//! Pagination and filter helpers shared by the list endpoints.
+
+use axum::http::HeaderValue;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, SecondsFormat};
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Row};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ApiError;
+
+pub const DEFAULT_LIMIT: u32 = 20;
+pub const MAX_LIMIT: u32 = 100;
+/// Response header carrying the number of rows before paging
+pub const TOTAL_COUNT: &str = "x-total-count";
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub struct Page {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+impl Page {
+    fn resolve(self) -> Result<(u32, u32), ApiError> {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT);
+        if limit == 0 || limit > MAX_LIMIT {
+            return Err(ApiError::BadRequest(format!("limit must be 1-{MAX_LIMIT}")));
+        }
+        Ok((limit, self.offset.unwrap_or(0)))
+    }
+}
+
+/// `AND`ed conditions with one positional parameter each
+#[derive(Default)]
+pub struct Where {
+    clauses: Vec<&'static str>,
+    params: Vec<Value>,
+}
+
+impl Where {
+    pub fn push(&mut self, clause: &'static str, value: Option<impl Into<Value>>) {
+        if let Some(value) = value {
+            self.clauses.push(clause);
+            self.params.push(value.into());
+        }
+    }
+
+    fn sql(&self) -> String {
+        if self.clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", self.clauses.join(" AND "))
+        }
+    }
+}
+
+/// The parts of a list query; `from` includes any joins
+pub struct Listing {
+    pub select: &'static str,
+    pub from: &'static str,
+    pub order: &'static str,
+}
+
+/// One page of results; serializes as a plain array like the mock routes
+pub struct Paged<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+impl<T: Serialize> IntoResponse for Paged<T> {
+    fn into_response(self) -> Response {
+        let mut response = Json(self.items).into_response();
+        response
+            .headers_mut()
+            .insert(TOTAL_COUNT, HeaderValue::from(self.total));
+        response
+    }
+}
+
+impl Listing {
+    pub fn fetch<T>(
+        &self,
+        conn: &Connection,
+        filter: Where,
+        page: Page,
+        map: impl FnMut(&Row) -> rusqlite::Result<T>,
+    ) -> Result<Paged<T>, ApiError> {
+        let (limit, offset) = page.resolve()?;
+        let conditions = filter.sql();
+
+        let total = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}{conditions}", self.from),
+            params_from_iter(&filter.params),
+            |row| row.get(0),
+        )?;
+
+        let sql = format!(
+            "SELECT {} FROM {}{conditions} ORDER BY {} LIMIT {limit} OFFSET {offset}",
+            self.select, self.from, self.order
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let items = stmt
+            .query_map(params_from_iter(&filter.params), map)?
+            .collect::<rusqlite::Result<_>>()?;
+
+        Ok(Paged { items, total })
+    }
+}
+
+/// Unix seconds as RFC 3339 in UTC
+pub fn iso(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+pub fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL
+}
@@ -0,0 +1,92 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use rusqlite::{params, OptionalExtension, Row};
+use serde::Deserialize;
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::models::{Certificate, CertificateKind};
+use crate::query::{iso, Listing, Page, Paged, Where};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CertificateFilter {
+    /// Institution account address
+    pub institution: Option<String>,
+    pub student: Option<String>,
+    pub course: Option<String>,
+    /// Instructor of the offering the certificate was earned in
+    pub instructor: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<CertificateKind>,
+}
+
+const CERTIFICATES: Listing = Listing {
+    select: "c.mint, c.kind, c.student, c.course_id, co.name, c.issued_at, c.grade, c.metadata_uri,
+             COALESCE(t.finalized, 0), i.authority, c.signature, c.gpa, c.metadata_hash",
+    from: "certificates c
+           LEFT JOIN transactions t ON t.signature = c.signature
+           LEFT JOIN courses co ON co.institution = c.institution AND co.course_id = c.course_id
+           LEFT JOIN institutions i ON i.address = c.institution",
+    order: "c.issued_at DESC, c.mint",
+};
+
+fn certificate(row: &Row) -> rusqlite::Result<Certificate> {
+    let kind = match row.get::<_, String>(1)?.as_str() {
+        "graduation" => CertificateKind::Graduation,
+        _ => CertificateKind::Course,
+    };
+    let course_id: Option<String> = row.get(3)?;
+    let title = match (kind, row.get::<_, Option<String>>(4)?) {
+        (CertificateKind::Graduation, _) => "Graduation Certificate".to_string(),
+        (CertificateKind::Course, Some(name)) => format!("{name} - Certificate"),
+        (CertificateKind::Course, None) => format!("{} - Certificate", course_id.as_deref().unwrap_or_default()),
+    };
+
+    Ok(Certificate {
+        id: row.get(0)?,
+        kind,
+        student_id: row.get(2)?,
+        course_id,
+        title,
+        issue_date: iso(row.get(5)?),
+        grade: row.get(6)?,
+        mint_address: row.get(0)?,
+        metadata_uri: row.get::<_, Option<String>>(7)?.unwrap_or_default(),
+        is_verified: row.get(8)?,
+        issuer: row.get::<_, Option<String>>(9)?.unwrap_or_default(),
+        blockchain: "solana".to_string(),
+        transaction_hash: row.get(10)?,
+        gpa: row.get(11)?,
+        metadata_hash: row.get(12)?,
+    })
+}
+
+pub async fn list(
+    State(db): State<Database>,
+    Query(page): Query<Page>,
+    Query(filter): Query<CertificateFilter>,
+) -> Result<Paged<Certificate>, ApiError> {
+    let mut conditions = Where::default();
+    conditions.push("c.institution = ?", filter.institution);
+    conditions.push("c.student = ?", filter.student);
+    conditions.push("c.course_id = ?", filter.course);
+    conditions.push(
+        "c.enrollment IN (SELECT e.address FROM enrollments e
+                          JOIN offerings o ON o.address = e.offering WHERE o.instructor = ?)",
+        filter.instructor,
+    );
+    conditions.push("c.kind = ?", filter.kind.map(|kind| kind.indexed().to_string()));
+
+    db.read(move |conn| CERTIFICATES.fetch(conn, conditions, page, certificate)).await
+}
+
+pub async fn get(State(db): State<Database>, Path(mint): Path<String>) -> Result<Json<Certificate>, ApiError> {
+    db.read(move |conn| {
+        let sql = format!("SELECT {} FROM {} WHERE c.mint = ?1", CERTIFICATES.select, CERTIFICATES.from);
+        conn.query_row(&sql, params![mint], certificate)
+            .optional()?
+            .map(Json)
+            .ok_or_else(|| ApiError::NotFound(format!("certificate {mint}")))
+    })
+    .await
+}
@@ -0,0 +1,84 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use rusqlite::{params, OptionalExtension, Row};
+use serde::Deserialize;
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::models::{Course, Difficulty};
+use crate::query::{iso, Listing, Page, Paged, Where};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CourseFilter {
+    /// Institution account address
+    pub institution: Option<String>,
+    pub instructor: Option<String>,
+    /// Courses the student has enrolled in
+    pub student: Option<String>,
+    pub active: Option<bool>,
+}
+
+const COURSES: Listing = Listing {
+    select: "c.course_id, c.name, c.instructor, c.required_credits, c.credit_hours, c.max_attempts,
+             c.is_active, c.created_at, c.address,
+             (SELECT COUNT(*) FROM enrollments e WHERE e.institution = c.institution AND e.course_id = c.course_id)",
+    from: "courses c",
+    order: "c.course_id",
+};
+
+fn course(row: &Row) -> rusqlite::Result<Course> {
+    let credit_hours: u8 = row.get(4)?;
+    Ok(Course {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        description: String::new(),
+        instructor: row.get(2)?,
+        instructor_avatar: None,
+        required_credits: row.get(3)?,
+        duration: format!("{credit_hours} credit hour{}", if credit_hours == 1 { "" } else { "s" }),
+        difficulty: Difficulty::default(),
+        category: String::new(),
+        tags: Vec::new(),
+        thumbnail: String::new(),
+        enrolled_count: row.get(9)?,
+        rating: 0.0,
+        is_active: row.get(6)?,
+        prerequisites: None,
+        learning_outcomes: Vec::new(),
+        created_at: iso(row.get(7)?),
+        address: row.get(8)?,
+        credit_hours,
+        max_attempts: row.get(5)?,
+    })
+}
+
+pub async fn list(
+    State(db): State<Database>,
+    Query(page): Query<Page>,
+    Query(filter): Query<CourseFilter>,
+) -> Result<Paged<Course>, ApiError> {
+    let mut conditions = Where::default();
+    conditions.push("c.institution = ?", filter.institution);
+    conditions.push("c.instructor = ?", filter.instructor);
+    conditions.push(
+        "c.address IN (SELECT co.address FROM enrollments e
+                       JOIN courses co ON co.institution = e.institution AND co.course_id = e.course_id
+                       WHERE e.student = ?)",
+        filter.student,
+    );
+    conditions.push("c.is_active = ?", filter.active);
+
+    db.read(move |conn| COURSES.fetch(conn, conditions, page, course)).await
+}
+
+/// A course by account address, or by course ID where only one institution uses it
+pub async fn get(State(db): State<Database>, Path(id): Path<String>) -> Result<Json<Course>, ApiError> {
+    db.read(move |conn| {
+        let sql = format!("SELECT {} FROM {} WHERE c.address = ?1 OR c.course_id = ?1", COURSES.select, COURSES.from);
+        conn.query_row(&sql, params![id], course)
+            .optional()?
+            .map(Json)
+            .ok_or_else(|| ApiError::NotFound(format!("course {id}")))
+    })
+    .await
+}
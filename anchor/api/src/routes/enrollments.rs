@@ -0,0 +1,63 @@
+use axum::extract::{Query, State};
+use rusqlite::Row;
+use serde::Deserialize;
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::models::{CourseEnrollment, EnrollmentStatus};
+use crate::query::{iso, Listing, Page, Paged, Where};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct EnrollmentFilter {
+    /// Institution account address
+    pub institution: Option<String>,
+    pub student: Option<String>,
+    pub course: Option<String>,
+    /// Instructor of the offering
+    pub instructor: Option<String>,
+    pub status: Option<EnrollmentStatus>,
+}
+
+const ENROLLMENTS: Listing = Listing {
+    select: "e.address, e.student, e.course_id, e.enrolled_at, e.completed_at, e.status, e.grade,
+             e.credits_paid, e.certificate_mint, e.term_id, e.letter_grade, e.attempt, e.transferred_from",
+    // Transferred completions have a course equivalency instead of an offering
+    from: "enrollments e LEFT JOIN offerings o ON o.address = e.offering",
+    order: "e.enrolled_at DESC, e.address",
+};
+
+fn enrollment(row: &Row) -> rusqlite::Result<CourseEnrollment> {
+    let status = EnrollmentStatus::from_indexed(&row.get::<_, String>(5)?);
+    Ok(CourseEnrollment {
+        id: row.get(0)?,
+        student_id: row.get(1)?,
+        course_id: row.get(2)?,
+        enrolled_at: iso(row.get(3)?),
+        completed_at: row.get::<_, Option<i64>>(4)?.map(iso),
+        status,
+        // Coursework progress is not tracked on-chain, only the final grade
+        progress: if status == EnrollmentStatus::Completed { 100 } else { 0 },
+        grade: row.get(6)?,
+        credits_paid: row.get(7)?,
+        certificate_id: row.get(8)?,
+        term_id: row.get(9)?,
+        letter_grade: row.get(10)?,
+        attempt: row.get(11)?,
+        transferred_from: row.get(12)?,
+    })
+}
+
+pub async fn list(
+    State(db): State<Database>,
+    Query(page): Query<Page>,
+    Query(filter): Query<EnrollmentFilter>,
+) -> Result<Paged<CourseEnrollment>, ApiError> {
+    let mut conditions = Where::default();
+    conditions.push("e.institution = ?", filter.institution);
+    conditions.push("e.student = ?", filter.student);
+    conditions.push("e.course_id = ?", filter.course);
+    conditions.push("o.instructor = ?", filter.instructor);
+    conditions.push("e.status = ?", filter.status.map(|status| status.indexed().to_string()));
+
+    db.read(move |conn| ENROLLMENTS.fetch(conn, conditions, page, enrollment)).await
+}
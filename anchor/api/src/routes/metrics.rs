@@ -0,0 +1,103 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use chrono::{Datelike, TimeZone, Utc};
+use rusqlite::{params, Connection};
+use serde::Deserialize;
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::models::{CreditsInCirculation, DashboardMetrics};
+use crate::query::lamports_to_sol;
+
+const ACTIVE_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MetricsFilter {
+    /// Institution account address
+    pub institution: Option<String>,
+}
+
+pub async fn dashboard(
+    State(db): State<Database>,
+    Query(filter): Query<MetricsFilter>,
+) -> Result<Json<DashboardMetrics>, ApiError> {
+    db.read(move |conn| Ok(Json(compute(conn, filter.institution.as_deref())?))).await
+}
+
+// Every query takes the institution as `?1` and matches all rows when it is NULL
+fn compute(conn: &Connection, institution: Option<&str>) -> rusqlite::Result<DashboardMetrics> {
+    let now = Utc::now();
+    let month_start = Utc
+        .with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now)
+        .timestamp();
+    let count = |sql: &str| conn.query_row(sql, params![institution], |row| row.get::<_, u64>(0));
+
+    let (average_grade, completion_rate): (f64, f64) = conn.query_row(
+        "SELECT COALESCE(AVG(CASE WHEN status = 'completed' THEN grade END), 0),
+                COALESCE(100.0 * SUM(status = 'completed') / COUNT(*), 0)
+         FROM enrollments WHERE ?1 IS NULL OR institution = ?1",
+        params![institution],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    let revenue: u64 = conn.query_row(
+        "SELECT COALESCE(SUM(total_cost), 0) FROM purchases
+         WHERE (?1 IS NULL OR institution = ?1) AND timestamp >= ?2",
+        params![institution, month_start],
+        |row| row.get(0),
+    )?;
+
+    // Each institution mints its own credits, so they are only summed per mint
+    let mut statement = conn.prepare(
+        "SELECT i.address, i.credit_mint,
+                MAX((SELECT COALESCE(SUM(amount), 0) FROM purchases p WHERE p.institution = i.address)
+                    - (SELECT COALESCE(SUM(credits_paid), 0) FROM enrollments e WHERE e.institution = i.address), 0)
+         FROM institutions i WHERE ?1 IS NULL OR i.address = ?1
+         ORDER BY i.institution_id",
+    )?;
+    let credits_in_circulation = statement
+        .query_map(params![institution], |row| {
+            Ok(CreditsInCirculation {
+                institution: row.get(0)?,
+                credit_mint: row.get(1)?,
+                credits: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Transactions are not tied to an institution; scoped requests count its students' activity
+    let monthly_active_users = match institution {
+        None => conn.query_row(
+            "SELECT COUNT(DISTINCT signer) FROM transactions WHERE success = 1 AND block_time >= ?1",
+            params![now.timestamp() - ACTIVE_WINDOW_SECS],
+            |row| row.get(0),
+        )?,
+        Some(institution) => conn.query_row(
+            "SELECT COUNT(DISTINCT student) FROM activity WHERE institution = ?1 AND timestamp >= ?2",
+            params![institution, now.timestamp() - ACTIVE_WINDOW_SECS],
+            |row| row.get(0),
+        )?,
+    };
+
+    Ok(DashboardMetrics {
+        total_students: count(
+            "SELECT COUNT(*) FROM profiles WHERE closed_at IS NULL AND (?1 IS NULL OR institution = ?1)",
+        )?,
+        total_courses: count(
+            "SELECT COUNT(*) FROM courses WHERE is_active = 1 AND (?1 IS NULL OR institution = ?1)",
+        )?,
+        certificates_issued: count("SELECT COUNT(*) FROM certificates WHERE ?1 IS NULL OR institution = ?1")?,
+        total_credits_in_circulation: institution
+            .map(|_| credits_in_circulation.iter().map(|entry| entry.credits).sum()),
+        credits_in_circulation,
+        monthly_active_users,
+        average_grade: round_tenth(average_grade),
+        completion_rate: round_tenth(completion_rate),
+        revenue_this_month: lamports_to_sol(revenue),
+    })
+}
+
+fn round_tenth(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
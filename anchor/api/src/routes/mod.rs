@@ -0,0 +1,5 @@
+pub mod certificates;
+pub mod courses;
+pub mod enrollments;
+pub mod metrics;
+pub mod transactions;
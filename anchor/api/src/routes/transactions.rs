@@ -0,0 +1,84 @@
+use axum::extract::{Query, State};
+use rusqlite::Row;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::db::Database;
+use crate::error::ApiError;
+use crate::models::{Transaction, TransactionStatus, TransactionType};
+use crate::query::{iso, lamports_to_sol, Listing, Page, Paged, Where};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TransactionFilter {
+    /// Institution account address
+    pub institution: Option<String>,
+    pub student: Option<String>,
+    pub course: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: Option<TransactionType>,
+}
+
+// Only successful transactions carry events, so every row is confirmed
+const ACTIVITY: Listing = Listing {
+    select: "a.signature, a.event_index, a.kind, a.student, a.amount, a.details, a.timestamp,
+             t.fee, co.name, i.treasury",
+    from: "activity a
+           JOIN transactions t ON t.signature = a.signature
+           LEFT JOIN courses co ON co.institution = a.institution AND co.course_id = a.course_id
+           LEFT JOIN institutions i ON i.address = a.institution",
+    order: "a.timestamp DESC, a.signature, a.event_index",
+};
+
+fn transaction(row: &Row) -> rusqlite::Result<Transaction> {
+    let signature: String = row.get(0)?;
+    let index: u32 = row.get(1)?;
+    let kind = match row.get::<_, String>(2)?.as_str() {
+        "credit_purchase" => TransactionType::CreditPurchase,
+        "course_registration" => TransactionType::CourseRegistration,
+        _ => TransactionType::CertificateMint,
+    };
+    let amount: u64 = row.get(4)?;
+
+    let mut details: Map<String, Value> = serde_json::from_str(&row.get::<_, String>(5)?).unwrap_or_default();
+    if let Some(name) = row.get::<_, Option<String>>(8)? {
+        details.insert("courseName".to_string(), name.into());
+    }
+    // Purchases are indexed in lamports; the frontend shows SOL
+    if let Some(total_cost) = details.remove("totalCost").and_then(|cost| cost.as_u64()) {
+        let sol_cost = lamports_to_sol(total_cost);
+        details.insert("solCost".to_string(), sol_cost.into());
+        if amount > 0 {
+            details.insert("pricePerCredit".to_string(), (sol_cost / amount as f64).into());
+        }
+    }
+
+    Ok(Transaction {
+        id: format!("{signature}:{index}"),
+        kind,
+        amount,
+        status: TransactionStatus::Confirmed,
+        hash: signature,
+        timestamp: iso(row.get(6)?),
+        from_address: Some(row.get(3)?),
+        to_address: match kind {
+            TransactionType::CreditPurchase => row.get(9)?,
+            _ => None,
+        },
+        gas_fee: lamports_to_sol(row.get(7)?),
+        details,
+    })
+}
+
+pub async fn list(
+    State(db): State<Database>,
+    Query(page): Query<Page>,
+    Query(filter): Query<TransactionFilter>,
+) -> Result<Paged<Transaction>, ApiError> {
+    let mut conditions = Where::default();
+    conditions.push("a.institution = ?", filter.institution);
+    conditions.push("a.student = ?", filter.student);
+    conditions.push("a.course_id = ?", filter.course);
+    conditions.push("a.kind = ?", filter.kind.map(|kind| kind.indexed().to_string()));
+
+    db.read(move |conn| ACTIVITY.fetch(conn, conditions, page, transaction)).await
+}
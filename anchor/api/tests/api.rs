@@ -0,0 +1,438 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use academic_api::models::{
+    Certificate, Course, CourseEnrollment, CreditsInCirculation, DashboardMetrics, EnrollmentStatus, Transaction,
+};
+use academic_api::{router, Database, TOTAL_COUNT};
+use academic_chain::events::*;
+use academic_indexer::{IndexedTransaction, RawEvent, Store};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Event;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use serde::de::DeserializeOwned;
+use tempfile::TempDir;
+use tower::ServiceExt;
+
+struct Fixture {
+    _dir: TempDir,
+    app: Router,
+    institution: Pubkey,
+    credit_mint: Pubkey,
+    other_institution: Pubkey,
+    alice: Pubkey,
+    bob: Pubkey,
+    instructor: Pubkey,
+    treasury: Pubkey,
+    certificate: Pubkey,
+}
+
+fn event<E: Event>(name: &'static str, event: E) -> RawEvent {
+    RawEvent { name, data: event.data() }
+}
+
+fn transaction(signature: &str, signer: Pubkey, events: Vec<RawEvent>, now: i64) -> IndexedTransaction {
+    IndexedTransaction {
+        signature: signature.to_string(),
+        slot: 1,
+        block_time: Some(now),
+        success: true,
+        fee: 5000,
+        signer: signer.to_string(),
+        instructions: vec![],
+        events,
+        finalized: true,
+    }
+}
+
+fn course(institution: Pubkey, course_id: &str, instructor: Pubkey, now: i64) -> RawEvent {
+    event(
+        "CourseCreated",
+        CourseCreated {
+            institution,
+            course: Pubkey::new_unique(),
+            course_id: course_id.to_string(),
+            course_name: format!("{course_id} Course"),
+            instructor,
+            required_credits: 5,
+            credit_hours: 3,
+            max_attempts: 3,
+            timestamp: now,
+        },
+    )
+}
+
+fn offering(institution: Pubkey, offering: Pubkey, course_id: &str, instructor: Pubkey, now: i64) -> RawEvent {
+    event(
+        "OfferingCreated",
+        OfferingCreated {
+            institution,
+            offering,
+            course_id: course_id.to_string(),
+            term_id: "2025FA".to_string(),
+            instructor,
+            capacity: 30,
+            enrollment_opens_at: now,
+            enrollment_closes_at: now + 100,
+            grading_deadline: now + 1000,
+            timestamp: now,
+        },
+    )
+}
+
+fn onboard(institution: Pubkey, student: Pubkey, credits: u64, now: i64) -> Vec<RawEvent> {
+    vec![
+        event(
+            "StudentProfileCreated",
+            StudentProfileCreated {
+                institution,
+                student,
+                student_profile: Pubkey::new_unique(),
+                display_name: None,
+                metadata_uri: None,
+                timestamp: now,
+            },
+        ),
+        event(
+            "CreditsPurchased",
+            CreditsPurchased {
+                institution,
+                student,
+                amount: credits,
+                total_cost: credits * 5_000_000,
+                total_credits_purchased: credits,
+                timestamp: now,
+            },
+        ),
+    ]
+}
+
+fn register(student: Pubkey, enrollment: Pubkey, offering: Pubkey, course_id: &str, now: i64) -> RawEvent {
+    event(
+        "StudentRegistered",
+        StudentRegistered {
+            student,
+            enrollment,
+            offering,
+            course_id: course_id.to_string(),
+            term_id: "2025FA".to_string(),
+            credits_paid: 5,
+            timestamp: now,
+        },
+    )
+}
+
+/// Two courses taught by different instructors. Alice completes SOL101 with
+/// 90 and mints its certificate; Bob is registered for RUST201. A second
+/// institution with its own credit mint has no activity yet.
+fn fixture() -> Fixture {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("academic_chain.db");
+
+    let (institution, other_institution) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let credit_mint = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let instructor = Pubkey::new_unique();
+    let other_instructor = Pubkey::new_unique();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (sol101, rust201) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (alice_enrollment, bob_enrollment) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let certificate = Pubkey::new_unique();
+
+    let mut store = Store::open(&path).unwrap();
+    let setup = vec![
+        event(
+            "InstitutionCreated",
+            InstitutionCreated {
+                institution,
+                institution_id: "EXU".to_string(),
+                name: "Example University".to_string(),
+                authority,
+                treasury,
+                credit_mint,
+                credit_price: 5_000_000,
+                timestamp: now,
+            },
+        ),
+        event(
+            "InstitutionCreated",
+            InstitutionCreated {
+                institution: other_institution,
+                institution_id: "OTU".to_string(),
+                name: "Other University".to_string(),
+                authority,
+                treasury,
+                credit_mint: Pubkey::new_unique(),
+                credit_price: 1_000_000,
+                timestamp: now,
+            },
+        ),
+        course(institution, "SOL101", instructor, now),
+        course(institution, "RUST201", other_instructor, now),
+        offering(institution, sol101, "SOL101", instructor, now),
+        offering(institution, rust201, "RUST201", other_instructor, now),
+    ];
+    store.record(&transaction("setup", authority, setup, now)).unwrap();
+    store.record(&transaction("alice-onboard", alice, onboard(institution, alice, 20, now), now + 1)).unwrap();
+    store.record(&transaction("bob-onboard", bob, onboard(institution, bob, 10, now), now + 2)).unwrap();
+    store
+        .record(&transaction(
+            "alice-register",
+            alice,
+            vec![register(alice, alice_enrollment, sol101, "SOL101", now + 3)],
+            now + 3,
+        ))
+        .unwrap();
+    store
+        .record(&transaction(
+            "bob-register",
+            bob,
+            vec![register(bob, bob_enrollment, rust201, "RUST201", now + 4)],
+            now + 4,
+        ))
+        .unwrap();
+
+    let graded = CourseCompleted {
+        student: alice,
+        enrollment: alice_enrollment,
+        offering: sol101,
+        instructor,
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        grade: 90,
+        letter_grade: "A".to_string(),
+        grade_points: 400,
+        credit_hours: 3,
+        gpa: 400,
+        timestamp: now + 5,
+    };
+    store
+        .record(&transaction("alice-grade", instructor, vec![event("CourseCompleted", graded)], now + 5))
+        .unwrap();
+
+    let minted = CertificateMinted {
+        student: alice,
+        enrollment: alice_enrollment,
+        certificate_mint: certificate,
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        grade: 90,
+        metadata_uri: "https://example.com/sol101.json".to_string(),
+        metadata_hash: [0x11; 32],
+        timestamp: now + 6,
+    };
+    store
+        .record(&transaction("alice-mint", alice, vec![event("CertificateMinted", minted)], now + 6))
+        .unwrap();
+    drop(store);
+
+    Fixture {
+        app: router(Database::open(&path).unwrap()),
+        _dir: dir,
+        institution,
+        credit_mint,
+        other_institution,
+        alice,
+        bob,
+        instructor,
+        treasury,
+        certificate,
+    }
+}
+
+impl Fixture {
+    async fn get(&self, uri: &str) -> (StatusCode, Option<u64>, Vec<u8>) {
+        let response = self
+            .app
+            .clone()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let total = response
+            .headers()
+            .get(TOTAL_COUNT)
+            .map(|value| value.to_str().unwrap().parse().unwrap());
+        let body = response.into_body().collect().await.unwrap().to_bytes().to_vec();
+        (status, total, body)
+    }
+
+    async fn json<T: DeserializeOwned>(&self, uri: &str) -> (T, Option<u64>) {
+        let (status, total, body) = self.get(uri).await;
+        assert_eq!(status, StatusCode::OK, "{uri}: {}", String::from_utf8_lossy(&body));
+        (serde_json::from_slice(&body).unwrap(), total)
+    }
+}
+
+#[tokio::test]
+async fn courses_are_paged_and_filtered() {
+    let fixture = fixture();
+
+    let (courses, total) = fixture.json::<Vec<Course>>("/courses?limit=1").await;
+    assert_eq!(total, Some(2));
+    assert_eq!(courses.len(), 1);
+    assert_eq!(courses[0].id, "RUST201");
+
+    let (courses, _) = fixture.json::<Vec<Course>>("/courses?limit=1&offset=1").await;
+    assert_eq!(courses[0].id, "SOL101");
+    assert_eq!(courses[0].title, "SOL101 Course");
+    assert_eq!(courses[0].enrolled_count, 1);
+    assert_eq!(courses[0].duration, "3 credit hours");
+
+    let uri = format!("/courses?instructor={}", fixture.instructor);
+    let (courses, total) = fixture.json::<Vec<Course>>(&uri).await;
+    assert_eq!(total, Some(1));
+    assert_eq!(courses[0].id, "SOL101");
+
+    let uri = format!("/courses?institution={}", fixture.institution);
+    let (_, total) = fixture.json::<Vec<Course>>(&uri).await;
+    assert_eq!(total, Some(2));
+    let uri = format!("/courses?institution={}", Pubkey::new_unique());
+    let (courses, total) = fixture.json::<Vec<Course>>(&uri).await;
+    assert_eq!((courses.len(), total), (0, Some(0)));
+
+    let uri = format!("/courses?student={}", fixture.bob);
+    let (courses, _) = fixture.json::<Vec<Course>>(&uri).await;
+    assert_eq!(courses.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), ["RUST201"]);
+
+    let (course, _) = fixture.json::<Course>("/courses/SOL101").await;
+    assert!(course.is_active);
+    let (status, _, _) = fixture.get("/courses/NOPE").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn enrollments_filter_by_student_instructor_and_status() {
+    let fixture = fixture();
+
+    let uri = format!("/enrollments?student={}", fixture.alice);
+    let (enrollments, total) = fixture.json::<Vec<CourseEnrollment>>(&uri).await;
+    assert_eq!(total, Some(1));
+    assert_eq!(enrollments[0].status, EnrollmentStatus::Completed);
+    assert_eq!(enrollments[0].grade, Some(90));
+    assert_eq!(enrollments[0].progress, 100);
+    assert_eq!(enrollments[0].certificate_id, Some(fixture.certificate.to_string()));
+
+    let uri = format!("/enrollments?instructor={}", fixture.instructor);
+    let (enrollments, _) = fixture.json::<Vec<CourseEnrollment>>(&uri).await;
+    assert_eq!(enrollments.len(), 1);
+    assert_eq!(enrollments[0].course_id, "SOL101");
+
+    let (enrollments, _) = fixture.json::<Vec<CourseEnrollment>>("/enrollments?status=enrolled").await;
+    assert_eq!(enrollments.len(), 1);
+    assert_eq!(enrollments[0].student_id, fixture.bob.to_string());
+    assert_eq!(enrollments[0].grade, None);
+
+    let (_, total) = fixture.json::<Vec<CourseEnrollment>>("/enrollments?course=RUST201").await;
+    assert_eq!(total, Some(1));
+}
+
+#[tokio::test]
+async fn certificates_use_the_frontend_shape() {
+    let fixture = fixture();
+
+    let uri = format!("/certificates?student={}", fixture.alice);
+    let (_, _, body) = fixture.get(&uri).await;
+    let raw: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(raw[0]["type"], "course");
+    assert_eq!(raw[0]["mintAddress"], fixture.certificate.to_string());
+    assert_eq!(raw[0]["transactionHash"], "alice-mint");
+    assert_eq!(raw[0]["blockchain"], "solana");
+    assert!(raw[0].get("gpa").is_none());
+
+    let (certificate, _) = fixture
+        .json::<Certificate>(&format!("/certificates/{}", fixture.certificate))
+        .await;
+    assert_eq!(certificate.title, "SOL101 Course - Certificate");
+    assert_eq!(certificate.metadata_hash, Some("11".repeat(32)));
+    assert!(certificate.is_verified);
+
+    let (certificates, _) = fixture.json::<Vec<Certificate>>("/certificates?type=graduation").await;
+    assert!(certificates.is_empty());
+}
+
+#[tokio::test]
+async fn transactions_list_student_activity() {
+    let fixture = fixture();
+
+    let uri = format!("/transactions?student={}", fixture.alice);
+    let (transactions, total) = fixture.json::<Vec<Transaction>>(&uri).await;
+    assert_eq!(total, Some(3));
+    // Newest first: mint, registration, purchase
+    assert_eq!(transactions[0].hash, "alice-mint");
+    assert_eq!(transactions[1].details["courseName"], "SOL101 Course");
+    assert_eq!(transactions[1].details["creditsBurned"], 5);
+
+    let purchase = &transactions[2];
+    assert_eq!(purchase.amount, 20);
+    assert_eq!(purchase.to_address, Some(fixture.treasury.to_string()));
+    assert_eq!(purchase.details["solCost"], 0.1);
+    assert_eq!(purchase.details["pricePerCredit"], 0.005);
+    assert_eq!(purchase.gas_fee, 0.000005);
+
+    let (purchases, _) = fixture.json::<Vec<Transaction>>("/transactions?type=credit_purchase").await;
+    assert_eq!(purchases.len(), 2);
+}
+
+#[tokio::test]
+async fn dashboard_metrics() {
+    let fixture = fixture();
+
+    let (metrics, _) = fixture.json::<DashboardMetrics>("/metrics/dashboard").await;
+    assert_eq!(
+        metrics,
+        DashboardMetrics {
+            total_students: 2,
+            total_courses: 2,
+            certificates_issued: 1,
+            total_credits_in_circulation: None,
+            credits_in_circulation: vec![
+                CreditsInCirculation {
+                    institution: fixture.institution.to_string(),
+                    credit_mint: fixture.credit_mint.to_string(),
+                    credits: 20,
+                },
+                CreditsInCirculation {
+                    institution: fixture.other_institution.to_string(),
+                    credit_mint: metrics.credits_in_circulation[1].credit_mint.clone(),
+                    credits: 0,
+                },
+            ],
+            monthly_active_users: 4,
+            average_grade: 90.0,
+            completion_rate: 50.0,
+            revenue_this_month: 0.15,
+        }
+    );
+    assert_ne!(metrics.credits_in_circulation[1].credit_mint, fixture.credit_mint.to_string());
+
+    // Scoped to one institution, the credits share a mint and are summed
+    let uri = format!("/metrics/dashboard?institution={}", fixture.institution);
+    let (metrics, _) = fixture.json::<DashboardMetrics>(&uri).await;
+    assert_eq!(metrics.total_credits_in_circulation, Some(20));
+    assert_eq!(metrics.credits_in_circulation.len(), 1);
+    assert_eq!((metrics.total_students, metrics.total_courses, metrics.certificates_issued), (2, 2, 1));
+    assert_eq!(metrics.monthly_active_users, 2);
+    assert_eq!(metrics.revenue_this_month, 0.15);
+
+    let uri = format!("/metrics/dashboard?institution={}", fixture.other_institution);
+    let (metrics, _) = fixture.json::<DashboardMetrics>(&uri).await;
+    assert_eq!(metrics.total_credits_in_circulation, Some(0));
+    assert_eq!((metrics.total_students, metrics.total_courses, metrics.monthly_active_users), (0, 0, 0));
+    assert_eq!((metrics.average_grade, metrics.completion_rate, metrics.revenue_this_month), (0.0, 0.0, 0.0));
+}
+
+#[tokio::test]
+async fn limit_is_bounded() {
+    let fixture = fixture();
+
+    let (status, _, body) = fixture.get("/courses?limit=0").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(String::from_utf8_lossy(&body).contains("limit must be 1-100"));
+    let (status, _, _) = fixture.get("/enrollments?limit=101").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
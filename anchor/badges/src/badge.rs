@@ -0,0 +1,223 @@
+//! Open Badges 3.0 credentials.
+//!
+//! A `Course` becomes an `Achievement` and a certificate becomes an
+//! `OpenBadgeCredential` awarded to the student, carrying what
+//! `mint_certificate` recorded on the enrollment: grade, completion date,
+//! certificate mint and metadata hash.
+
+use academic_chain::constants::PASSING_GRADE;
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering};
+use academic_chain::ID;
+use academic_chain_client::credential::{DataIntegrityProof, DidMethod, CREDENTIALS_V2_CONTEXT};
+use academic_chain_client::metadata::hash_to_hex;
+use academic_chain_client::pda::course_pda;
+use academic_chain_client::verify::{check_enrollment, VerificationFailure};
+use anchor_lang::prelude::Pubkey;
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const OPEN_BADGES_CONTEXT: &str = "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json";
+pub const CREDENTIAL_TYPE: &str = "OpenBadgeCredential";
+
+/// The issuing institution
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// The issuer's DID
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Profile {
+    pub fn new(id: String, name: &str, url: Option<&str>) -> Self {
+        Self {
+            id,
+            kind: vec!["Profile".to_string()],
+            name: name.to_string(),
+            url: url.map(str::to_string),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Achievement {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub achievement_type: String,
+    pub name: String,
+    pub description: String,
+    pub criteria: Criteria,
+    pub human_code: String,
+    pub credits_available: u8,
+    pub result_descriptions: Vec<ResultDescription>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Criteria {
+    pub narrative: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultDescription {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub name: String,
+    pub result_type: String,
+}
+
+impl Achievement {
+    pub fn from_course(course: &Course) -> Self {
+        let id = format!("urn:academic-chain:course:{}", course_pda(&course.institution, &course.course_id).0);
+        Self {
+            result_descriptions: vec![
+                ResultDescription::new(&id, "grade", "Grade", "Percent"),
+                ResultDescription::new(&id, "letter-grade", "Letter grade", "LetterGrade"),
+            ],
+            id,
+            kind: vec!["Achievement".to_string()],
+            achievement_type: "Course".to_string(),
+            name: course.course_name.clone(),
+            description: format!("{} ({}), {} credit hours.", course.course_name, course.course_id, course.credit_hours),
+            criteria: Criteria {
+                narrative: format!("Complete {} with a grade of {PASSING_GRADE} or higher.", course.course_id),
+            },
+            human_code: course.course_id.clone(),
+            credits_available: course.credit_hours,
+        }
+    }
+}
+
+impl ResultDescription {
+    fn new(achievement: &str, key: &str, name: &str, result_type: &str) -> Self {
+        Self {
+            id: format!("{achievement}:{key}"),
+            kind: vec!["ResultDescription".to_string()],
+            name: name.to_string(),
+            result_type: result_type.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementSubject {
+    /// The student's DID
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub achievement: Achievement,
+    pub credits_earned: u8,
+    pub term: String,
+    pub result: Vec<BadgeResult>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BadgeResult {
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub result_description: String,
+    pub value: String,
+}
+
+/// The on-chain accounts backing the badge
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Evidence {
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub program_id: String,
+    pub enrollment: String,
+    pub certificate_mint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_hash: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenBadgeCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub name: String,
+    pub issuer: Profile,
+    pub valid_from: String,
+    pub credential_subject: AchievementSubject,
+    pub evidence: Vec<Evidence>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<DataIntegrityProof>,
+}
+
+impl OpenBadgeCredential {
+    /// Unsigned badge for a course certificate that currently verifies
+    pub fn course_completion(
+        address: &Pubkey,
+        enrollment: &CourseEnrollment,
+        course: &Course,
+        offering: &CourseOffering,
+        issuer: Profile,
+        subject_method: &DidMethod,
+    ) -> Result<Self, VerificationFailure> {
+        let mint = enrollment.certificate_mint.ok_or(VerificationFailure::NotFound)?;
+        check_enrollment(&mint, address, enrollment)?;
+
+        let achievement = Achievement::from_course(course);
+        let result = vec![
+            BadgeResult::new(&achievement.result_descriptions[0], enrollment.grade.to_string()),
+            BadgeResult::new(&achievement.result_descriptions[1], enrollment.letter_grade.clone()),
+        ];
+        let valid_from = DateTime::from_timestamp(enrollment.completion_date.unwrap_or_default(), 0)
+            .unwrap_or_default()
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+
+        Ok(Self {
+            context: vec![CREDENTIALS_V2_CONTEXT.to_string(), OPEN_BADGES_CONTEXT.to_string()],
+            id: format!("urn:academic-chain:badge:{address}"),
+            kind: vec!["VerifiableCredential".to_string(), CREDENTIAL_TYPE.to_string()],
+            name: format!("{} Certificate", course.course_id),
+            issuer,
+            valid_from,
+            credential_subject: AchievementSubject {
+                id: subject_method.did(&enrollment.student),
+                kind: vec!["AchievementSubject".to_string()],
+                achievement,
+                credits_earned: enrollment.credit_hours,
+                term: offering.term_id.clone(),
+                result,
+            },
+            evidence: vec![Evidence {
+                kind: vec!["Evidence".to_string(), "SolanaAccount".to_string()],
+                program_id: ID.to_string(),
+                enrollment: address.to_string(),
+                certificate_mint: mint.to_string(),
+                metadata_hash: enrollment.metadata_hash.as_ref().map(hash_to_hex),
+            }],
+            proof: None,
+        })
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).expect("badge serializes")
+    }
+}
+
+impl BadgeResult {
+    fn new(description: &ResultDescription, value: String) -> Self {
+        Self {
+            kind: vec!["Result".to_string()],
+            result_description: description.id.clone(),
+            value,
+        }
+    }
+}
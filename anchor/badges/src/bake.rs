@@ -0,0 +1,149 @@
+//! Baking: embedding a credential in a badge image so the image alone can
+//! be imported.
+//!
+//! Open Badges 3.0 bakes PNGs with an `iTXt` chunk keyed
+//! `openbadgecredential` and SVGs with an `<openbadges:credential>` element
+//! directly inside the root `<svg>`. Baking replaces any credential already
+//! in the image.
+
+pub const PNG_KEYWORD: &str = "openbadgecredential";
+pub const SVG_NAMESPACE: &str = "https://purl.imsglobal.org/ob/v3p0";
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const SVG_ELEMENT: &str = "openbadges:credential";
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BakeError {
+    #[error("not a PNG image")]
+    NotPng,
+
+    #[error("truncated PNG chunk at byte {0}")]
+    TruncatedPng(usize),
+
+    #[error("no <svg> root element")]
+    NotSvg,
+}
+
+struct Chunk<'a> {
+    kind: &'a [u8],
+    data: &'a [u8],
+    // The whole chunk: length, type, data and CRC
+    raw: &'a [u8],
+}
+
+fn chunks(png: &[u8]) -> Result<Vec<Chunk<'_>>, BakeError> {
+    let mut rest = png.strip_prefix(&PNG_SIGNATURE[..]).ok_or(BakeError::NotPng)?;
+    let mut offset = PNG_SIGNATURE.len();
+    let mut chunks = Vec::new();
+    while !rest.is_empty() {
+        let length = rest
+            .get(..4)
+            .map(|bytes| u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or(BakeError::TruncatedPng(offset))?;
+        let raw = rest.get(..12 + length).ok_or(BakeError::TruncatedPng(offset))?;
+        chunks.push(Chunk {
+            kind: &raw[4..8],
+            data: &raw[8..8 + length],
+            raw,
+        });
+        rest = &rest[raw.len()..];
+        offset += raw.len();
+    }
+    Ok(chunks)
+}
+
+// keyword NUL, uncompressed, no language tag or translated keyword, text
+fn itxt_text(data: &[u8]) -> Option<&[u8]> {
+    let rest = data.strip_prefix(PNG_KEYWORD.as_bytes())?.strip_prefix(&[0, 0, 0][..])?;
+    let language_end = rest.iter().position(|&byte| byte == 0)?;
+    let rest = &rest[language_end + 1..];
+    let keyword_end = rest.iter().position(|&byte| byte == 0)?;
+    Some(&rest[keyword_end + 1..])
+}
+
+fn is_credential(chunk: &Chunk) -> bool {
+    chunk.kind == b"iTXt" && chunk.data.starts_with(format!("{PNG_KEYWORD}\0").as_bytes())
+}
+
+/// Bake `credential` (JSON) into a PNG, just before `IEND`
+pub fn bake_png(png: &[u8], credential: &str) -> Result<Vec<u8>, BakeError> {
+    let mut data = format!("{PNG_KEYWORD}\0").into_bytes();
+    data.extend_from_slice(&[0, 0, 0, 0]);
+    data.extend_from_slice(credential.as_bytes());
+
+    let mut baked = PNG_SIGNATURE.to_vec();
+    for chunk in chunks(png)? {
+        if is_credential(&chunk) {
+            continue;
+        }
+        if chunk.kind == b"IEND" {
+            baked.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            let crc_start = baked.len();
+            baked.extend_from_slice(b"iTXt");
+            baked.extend_from_slice(&data);
+            let crc = crc32fast::hash(&baked[crc_start..]);
+            baked.extend_from_slice(&crc.to_be_bytes());
+        }
+        baked.extend_from_slice(chunk.raw);
+    }
+    Ok(baked)
+}
+
+/// The credential baked into a PNG, if any
+pub fn extract_png(png: &[u8]) -> Result<Option<String>, BakeError> {
+    Ok(chunks(png)?
+        .iter()
+        .filter(|chunk| is_credential(chunk))
+        .find_map(|chunk| itxt_text(chunk.data))
+        .map(|text| String::from_utf8_lossy(text).into_owned()))
+}
+
+/// Bake `credential` (JSON) into an SVG as CDATA
+pub fn bake_svg(svg: &str, credential: &str) -> Result<String, BakeError> {
+    let svg = strip_svg_credential(svg);
+    let start = svg.find("<svg").ok_or(BakeError::NotSvg)?;
+    let end = start + svg[start..].find('>').ok_or(BakeError::NotSvg)?;
+
+    let mut open_tag = svg[start..end].trim_end_matches('/').to_string();
+    let self_closing = svg[start..end].ends_with('/');
+    if !open_tag.contains("xmlns:openbadges=") {
+        open_tag.push_str(&format!(r#" xmlns:openbadges="{SVG_NAMESPACE}""#));
+    }
+    // "]]>" cannot appear inside CDATA, so split the section around it
+    let element = format!(
+        "<{SVG_ELEMENT}><![CDATA[{}]]></{SVG_ELEMENT}>",
+        credential.replace("]]>", "]]]]><![CDATA[>")
+    );
+
+    let rest = if self_closing { "</svg>" } else { "" };
+    Ok(format!("{}{open_tag}>\n{element}{rest}{}", &svg[..start], &svg[end + 1..]))
+}
+
+/// The credential baked into an SVG, if any
+pub fn extract_svg(svg: &str) -> Option<String> {
+    let open = format!("<{SVG_ELEMENT}>");
+    let close = format!("</{SVG_ELEMENT}>");
+    let start = svg.find(&open)? + open.len();
+    let end = start + svg[start..].find(&close)?;
+    let content = svg[start..end].trim();
+    Some(match content.strip_prefix("<![CDATA[") {
+        Some(cdata) => cdata
+            .strip_suffix("]]>")
+            .unwrap_or(cdata)
+            .replace("]]]]><![CDATA[>", "]]>"),
+        None => content.to_string(),
+    })
+}
+
+fn strip_svg_credential(svg: &str) -> String {
+    let close = format!("</{SVG_ELEMENT}>");
+    let (Some(start), Some(end)) = (svg.find(&format!("<{SVG_ELEMENT}>")), svg.find(&close)) else {
+        return svg.to_string();
+    };
+    if end < start {
+        return svg.to_string();
+    }
+    // Also drop the newline `bake_svg` put before the element
+    let start = if svg[..start].ends_with('\n') { start - 1 } else { start };
+    format!("{}{}", &svg[..start], &svg[end + close.len()..])
+}
@@ -0,0 +1,51 @@
+//! The default badge artwork, used when no template image is given.
+
+use crate::badge::OpenBadgeCredential;
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+// Long course names are cut to fit the badge face
+fn fit(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max_chars - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// A round badge showing the course code, course name, term and issuer
+pub fn badge_svg(badge: &OpenBadgeCredential) -> String {
+    let subject = &badge.credential_subject;
+    let achievement = &subject.achievement;
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="400" height="400" viewBox="0 0 400 400">
+<title>{title}</title>
+<circle cx="200" cy="200" r="190" fill="#1e1b4b"/>
+<circle cx="200" cy="200" r="172" fill="none" stroke="#a78bfa" stroke-width="6"/>
+<text x="200" y="150" text-anchor="middle" font-family="sans-serif" font-size="20" fill="#c4b5fd">CERTIFICATE</text>
+<text x="200" y="212" text-anchor="middle" font-family="sans-serif" font-size="52" font-weight="bold" fill="#ffffff">{code}</text>
+<text x="200" y="252" text-anchor="middle" font-family="sans-serif" font-size="18" fill="#e0e7ff">{name}</text>
+<text x="200" y="282" text-anchor="middle" font-family="sans-serif" font-size="16" fill="#c4b5fd">{term}</text>
+<text x="200" y="322" text-anchor="middle" font-family="sans-serif" font-size="14" fill="#a5b4fc">{issuer}</text>
+</svg>
+"##,
+        title = escape(&badge.name),
+        code = escape(&fit(&achievement.human_code, 10)),
+        name = escape(&fit(&achievement.name, 30)),
+        term = escape(&subject.term),
+        issuer = escape(&fit(&badge.issuer.name, 36)),
+    )
+}
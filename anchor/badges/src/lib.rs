@@ -0,0 +1,86 @@
+//! Open Badges 3.0 export for academic_chain certificates.
+//!
+//! `issue_badge` turns a verified course certificate into a signed
+//! `OpenBadgeCredential`, and `bake` embeds it in a PNG or SVG badge that
+//! LinkedIn and LMSes can import. The proof uses the same `eddsa-jcs-2022`
+//! Data Integrity suite as `academic_chain_client::credential`.
+
+pub mod badge;
+pub mod bake;
+pub mod image;
+
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering};
+use academic_chain_client::credential::{sign, DidMethod};
+use academic_chain_client::verify::VerificationFailure;
+use anchor_lang::prelude::Pubkey;
+use solana_signer::Signer;
+
+pub use badge::{Achievement, OpenBadgeCredential, Profile};
+pub use bake::BakeError;
+
+/// The institution signing badges
+pub struct Issuer<'a> {
+    pub signer: &'a dyn Signer,
+    pub method: DidMethod,
+    pub name: String,
+    pub url: Option<String>,
+}
+
+impl Issuer<'_> {
+    pub fn profile(&self) -> Profile {
+        Profile::new(self.method.did(&self.signer.pubkey()), &self.name, self.url.as_deref())
+    }
+}
+
+/// Issue and sign a badge for a course certificate that currently verifies
+pub fn issue_badge(
+    address: &Pubkey,
+    enrollment: &CourseEnrollment,
+    course: &Course,
+    offering: &CourseOffering,
+    issuer: &Issuer,
+    issued_at: i64,
+) -> Result<OpenBadgeCredential, VerificationFailure> {
+    let mut badge =
+        OpenBadgeCredential::course_completion(address, enrollment, course, offering, issuer.profile(), &issuer.method)?;
+    badge.proof = Some(sign(
+        &badge.to_value(),
+        issuer.signer,
+        &issuer.method.verification_method(&issuer.signer.pubkey()),
+        issued_at,
+    ));
+    Ok(badge)
+}
+
+/// Badge artwork, baked or not
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BadgeImage {
+    Png(Vec<u8>),
+    Svg(String),
+}
+
+impl BadgeImage {
+    /// Embed `badge`, replacing any credential already baked in
+    pub fn bake(&self, badge: &OpenBadgeCredential) -> Result<BadgeImage, BakeError> {
+        let json = serde_json::to_string(badge).expect("badge serializes");
+        Ok(match self {
+            BadgeImage::Png(png) => BadgeImage::Png(bake::bake_png(png, &json)?),
+            BadgeImage::Svg(svg) => BadgeImage::Svg(bake::bake_svg(svg, &json)?),
+        })
+    }
+
+    /// The baked credential JSON, if any
+    pub fn extract(&self) -> Result<Option<String>, BakeError> {
+        match self {
+            BadgeImage::Png(png) => bake::extract_png(png),
+            BadgeImage::Svg(svg) => Ok(bake::extract_svg(svg)),
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            BadgeImage::Png(png) => png,
+            BadgeImage::Svg(svg) => svg.into_bytes(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use academic_badges::{image, issue_badge, BadgeImage, Issuer};
+use academic_chain::state::{CourseEnrollment, CourseOffering};
+use academic_chain_client::accounts::{fetch_account, fetch_course, fetch_enrollment_or_record};
+use academic_chain_client::credential::DidMethod;
+use academic_chain_client::pda::{enrollment_pda, institution_pda, offering_pda};
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context as _, Result};
+use clap::{Args, Parser, Subcommand};
+use solana_commitment_config::CommitmentConfig;
+use solana_keypair::read_keypair_file;
+use solana_rpc_client::rpc_client::RpcClient;
+
+#[derive(Parser)]
+#[command(name = "academic-badge", version, about = "Export academic_chain certificates as Open Badges 3.0")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Issue a signed OpenBadgeCredential for a course certificate and bake it into a badge
+    Issue(Box<IssueArgs>),
+
+    /// Print the credential baked into a PNG or SVG badge
+    Extract { file: PathBuf },
+}
+
+#[derive(Args)]
+struct IssueArgs {
+    /// Student wallet
+    student: Pubkey,
+    course_id: String,
+    term_id: String,
+
+    /// Output file; `.png`, `.svg` or `.json` for the bare credential
+    #[arg(short, long)]
+    output: PathBuf,
+
+    /// PNG or SVG artwork to bake into; SVG output defaults to a generated badge
+    #[arg(long)]
+    template: Option<PathBuf>,
+
+    /// Institution ID the course belongs to
+    #[arg(short, long, env = "ACADEMIC_INSTITUTION")]
+    institution: String,
+
+    /// Institution name shown as the issuer
+    #[arg(long, env = "ACADEMIC_ISSUER_NAME")]
+    issuer_name: String,
+
+    /// Institution website
+    #[arg(long, env = "ACADEMIC_ISSUER_URL")]
+    issuer_url: Option<String>,
+
+    /// DID method for the issuer and student: key, sol or sol:<cluster>
+    #[arg(long, default_value = "key")]
+    did: DidMethod,
+
+    /// RPC endpoint the certificate is read from
+    #[arg(short = 'u', long, default_value = "http://127.0.0.1:8899", env = "ACADEMIC_RPC_URL")]
+    rpc_url: String,
+
+    /// Issuer keypair that signs the badge
+    #[arg(short, long, default_value = "~/.config/solana/id.json", env = "ACADEMIC_KEYPAIR")]
+    keypair: String,
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => Path::new(&home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+fn read_image(path: &Path) -> Result<BadgeImage> {
+    let bytes = std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(match extension(path).as_str() {
+        "png" => BadgeImage::Png(bytes),
+        "svg" => BadgeImage::Svg(String::from_utf8(bytes).context("SVG is not UTF-8")?),
+        other => bail!("unsupported image type `.{other}`; use .png or .svg"),
+    })
+}
+
+fn issue(args: IssueArgs) -> Result<()> {
+    let IssueArgs {
+        student,
+        course_id,
+        term_id,
+        output,
+        template,
+        institution,
+        issuer_name,
+        issuer_url,
+        did,
+        rpc_url,
+        keypair,
+    } = args;
+    let format = extension(&output);
+    let artwork = match (format.as_str(), &template) {
+        ("json", _) => None,
+        ("png", None) => bail!("PNG badges need a --template PNG to bake into"),
+        ("png" | "svg", Some(template)) => {
+            let artwork = read_image(template)?;
+            if extension(template) != format {
+                bail!("--template must be a .{format} image");
+            }
+            Some(artwork)
+        }
+        ("svg", None) => None,
+        (other, _) => bail!("unsupported output type `.{other}`; use .png, .svg or .json"),
+    };
+
+    let keypair_path = expand_home(&keypair);
+    let signer = read_keypair_file(&keypair_path)
+        .map_err(|err| anyhow!("failed to read keypair {}: {err}", keypair_path.display()))?;
+    let rpc = RpcClient::new_with_commitment(rpc_url, CommitmentConfig::finalized());
+
+    let institution = institution_pda(&institution).0;
+    let offering_address = offering_pda(&institution, &course_id, &term_id).0;
+    let address = enrollment_pda(&student, &offering_address).0;
+    let enrollment: CourseEnrollment = fetch_enrollment_or_record(&rpc, &address)?
+        .with_context(|| format!("no enrollment for {student} in {course_id} {term_id}"))?;
+    let offering: CourseOffering = fetch_account(&rpc, &offering_address)?;
+    let course = fetch_course(&rpc, &institution, &course_id)?;
+
+    let issuer = Issuer {
+        signer: &signer,
+        method: did,
+        name: issuer_name,
+        url: issuer_url,
+    };
+    let issued_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let badge = issue_badge(&address, &enrollment, &course, &offering, &issuer, issued_at)
+        .map_err(|failure| anyhow!("cannot issue a badge: {failure}"))?;
+
+    let bytes = if format == "json" {
+        serde_json::to_vec_pretty(&badge)?
+    } else {
+        let artwork = artwork.unwrap_or_else(|| BadgeImage::Svg(image::badge_svg(&badge)));
+        artwork.bake(&badge)?.into_bytes()
+    };
+    std::fs::write(&output, bytes).with_context(|| format!("failed to write {}", output.display()))?;
+    println!("✅ Issued {} to {} as {}", badge.name, badge.credential_subject.id, badge.issuer.id);
+    println!("Badge: {}", output.display());
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Issue(args) => issue(*args)?,
+        Command::Extract { file } => match read_image(&file)?.extract()? {
+            Some(credential) => println!("{credential}"),
+            None => bail!("no credential baked into {}", file.display()),
+        },
+    }
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use academic_badges::bake::{bake_png, bake_svg, extract_png, extract_svg, BakeError};
+use academic_badges::{image, issue_badge, BadgeImage, Issuer};
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering, EnrollmentStatus};
+use academic_chain_client::credential::{verify_proof, DidMethod};
+use academic_chain_client::pda::certificate_mint_pda;
+use academic_chain_client::verify::VerificationFailure;
+use anchor_lang::prelude::Pubkey;
+use serde_json::Value;
+use solana_keypair::Keypair;
+
+// 1x1 transparent PNG: IHDR, IDAT, IEND
+const PIXEL_PNG: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4, 0x89, 0x00, 0x00, 0x00, 0x0b, 0x49,
+    0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x60, 0x00, 0x02, 0x00, 0x00, 0x05, 0x00, 0x01, 0x7a, 0x5e, 0xab, 0x3f, 0x00,
+    0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+fn certified() -> (Pubkey, CourseEnrollment, Course, CourseOffering) {
+    let address = Pubkey::new_unique();
+    let instructor = Pubkey::new_unique();
+    let enrollment = CourseEnrollment {
+        student: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        offering: Pubkey::new_unique(),
+        credits_paid: 5,
+        enrollment_date: 1_760_000_000,
+        completion_date: Some(1_760_100_000),
+        status: EnrollmentStatus::Completed,
+        grade: 92,
+        letter_grade: "A".to_string(),
+        grade_points: 400,
+        credit_hours: 3,
+        certificate_mint: Some(certificate_mint_pda(&address).0),
+        metadata_hash: Some([0x42; 32]),
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    let course = Course {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        course_name: "Intro to <Solana>".to_string(),
+        instructor,
+        required_credits: 5,
+        credit_hours: 3,
+        max_attempts: 3,
+        is_active: true,
+        created_at: 1_700_000_000,
+        bump: 255,
+    };
+    let offering = CourseOffering {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        instructor,
+        capacity: 30,
+        enrollment_count: 1,
+        enrollment_opens_at: 1_750_000_000,
+        enrollment_closes_at: 1_755_000_000,
+        grading_deadline: 1_765_000_000,
+        created_at: 1_750_000_000,
+        bump: 255,
+    };
+    (address, enrollment, course, offering)
+}
+
+fn issuer(signer: &Keypair) -> Issuer<'_> {
+    Issuer {
+        signer,
+        method: DidMethod::Key,
+        name: "Example University".to_string(),
+        url: Some("https://example.edu".to_string()),
+    }
+}
+
+#[test]
+fn badges_map_courses_to_achievements() {
+    let (address, enrollment, course, offering) = certified();
+    let signer = Keypair::new();
+    let badge = issue_badge(&address, &enrollment, &course, &offering, &issuer(&signer), 1_760_200_000).unwrap();
+    let json = badge.to_value();
+
+    assert_eq!(json["type"][1], "OpenBadgeCredential");
+    assert_eq!(json["issuer"]["type"][0], "Profile");
+    assert_eq!(json["issuer"]["name"], "Example University");
+    let subject = &json["credentialSubject"];
+    assert_eq!(subject["type"][0], "AchievementSubject");
+    assert_eq!(subject["term"], "2025FA");
+    assert_eq!(subject["achievement"]["type"][0], "Achievement");
+    assert_eq!(subject["achievement"]["humanCode"], "SOL101");
+    assert_eq!(subject["achievement"]["creditsAvailable"], 3);
+    assert_eq!(subject["result"][1]["value"], "A");
+    assert_eq!(json["evidence"][0]["metadataHash"], "42".repeat(32));
+
+    // The issuer profile's id is what the proof is checked against
+    assert_eq!(verify_proof(&json), Ok(badge.issuer.id.clone()));
+
+    let revoked = CourseEnrollment { grade: 30, ..enrollment };
+    assert_eq!(
+        issue_badge(&address, &revoked, &course, &offering, &issuer(&signer), 1_760_200_000),
+        Err(VerificationFailure::Revoked)
+    );
+}
+
+#[test]
+fn png_baking_round_trips_and_replaces_earlier_credentials() {
+    let first = bake_png(PIXEL_PNG, r#"{"id":"first"}"#).unwrap();
+    let second = bake_png(&first, r#"{"id":"second"}"#).unwrap();
+
+    assert_eq!(extract_png(PIXEL_PNG), Ok(None));
+    assert_eq!(extract_png(&first).unwrap().as_deref(), Some(r#"{"id":"first"}"#));
+    assert_eq!(extract_png(&second).unwrap().as_deref(), Some(r#"{"id":"second"}"#));
+    assert_eq!(second.len(), first.len() + 1);
+    assert!(second.ends_with(&PIXEL_PNG[PIXEL_PNG.len() - 12..]), "IEND stays last");
+
+    assert_eq!(bake_png(b"GIF89a", "{}"), Err(BakeError::NotPng));
+    assert_eq!(bake_png(&PIXEL_PNG[..40], "{}"), Err(BakeError::TruncatedPng(33)));
+}
+
+#[test]
+fn svg_baking_round_trips() {
+    let (address, enrollment, course, offering) = certified();
+    let signer = Keypair::new();
+    let badge = issue_badge(&address, &enrollment, &course, &offering, &issuer(&signer), 1_760_200_000).unwrap();
+
+    let artwork = image::badge_svg(&badge);
+    assert!(artwork.contains("Intro to &lt;Solana&gt;"));
+    let baked = BadgeImage::Svg(artwork).bake(&badge).unwrap();
+    let BadgeImage::Svg(svg) = &baked else { unreachable!() };
+    assert!(svg.contains(r#"xmlns:openbadges="https://purl.imsglobal.org/ob/v3p0""#));
+
+    let extracted: Value = serde_json::from_str(&baked.extract().unwrap().unwrap()).unwrap();
+    assert_eq!(extracted, badge.to_value());
+    assert_eq!(verify_proof(&extracted), Ok(badge.issuer.id.clone()));
+
+    // CDATA terminators in the payload survive, and re-baking keeps one credential
+    let tricky = bake_svg(svg, r#"{"note":"a]]>b"}"#).unwrap();
+    assert_eq!(tricky.matches("<openbadges:credential>").count(), 1);
+    assert_eq!(extract_svg(&tricky).as_deref(), Some(r#"{"note":"a]]>b"}"#));
+    assert_eq!(bake_svg("<html/>", "{}"), Err(BakeError::NotSvg));
+}
@@ -0,0 +1,201 @@
+use std::path::Path;
+
+use academic_chain::constants::MAX_MERKLE_PROOF_LEN;
+use academic_chain::state::{CertificateBatch, Role};
+use academic_chain_client::accounts::{fetch_account_optional, fetch_offering_enrollments};
+use academic_chain_client::batch::{self, check_certificate, check_manifest, BatchCertificate, BatchManifest};
+use academic_chain_client::instructions;
+use academic_chain_client::pda::offering_pda;
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, ensure, Context as _, Result};
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::output::TxOutcome;
+
+#[derive(Serialize)]
+struct CreatedBatch {
+    course_id: String,
+    term_id: String,
+    certificates: usize,
+    merkle_root: String,
+    certificate_batch: String,
+    /// Written unless `--dry-run`
+    path: Option<String>,
+    /// `None` when the batch already exists
+    outcome: Option<TxOutcome>,
+}
+
+/// Build a batch over every completed enrollment in the offering that has
+/// no certificate yet and post its root. The keypair must be the offering's
+/// instructor or the institution authority.
+pub fn create(ctx: &Context, course_id: &str, term_id: &str, output: &Path) -> Result<()> {
+    let institution = ctx.institution()?;
+    let enrollments = fetch_offering_enrollments(&ctx.rpc, &offering_pda(&institution, course_id, term_id).0)?;
+    let manifest = BatchManifest::new(&institution, course_id, term_id, &enrollments);
+    ensure!(
+        !manifest.certificates.is_empty(),
+        "{course_id} {term_id} has no completed enrollments without a certificate"
+    );
+    // Larger batches would need proofs the program does not accept
+    ensure!(
+        manifest.certificates.len() <= 1 << MAX_MERKLE_PROOF_LEN,
+        "{} certificates do not fit in one batch",
+        manifest.certificates.len()
+    );
+    let root = manifest.root()?;
+    let address: Pubkey = manifest.certificate_batch.parse()?;
+
+    let existing: Option<CertificateBatch> = fetch_account_optional(&ctx.rpc, &address)?;
+    let outcome = match existing {
+        Some(_) => None,
+        None => {
+            let count = u16::try_from(manifest.certificates.len())?;
+            let issuer_role = ctx.signer_role(Role::Reviewer)?;
+            let ix = instructions::create_certificate_batch(
+                &institution,
+                &ctx.signer()?,
+                issuer_role,
+                course_id,
+                term_id,
+                root,
+                count,
+            );
+            Some(ctx.send(&[ix], &[])?)
+        }
+    };
+
+    let path = if ctx.dry_run {
+        None
+    } else {
+        std::fs::write(output, serde_json::to_vec_pretty(&manifest)?)
+            .with_context(|| format!("failed to write {}", output.display()))?;
+        Some(output.display().to_string())
+    };
+
+    let created = CreatedBatch {
+        course_id: course_id.to_string(),
+        term_id: term_id.to_string(),
+        certificates: manifest.certificates.len(),
+        merkle_root: manifest.merkle_root.clone(),
+        certificate_batch: manifest.certificate_batch.clone(),
+        path,
+        outcome,
+    };
+    ctx.emit(&created, |created| {
+        match &created.outcome {
+            Some(TxOutcome::Confirmed { signature }) => {
+                println!("✅ Created batch of {} certificates for {course_id} {term_id}", created.certificates);
+                println!("Signature: {signature}");
+            }
+            Some(TxOutcome::Simulated { units_consumed, .. }) => {
+                println!("🧪 Simulated: create certificate batch for {course_id} {term_id}");
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+            }
+            None => println!("✅ Batch for {course_id} {term_id} already exists"),
+        }
+        println!("Merkle root: {}", created.merkle_root);
+        println!("Batch: {}", created.certificate_batch);
+        if let Some(path) = &created.path {
+            println!("Manifest: {path}");
+        }
+    })
+}
+
+/// Take one student's certificate and proof out of a batch manifest
+pub fn extract(file: &Path, student: &Pubkey, output: Option<&Path>) -> Result<()> {
+    let bytes = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let manifest: BatchManifest =
+        serde_json::from_slice(&bytes).with_context(|| format!("{} is not a batch manifest", file.display()))?;
+    check_manifest(&manifest).map_err(|failure| anyhow!("invalid manifest: {failure}"))?;
+    let certificate = manifest
+        .certificate(student)
+        .with_context(|| format!("{student} is not in the batch"))?;
+
+    let json = serde_json::to_string_pretty(&certificate)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!("✅ Extracted the certificate of {student} to {}", path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+fn read_certificate(file: &Path) -> Result<BatchCertificate> {
+    let bytes = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    serde_json::from_slice(&bytes).with_context(|| format!("{} is not a batch certificate", file.display()))
+}
+
+/// Link the student's enrollment to the batch; the keypair must be the student
+pub fn claim(ctx: &Context, file: &Path) -> Result<()> {
+    let certificate = read_certificate(file)?;
+    check_certificate(&certificate).map_err(|failure| anyhow!("invalid certificate: {failure}"))?;
+    let student = ctx.signer()?;
+    ensure!(
+        certificate.certificate.student == student.to_string(),
+        "the certificate belongs to {}, not the keypair",
+        certificate.certificate.student
+    );
+
+    let (_, proof) = certificate.instruction_args()?;
+    let ix = instructions::claim_batch_certificate(
+        &certificate.institution()?,
+        &student,
+        &certificate.course_id,
+        &certificate.term_id,
+        &certificate.root()?,
+        proof,
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+
+    ctx.emit_tx(
+        &format!("Claimed batch certificate for {} {}", certificate.course_id, certificate.term_id),
+        &outcome,
+    )
+}
+
+#[derive(Serialize)]
+struct BatchCheck {
+    valid: bool,
+    claimed: bool,
+    certificate_batch: Option<String>,
+    issuer: Option<String>,
+    reason: Option<String>,
+}
+
+/// Check a batch certificate's proof against the on-chain batch root
+pub fn verify(ctx: &Context, file: &Path) -> Result<()> {
+    let certificate = read_certificate(file)?;
+    let result = batch::verify_batch_certificate(&ctx.rpc, &certificate)?;
+
+    let check = BatchCheck {
+        valid: result.is_valid(),
+        claimed: result.claimed,
+        certificate_batch: result.certificate_batch.map(|batch| batch.to_string()),
+        issuer: result.issuer.map(|issuer| issuer.to_string()),
+        reason: result.failure.map(|failure| failure.to_string()),
+    };
+    let entry = &certificate.certificate;
+    ctx.emit(&check, |check| {
+        match &check.reason {
+            None => println!("✅ Valid batch certificate"),
+            Some(reason) => println!("❌ Invalid batch certificate: {reason}"),
+        }
+        println!("Student: {}", entry.student);
+        println!("Course: {} {}", certificate.course_id, certificate.term_id);
+        println!("Grade: {}", entry.grade);
+        println!("Claimed: {}", if check.claimed { "yes" } else { "no" });
+        if let Some(issuer) = &check.issuer {
+            println!("Issuer: {issuer}");
+        }
+    })?;
+
+    if !check.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
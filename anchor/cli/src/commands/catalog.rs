@@ -0,0 +1,191 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use academic_chain::state::Role;
+use academic_chain_client::accounts::fetch_all_courses;
+use academic_chain_client::catalog::{self, CatalogChange, CatalogEntry};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::{bail, Context as _, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::output::TxOutcome;
+
+/// Instructions per transaction. Four maximum-length `create_course` or
+/// `update_course` instructions still fit in a legacy transaction.
+const CATALOG_TX_INSTRUCTIONS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CatalogFormat {
+    Csv,
+    Json,
+}
+
+impl CatalogFormat {
+    /// `--format` if given, otherwise the file extension, otherwise CSV
+    fn resolve(format: Option<Self>, path: Option<&Path>) -> Self {
+        format.unwrap_or_else(|| {
+            match path.and_then(|path| path.extension()).and_then(|ext| ext.to_str()) {
+                Some(ext) if ext.eq_ignore_ascii_case("json") => CatalogFormat::Json,
+                _ => CatalogFormat::Csv,
+            }
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ImportReport {
+    created: Vec<String>,
+    updated: Vec<UpdatedCourse>,
+    unchanged: Vec<String>,
+    unlisted: Vec<String>,
+    transactions: Vec<TxOutcome>,
+}
+
+#[derive(Serialize)]
+struct UpdatedCourse {
+    course_id: String,
+    fields: Vec<&'static str>,
+}
+
+pub fn import(ctx: &Context, file: &Path, format: Option<CatalogFormat>) -> Result<()> {
+    let entries = read_catalog(file, CatalogFormat::resolve(format, Some(file)))?;
+    if let Err(errors) = catalog::validate(&entries) {
+        let details: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        bail!("{} invalid catalog rows:\n{}", errors.len(), details.join("\n"));
+    }
+
+    let institution = ctx.institution()?;
+    let on_chain: Vec<_> = fetch_all_courses(&ctx.rpc, &institution)?
+        .into_iter()
+        .map(|(_, course)| course)
+        .collect();
+    let plan = catalog::plan(&entries, &on_chain);
+
+    let authority = ctx.signer()?;
+    let authority_role = ctx.signer_role(Role::CourseManager)?;
+    let mut transactions = Vec::new();
+    for (index, batch) in batch_changes(&plan.changes, &institution, &authority, authority_role).iter().enumerate() {
+        let outcome = ctx
+            .send(batch, &[])
+            .with_context(|| format!("transaction {} failed; earlier transactions were already applied", index + 1))?;
+        transactions.push(outcome);
+    }
+
+    let mut report = ImportReport {
+        created: Vec::new(),
+        updated: Vec::new(),
+        unchanged: plan.unchanged,
+        unlisted: plan.unlisted,
+        transactions,
+    };
+    for change in &plan.changes {
+        match change {
+            CatalogChange::Create(entry) => report.created.push(entry.course_id.clone()),
+            CatalogChange::Update { course_id, update } => report.updated.push(UpdatedCourse {
+                course_id: course_id.clone(),
+                fields: catalog::changed_fields(update),
+            }),
+        }
+    }
+
+    ctx.emit(&report, |report| {
+        for course_id in &report.created {
+            println!("+ {course_id}");
+        }
+        for course in &report.updated {
+            println!("~ {} ({})", course.course_id, course.fields.join(", "));
+        }
+        for course_id in &report.unlisted {
+            println!("? {course_id} is on-chain but not in the catalog; left untouched");
+        }
+        let verb = if ctx.dry_run { "Simulated" } else { "Sent" };
+        println!(
+            "✅ {} created, {} updated, {} unchanged. {verb} {} transactions",
+            report.created.len(),
+            report.updated.len(),
+            report.unchanged.len(),
+            report.transactions.len(),
+        );
+        for outcome in &report.transactions {
+            if let TxOutcome::Confirmed { signature } = outcome {
+                println!("Signature: {signature}");
+            }
+        }
+    })
+}
+
+pub fn export(ctx: &Context, output: Option<&PathBuf>, format: Option<CatalogFormat>) -> Result<()> {
+    let mut entries: Vec<CatalogEntry> = fetch_all_courses(&ctx.rpc, &ctx.institution()?)?
+        .iter()
+        .map(|(_, course)| CatalogEntry::from_course(course))
+        .collect();
+    entries.sort_by(|a, b| a.course_id.cmp(&b.course_id));
+
+    let format = CatalogFormat::resolve(format, output.map(PathBuf::as_path));
+    match output {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("failed to create {}", path.display()))?;
+            write_catalog(file, &entries, format)?;
+            eprintln!("✅ Exported {} courses to {}", entries.len(), path.display());
+        }
+        None => write_catalog(std::io::stdout().lock(), &entries, format)?,
+    }
+    Ok(())
+}
+
+/// Pack changes into transactions without splitting a change across two
+fn batch_changes(
+    changes: &[CatalogChange],
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+) -> Vec<Vec<Instruction>> {
+    let mut batches: Vec<Vec<Instruction>> = Vec::new();
+    for change in changes {
+        let ixs = change.instructions(institution, authority, authority_role);
+        match batches.last_mut() {
+            Some(batch) if batch.len() + ixs.len() <= CATALOG_TX_INSTRUCTIONS => batch.extend(ixs),
+            _ => batches.push(ixs),
+        }
+    }
+    batches
+}
+
+fn read_catalog(file: &Path, format: CatalogFormat) -> Result<Vec<CatalogEntry>> {
+    let contents = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+
+    match format {
+        CatalogFormat::Json => serde_json::from_slice(&contents)
+            .with_context(|| format!("{} is not a valid JSON catalog", file.display())),
+        CatalogFormat::Csv => csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(contents.as_slice())
+            .deserialize()
+            .enumerate()
+            // Line 1 is the header
+            .map(|(index, row)| row.with_context(|| format!("line {}: invalid catalog row", index + 2)))
+            .collect(),
+    }
+}
+
+fn write_catalog(writer: impl Write, entries: &[CatalogEntry], format: CatalogFormat) -> Result<()> {
+    match format {
+        CatalogFormat::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, entries)?;
+            writeln!(writer)?;
+        }
+        CatalogFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for entry in entries {
+                writer.serialize(entry)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
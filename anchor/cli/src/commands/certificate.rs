@@ -0,0 +1,438 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use academic_chain::state::{CourseEnrollment, CourseOffering, EnrollmentStatus, Role};
+use academic_chain_client::accounts::{
+    fetch_account, fetch_course, fetch_enrollment, fetch_enrollment_or_record, fetch_institution, fetch_student_profile,
+};
+use academic_chain_client::compression::{self, allocate_tree, is_supported_tree_size, merkle_tree_account_size};
+use academic_chain_client::credential::{self, issue_course_credential, DidMethod};
+use academic_chain_client::instructions;
+use academic_chain_client::metadata::{
+    hash_to_hex, matches_enrollment, CertificateLinks, CertificateMetadata, ContentStore,
+};
+use academic_chain_client::pda::{certificate_tree_pda, enrollment_pda, offering_pda};
+use academic_chain_client::verify::verify_certificate;
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, ensure, Context as _, Result};
+use serde::Serialize;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+use crate::context::{rpc_error, Context};
+use crate::output::{EnrollmentView, TxOutcome};
+
+#[derive(Serialize)]
+struct Verification {
+    mint: String,
+    valid: bool,
+    reason: Option<String>,
+    /// Whether `--metadata` matches the hash on the enrollment
+    metadata_matches: Option<bool>,
+    enrollment: Option<EnrollmentView>,
+}
+
+#[derive(Serialize)]
+struct StoredMetadata {
+    hash: String,
+    path: String,
+}
+
+#[derive(Serialize)]
+struct MintedCertificate {
+    metadata_uri: String,
+    #[serde(flatten)]
+    metadata: StoredMetadata,
+    #[serde(flatten)]
+    outcome: TxOutcome,
+}
+
+pub fn verify(ctx: &Context, mint: &Pubkey, metadata: Option<&Path>) -> Result<()> {
+    let result = verify_certificate(&ctx.rpc, mint)?;
+
+    let metadata_matches = match (metadata, &result.enrollment) {
+        (Some(path), Some((_, enrollment))) => {
+            let document = std::fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+            Some(matches_enrollment(&document, enrollment))
+        }
+        _ => None,
+    };
+    let reason = match result.failure {
+        Some(failure) => Some(failure.to_string()),
+        None if metadata_matches == Some(false) => Some("metadata does not match the on-chain hash".to_string()),
+        None => None,
+    };
+
+    let verification = Verification {
+        mint: mint.to_string(),
+        valid: reason.is_none(),
+        reason,
+        metadata_matches,
+        enrollment: result
+            .enrollment
+            .map(|(address, enrollment)| EnrollmentView::new(&address, &enrollment)),
+    };
+
+    ctx.emit(&verification, |verification| {
+        match &verification.reason {
+            None => println!("✅ Valid certificate {}", verification.mint),
+            Some(reason) => println!("❌ Invalid certificate {}: {reason}", verification.mint),
+        }
+        if let Some(enrollment) = &verification.enrollment {
+            println!("Student: {}", enrollment.student);
+            println!("Course: {}", enrollment.course_id);
+            println!("Grade: {} ({})", enrollment.grade, enrollment.letter_grade);
+            if let Some(completed) = enrollment.completion_date {
+                println!("Completed At: {completed}");
+            }
+            if let Some(hash) = &enrollment.metadata_hash {
+                println!("Metadata SHA-256: {hash}");
+            }
+        }
+    })?;
+
+    if !verification.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
+
+fn course_metadata(
+    ctx: &Context,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    links: &CertificateLinks,
+) -> Result<CertificateMetadata> {
+    let institution = ctx.institution()?;
+    let enrollment: CourseEnrollment = fetch_enrollment(&ctx.rpc, &institution, student, course_id, term_id)
+        .with_context(|| format!("no enrollment for {student} in {course_id} {term_id}"))?;
+    ensure!(
+        enrollment.status == EnrollmentStatus::Completed,
+        "{course_id} {term_id} is not completed"
+    );
+    let course = fetch_course(&ctx.rpc, &institution, course_id)?;
+    let offering: CourseOffering = fetch_account(&ctx.rpc, &enrollment.offering)?;
+    Ok(CertificateMetadata::course(&enrollment, &course, &offering, links))
+}
+
+fn store(document: &CertificateMetadata, store_dir: &Path) -> Result<([u8; 32], StoredMetadata)> {
+    let store = ContentStore::open(store_dir).with_context(|| format!("failed to open {}", store_dir.display()))?;
+    let hash = store.put(&document.to_bytes())?;
+    let stored = StoredMetadata {
+        hash: hash_to_hex(&hash),
+        path: store.path(&hash).display().to_string(),
+    };
+    Ok((hash, stored))
+}
+
+/// Build certificate metadata from on-chain state and store it. Without an
+/// offering this is the graduation diploma.
+pub fn metadata(
+    ctx: &Context,
+    student: &Pubkey,
+    offering: Option<(&str, &str)>,
+    links: &CertificateLinks,
+    store_dir: &Path,
+) -> Result<()> {
+    let document = match offering {
+        Some((course_id, term_id)) => course_metadata(ctx, student, course_id, term_id, links)?,
+        None => {
+            let profile = fetch_student_profile(&ctx.rpc, &ctx.institution()?, student)?;
+            ensure!(profile.graduation_nft.is_some(), "{student} has not graduated");
+            CertificateMetadata::graduation(&profile, links)
+        }
+    };
+
+    let (_, stored) = store(&document, store_dir)?;
+    ctx.emit(&stored, |stored| {
+        println!("✅ Stored certificate metadata");
+        println!("SHA-256: {}", stored.hash);
+        println!("Path: {}", stored.path);
+    })
+}
+
+/// Store the signer's course certificate metadata and mint the certificate
+/// with its hash; `{hash}` in `uri` is replaced by the hex hash
+pub fn mint(
+    ctx: &Context,
+    course_id: &str,
+    term_id: &str,
+    uri: &str,
+    links: &CertificateLinks,
+    store_dir: &Path,
+) -> Result<()> {
+    let student = ctx.signer()?;
+    let document = course_metadata(ctx, &student, course_id, term_id, links)?;
+    let (hash, stored) = store(&document, store_dir)?;
+    let metadata_uri = uri.replace("{hash}", &stored.hash);
+
+    let ix = instructions::mint_certificate(&ctx.institution()?, &student, course_id, term_id, &metadata_uri, hash);
+    let outcome = ctx.send(&[ix], &[])?;
+
+    let minted = MintedCertificate {
+        metadata_uri,
+        metadata: stored,
+        outcome,
+    };
+    ctx.emit(&minted, |minted| {
+        match &minted.outcome {
+            TxOutcome::Confirmed { signature } => {
+                println!("✅ Minted certificate for {course_id} {term_id}");
+                println!("Signature: {signature}");
+            }
+            TxOutcome::Simulated { units_consumed, .. } => {
+                println!("🧪 Simulated: mint certificate for {course_id} {term_id}");
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+            }
+        }
+        println!("Metadata: {} (SHA-256 {})", minted.metadata_uri, minted.metadata.hash);
+        println!("Upload {} to that URI", minted.metadata.path);
+    })
+}
+
+#[derive(Serialize)]
+struct CreatedTree {
+    merkle_tree: String,
+    certificate_tree: String,
+    max_depth: u32,
+    max_buffer_size: u32,
+    capacity: u64,
+    #[serde(flatten)]
+    outcome: TxOutcome,
+}
+
+/// Allocate a concurrent Merkle tree and hand it to a new `CertificateTree`;
+/// the keypair must be the institution authority or a Reviewer
+pub fn create_tree(ctx: &Context, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    ensure!(
+        is_supported_tree_size(max_depth, max_buffer_size),
+        "depth {max_depth} with buffer {max_buffer_size} is not a tree size the compression program supports"
+    );
+    let authority = ctx.signer()?;
+    let merkle_tree = Keypair::new();
+    let rent = ctx
+        .rpc
+        .get_minimum_balance_for_rent_exemption(merkle_tree_account_size(max_depth, max_buffer_size))
+        .map_err(rpc_error)?;
+    let ixs = [
+        allocate_tree(&authority, &merkle_tree.pubkey(), rent, max_depth, max_buffer_size),
+        instructions::create_certificate_tree(
+            &ctx.institution()?,
+            &authority,
+            ctx.signer_role(Role::Reviewer)?,
+            &merkle_tree.pubkey(),
+            max_depth,
+            max_buffer_size,
+        ),
+    ];
+    let outcome = ctx.send(&ixs, &[&merkle_tree])?;
+
+    let created = CreatedTree {
+        merkle_tree: merkle_tree.pubkey().to_string(),
+        certificate_tree: certificate_tree_pda(&merkle_tree.pubkey()).0.to_string(),
+        max_depth,
+        max_buffer_size,
+        capacity: 1 << max_depth,
+        outcome,
+    };
+    ctx.emit(&created, |created| {
+        match &created.outcome {
+            TxOutcome::Confirmed { signature } => {
+                println!("✅ Created certificate tree {}", created.merkle_tree);
+                println!("Signature: {signature}");
+            }
+            TxOutcome::Simulated { units_consumed, .. } => {
+                println!("🧪 Simulated: create certificate tree {}", created.merkle_tree);
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+            }
+        }
+        println!("Certificate Tree: {}", created.certificate_tree);
+        println!("Capacity: {} certificates", created.capacity);
+    })
+}
+
+/// Store the signer's course certificate metadata and append the certificate
+/// to `merkle_tree` instead of minting it
+pub fn mint_compressed(
+    ctx: &Context,
+    course_id: &str,
+    term_id: &str,
+    merkle_tree: &Pubkey,
+    uri: &str,
+    links: &CertificateLinks,
+    store_dir: &Path,
+) -> Result<()> {
+    let student = ctx.signer()?;
+    let document = course_metadata(ctx, &student, course_id, term_id, links)?;
+    let (hash, stored) = store(&document, store_dir)?;
+    let metadata_uri = uri.replace("{hash}", &stored.hash);
+
+    let ix = instructions::mint_compressed_certificate(
+        &ctx.institution()?,
+        &student,
+        merkle_tree,
+        course_id,
+        term_id,
+        &metadata_uri,
+        hash,
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+
+    let minted = MintedCertificate {
+        metadata_uri,
+        metadata: stored,
+        outcome,
+    };
+    ctx.emit(&minted, |minted| {
+        match &minted.outcome {
+            TxOutcome::Confirmed { signature } => {
+                println!("✅ Minted compressed certificate for {course_id} {term_id} in {merkle_tree}");
+                println!("Signature: {signature}");
+            }
+            TxOutcome::Simulated { units_consumed, .. } => {
+                println!("🧪 Simulated: mint compressed certificate for {course_id} {term_id}");
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+            }
+        }
+        println!("Metadata: {} (SHA-256 {})", minted.metadata_uri, minted.metadata.hash);
+        println!("Upload {} to that URI", minted.metadata.path);
+    })
+}
+
+#[derive(Serialize)]
+struct CompressedCheck {
+    enrollment: String,
+    valid: bool,
+    merkle_tree: Option<String>,
+    leaf_index: Option<u32>,
+    reason: Option<String>,
+    /// Written with `--proof`
+    proof: Option<String>,
+}
+
+/// Rebuild a compressed certificate's proof and check it against the tree's
+/// current root, optionally saving the proof
+pub fn verify_compressed(
+    ctx: &Context,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    proof_path: Option<&Path>,
+) -> Result<()> {
+    let address = enrollment_pda(student, &offering_pda(&ctx.institution()?, course_id, term_id).0).0;
+    let result = compression::verify_compressed_certificate(&ctx.rpc, &address)?;
+
+    let proof = match (proof_path, &result.proof) {
+        (Some(path), Some(proof)) => {
+            std::fs::write(path, serde_json::to_vec_pretty(proof)?)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            Some(path.display().to_string())
+        }
+        _ => None,
+    };
+    let check = CompressedCheck {
+        enrollment: address.to_string(),
+        valid: result.is_valid(),
+        merkle_tree: result.compressed_certificate.map(|leaf| leaf.merkle_tree.to_string()),
+        leaf_index: result.compressed_certificate.map(|leaf| leaf.leaf_index),
+        reason: result.failure.map(|failure| failure.to_string()),
+        proof,
+    };
+    ctx.emit(&check, |check| {
+        match &check.reason {
+            None => println!("✅ Valid compressed certificate"),
+            Some(reason) => println!("❌ Invalid compressed certificate: {reason}"),
+        }
+        println!("Student: {student}");
+        println!("Course: {course_id} {term_id}");
+        if let (Some(tree), Some(index)) = (&check.merkle_tree, check.leaf_index) {
+            println!("Tree: {tree} leaf {index}");
+        }
+        if let Some(path) = &check.proof {
+            println!("Proof: {path}");
+        }
+    })?;
+
+    if !check.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
+
+/// Issue a Verifiable Credential for a verified course certificate, signed
+/// by the keypair as issuer; the keypair must be the institution authority
+/// or a Registrar, since verifiers reject any other issuer
+pub fn credential(
+    ctx: &Context,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    did: &DidMethod,
+    output: Option<&Path>,
+) -> Result<()> {
+    let institution = ctx.institution()?;
+    let address = enrollment_pda(student, &offering_pda(&institution, course_id, term_id).0).0;
+    let enrollment: CourseEnrollment = fetch_enrollment_or_record(&ctx.rpc, &address)?
+        .with_context(|| format!("no enrollment for {student} in {course_id} {term_id}"))?;
+    let course = fetch_course(&ctx.rpc, &institution, course_id)?;
+    ensure!(
+        fetch_institution(&ctx.rpc, ctx.institution_id()?)?.authority == ctx.signer()?
+            || ctx.signer_role(Role::Registrar)?.is_some(),
+        "{} is neither the institution authority nor a Registrar",
+        ctx.signer()?
+    );
+    let issued_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+
+    let credential = issue_course_credential(&address, &enrollment, &course, ctx.payer()?, did, issued_at)
+        .map_err(|failure| anyhow!("cannot issue a credential: {failure}"))?;
+    let json = serde_json::to_string_pretty(&credential)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!("✅ Issued credential as {} to {}", credential.issuer, path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct CredentialCheck {
+    valid: bool,
+    issuer: Option<String>,
+    reason: Option<String>,
+}
+
+pub fn verify_credential(ctx: &Context, file: &Path) -> Result<()> {
+    let document = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let document: serde_json::Value =
+        serde_json::from_slice(&document).with_context(|| format!("{} is not JSON", file.display()))?;
+    let result = credential::verify_credential(&ctx.rpc, &document)?;
+
+    let check = CredentialCheck {
+        valid: result.is_valid(),
+        issuer: result.issuer,
+        reason: result.failure.map(|failure| failure.to_string()),
+    };
+    ctx.emit(&check, |check| {
+        match &check.reason {
+            None => println!("✅ Valid credential"),
+            Some(reason) => println!("❌ Invalid credential: {reason}"),
+        }
+        if let Some(issuer) = &check.issuer {
+            println!("Issuer: {issuer}");
+        }
+    })?;
+
+    if !check.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
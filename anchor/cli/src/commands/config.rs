@@ -0,0 +1,54 @@
+use academic_chain::state::Role;
+use academic_chain_client::accounts::fetch_institution;
+use academic_chain_client::instructions::{self, ConfigUpdate};
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Result};
+
+use crate::context::Context;
+use crate::output::InstitutionView;
+
+pub fn show(ctx: &Context) -> Result<()> {
+    let institution = fetch_institution(&ctx.rpc, ctx.institution_id()?)?;
+    let view = InstitutionView::new(&ctx.institution()?, &institution);
+
+    ctx.emit(&view, |view| {
+        println!("Institution: {} ({})", view.name, view.institution_id);
+        println!("Address: {}", view.address);
+        println!("Authority: {}", view.authority);
+        println!("Treasury: {}", view.treasury);
+        println!("Credit Mint: {}", view.credit_mint);
+        println!("Credit Price: {} lamports", view.credit_price);
+        if view.governed {
+            println!("Governed: authority, treasury and credit mint change through `governance propose`");
+        }
+        println!("Grade Scale:");
+        for entry in &view.grade_scale {
+            println!(
+                "  >= {:>3}  {:<2}  {}",
+                entry.min_score,
+                entry.letter,
+                crate::output::format_gpa(entry.grade_points)
+            );
+        }
+    })
+}
+
+pub fn update(
+    ctx: &Context,
+    new_authority: Option<Pubkey>,
+    treasury: Option<Pubkey>,
+    credit_price: Option<u64>,
+) -> Result<()> {
+    if new_authority.is_none() && treasury.is_none() && credit_price.is_none() {
+        bail!("nothing to update; pass --new-authority, --treasury or --credit-price");
+    }
+
+    let ix = instructions::update_config(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::FinanceAdmin)?,
+        ConfigUpdate { new_authority, treasury, credit_price },
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx("Institution config updated", &outcome)
+}
@@ -0,0 +1,106 @@
+use academic_chain::state::{Course, Role};
+use academic_chain_client::accounts::fetch_all_courses;
+use academic_chain_client::instructions::{self, CourseUpdate};
+use academic_chain_client::pda::institution_pda;
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, ensure, Result};
+use solana_signer::Signer;
+
+use crate::context::{read_keypair, Context};
+use crate::output::CourseView;
+
+pub fn create(
+    ctx: &Context,
+    course_id: &str,
+    course_name: &str,
+    instructor: &Pubkey,
+    required_credits: u64,
+    credit_hours: u8,
+    max_attempts: u8,
+) -> Result<()> {
+    ensure!(course_id.len() <= Course::MAX_ID_LEN, "course ID longer than {} bytes", Course::MAX_ID_LEN);
+    ensure!(course_name.len() <= Course::MAX_NAME_LEN, "course name longer than {} bytes", Course::MAX_NAME_LEN);
+
+    let ix = instructions::create_course(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::CourseManager)?,
+        course_id,
+        course_name,
+        instructor,
+        required_credits,
+        credit_hours,
+        max_attempts,
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Course created: {course_id}"), &outcome)
+}
+
+pub fn update(ctx: &Context, course_id: &str, update: CourseUpdate) -> Result<()> {
+    if update == CourseUpdate::default() {
+        bail!("nothing to update; pass at least one course field");
+    }
+
+    let ix = instructions::update_course(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::CourseManager)?,
+        course_id,
+        update,
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Course updated: {course_id}"), &outcome)
+}
+
+/// Create a course equivalency signed by this institution's authority or a
+/// Registrar (the keypair) and the source institution's (`source_keypair`)
+pub fn equivalency(
+    ctx: &Context,
+    source_institution_id: &str,
+    source_course_id: &str,
+    course_id: &str,
+    source_keypair: &str,
+) -> Result<()> {
+    let source_authority = read_keypair(source_keypair)?;
+    let source_institution = institution_pda(source_institution_id).0;
+    let ix = instructions::create_course_equivalency(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::Registrar)?,
+        &source_institution,
+        &source_authority.pubkey(),
+        ctx.role_assignment(&source_institution, &source_authority.pubkey(), Role::Registrar)?,
+        source_course_id,
+        course_id,
+    );
+    let outcome = ctx.send(&[ix], &[&source_authority])?;
+    ctx.emit_tx(
+        &format!("Course equivalency created: {source_course_id} ({source_institution_id}) -> {course_id}"),
+        &outcome,
+    )
+}
+
+pub fn list(ctx: &Context) -> Result<()> {
+    let mut courses = fetch_all_courses(&ctx.rpc, &ctx.institution()?)?;
+    courses.sort_by(|(_, a), (_, b)| a.course_id.cmp(&b.course_id));
+    let views: Vec<CourseView> = courses
+        .iter()
+        .map(|(address, course)| CourseView::new(address, course))
+        .collect();
+
+    ctx.emit(&views, |views| {
+        if views.is_empty() {
+            println!("No courses found");
+        }
+        for course in views {
+            println!(
+                "{:<12} {:<40} {:>3} credits  {} h  {}",
+                course.course_id,
+                course.course_name,
+                course.required_credits,
+                course.credit_hours,
+                if course.is_active { "active" } else { "inactive" },
+            );
+        }
+    })
+}
@@ -0,0 +1,90 @@
+use academic_chain::state::{ConfigChange, ConfigProposal};
+use academic_chain_client::accounts::{fetch_account, fetch_config_governance, fetch_config_proposals};
+use academic_chain_client::instructions;
+use academic_chain_client::pda::{config_governance_pda, config_proposal_pda};
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Result};
+
+use crate::context::Context;
+use crate::output::GovernanceView;
+
+/// Put the institution under governance; the keypair must be the authority
+pub fn init(ctx: &Context, admins: Vec<Pubkey>, threshold: u8, min_delay: i64) -> Result<()> {
+    let ix = instructions::create_config_governance(&ctx.institution()?, &ctx.signer()?, admins, threshold, min_delay);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx("Config governance created", &outcome)
+}
+
+pub fn show(ctx: &Context) -> Result<()> {
+    let institution = ctx.institution()?;
+    let governance = fetch_config_governance(&ctx.rpc, &institution)?
+        .ok_or_else(|| anyhow!("institution has no config governance; create it with `governance init`"))?;
+    let mut proposals = fetch_config_proposals(&ctx.rpc, &institution)?;
+    proposals.sort_by_key(|(_, proposal)| proposal.proposal_id);
+    let view = GovernanceView::new(&config_governance_pda(&institution).0, &governance, &proposals);
+
+    ctx.emit(&view, |view| {
+        println!("Governance: {}-of-{} admins, {}s delay", view.threshold, view.admins.len(), view.min_delay);
+        for admin in &view.admins {
+            println!("  {admin}");
+        }
+        if view.proposals.is_empty() {
+            println!("No pending proposals");
+        }
+        for proposal in &view.proposals {
+            println!(
+                "#{:<4} by {}  {}/{} approvals  executable at {}",
+                proposal.proposal_id,
+                proposal.proposer,
+                proposal.approvals.len(),
+                view.threshold,
+                proposal.executable_at,
+            );
+            for change in &proposal.changes {
+                println!("      {change}");
+            }
+        }
+    })
+}
+
+pub fn propose(ctx: &Context, change: ConfigChange) -> Result<()> {
+    if change.is_empty() {
+        bail!("nothing to propose; pass --new-authority, --treasury, --credit-mint, --admin, --threshold or --delay");
+    }
+
+    let institution = ctx.institution()?;
+    let governance = fetch_config_governance(&ctx.rpc, &institution)?
+        .ok_or_else(|| anyhow!("institution has no config governance; create it with `governance init`"))?;
+    let proposal_id = governance.proposal_count;
+    let ix = instructions::propose_config_change(&institution, &ctx.signer()?, proposal_id, change);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Config change #{proposal_id} proposed"), &outcome)
+}
+
+pub fn approve(ctx: &Context, proposal_id: u64) -> Result<()> {
+    let ix = instructions::approve_config_change(&ctx.institution()?, &ctx.signer()?, proposal_id);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Config change #{proposal_id} approved"), &outcome)
+}
+
+pub fn execute(ctx: &Context, proposal_id: u64) -> Result<()> {
+    let institution = ctx.institution()?;
+    let proposal = fetch_proposal(ctx, &institution, proposal_id)?;
+    let ix = instructions::execute_config_change(&institution, &ctx.signer()?, &proposal.proposer, proposal_id);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Config change #{proposal_id} executed"), &outcome)
+}
+
+pub fn cancel(ctx: &Context, proposal_id: u64) -> Result<()> {
+    let institution = ctx.institution()?;
+    let proposal = fetch_proposal(ctx, &institution, proposal_id)?;
+    let ix = instructions::cancel_config_change(&institution, &ctx.signer()?, &proposal.proposer, proposal_id);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Config change #{proposal_id} cancelled"), &outcome)
+}
+
+// Executed and cancelled proposals are closed, so they read as missing
+fn fetch_proposal(ctx: &Context, institution: &Pubkey, proposal_id: u64) -> Result<ConfigProposal> {
+    fetch_account(&ctx.rpc, &config_proposal_pda(institution, proposal_id).0)
+        .map_err(|err| anyhow!("no pending config proposal #{proposal_id}: {err}"))
+}
@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use academic_chain::constants::MAX_GRADING_BATCH_SIZE;
+use academic_chain::state::StudentGrade;
+use academic_chain_client::grading::{self, GradingBatch};
+use academic_chain_client::instructions;
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, ensure, Context as _, Result};
+use serde::{Deserialize, Serialize};
+use solana_commitment_config::CommitmentConfig;
+use solana_message::AddressLookupTableAccount;
+
+use crate::context::{rpc_error, Context};
+use crate::output::TxOutcome;
+
+/// Students per `complete_course_batch` transaction without a lookup table.
+/// Each student adds two accounts, so larger batches exceed the transaction
+/// size limit. `--dry-run` simulates batches of this size, since lookup
+/// tables are only created on a real run.
+const DRY_RUN_BATCH_SIZE: usize = 8;
+const _: () = assert!(DRY_RUN_BATCH_SIZE <= MAX_GRADING_BATCH_SIZE);
+
+#[derive(Deserialize)]
+struct GradeRow {
+    student: String,
+    grade: u8,
+}
+
+#[derive(Serialize)]
+struct BatchResult {
+    students: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lookup_table: Option<String>,
+    #[serde(flatten)]
+    outcome: TxOutcome,
+}
+
+pub fn set(ctx: &Context, course_id: &str, term_id: &str, student: &Pubkey, grade: u8) -> Result<()> {
+    ensure!(grade <= 100, "grade must be between 0 and 100");
+
+    let ix = instructions::complete_course(&ctx.institution()?, &ctx.signer()?, student, course_id, term_id, grade);
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Graded {student}: {grade}"), &outcome)
+}
+
+pub fn import(ctx: &Context, course_id: &str, term_id: &str, file: &Path) -> Result<()> {
+    let grades = read_grades(file)?;
+    if grades.is_empty() {
+        bail!("{} contains no grades", file.display());
+    }
+
+    let institution = ctx.institution()?;
+    let instructor = ctx.signer()?;
+    let batch_size = if ctx.dry_run { DRY_RUN_BATCH_SIZE } else { MAX_GRADING_BATCH_SIZE };
+    let plans = grading::plan_grading_batches(&institution, &instructor, course_id, term_id, &grades, batch_size);
+
+    let mut results = Vec::new();
+    for plan in plans {
+        if ctx.dry_run {
+            for batch in plan.batches {
+                let outcome = send_batch(ctx, &batch, None, results.len())?;
+                results.push(BatchResult::new(&batch, None, outcome));
+            }
+            continue;
+        }
+
+        let table = create_lookup_table(ctx, &plan.addresses)?;
+        for batch in plan.batches {
+            let outcome = send_batch(ctx, &batch, Some(&table), results.len())?;
+            results.push(BatchResult::new(&batch, Some(&table.key), outcome));
+        }
+
+        // Rent can be reclaimed with `close_lookup_table` once deactivation completes
+        ctx.send(&[grading::deactivate_lookup_table(&table.key, &instructor)], &[])
+            .with_context(|| format!("failed to deactivate lookup table {}", table.key))?;
+    }
+
+    ctx.emit(&results, |results| {
+        for (index, batch) in results.iter().enumerate() {
+            let action = format!("Batch {}: graded {} students", index + 1, batch.students.len());
+            // Human output never fails to serialize
+            let _ = ctx.emit_tx(&action, &batch.outcome);
+        }
+    })
+}
+
+impl BatchResult {
+    fn new(batch: &GradingBatch, lookup_table: Option<&Pubkey>, outcome: TxOutcome) -> Self {
+        Self {
+            students: batch.grades.iter().map(|entry| entry.student.to_string()).collect(),
+            lookup_table: lookup_table.map(Pubkey::to_string),
+            outcome,
+        }
+    }
+}
+
+/// Send one batch, as a v0 transaction when its accounts are in `table`
+fn send_batch(
+    ctx: &Context,
+    batch: &GradingBatch,
+    table: Option<&AddressLookupTableAccount>,
+    index: usize,
+) -> Result<TxOutcome> {
+    let instructions = [grading::compute_unit_limit(batch.grades.len()), batch.instruction.clone()];
+    match table {
+        Some(table) => ctx.send_v0(&instructions, std::slice::from_ref(table)),
+        None => ctx.send(&instructions, &[]),
+    }
+    .with_context(|| format!("batch {} failed; earlier batches were already applied", index + 1))
+}
+
+/// Create a lookup table holding `addresses` and wait until they can be used
+fn create_lookup_table(ctx: &Context, addresses: &[Pubkey]) -> Result<AddressLookupTableAccount> {
+    let payer = ctx.signer()?;
+    let recent_slot = ctx
+        .rpc
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .map_err(rpc_error)?;
+    let (create_ix, table) = grading::create_lookup_table(&payer, &payer, recent_slot);
+    ctx.send(&[create_ix], &[]).context("failed to create a lookup table")?;
+    for extend_ix in grading::extend_lookup_table(&table, &payer, &payer, addresses) {
+        ctx.send(&[extend_ix], &[])
+            .with_context(|| format!("failed to extend lookup table {table}"))?;
+    }
+
+    // New addresses become usable one slot after they are added
+    let extended_at = ctx.rpc.get_slot().map_err(rpc_error)?;
+    while ctx.rpc.get_slot().map_err(rpc_error)? <= extended_at {
+        thread::sleep(Duration::from_millis(400));
+    }
+
+    Ok(grading::fetch_lookup_table(&ctx.rpc, &table)?)
+}
+
+/// Parse a `student,grade` CSV, rejecting bad rows before anything is sent
+fn read_grades(file: &Path) -> Result<Vec<StudentGrade>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_path(file)
+        .with_context(|| format!("failed to open {}", file.display()))?;
+
+    let mut seen = HashSet::new();
+    let mut grades = Vec::new();
+    for (index, row) in reader.deserialize::<GradeRow>().enumerate() {
+        // Line 1 is the header
+        let line = index + 2;
+        let row = row.with_context(|| format!("line {line}: invalid row"))?;
+        let student: Pubkey = row
+            .student
+            .parse()
+            .with_context(|| format!("line {line}: invalid student address `{}`", row.student))?;
+
+        ensure!(row.grade <= 100, "line {line}: grade must be between 0 and 100");
+        ensure!(seen.insert(student), "line {line}: duplicate student {student}");
+
+        grades.push(StudentGrade { student, grade: row.grade });
+    }
+
+    Ok(grades)
+}
@@ -0,0 +1,57 @@
+use academic_chain_client::instructions;
+use academic_chain_client::pda::institution_pda;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token::spl_token;
+use anyhow::Result;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+use crate::context::{rpc_error, Context};
+
+pub fn run(ctx: &Context, name: &str, treasury: Option<Pubkey>, credit_mint: Option<Pubkey>) -> Result<()> {
+    let authority = ctx.signer()?;
+    let treasury = treasury.unwrap_or(authority);
+    let institution_id = ctx.institution_id()?;
+    let institution = institution_pda(institution_id).0;
+
+    let mut ixs = Vec::new();
+    let mint_keypair = Keypair::new();
+    let credit_mint = match credit_mint {
+        Some(mint) => mint,
+        None => {
+            // Credits are whole tokens minted by the institution PDA on purchase
+            let rent = ctx
+                .rpc
+                .get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)
+                .map_err(rpc_error)?;
+            ixs.push(system_instruction::create_account(
+                &authority,
+                &mint_keypair.pubkey(),
+                rent,
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ));
+            ixs.push(spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint_keypair.pubkey(),
+                &institution,
+                None,
+                0,
+            )?);
+            mint_keypair.pubkey()
+        }
+    };
+    ixs.push(instructions::create_institution(&authority, institution_id, name, &treasury, &credit_mint));
+
+    let signers: Vec<&Keypair> = if ixs.len() > 1 { vec![&mint_keypair] } else { vec![] };
+    let outcome = ctx.send(&ixs, &signers)?;
+
+    if !ctx.json {
+        println!("Institution: {institution}");
+        println!("Treasury: {treasury}");
+        println!("Credit Mint: {credit_mint}");
+    }
+    ctx.emit_tx("Institution created", &outcome)
+}
@@ -0,0 +1,11 @@
+pub mod batch;
+pub mod catalog;
+pub mod certificate;
+pub mod config;
+pub mod course;
+pub mod governance;
+pub mod grade;
+pub mod init;
+pub mod profile;
+pub mod role;
+pub mod transcript;
@@ -0,0 +1,70 @@
+use academic_chain_client::accounts::{fetch_student_enrollments, fetch_student_profile};
+use academic_chain_client::instructions;
+use academic_chain_client::pda::{institution_pda, student_profile_pda};
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+
+use crate::context::Context;
+use crate::output::{EnrollmentView, ProfileView};
+
+/// Transfer the signer's completed `source_course_id` enrollment at
+/// `source_institution_id` to `course_id` at this institution
+pub fn transfer(
+    ctx: &Context,
+    source_institution_id: &str,
+    source_course_id: &str,
+    source_term_id: &str,
+    course_id: &str,
+) -> Result<()> {
+    let ix = instructions::transfer_completion(
+        &ctx.institution()?,
+        &institution_pda(source_institution_id).0,
+        &ctx.signer()?,
+        source_course_id,
+        source_term_id,
+        course_id,
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Completion transferred: {source_course_id} -> {course_id}"), &outcome)
+}
+
+pub fn show(ctx: &Context, student: &Pubkey) -> Result<()> {
+    let institution = ctx.institution()?;
+    let profile = fetch_student_profile(&ctx.rpc, &institution, student)?;
+    let mut enrollments = fetch_student_enrollments(&ctx.rpc, &institution, student)?;
+    enrollments.sort_by_key(|(_, enrollment)| enrollment.enrollment_date);
+
+    let view = ProfileView::new(
+        &student_profile_pda(&institution, student).0,
+        &profile,
+        enrollments
+            .iter()
+            .map(|(address, enrollment)| EnrollmentView::new(address, enrollment))
+            .collect(),
+    );
+
+    ctx.emit(&view, |view| {
+        println!("Student: {}", view.student);
+        if let Some(name) = &view.display_name {
+            println!("Name: {name}");
+        }
+        println!("GPA: {}", view.gpa);
+        println!("Courses Completed: {}", view.courses_completed);
+        println!("Credit Hours: {} earned / {} attempted", view.credit_hours_earned, view.credit_hours_attempted);
+        println!("Credits: {} purchased / {} spent", view.total_credits_purchased, view.total_credits_spent);
+        if let Some(nft) = &view.graduation_nft {
+            println!("Graduation NFT: {nft}");
+        }
+        println!("Enrollments:");
+        for enrollment in &view.enrollments {
+            println!(
+                "  {:<12} {:<10} {:>3} {:<2}  attempt {}",
+                enrollment.course_id,
+                enrollment.status,
+                enrollment.grade,
+                enrollment.letter_grade,
+                enrollment.attempts,
+            );
+        }
+    })
+}
@@ -0,0 +1,100 @@
+use academic_chain::state::Role;
+use academic_chain_client::accounts::fetch_institution_roles;
+use academic_chain_client::instructions;
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::ValueEnum;
+
+use crate::context::Context;
+use crate::output::RoleView;
+
+/// `Role` as a command-line value: `super-admin`, `registrar`, ...
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum RoleArg {
+    SuperAdmin,
+    Registrar,
+    FinanceAdmin,
+    CourseManager,
+    Reviewer,
+}
+
+impl From<RoleArg> for Role {
+    fn from(role: RoleArg) -> Self {
+        match role {
+            RoleArg::SuperAdmin => Role::SuperAdmin,
+            RoleArg::Registrar => Role::Registrar,
+            RoleArg::FinanceAdmin => Role::FinanceAdmin,
+            RoleArg::CourseManager => Role::CourseManager,
+            RoleArg::Reviewer => Role::Reviewer,
+        }
+    }
+}
+
+impl From<Role> for RoleArg {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::SuperAdmin => RoleArg::SuperAdmin,
+            Role::Registrar => RoleArg::Registrar,
+            Role::FinanceAdmin => RoleArg::FinanceAdmin,
+            Role::CourseManager => RoleArg::CourseManager,
+            Role::Reviewer => RoleArg::Reviewer,
+        }
+    }
+}
+
+impl RoleArg {
+    pub fn name(self) -> &'static str {
+        match self {
+            RoleArg::SuperAdmin => "super-admin",
+            RoleArg::Registrar => "registrar",
+            RoleArg::FinanceAdmin => "finance-admin",
+            RoleArg::CourseManager => "course-manager",
+            RoleArg::Reviewer => "reviewer",
+        }
+    }
+}
+
+/// Grant `role` to `holder`; the keypair must be the authority or, for any
+/// role but super-admin, a super-admin
+pub fn grant(ctx: &Context, holder: &Pubkey, role: RoleArg) -> Result<()> {
+    let ix = instructions::grant_role(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::SuperAdmin)?,
+        holder,
+        role.into(),
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Granted {} to {holder}", role.name()), &outcome)
+}
+
+/// Revoke `role` from `holder`, returning the rent to the keypair
+pub fn revoke(ctx: &Context, holder: &Pubkey, role: RoleArg) -> Result<()> {
+    let ix = instructions::revoke_role(
+        &ctx.institution()?,
+        &ctx.signer()?,
+        ctx.signer_role(Role::SuperAdmin)?,
+        holder,
+        role.into(),
+    );
+    let outcome = ctx.send(&[ix], &[])?;
+    ctx.emit_tx(&format!("Revoked {} from {holder}", role.name()), &outcome)
+}
+
+pub fn list(ctx: &Context) -> Result<()> {
+    let mut assignments = fetch_institution_roles(&ctx.rpc, &ctx.institution()?)?;
+    assignments.sort_by_key(|(_, assignment)| (assignment.holder.to_string(), assignment.role as u8));
+    let views: Vec<RoleView> = assignments
+        .iter()
+        .map(|(address, assignment)| RoleView::new(address, assignment, RoleArg::from(assignment.role).name()))
+        .collect();
+
+    ctx.emit(&views, |views| {
+        if views.is_empty() {
+            println!("No roles granted; only the authority can administer the institution");
+        }
+        for view in views {
+            println!("{:<44} {:<14} granted by {}", view.holder, view.role, view.granted_by);
+        }
+    })
+}
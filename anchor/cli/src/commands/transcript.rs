@@ -0,0 +1,210 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use academic_chain::state::{Role, TranscriptAnchor};
+use academic_chain_client::accounts::{fetch_account_optional, fetch_student_profile};
+use academic_chain_client::credential::DidMethod;
+use academic_chain_client::instructions;
+use academic_chain_client::transcript::{
+    self, check_transcript, fetch_transcript_records, issue_transcript, read_transcript, TranscriptDisclosure,
+    TranscriptVerification,
+};
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, ensure, Context as _, Result};
+use serde::Serialize;
+
+use crate::context::Context;
+use crate::output::TxOutcome;
+
+#[derive(Serialize)]
+struct IssuedTranscript {
+    student: String,
+    entries: usize,
+    merkle_root: String,
+    transcript_anchor: String,
+    /// Written unless `--dry-run`
+    path: Option<String>,
+    /// `None` when the root was already anchored
+    outcome: Option<TxOutcome>,
+}
+
+/// Sign a transcript of the student's enrollments with the keypair and
+/// anchor its Merkle root, unless the same root is already anchored. The
+/// keypair must be the institution authority.
+pub fn issue(ctx: &Context, student: &Pubkey, did: &DidMethod, output: &Path) -> Result<()> {
+    let pdf = match output.extension().and_then(|extension| extension.to_str()) {
+        Some("pdf") => true,
+        Some("json") => false,
+        _ => bail!("output must be a .json or .pdf file"),
+    };
+    let institution = ctx.institution()?;
+    let profile = fetch_student_profile(&ctx.rpc, &institution, student)?;
+    let records = fetch_transcript_records(&ctx.rpc, &institution, student)?;
+    ensure!(!records.is_empty(), "{student} has no enrollments to put on a transcript");
+
+    let issued_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+    let transcript = issue_transcript(&profile, &records, ctx.payer()?, did, issued_at);
+    let root = transcript.root()?;
+    let anchor_address: Pubkey = transcript.transcript_anchor.parse()?;
+
+    let anchored: Option<TranscriptAnchor> = fetch_account_optional(&ctx.rpc, &anchor_address)?;
+    let outcome = match anchored {
+        Some(_) => None,
+        None => {
+            let entry_count = u16::try_from(transcript.entries.len())?;
+            let authority_role = ctx.signer_role(Role::Registrar)?;
+            let ix = instructions::anchor_transcript(&institution, &ctx.signer()?, authority_role, student, root, entry_count);
+            Some(ctx.send(&[ix], &[])?)
+        }
+    };
+
+    let path = if ctx.dry_run {
+        None
+    } else {
+        let bytes = if pdf {
+            transcript.to_pdf()
+        } else {
+            serde_json::to_vec_pretty(&transcript)?
+        };
+        std::fs::write(output, bytes).with_context(|| format!("failed to write {}", output.display()))?;
+        Some(output.display().to_string())
+    };
+
+    let issued = IssuedTranscript {
+        student: student.to_string(),
+        entries: transcript.entries.len(),
+        merkle_root: transcript.merkle_root.clone(),
+        transcript_anchor: transcript.transcript_anchor.clone(),
+        path,
+        outcome,
+    };
+    ctx.emit(&issued, |issued| {
+        match &issued.outcome {
+            Some(TxOutcome::Confirmed { signature }) => {
+                println!("✅ Anchored transcript of {} ({} entries)", issued.student, issued.entries);
+                println!("Signature: {signature}");
+            }
+            Some(TxOutcome::Simulated { units_consumed, .. }) => {
+                println!("🧪 Simulated: anchor transcript of {}", issued.student);
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+            }
+            None => println!("✅ Transcript of {} is already anchored", issued.student),
+        }
+        println!("Merkle root: {}", issued.merkle_root);
+        println!("Anchor: {}", issued.transcript_anchor);
+        if let Some(path) = &issued.path {
+            println!("Transcript: {path}");
+        }
+    })
+}
+
+/// Extract one course from a signed transcript with its Merkle proof, so it
+/// can be shown without the other entries
+pub fn disclose(file: &Path, course_id: &str, term_id: Option<&str>, output: Option<&Path>) -> Result<()> {
+    let bytes = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let document = read_transcript(&bytes)?;
+    let transcript = check_transcript(&document).map_err(|failure| anyhow!("invalid transcript: {failure}"))?;
+
+    let matches: Vec<usize> = transcript
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.course_id == course_id && term_id.is_none_or(|term_id| line.term_id == term_id))
+        .map(|(index, _)| index)
+        .collect();
+    let index = match matches[..] {
+        [index] => index,
+        [] => bail!("{course_id} is not on the transcript"),
+        _ => bail!("{course_id} was taken in several terms; pass --term"),
+    };
+    let disclosure = transcript.disclose(index).context("transcript entries do not parse")?;
+
+    let json = serde_json::to_string_pretty(&disclosure)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))?;
+            eprintln!("✅ Disclosed {course_id} {} to {}", disclosure.entry.term_id, path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct TranscriptCheck {
+    valid: bool,
+    issuer: Option<String>,
+    transcript_anchor: Option<String>,
+    reason: Option<String>,
+}
+
+/// Check a transcript JSON or PDF against its on-chain anchor
+pub fn verify(ctx: &Context, file: &Path) -> Result<()> {
+    let bytes = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let result = match read_transcript(&bytes) {
+        Ok(document) => transcript::verify_transcript(&ctx.rpc, &document)?,
+        Err(failure) => TranscriptVerification {
+            issuer: None,
+            anchor: None,
+            failure: Some(failure),
+        },
+    };
+
+    let check = TranscriptCheck {
+        valid: result.is_valid(),
+        issuer: result.issuer,
+        transcript_anchor: result.anchor.map(|anchor| anchor.to_string()),
+        reason: result.failure.map(|failure| failure.to_string()),
+    };
+    ctx.emit(&check, |check| {
+        match &check.reason {
+            None => println!("✅ Valid transcript"),
+            Some(reason) => println!("❌ Invalid transcript: {reason}"),
+        }
+        if let Some(issuer) = &check.issuer {
+            println!("Issuer: {issuer}");
+        }
+        if let Some(anchor) = &check.transcript_anchor {
+            println!("Anchor: {anchor}");
+        }
+    })?;
+
+    if !check.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
+
+/// Check a disclosed transcript entry against its on-chain anchor
+pub fn verify_entry(ctx: &Context, file: &Path) -> Result<()> {
+    let bytes = std::fs::read(file).with_context(|| format!("failed to read {}", file.display()))?;
+    let disclosure: TranscriptDisclosure =
+        serde_json::from_slice(&bytes).with_context(|| format!("{} is not a transcript disclosure", file.display()))?;
+    let result = transcript::verify_disclosure(&ctx.rpc, &disclosure)?;
+
+    let check = TranscriptCheck {
+        valid: result.is_valid(),
+        issuer: result.issuer,
+        transcript_anchor: result.anchor.map(|anchor| anchor.to_string()),
+        reason: result.failure.map(|failure| failure.to_string()),
+    };
+    let entry = &disclosure.entry;
+    ctx.emit(&check, |check| {
+        match &check.reason {
+            None => println!("✅ Valid transcript entry"),
+            Some(reason) => println!("❌ Invalid transcript entry: {reason}"),
+        }
+        println!("Course: {} {} ({})", entry.course_id, entry.term_id, entry.status);
+        println!("Grade: {} ({})", entry.grade, entry.letter_grade);
+        if let Some(issuer) = &check.issuer {
+            println!("Issuer: {issuer}");
+        }
+    })?;
+
+    if !check.valid {
+        std::process::exit(2);
+    }
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use std::cell::OnceCell;
+use std::str::FromStr;
+
+use academic_chain::state::{Role, RoleAssignment};
+use academic_chain_client::accounts::fetch_account_optional;
+use academic_chain_client::pda::{institution_pda, role_assignment_pda};
+use academic_chain_client::{decode_rpc_error, decode_transaction_error, AcademicChainError};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::{anyhow, Context as _, Result};
+use solana_commitment_config::CommitmentConfig;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_message::{v0, AddressLookupTableAccount, VersionedMessage};
+use solana_rpc_client::rpc_client::{RpcClient, SerializableTransaction};
+use solana_rpc_client_api::client_error::Error as RpcError;
+use solana_signer::Signer;
+use solana_transaction::versioned::VersionedTransaction;
+use solana_transaction::Transaction;
+
+use crate::output::TxOutcome;
+
+/// RPC endpoint chosen with `--cluster`
+#[derive(Clone, Debug)]
+pub struct Cluster(String);
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        let url = match value {
+            "localnet" | "l" => "http://127.0.0.1:8899",
+            "devnet" | "d" => "https://api.devnet.solana.com",
+            "testnet" | "t" => "https://api.testnet.solana.com",
+            "mainnet" | "mainnet-beta" | "m" => "https://api.mainnet-beta.solana.com",
+            url if url.starts_with("http://") || url.starts_with("https://") => url,
+            other => return Err(format!("unknown cluster `{other}`")),
+        };
+        Ok(Self(url.to_string()))
+    }
+}
+
+pub struct Context {
+    pub rpc: RpcClient,
+    pub dry_run: bool,
+    pub json: bool,
+    institution_id: Option<String>,
+    keypair_path: String,
+    // Loaded on first use so read-only commands work without a keypair
+    payer: OnceCell<Keypair>,
+}
+
+impl Context {
+    pub fn new(cluster: &Cluster, keypair: &str, institution_id: Option<String>, dry_run: bool, json: bool) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(cluster.0.clone(), CommitmentConfig::confirmed()),
+            dry_run,
+            json,
+            institution_id,
+            keypair_path: expand_home(keypair),
+            payer: OnceCell::new(),
+        }
+    }
+
+    pub fn payer(&self) -> Result<&Keypair> {
+        if let Some(payer) = self.payer.get() {
+            return Ok(payer);
+        }
+        let payer = read_keypair(&self.keypair_path)?;
+        Ok(self.payer.get_or_init(|| payer))
+    }
+
+    pub fn signer(&self) -> Result<Pubkey> {
+        Ok(self.payer()?.pubkey())
+    }
+
+    /// Institution ID chosen with `--institution`
+    pub fn institution_id(&self) -> Result<&str> {
+        self.institution_id
+            .as_deref()
+            .ok_or_else(|| anyhow!("no institution; pass --institution or set ACADEMIC_INSTITUTION"))
+    }
+
+    /// Address of the institution chosen with `--institution`
+    pub fn institution(&self) -> Result<Pubkey> {
+        Ok(institution_pda(self.institution_id()?).0)
+    }
+
+    /// `holder`'s assignment for `role` at `institution`, if it exists
+    pub fn role_assignment(&self, institution: &Pubkey, holder: &Pubkey, role: Role) -> Result<Option<Pubkey>> {
+        let address = role_assignment_pda(institution, holder, role).0;
+        let assignment: Option<RoleAssignment> = fetch_account_optional(&self.rpc, &address)?;
+        Ok(assignment.map(|_| address))
+    }
+
+    /// The signer's assignment for `role` at the `--institution`; `None` for
+    /// the institution authority, which needs none
+    pub fn signer_role(&self, role: Role) -> Result<Option<Pubkey>> {
+        self.role_assignment(&self.institution()?, &self.signer()?, role)
+    }
+
+    /// Sign with the payer plus `signers` and send, or simulate on `--dry-run`
+    pub fn send(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<TxOutcome> {
+        let payer = self.payer()?;
+        let blockhash = self.latest_blockhash()?;
+
+        let mut all_signers = vec![payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.submit(&tx)
+    }
+
+    /// Send as a v0 transaction signed by the payer, resolving accounts
+    /// through `lookup_tables`
+    pub fn send_v0(
+        &self,
+        instructions: &[Instruction],
+        lookup_tables: &[AddressLookupTableAccount],
+    ) -> Result<TxOutcome> {
+        let payer = self.payer()?;
+        let message = v0::Message::try_compile(&payer.pubkey(), instructions, lookup_tables, self.latest_blockhash()?)
+            .context("failed to compile the v0 message")?;
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[payer])
+            .context("failed to sign the v0 transaction")?;
+        self.submit(&tx)
+    }
+
+    fn latest_blockhash(&self) -> Result<Hash> {
+        self.rpc
+            .get_latest_blockhash()
+            .context("failed to fetch a recent blockhash")
+    }
+
+    fn submit(&self, tx: &impl SerializableTransaction) -> Result<TxOutcome> {
+        if self.dry_run {
+            let result = self
+                .rpc
+                .simulate_transaction(tx)
+                .map_err(rpc_error)?
+                .value;
+
+            if let Some(err) = result.err {
+                let logs = result.logs.unwrap_or_default().join("\n");
+                return Err(match decode_transaction_error(&err) {
+                    Some(program_err) => program_error(program_err),
+                    None => anyhow!("simulation failed: {err}"),
+                }
+                .context(format!("simulation logs:\n{logs}")));
+            }
+
+            return Ok(TxOutcome::Simulated {
+                units_consumed: result.units_consumed,
+                logs: result.logs.unwrap_or_default(),
+            });
+        }
+
+        let signature = self
+            .rpc
+            .send_and_confirm_transaction(tx)
+            .map_err(rpc_error)?;
+
+        Ok(TxOutcome::Confirmed {
+            signature: signature.to_string(),
+        })
+    }
+}
+
+fn program_error(err: AcademicChainError) -> anyhow::Error {
+    anyhow!("{} ({}): {}", err.name(), u32::from(err), err)
+}
+
+/// Surface `AcademicChainError`s by name instead of as raw custom codes
+pub fn rpc_error(err: RpcError) -> anyhow::Error {
+    match decode_rpc_error(&err) {
+        Some(program_err) => program_error(program_err),
+        None => anyhow::Error::new(err),
+    }
+}
+
+/// Read a keypair file, expanding a leading `~/`
+pub fn read_keypair(path: &str) -> Result<Keypair> {
+    let path = expand_home(path);
+    read_keypair_file(&path).map_err(|err| anyhow!("failed to read keypair {path}: {err}"))
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
@@ -0,0 +1,710 @@
+//! `academic-cli` – administer the academic_chain program from the terminal.
+//!
+//! Every subcommand that sends a transaction honours `--dry-run`, which
+//! simulates it instead, and `--json`, which prints machine-readable output.
+
+mod commands;
+mod context;
+mod output;
+
+use std::path::PathBuf;
+
+use academic_chain::state::ConfigChange;
+use academic_chain_client::credential::DidMethod;
+use academic_chain_client::metadata::CertificateLinks;
+use anchor_lang::prelude::Pubkey;
+use clap::{Args, Parser, Subcommand};
+
+use crate::commands::catalog::CatalogFormat;
+use crate::commands::role::RoleArg;
+use crate::context::{Cluster, Context};
+
+#[derive(Parser)]
+#[command(name = "academic-cli", version, about = "Administer the academic_chain program")]
+struct Cli {
+    /// Cluster moniker (localnet, devnet, testnet, mainnet) or RPC URL
+    #[arg(short = 'u', long, global = true, default_value = "localnet", env = "ACADEMIC_CLUSTER")]
+    cluster: Cluster,
+
+    /// Keypair that signs and pays for transactions
+    #[arg(short, long, global = true, default_value = "~/.config/solana/id.json", env = "ACADEMIC_KEYPAIR")]
+    keypair: String,
+
+    /// Institution ID whose accounts the command works on
+    #[arg(short, long, global = true, env = "ACADEMIC_INSTITUTION")]
+    institution: Option<String>,
+
+    /// Simulate transactions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Print JSON instead of human-readable output
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the credit mint (unless given) and register the `--institution`
+    Init {
+        /// Display name of the institution
+        name: String,
+
+        /// Treasury that receives credit payments (defaults to the signer)
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+
+        /// Existing credit mint whose mint authority is already the institution PDA
+        #[arg(long)]
+        credit_mint: Option<Pubkey>,
+    },
+
+    /// Show or update the institution config
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// Multisig, timelocked changes to the authority, treasury and credit mint
+    #[command(subcommand)]
+    Governance(GovernanceCommand),
+
+    /// Grant, revoke and list administrative roles
+    #[command(subcommand)]
+    Role(RoleCommand),
+
+    /// Manage the course catalog
+    #[command(subcommand)]
+    Course(CourseCommand),
+
+    /// Grade students in a course offering
+    #[command(subcommand)]
+    Grade(GradeCommand),
+
+    /// Mint, verify and export course certificates
+    #[command(subcommand)]
+    Certificate(CertificateCommand),
+
+    /// Inspect student profiles
+    #[command(subcommand)]
+    Profile(ProfileCommand),
+
+    /// Issue and verify signed transcripts
+    #[command(subcommand)]
+    Transcript(TranscriptCommand),
+
+    /// Issue course certificates in bulk under one Merkle root
+    #[command(subcommand)]
+    Batch(BatchCommand),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Show the institution config
+    Show,
+
+    /// Update the credit price, or the authority and treasury of an ungoverned institution
+    Update {
+        #[arg(long)]
+        new_authority: Option<Pubkey>,
+
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+
+        /// Price per credit in lamports
+        #[arg(long)]
+        credit_price: Option<u64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum GovernanceCommand {
+    /// Require admin approvals and a delay for config changes; the keypair must be the authority
+    Init {
+        /// Admin that can propose, approve and cancel (repeat for each)
+        #[arg(long = "admin", required = true)]
+        admins: Vec<Pubkey>,
+
+        /// Approvals needed to execute a proposal
+        #[arg(long)]
+        threshold: u8,
+
+        /// Seconds between proposing and executing (3600 to 2592000)
+        #[arg(long)]
+        delay: i64,
+    },
+
+    /// Show the admins and pending proposals
+    Show,
+
+    /// Propose a config change; the keypair must be an admin and counts as the first approval
+    Propose {
+        #[arg(long)]
+        new_authority: Option<Pubkey>,
+
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+
+        #[arg(long)]
+        credit_mint: Option<Pubkey>,
+
+        /// Replace the admins (repeat for each)
+        #[arg(long = "admin")]
+        admins: Vec<Pubkey>,
+
+        #[arg(long)]
+        threshold: Option<u8>,
+
+        /// New delay in seconds (3600 to 2592000)
+        #[arg(long)]
+        delay: Option<i64>,
+    },
+
+    /// Approve a proposal; the keypair must be an admin
+    Approve { proposal_id: u64 },
+
+    /// Apply a proposal with enough approvals once its delay has passed
+    Execute { proposal_id: u64 },
+
+    /// Drop a pending proposal; the keypair must be an admin
+    Cancel { proposal_id: u64 },
+}
+
+#[derive(Subcommand)]
+enum RoleCommand {
+    /// Grant a role; the keypair must be the authority or, except for super-admin, a super-admin
+    Grant {
+        /// Wallet receiving the role
+        holder: Pubkey,
+
+        #[arg(value_enum)]
+        role: RoleArg,
+    },
+
+    /// Revoke a role; the keypair must be the authority or, except for super-admin, a super-admin
+    Revoke {
+        holder: Pubkey,
+
+        #[arg(value_enum)]
+        role: RoleArg,
+    },
+
+    /// List every role granted at the institution
+    List,
+}
+
+#[derive(Subcommand)]
+enum CourseCommand {
+    /// Create a course
+    Create {
+        course_id: String,
+        course_name: String,
+
+        #[arg(long)]
+        instructor: Pubkey,
+
+        /// Price of the course in credit tokens
+        #[arg(long)]
+        required_credits: u64,
+
+        #[arg(long, default_value_t = 3)]
+        credit_hours: u8,
+
+        #[arg(long, default_value_t = 3)]
+        max_attempts: u8,
+    },
+
+    /// Update a course; omitted fields keep their current value
+    Update {
+        course_id: String,
+
+        #[arg(long)]
+        name: Option<String>,
+
+        #[arg(long)]
+        instructor: Option<Pubkey>,
+
+        #[arg(long)]
+        required_credits: Option<u64>,
+
+        #[arg(long)]
+        credit_hours: Option<u8>,
+
+        #[arg(long)]
+        max_attempts: Option<u8>,
+
+        /// Open (true) or close (false) the course to new offerings and registrations
+        #[arg(long)]
+        active: Option<bool>,
+    },
+
+    /// List all courses
+    List,
+
+    /// Create and update courses so the chain matches a CSV or JSON catalog
+    Import {
+        file: PathBuf,
+
+        /// Catalog format; inferred from the file extension by default
+        #[arg(long, value_enum)]
+        format: Option<CatalogFormat>,
+    },
+
+    /// Export the on-chain catalog in the import format
+    Export {
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Catalog format; inferred from the output extension, CSV otherwise
+        #[arg(long, value_enum)]
+        format: Option<CatalogFormat>,
+    },
+
+    /// Accept a course at another institution as one of ours; the keypair must be the authority or a registrar
+    Equivalency {
+        /// Institution ID of the institution teaching the course
+        source_institution: String,
+        source_course_id: String,
+
+        /// Course it counts as at this institution
+        course_id: String,
+
+        /// Keypair of the source institution's authority or registrar, which co-signs
+        #[arg(long)]
+        source_keypair: String,
+    },
+}
+
+#[derive(Args)]
+struct OfferingArgs {
+    course_id: String,
+    term_id: String,
+}
+
+#[derive(Subcommand)]
+enum GradeCommand {
+    /// Grade a single student; the keypair must be the offering instructor
+    Set {
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        student: Pubkey,
+
+        /// Score between 0 and 100
+        grade: u8,
+    },
+
+    /// Grade many students from a CSV file with `student,grade` columns
+    Import {
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        file: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct MetadataArgs {
+    /// Certificate image URI
+    #[arg(long)]
+    image: String,
+
+    /// Link shown by wallets, e.g. the public verify page
+    #[arg(long)]
+    external_url: Option<String>,
+
+    /// Content-addressed directory the JSON is written to
+    #[arg(long, default_value = "certificate-metadata", env = "ACADEMIC_METADATA_STORE")]
+    store: PathBuf,
+}
+
+impl MetadataArgs {
+    fn links(&self) -> CertificateLinks {
+        CertificateLinks {
+            image: self.image.clone(),
+            external_url: self.external_url.clone(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum CertificateCommand {
+    /// Check that a certificate mint was issued by the program for a completed course
+    Verify {
+        mint: Pubkey,
+
+        /// Also check a downloaded metadata file against the hash on the enrollment
+        #[arg(long)]
+        metadata: Option<PathBuf>,
+    },
+
+    /// Build Metaplex metadata JSON from on-chain data and store it by SHA-256
+    Metadata {
+        /// Student wallet
+        #[arg(long)]
+        student: Pubkey,
+
+        /// Course of a course certificate; omit with --term for the graduation diploma
+        #[arg(long, requires = "term")]
+        course: Option<String>,
+
+        #[arg(long, requires = "course")]
+        term: Option<String>,
+
+        #[command(flatten)]
+        metadata: MetadataArgs,
+    },
+
+    /// Store metadata and mint the certificate with its hash; the keypair must be the student
+    Mint {
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        /// Where the JSON will be hosted; `{hash}` is replaced by its SHA-256
+        #[arg(long)]
+        uri: String,
+
+        #[command(flatten)]
+        metadata: MetadataArgs,
+    },
+
+    /// Export a certificate as a W3C Verifiable Credential signed by the keypair,
+    /// which must be the institution authority or a Registrar
+    Credential {
+        /// Student wallet
+        student: Pubkey,
+
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        /// DID method for the issuer and student: key, sol or sol:<cluster>
+        #[arg(long, default_value = "key")]
+        did: DidMethod,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check a Verifiable Credential's signature and its on-chain certificate
+    VerifyCredential { file: PathBuf },
+
+    /// Create a concurrent Merkle tree for compressed certificates; the keypair must be the authority or a reviewer
+    CreateTree {
+        /// Capacity is 2^depth certificates
+        #[arg(long, default_value_t = 14)]
+        depth: u32,
+
+        /// Concurrent changes the tree accepts per slot
+        #[arg(long, default_value_t = 64)]
+        buffer: u32,
+    },
+
+    /// Store metadata and append the certificate to a tree instead of minting; the keypair must be the student
+    MintCompressed {
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        /// Merkle tree of a certificate tree
+        #[arg(long)]
+        tree: Pubkey,
+
+        /// Where the JSON will be hosted; `{hash}` is replaced by its SHA-256
+        #[arg(long)]
+        uri: String,
+
+        #[command(flatten)]
+        metadata: MetadataArgs,
+    },
+
+    /// Check a compressed certificate against its tree's current root
+    VerifyCompressed {
+        /// Student wallet
+        student: Pubkey,
+
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        /// Also write the Merkle proof to a file
+        #[arg(long)]
+        proof: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProfileCommand {
+    /// Show a student's profile and enrollments
+    Show { student: Pubkey },
+
+    /// Transfer a passed course from another institution through a course equivalency;
+    /// the keypair must be the student
+    Transfer {
+        /// Institution ID of the institution the course was passed at
+        source_institution: String,
+        source_course_id: String,
+        source_term_id: String,
+
+        /// Equivalent course at this institution
+        course_id: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TranscriptCommand {
+    /// Sign a student's transcript and anchor its Merkle root; the keypair must be the authority or a registrar
+    Issue {
+        /// Student wallet
+        student: Pubkey,
+
+        /// Output file, `.json` or `.pdf` with the signed JSON attached
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// DID method for the issuer: key, sol or sol:<cluster>
+        #[arg(long, default_value = "key")]
+        did: DidMethod,
+    },
+
+    /// Check a transcript JSON or PDF against its on-chain anchor
+    Verify { file: PathBuf },
+
+    /// Extract one course with its Merkle proof from a signed transcript JSON or PDF
+    Disclose {
+        file: PathBuf,
+
+        #[arg(long)]
+        course: String,
+
+        /// Needed when the course was taken in more than one term
+        #[arg(long)]
+        term: Option<String>,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check a disclosed transcript entry against its on-chain anchor
+    VerifyEntry { file: PathBuf },
+}
+
+#[derive(Subcommand)]
+enum BatchCommand {
+    /// Post a batch root over the offering's uncertified completions; the keypair must be the instructor, a
+    /// reviewer or the authority
+    Create {
+        #[command(flatten)]
+        offering: OfferingArgs,
+
+        /// Manifest with every certificate in the batch
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Extract one student's certificate and Merkle proof from a batch manifest
+    Extract {
+        file: PathBuf,
+
+        /// Student wallet
+        student: Pubkey,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Link the enrollment to its batch certificate; the keypair must be the student
+    Claim { file: PathBuf },
+
+    /// Check a batch certificate's proof against the on-chain root
+    Verify { file: PathBuf },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(err) = run(cli) {
+        eprintln!("Error: {err:#}");
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> anyhow::Result<()> {
+    let ctx = Context::new(&cli.cluster, &cli.keypair, cli.institution, cli.dry_run, cli.json);
+
+    match cli.command {
+        Command::Init { name, treasury, credit_mint } => commands::init::run(&ctx, &name, treasury, credit_mint),
+        Command::Config(ConfigCommand::Show) => commands::config::show(&ctx),
+        Command::Config(ConfigCommand::Update { new_authority, treasury, credit_price }) => {
+            commands::config::update(&ctx, new_authority, treasury, credit_price)
+        }
+        Command::Governance(GovernanceCommand::Init { admins, threshold, delay }) => {
+            commands::governance::init(&ctx, admins, threshold, delay)
+        }
+        Command::Governance(GovernanceCommand::Show) => commands::governance::show(&ctx),
+        Command::Governance(GovernanceCommand::Propose {
+            new_authority,
+            treasury,
+            credit_mint,
+            admins,
+            threshold,
+            delay,
+        }) => commands::governance::propose(
+            &ctx,
+            ConfigChange {
+                authority: new_authority,
+                treasury,
+                credit_mint,
+                admins: (!admins.is_empty()).then_some(admins),
+                threshold,
+                min_delay: delay,
+            },
+        ),
+        Command::Governance(GovernanceCommand::Approve { proposal_id }) => {
+            commands::governance::approve(&ctx, proposal_id)
+        }
+        Command::Governance(GovernanceCommand::Execute { proposal_id }) => {
+            commands::governance::execute(&ctx, proposal_id)
+        }
+        Command::Governance(GovernanceCommand::Cancel { proposal_id }) => {
+            commands::governance::cancel(&ctx, proposal_id)
+        }
+        Command::Role(RoleCommand::Grant { holder, role }) => commands::role::grant(&ctx, &holder, role),
+        Command::Role(RoleCommand::Revoke { holder, role }) => commands::role::revoke(&ctx, &holder, role),
+        Command::Role(RoleCommand::List) => commands::role::list(&ctx),
+        Command::Course(CourseCommand::Create {
+            course_id,
+            course_name,
+            instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+        }) => commands::course::create(
+            &ctx,
+            &course_id,
+            &course_name,
+            &instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+        ),
+        Command::Course(CourseCommand::Update {
+            course_id,
+            name,
+            instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+            active,
+        }) => commands::course::update(
+            &ctx,
+            &course_id,
+            academic_chain_client::instructions::CourseUpdate {
+                course_name: name,
+                instructor,
+                required_credits,
+                credit_hours,
+                max_attempts,
+                is_active: active,
+            },
+        ),
+        Command::Course(CourseCommand::List) => commands::course::list(&ctx),
+        Command::Course(CourseCommand::Import { file, format }) => commands::catalog::import(&ctx, &file, format),
+        Command::Course(CourseCommand::Export { output, format }) => {
+            commands::catalog::export(&ctx, output.as_ref(), format)
+        }
+        Command::Course(CourseCommand::Equivalency {
+            source_institution,
+            source_course_id,
+            course_id,
+            source_keypair,
+        }) => commands::course::equivalency(&ctx, &source_institution, &source_course_id, &course_id, &source_keypair),
+        Command::Grade(GradeCommand::Set { offering, student, grade }) => {
+            commands::grade::set(&ctx, &offering.course_id, &offering.term_id, &student, grade)
+        }
+        Command::Grade(GradeCommand::Import { offering, file }) => {
+            commands::grade::import(&ctx, &offering.course_id, &offering.term_id, &file)
+        }
+        Command::Certificate(CertificateCommand::Verify { mint, metadata }) => {
+            commands::certificate::verify(&ctx, &mint, metadata.as_deref())
+        }
+        Command::Certificate(CertificateCommand::Metadata { student, course, term, metadata }) => {
+            commands::certificate::metadata(
+                &ctx,
+                &student,
+                course.as_deref().zip(term.as_deref()),
+                &metadata.links(),
+                &metadata.store,
+            )
+        }
+        Command::Certificate(CertificateCommand::Mint { offering, uri, metadata }) => commands::certificate::mint(
+            &ctx,
+            &offering.course_id,
+            &offering.term_id,
+            &uri,
+            &metadata.links(),
+            &metadata.store,
+        ),
+        Command::Certificate(CertificateCommand::Credential { student, offering, did, output }) => {
+            commands::certificate::credential(
+                &ctx,
+                &student,
+                &offering.course_id,
+                &offering.term_id,
+                &did,
+                output.as_deref(),
+            )
+        }
+        Command::Certificate(CertificateCommand::VerifyCredential { file }) => {
+            commands::certificate::verify_credential(&ctx, &file)
+        }
+        Command::Certificate(CertificateCommand::CreateTree { depth, buffer }) => {
+            commands::certificate::create_tree(&ctx, depth, buffer)
+        }
+        Command::Certificate(CertificateCommand::MintCompressed { offering, tree, uri, metadata }) => {
+            commands::certificate::mint_compressed(
+                &ctx,
+                &offering.course_id,
+                &offering.term_id,
+                &tree,
+                &uri,
+                &metadata.links(),
+                &metadata.store,
+            )
+        }
+        Command::Certificate(CertificateCommand::VerifyCompressed { student, offering, proof }) => {
+            commands::certificate::verify_compressed(
+                &ctx,
+                &student,
+                &offering.course_id,
+                &offering.term_id,
+                proof.as_deref(),
+            )
+        }
+        Command::Profile(ProfileCommand::Show { student }) => commands::profile::show(&ctx, &student),
+        Command::Profile(ProfileCommand::Transfer {
+            source_institution,
+            source_course_id,
+            source_term_id,
+            course_id,
+        }) => commands::profile::transfer(&ctx, &source_institution, &source_course_id, &source_term_id, &course_id),
+        Command::Transcript(TranscriptCommand::Issue { student, output, did }) => {
+            commands::transcript::issue(&ctx, &student, &did, &output)
+        }
+        Command::Transcript(TranscriptCommand::Verify { file }) => commands::transcript::verify(&ctx, &file),
+        Command::Transcript(TranscriptCommand::Disclose { file, course, term, output }) => {
+            commands::transcript::disclose(&file, &course, term.as_deref(), output.as_deref())
+        }
+        Command::Transcript(TranscriptCommand::VerifyEntry { file }) => commands::transcript::verify_entry(&ctx, &file),
+        Command::Batch(BatchCommand::Create { offering, output }) => {
+            commands::batch::create(&ctx, &offering.course_id, &offering.term_id, &output)
+        }
+        Command::Batch(BatchCommand::Extract { file, student, output }) => {
+            commands::batch::extract(&file, &student, output.as_deref())
+        }
+        Command::Batch(BatchCommand::Claim { file }) => commands::batch::claim(&ctx, &file),
+        Command::Batch(BatchCommand::Verify { file }) => commands::batch::verify(&ctx, &file),
+    }
+}
@@ -0,0 +1,306 @@
+//! Serializable views of program accounts and transaction results.
+
+use academic_chain::state::*;
+use academic_chain_client::metadata::hash_to_hex;
+use anchor_lang::prelude::Pubkey;
+use serde::Serialize;
+
+use crate::context::Context;
+
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TxOutcome {
+    Confirmed { signature: String },
+    Simulated { units_consumed: Option<u64>, logs: Vec<String> },
+}
+
+impl Context {
+    /// Print `value` as JSON with `--json`, otherwise with `human`
+    pub fn emit<T: Serialize>(&self, value: &T, human: impl FnOnce(&T)) -> anyhow::Result<()> {
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(value)?);
+        } else {
+            human(value);
+        }
+        Ok(())
+    }
+
+    /// Report a sent or simulated transaction
+    pub fn emit_tx(&self, action: &str, outcome: &TxOutcome) -> anyhow::Result<()> {
+        self.emit(outcome, |outcome| match outcome {
+            TxOutcome::Confirmed { signature } => {
+                println!("✅ {action}");
+                println!("Signature: {signature}");
+            }
+            TxOutcome::Simulated { units_consumed, logs } => {
+                println!("🧪 Simulated: {action}");
+                if let Some(units) = units_consumed {
+                    println!("Compute units: {units}");
+                }
+                for log in logs {
+                    println!("  {log}");
+                }
+            }
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct GradeScaleView {
+    pub min_score: u8,
+    pub letter: String,
+    pub grade_points: u16,
+}
+
+#[derive(Serialize)]
+pub struct InstitutionView {
+    pub address: String,
+    pub institution_id: String,
+    pub name: String,
+    pub authority: String,
+    pub treasury: String,
+    pub credit_mint: String,
+    pub credit_price: u64,
+    pub governed: bool,
+    pub grade_scale: Vec<GradeScaleView>,
+}
+
+impl InstitutionView {
+    pub fn new(address: &Pubkey, institution: &Institution) -> Self {
+        Self {
+            address: address.to_string(),
+            institution_id: institution.institution_id.clone(),
+            name: institution.name.clone(),
+            authority: institution.authority.to_string(),
+            treasury: institution.treasury.to_string(),
+            credit_mint: institution.credit_mint.to_string(),
+            credit_price: institution.credit_price,
+            governed: institution.governed,
+            grade_scale: institution
+                .grade_scale
+                .iter()
+                .map(|entry| GradeScaleView {
+                    min_score: entry.min_score,
+                    letter: entry.letter.clone(),
+                    grade_points: entry.grade_points,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CourseView {
+    pub address: String,
+    pub course_id: String,
+    pub course_name: String,
+    pub instructor: String,
+    pub required_credits: u64,
+    pub credit_hours: u8,
+    pub max_attempts: u8,
+    pub is_active: bool,
+    pub created_at: i64,
+}
+
+impl CourseView {
+    pub fn new(address: &Pubkey, course: &Course) -> Self {
+        Self {
+            address: address.to_string(),
+            course_id: course.course_id.clone(),
+            course_name: course.course_name.clone(),
+            instructor: course.instructor.to_string(),
+            required_credits: course.required_credits,
+            credit_hours: course.credit_hours,
+            max_attempts: course.max_attempts,
+            is_active: course.is_active,
+            created_at: course.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct GovernanceView {
+    pub address: String,
+    pub admins: Vec<String>,
+    pub threshold: u8,
+    pub min_delay: i64,
+    pub proposal_count: u64,
+    pub proposals: Vec<ProposalView>,
+}
+
+impl GovernanceView {
+    pub fn new(address: &Pubkey, governance: &ConfigGovernance, proposals: &[(Pubkey, ConfigProposal)]) -> Self {
+        Self {
+            address: address.to_string(),
+            admins: governance.admins.iter().map(ToString::to_string).collect(),
+            threshold: governance.threshold,
+            min_delay: governance.min_delay,
+            proposal_count: governance.proposal_count,
+            proposals: proposals
+                .iter()
+                .map(|(address, proposal)| ProposalView::new(address, proposal))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ProposalView {
+    pub address: String,
+    pub proposal_id: u64,
+    pub proposer: String,
+    pub changes: Vec<String>,  // `field: value` for each field the proposal sets
+    pub approvals: Vec<String>,
+    pub created_at: i64,
+    pub executable_at: i64,
+}
+
+impl ProposalView {
+    pub fn new(address: &Pubkey, proposal: &ConfigProposal) -> Self {
+        let change = &proposal.change;
+        let mut changes = Vec::new();
+        if let Some(authority) = change.authority {
+            changes.push(format!("authority: {authority}"));
+        }
+        if let Some(treasury) = change.treasury {
+            changes.push(format!("treasury: {treasury}"));
+        }
+        if let Some(credit_mint) = change.credit_mint {
+            changes.push(format!("credit_mint: {credit_mint}"));
+        }
+        if let Some(admins) = &change.admins {
+            let admins: Vec<String> = admins.iter().map(ToString::to_string).collect();
+            changes.push(format!("admins: {}", admins.join(", ")));
+        }
+        if let Some(threshold) = change.threshold {
+            changes.push(format!("threshold: {threshold}"));
+        }
+        if let Some(min_delay) = change.min_delay {
+            changes.push(format!("min_delay: {min_delay}"));
+        }
+
+        Self {
+            address: address.to_string(),
+            proposal_id: proposal.proposal_id,
+            proposer: proposal.proposer.to_string(),
+            changes,
+            approvals: proposal.approvals.iter().map(ToString::to_string).collect(),
+            created_at: proposal.created_at,
+            executable_at: proposal.executable_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct RoleView {
+    pub address: String,
+    pub holder: String,
+    pub role: &'static str,
+    pub granted_by: String,
+    pub granted_at: i64,
+}
+
+impl RoleView {
+    pub fn new(address: &Pubkey, assignment: &RoleAssignment, role: &'static str) -> Self {
+        Self {
+            address: address.to_string(),
+            holder: assignment.holder.to_string(),
+            role,
+            granted_by: assignment.granted_by.to_string(),
+            granted_at: assignment.granted_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct EnrollmentView {
+    pub address: String,
+    pub student: String,
+    pub course_id: String,
+    pub offering: String,
+    pub status: String,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,
+    pub credit_hours: u8,
+    pub enrollment_date: i64,
+    pub completion_date: Option<i64>,
+    pub certificate_mint: Option<String>,
+    pub metadata_hash: Option<String>,
+    pub transferred_from: Option<String>,
+    pub attempts: usize,
+}
+
+impl EnrollmentView {
+    pub fn new(address: &Pubkey, enrollment: &CourseEnrollment) -> Self {
+        Self {
+            address: address.to_string(),
+            student: enrollment.student.to_string(),
+            course_id: enrollment.course_id.clone(),
+            offering: enrollment.offering.to_string(),
+            status: status_name(&enrollment.status).to_string(),
+            grade: enrollment.grade,
+            letter_grade: enrollment.letter_grade.clone(),
+            grade_points: enrollment.grade_points,
+            credit_hours: enrollment.credit_hours,
+            enrollment_date: enrollment.enrollment_date,
+            completion_date: enrollment.completion_date,
+            certificate_mint: enrollment.certificate_mint.map(|mint| mint.to_string()),
+            metadata_hash: enrollment.metadata_hash.map(|hash| hash_to_hex(&hash)),
+            transferred_from: enrollment.transferred_from.map(|source| source.to_string()),
+            attempts: enrollment.attempts.len() + 1,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ProfileView {
+    pub address: String,
+    pub student: String,
+    pub display_name: Option<String>,
+    pub metadata_uri: Option<String>,
+    pub total_credits_purchased: u64,
+    pub total_credits_spent: u64,
+    pub courses_completed: u16,
+    pub open_enrollments: u16,
+    pub credit_hours_attempted: u16,
+    pub credit_hours_earned: u16,
+    pub gpa: String,
+    pub graduation_nft: Option<String>,
+    pub created_at: i64,
+    pub enrollments: Vec<EnrollmentView>,
+}
+
+impl ProfileView {
+    pub fn new(address: &Pubkey, profile: &StudentProfile, enrollments: Vec<EnrollmentView>) -> Self {
+        Self {
+            address: address.to_string(),
+            student: profile.student.to_string(),
+            display_name: profile.display_name.clone(),
+            metadata_uri: profile.metadata_uri.clone(),
+            total_credits_purchased: profile.total_credits_purchased,
+            total_credits_spent: profile.total_credits_spent,
+            courses_completed: profile.courses_completed,
+            open_enrollments: profile.open_enrollments,
+            credit_hours_attempted: profile.credit_hours_attempted,
+            credit_hours_earned: profile.credit_hours_earned,
+            gpa: format_gpa(profile.gpa),
+            graduation_nft: profile.graduation_nft.map(|mint| mint.to_string()),
+            created_at: profile.created_at,
+            enrollments,
+        }
+    }
+}
+
+pub fn status_name(status: &EnrollmentStatus) -> &'static str {
+    match status {
+        EnrollmentStatus::Enrolled => "enrolled",
+        EnrollmentStatus::Completed => "completed",
+        EnrollmentStatus::Incomplete => "incomplete",
+    }
+}
+
+/// GPA is stored x100 on-chain (350 = 3.50)
+pub fn format_gpa(gpa: u16) -> String {
+    format!("{}.{:02}", gpa / 100, gpa % 100)
+}
@@ -0,0 +1,191 @@
+//! Input validation that fails before any RPC call, so no validator is needed.
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_academic-cli"))
+        .args(["--cluster", "http://127.0.0.1:1", "--keypair", "/nonexistent/id.json", "--institution", "EXU"])
+        .args(args)
+        .output()
+        .expect("failed to run academic-cli")
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn write_csv(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("academic-cli-{}-{name}.csv", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+const STUDENT_A: &str = "9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F";
+const STUDENT_B: &str = "Count3AcZucFDPSFBAeHkQ6AvttieKUkyJ8HiQGhQwe";
+
+#[test]
+fn rejects_unknown_cluster() {
+    let output = Command::new(env!("CARGO_BIN_EXE_academic-cli"))
+        .args(["--cluster", "moonnet", "config", "show"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("unknown cluster `moonnet`"));
+}
+
+#[test]
+fn institution_commands_need_an_institution() {
+    let output = Command::new(env!("CARGO_BIN_EXE_academic-cli"))
+        .args(["--cluster", "http://127.0.0.1:1", "course", "list"])
+        .env_remove("ACADEMIC_INSTITUTION")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("no institution; pass --institution or set ACADEMIC_INSTITUTION"));
+}
+
+#[test]
+fn config_update_requires_a_field() {
+    let output = cli(&["config", "update"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("nothing to update"));
+}
+
+#[test]
+fn course_update_requires_a_field() {
+    let output = cli(&["course", "update", "SOL101"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("nothing to update"));
+}
+
+#[test]
+fn course_equivalency_needs_the_source_authority_keypair() {
+    let output = cli(&["course", "equivalency", "PTU", "BC200", "SOL101", "--source-keypair", "/nonexistent/ptu.json"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("failed to read keypair /nonexistent/ptu.json"));
+}
+
+#[test]
+fn course_create_checks_id_length() {
+    let long_id = "X".repeat(33);
+    let output = cli(&["course", "create", &long_id, "Name", "--instructor", STUDENT_A, "--required-credits", "5"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("course ID longer than 32 bytes"));
+}
+
+#[test]
+fn signing_commands_report_a_missing_keypair() {
+    let output = cli(&["grade", "set", "SOL101", "2025FA", STUDENT_A, "90"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("failed to read keypair /nonexistent/id.json"));
+}
+
+#[test]
+fn grade_import_rejects_out_of_range_grades() {
+    let file = write_csv("range", &format!("student,grade\n{STUDENT_A},90\n{STUDENT_B},101\n"));
+    let output = cli(&["grade", "import", "SOL101", "2025FA", file.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("line 3: grade must be between 0 and 100"));
+}
+
+#[test]
+fn grade_import_rejects_duplicate_students() {
+    let file = write_csv("duplicate", &format!("student,grade\n{STUDENT_A},90\n{STUDENT_A},80\n"));
+    let output = cli(&["grade", "import", "SOL101", "2025FA", file.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("line 3: duplicate student"));
+}
+
+#[test]
+fn grade_import_rejects_invalid_addresses() {
+    let file = write_csv("address", "student,grade\nnot-a-key,90\n");
+    let output = cli(&["grade", "import", "SOL101", "2025FA", file.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("line 2: invalid student address `not-a-key`"));
+}
+
+#[test]
+fn course_import_reports_every_invalid_row() {
+    let long_id = "X".repeat(33);
+    // Optional columns may be omitted entirely
+    let file = write_csv(
+        "catalog",
+        &format!(
+            "course_id,name,instructor,required_credits\n\
+             SOL101,Solana Fundamentals,{STUDENT_A},5\n\
+             {long_id},Too Long,{STUDENT_A},5\n\
+             SOL101,Duplicate,{STUDENT_A},5\n"
+        ),
+    );
+    let output = cli(&["course", "import", file.to_str().unwrap()]);
+    let stderr = stderr(&output);
+    assert!(!output.status.success());
+    assert!(stderr.contains("2 invalid catalog rows"));
+    assert!(stderr.contains("row 2 (XXXX"));
+    assert!(stderr.contains("row 3 (SOL101): duplicate course_id"));
+}
+
+#[test]
+fn course_import_reads_json_catalogs() {
+    let path = std::env::temp_dir().join(format!("academic-cli-{}-catalog.json", std::process::id()));
+    std::fs::write(
+        &path,
+        format!(r#"[{{"course_id": "SOL101", "name": "Solana", "instructor": "{STUDENT_A}", "required_credits": 5, "credit_hours": 13}}]"#),
+    )
+    .unwrap();
+    let output = cli(&["course", "import", path.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("row 1 (SOL101): credit_hours must be 1-12"));
+}
+
+#[test]
+fn certificate_metadata_needs_course_and_term_together() {
+    let output = cli(&["certificate", "metadata", "--student", STUDENT_A, "--course", "SOL101", "--image", "https://img"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--term <TERM>"));
+}
+
+#[test]
+fn transcript_issue_needs_a_json_or_pdf_output() {
+    let output = cli(&["transcript", "issue", STUDENT_A, "--output", "transcript.txt"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("output must be a .json or .pdf file"));
+}
+
+#[test]
+fn transcript_disclose_rejects_unsigned_documents() {
+    let file = write_csv("unsigned-transcript", r#"{"type": "AcademicTranscript"}"#);
+    let output = cli(&["transcript", "disclose", file.to_str().unwrap(), "--course", "SOL101"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("invalid transcript: malformed credential: missing issuer"));
+}
+
+#[test]
+fn batch_extract_checks_the_manifest_batch_address() {
+    let manifest = format!(
+        r#"{{"type": "CertificateBatchManifest", "programId": "", "institution": "{STUDENT_B}",
+            "courseId": "SOL101", "termId": "2025FA",
+            "merkleRoot": "{}", "certificateBatch": "{STUDENT_A}", "certificates": []}}"#,
+        "ab".repeat(32)
+    );
+    let file = write_csv("batch-manifest", &manifest);
+    let output = cli(&["batch", "extract", file.to_str().unwrap(), STUDENT_B]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains(&format!("invalid manifest: malformed batch: certificateBatch {STUDENT_A}")));
+}
+
+#[test]
+fn certificate_create_tree_rejects_unsupported_sizes() {
+    let output = cli(&["certificate", "create-tree", "--depth", "14", "--buffer", "100"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("depth 14 with buffer 100 is not a tree size the compression program supports"));
+}
+
+#[test]
+fn role_grant_rejects_unknown_roles() {
+    let output = cli(&["role", "grant", "11111111111111111111111111111111", "dean"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("invalid value 'dean'"));
+    assert!(stderr(&output).contains("course-manager"));
+}
@@ -0,0 +1,207 @@
+//! Account fetch and deserialize helpers.
+
+use std::collections::HashSet;
+
+use academic_chain::state::*;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_account_decoder_client_types::UiAccountEncoding;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_rpc_client_api::config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_rpc_client_api::filter::{Memcmp, RpcFilterType};
+
+use crate::error::{Error, Result};
+use crate::pda::*;
+
+/// Deserialize raw account data, checking the Anchor discriminator
+pub fn deserialize_account<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|err| Error::Deserialize(*address, Box::new(err)))
+}
+
+/// Fetch and deserialize any program account
+pub fn fetch_account<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .ok_or(Error::AccountNotFound(*address))?;
+    deserialize_account(address, &account.data)
+}
+
+/// Fetch an account, returning `None` when it does not exist
+pub fn fetch_account_optional<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    match fetch_account(rpc, address) {
+        Ok(account) => Ok(Some(account)),
+        Err(Error::AccountNotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn fetch_institution(rpc: &RpcClient, institution_id: &str) -> Result<Institution> {
+    fetch_account(rpc, &institution_pda(institution_id).0)
+}
+
+pub fn fetch_course(rpc: &RpcClient, institution: &Pubkey, course_id: &str) -> Result<Course> {
+    fetch_account(rpc, &course_pda(institution, course_id).0)
+}
+
+pub fn fetch_term(rpc: &RpcClient, institution: &Pubkey, term_id: &str) -> Result<Term> {
+    fetch_account(rpc, &term_pda(institution, term_id).0)
+}
+
+pub fn fetch_offering(rpc: &RpcClient, institution: &Pubkey, course_id: &str, term_id: &str) -> Result<CourseOffering> {
+    fetch_account(rpc, &offering_pda(institution, course_id, term_id).0)
+}
+
+pub fn fetch_enrollment(
+    rpc: &RpcClient,
+    institution: &Pubkey,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+) -> Result<CourseEnrollment> {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    fetch_account(rpc, &enrollment_pda(student, &offering).0)
+}
+
+/// The closed enrollment an `EnrollmentRecord` was left by. Credits paid and
+/// earlier attempts are not kept and come back empty.
+pub fn recorded_enrollment(record: &EnrollmentRecord) -> CourseEnrollment {
+    let entry = &record.entry;
+    CourseEnrollment {
+        student: record.student,
+        course_id: entry.course_id.clone(),
+        offering: record.offering,
+        credits_paid: 0,
+        enrollment_date: record.enrollment_date,
+        completion_date: entry.completion_date,
+        status: entry.status,
+        grade: entry.grade,
+        letter_grade: entry.letter_grade.clone(),
+        grade_points: record.grade_points,
+        credit_hours: entry.credit_hours,
+        certificate_mint: record.certificate_mint,
+        metadata_hash: record.metadata_hash,
+        certificate_batch: record.certificate_batch,
+        compressed_certificate: record.compressed_certificate,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 0,
+    }
+}
+
+/// The enrollment at `address`, or once it is closed, the one its
+/// `EnrollmentRecord` keeps; `None` when neither exists
+pub fn fetch_enrollment_or_record(rpc: &RpcClient, address: &Pubkey) -> Result<Option<CourseEnrollment>> {
+    if let Some(enrollment) = fetch_account_optional(rpc, address)? {
+        return Ok(Some(enrollment));
+    }
+    let record: Option<EnrollmentRecord> = fetch_account_optional(rpc, &enrollment_record_pda(address).0)?;
+    Ok(record.as_ref().map(recorded_enrollment))
+}
+
+pub fn fetch_student_profile(rpc: &RpcClient, institution: &Pubkey, student: &Pubkey) -> Result<StudentProfile> {
+    fetch_account(rpc, &student_profile_pda(institution, student).0)
+}
+
+/// Fetch every account of type `T`, filtered by discriminator and optional extra filters
+pub fn fetch_all<T: AccountDeserialize + Discriminator>(
+    rpc: &RpcClient,
+    mut filters: Vec<RpcFilterType>,
+) -> Result<Vec<(Pubkey, T)>> {
+    filters.insert(0, RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, T::DISCRIMINATOR)));
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    rpc.get_program_accounts_with_config(&academic_chain::ID, config)?
+        .into_iter()
+        .map(|(address, account)| Ok((address, deserialize_account(&address, &account.data)?)))
+        .collect()
+}
+
+// Memcmp filter on the `institution` field that courses, offerings, course
+// equivalencies, role assignments and config proposals start with
+fn institution_filter(institution: &Pubkey) -> RpcFilterType {
+    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, institution.as_ref()))
+}
+
+/// All courses in an institution's catalog
+pub fn fetch_all_courses(rpc: &RpcClient, institution: &Pubkey) -> Result<Vec<(Pubkey, Course)>> {
+    fetch_all(rpc, vec![institution_filter(institution)])
+}
+
+/// All offerings of an institution
+pub fn fetch_institution_offerings(rpc: &RpcClient, institution: &Pubkey) -> Result<Vec<(Pubkey, CourseOffering)>> {
+    fetch_all(rpc, vec![institution_filter(institution)])
+}
+
+/// Course equivalencies an institution accepts from other institutions
+pub fn fetch_institution_equivalencies(
+    rpc: &RpcClient,
+    institution: &Pubkey,
+) -> Result<Vec<(Pubkey, CourseEquivalency)>> {
+    fetch_all(rpc, vec![institution_filter(institution)])
+}
+
+/// Role assignments at an institution
+pub fn fetch_institution_roles(rpc: &RpcClient, institution: &Pubkey) -> Result<Vec<(Pubkey, RoleAssignment)>> {
+    fetch_all(rpc, vec![institution_filter(institution)])
+}
+
+/// Config governance of an institution, `None` when it has none
+pub fn fetch_config_governance(rpc: &RpcClient, institution: &Pubkey) -> Result<Option<ConfigGovernance>> {
+    fetch_account_optional(rpc, &config_governance_pda(institution).0)
+}
+
+/// Config proposals of an institution that were neither executed nor cancelled
+pub fn fetch_config_proposals(rpc: &RpcClient, institution: &Pubkey) -> Result<Vec<(Pubkey, ConfigProposal)>> {
+    fetch_all(rpc, vec![institution_filter(institution)])
+}
+
+/// All enrollments of a student at an institution, including completions
+/// transferred in and closed enrollments rebuilt from their records.
+/// `student` is the first field after the discriminator of both; enrollments
+/// at other institutions are dropped by their offering, which for a transfer
+/// is the course equivalency.
+pub fn fetch_student_enrollments(
+    rpc: &RpcClient,
+    institution: &Pubkey,
+    student: &Pubkey,
+) -> Result<Vec<(Pubkey, CourseEnrollment)>> {
+    let offerings: HashSet<Pubkey> = fetch_institution_offerings(rpc, institution)?
+        .into_iter()
+        .map(|(address, _)| address)
+        .chain(fetch_institution_equivalencies(rpc, institution)?.into_iter().map(|(address, _)| address))
+        .collect();
+    let offset = CourseEnrollment::DISCRIMINATOR.len();
+    let filter = || vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, student.as_ref()))];
+    let closed = fetch_all::<EnrollmentRecord>(rpc, filter())?
+        .into_iter()
+        .map(|(_, record)| (record.entry.enrollment, recorded_enrollment(&record)));
+    Ok(fetch_all::<CourseEnrollment>(rpc, filter())?
+        .into_iter()
+        .chain(closed)
+        .filter(|(_, enrollment)| offerings.contains(&enrollment.offering))
+        .collect())
+}
+
+/// All enrollments in an offering. The offering sits after the variable-length
+/// course id, so enrollments are filtered client-side.
+pub fn fetch_offering_enrollments(
+    rpc: &RpcClient,
+    offering: &Pubkey,
+) -> Result<Vec<(Pubkey, CourseEnrollment)>> {
+    Ok(fetch_all::<CourseEnrollment>(rpc, Vec::new())?
+        .into_iter()
+        .filter(|(_, enrollment)| enrollment.offering == *offering)
+        .collect())
+}
@@ -0,0 +1,334 @@
+//! Merkle-batched course certificates.
+//!
+//! Instead of a mint per student, the instructor or authority posts one
+//! `CertificateBatch` holding the Merkle root over an offering's completed
+//! enrollments. Each leaf is `academic_chain::utils::certificate_leaf` of the
+//! student, course, grade and completion date, all read from the enrollment,
+//! so a batch can only certify what the enrollment already records.
+//!
+//! The issuer keeps the `BatchManifest` listing every certificate and hands
+//! each student a `BatchCertificate`: one leaf plus its proof. The student
+//! links their enrollment to the batch with `claim_batch_certificate`;
+//! anyone can check a certificate offline, against the stored root, or
+//! on-chain with `verify_batch_certificate`.
+
+use academic_chain::state::{CertificateBatch, CertificateLeaf, CourseEnrollment, EnrollmentStatus};
+use academic_chain::utils::{certificate_leaf, merkle_proof, merkle_root, verify_merkle_proof};
+use academic_chain::ID;
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::accounts::{fetch_account_optional, fetch_enrollment_or_record};
+use crate::error::Result;
+use crate::metadata::{hash_from_hex, hash_to_hex};
+use crate::pda::{certificate_batch_pda, enrollment_pda, offering_pda};
+
+pub const MANIFEST_TYPE: &str = "CertificateBatchManifest";
+pub const CERTIFICATE_TYPE: &str = "BatchCertificate";
+
+/// The leaf an enrollment contributes to a batch; `None` unless it is
+/// completed and has no certificate yet
+pub fn batch_leaf(enrollment: &CourseEnrollment) -> Option<CertificateLeaf> {
+    if enrollment.status != EnrollmentStatus::Completed
+        || enrollment.certificate_mint.is_some()
+        || enrollment.certificate_batch.is_some()
+        || enrollment.compressed_certificate.is_some()
+    {
+        return None;
+    }
+    Some(CertificateLeaf {
+        student: enrollment.student,
+        course_id: enrollment.course_id.clone(),
+        grade: enrollment.grade,
+        completion_date: enrollment.completion_date?,
+    })
+}
+
+/// One certified enrollment
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEntry {
+    pub student: String,
+    pub enrollment: String,
+    pub grade: u8,
+    pub completion_date: i64,
+}
+
+impl BatchEntry {
+    /// The Merkle leaf for this entry; `None` if the student does not parse
+    pub fn leaf(&self, course_id: &str) -> Option<CertificateLeaf> {
+        Some(CertificateLeaf {
+            student: self.student.parse().ok()?,
+            course_id: course_id.to_string(),
+            grade: self.grade,
+            completion_date: self.completion_date,
+        })
+    }
+}
+
+/// Every certificate of a batch, kept by the issuer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchManifest {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub program_id: String,
+    pub institution: String,
+    pub course_id: String,
+    pub term_id: String,
+    pub merkle_root: String,
+    pub certificate_batch: String,
+    /// Sorted by student
+    pub certificates: Vec<BatchEntry>,
+}
+
+impl BatchManifest {
+    /// A batch over the eligible enrollments of the offering; others are skipped
+    pub fn new(
+        institution: &Pubkey,
+        course_id: &str,
+        term_id: &str,
+        enrollments: &[(Pubkey, CourseEnrollment)],
+    ) -> Self {
+        let offering = offering_pda(institution, course_id, term_id).0;
+        let mut certified: Vec<(&Pubkey, CertificateLeaf)> = enrollments
+            .iter()
+            .filter(|(_, enrollment)| enrollment.offering == offering)
+            .filter_map(|(address, enrollment)| Some((address, batch_leaf(enrollment)?)))
+            .collect();
+        certified.sort_by_key(|(_, leaf)| leaf.student);
+
+        let leaves: Vec<[u8; 32]> = certified.iter().map(|(_, leaf)| certificate_leaf(leaf)).collect();
+        let root = merkle_root(&leaves);
+        Self {
+            kind: MANIFEST_TYPE.to_string(),
+            program_id: ID.to_string(),
+            institution: institution.to_string(),
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            merkle_root: hash_to_hex(&root),
+            certificate_batch: certificate_batch_pda(&offering, &root).0.to_string(),
+            certificates: certified
+                .into_iter()
+                .map(|(address, leaf)| BatchEntry {
+                    student: leaf.student.to_string(),
+                    enrollment: address.to_string(),
+                    grade: leaf.grade,
+                    completion_date: leaf.completion_date,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn leaves(&self) -> std::result::Result<Vec<[u8; 32]>, BatchFailure> {
+        self.certificates
+            .iter()
+            .map(|entry| {
+                let leaf = entry
+                    .leaf(&self.course_id)
+                    .ok_or_else(|| BatchFailure::Malformed(format!("student {}", entry.student)))?;
+                Ok(certificate_leaf(&leaf))
+            })
+            .collect()
+    }
+
+    pub fn root(&self) -> std::result::Result<[u8; 32], BatchFailure> {
+        hash_from_hex(&self.merkle_root).ok_or_else(|| BatchFailure::Malformed("merkleRoot".to_string()))
+    }
+
+    /// The certificate of `student` with its inclusion proof
+    pub fn certificate(&self, student: &Pubkey) -> Option<BatchCertificate> {
+        let student = student.to_string();
+        let index = self.certificates.iter().position(|entry| entry.student == student)?;
+        let proof = merkle_proof(&self.leaves().ok()?, index)?;
+        Some(BatchCertificate {
+            kind: CERTIFICATE_TYPE.to_string(),
+            program_id: self.program_id.clone(),
+            institution: self.institution.clone(),
+            course_id: self.course_id.clone(),
+            term_id: self.term_id.clone(),
+            merkle_root: self.merkle_root.clone(),
+            certificate_batch: self.certificate_batch.clone(),
+            certificate: self.certificates[index].clone(),
+            proof: proof.iter().map(hash_to_hex).collect(),
+        })
+    }
+}
+
+/// One student's certificate with the sibling hashes linking it to the batch root
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCertificate {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub program_id: String,
+    pub institution: String,
+    pub course_id: String,
+    pub term_id: String,
+    pub merkle_root: String,
+    pub certificate_batch: String,
+    pub certificate: BatchEntry,
+    /// Hex sibling hashes, leaf level first
+    pub proof: Vec<String>,
+}
+
+impl BatchCertificate {
+    pub fn institution(&self) -> std::result::Result<Pubkey, BatchFailure> {
+        parse_institution(&self.institution)
+    }
+
+    pub fn root(&self) -> std::result::Result<[u8; 32], BatchFailure> {
+        hash_from_hex(&self.merkle_root).ok_or_else(|| BatchFailure::Malformed("merkleRoot".to_string()))
+    }
+
+    /// The leaf and proof as `verify_batch_certificate` takes them
+    pub fn instruction_args(&self) -> std::result::Result<(CertificateLeaf, Vec<[u8; 32]>), BatchFailure> {
+        let leaf = self
+            .certificate
+            .leaf(&self.course_id)
+            .ok_or_else(|| BatchFailure::Malformed(format!("student {}", self.certificate.student)))?;
+        let proof = self
+            .proof
+            .iter()
+            .map(|node| hash_from_hex(node).ok_or_else(|| BatchFailure::Malformed(format!("proof node {node}"))))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok((leaf, proof))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BatchFailure {
+    #[error("malformed batch: {0}")]
+    Malformed(String),
+
+    #[error("certificates do not hash to the stated Merkle root")]
+    RootMismatch,
+
+    #[error("Merkle proof does not lead from the certificate to the root")]
+    InvalidProof,
+
+    #[error("certificate batch is not on-chain")]
+    NotCreated,
+
+    #[error("on-chain batch does not match the stated offering or root")]
+    BatchMismatch,
+}
+
+/// The outcome of verifying one batch certificate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchVerification {
+    pub certificate_batch: Option<Pubkey>,
+    pub issuer: Option<Pubkey>,
+    /// Whether the student's enrollment links to this batch
+    pub claimed: bool,
+    pub failure: Option<BatchFailure>,
+}
+
+impl BatchVerification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+fn parse_institution(institution: &str) -> std::result::Result<Pubkey, BatchFailure> {
+    institution
+        .parse()
+        .map_err(|_| BatchFailure::Malformed(format!("institution {institution}")))
+}
+
+fn check_batch_address(
+    institution: &str,
+    course_id: &str,
+    term_id: &str,
+    root: &[u8; 32],
+    certificate_batch: &str,
+) -> std::result::Result<Pubkey, BatchFailure> {
+    let offering = offering_pda(&parse_institution(institution)?, course_id, term_id).0;
+    let address = certificate_batch_pda(&offering, root).0;
+    if address.to_string() != certificate_batch {
+        return Err(BatchFailure::Malformed(format!("certificateBatch {certificate_batch}")));
+    }
+    Ok(address)
+}
+
+/// Check that a manifest's certificates hash to its root, offline
+pub fn check_manifest(manifest: &BatchManifest) -> std::result::Result<(), BatchFailure> {
+    if manifest.kind != MANIFEST_TYPE {
+        return Err(BatchFailure::Malformed(format!("type {}", manifest.kind)));
+    }
+    let root = manifest.root()?;
+    check_batch_address(
+        &manifest.institution,
+        &manifest.course_id,
+        &manifest.term_id,
+        &root,
+        &manifest.certificate_batch,
+    )?;
+    if merkle_root(&manifest.leaves()?) != root {
+        return Err(BatchFailure::RootMismatch);
+    }
+    Ok(())
+}
+
+/// Check that a certificate's proof leads to the stated root, offline
+pub fn check_certificate(certificate: &BatchCertificate) -> std::result::Result<(), BatchFailure> {
+    if certificate.kind != CERTIFICATE_TYPE {
+        return Err(BatchFailure::Malformed(format!("type {}", certificate.kind)));
+    }
+    let root = certificate.root()?;
+    check_batch_address(
+        &certificate.institution,
+        &certificate.course_id,
+        &certificate.term_id,
+        &root,
+        &certificate.certificate_batch,
+    )?;
+    let (leaf, proof) = certificate.instruction_args()?;
+    if !verify_merkle_proof(&certificate_leaf(&leaf), &proof, &root) {
+        return Err(BatchFailure::InvalidProof);
+    }
+    Ok(())
+}
+
+/// Check a certificate that passed `check_certificate` against the batch
+/// stored for its offering and root
+pub fn check_batch(
+    certificate: &BatchCertificate,
+    batch: Option<&CertificateBatch>,
+) -> std::result::Result<(), BatchFailure> {
+    let batch = batch.ok_or(BatchFailure::NotCreated)?;
+    let offering = offering_pda(&certificate.institution()?, &certificate.course_id, &certificate.term_id).0;
+    if batch.offering != offering || batch.merkle_root != certificate.root()? {
+        return Err(BatchFailure::BatchMismatch);
+    }
+    Ok(())
+}
+
+/// Verify a certificate's proof, then that its root is stored on-chain, and
+/// report whether the student has claimed it
+pub fn verify_batch_certificate(rpc: &RpcClient, certificate: &BatchCertificate) -> Result<BatchVerification> {
+    let failed = |failure| BatchVerification {
+        certificate_batch: None,
+        issuer: None,
+        claimed: false,
+        failure: Some(failure),
+    };
+    let checked = check_certificate(certificate)
+        .and_then(|()| Ok((certificate.instruction_args()?.0, certificate.root()?, certificate.institution()?)));
+    let (leaf, root, institution) = match checked {
+        Ok(checked) => checked,
+        Err(failure) => return Ok(failed(failure)),
+    };
+    let offering = offering_pda(&institution, &certificate.course_id, &certificate.term_id).0;
+    let address = certificate_batch_pda(&offering, &root).0;
+
+    let batch: Option<CertificateBatch> = fetch_account_optional(rpc, &address)?;
+    let enrollment = fetch_enrollment_or_record(rpc, &enrollment_pda(&leaf.student, &offering).0)?;
+    Ok(BatchVerification {
+        certificate_batch: batch.as_ref().map(|_| address),
+        issuer: batch.as_ref().map(|batch| batch.issuer),
+        claimed: enrollment.is_some_and(|enrollment| enrollment.certificate_batch == Some(address)),
+        failure: check_batch(certificate, batch.as_ref()).err(),
+    })
+}
@@ -0,0 +1,260 @@
+//! Course catalog rows, validation and diffing against on-chain courses.
+//!
+//! A catalog is a list of `CatalogEntry` rows, read from CSV or JSON by the
+//! caller. `plan` compares it with the courses already on-chain and returns
+//! only the creates and updates needed to make the chain match.
+
+use std::collections::{BTreeMap, HashSet};
+
+use academic_chain::constants::{MAX_COURSE_ATTEMPTS, MAX_CREDIT_HOURS};
+use academic_chain::state::Course;
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+
+use crate::instructions::{self, CourseUpdate};
+
+/// Credit hours used when a new course row leaves them blank
+pub const DEFAULT_CREDIT_HOURS: u8 = 3;
+/// Attempts allowed when a new course row leaves them blank
+pub const DEFAULT_MAX_ATTEMPTS: u8 = 3;
+
+/// One course in a catalog file. Optional columns left blank keep the
+/// on-chain value for existing courses and use the defaults for new ones.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    pub course_id: String,
+    #[serde(rename = "name", alias = "course_name")]
+    pub course_name: String,
+    #[serde(with = "pubkey_string")]
+    pub instructor: Pubkey,
+    pub required_credits: u64,
+    #[serde(default)]
+    pub credit_hours: Option<u8>,
+    #[serde(default)]
+    pub max_attempts: Option<u8>,
+    #[serde(default)]
+    pub is_active: Option<bool>,
+}
+
+impl CatalogEntry {
+    /// Export an on-chain course with every column filled in
+    pub fn from_course(course: &Course) -> Self {
+        Self {
+            course_id: course.course_id.clone(),
+            course_name: course.course_name.clone(),
+            instructor: course.instructor,
+            required_credits: course.required_credits,
+            credit_hours: Some(course.credit_hours),
+            max_attempts: Some(course.max_attempts),
+            is_active: Some(course.is_active),
+        }
+    }
+
+    /// Apply the same checks as `create_course` so bad rows fail before sending
+    pub fn validate(&self) -> Result<(), String> {
+        if self.course_id.is_empty() || self.course_id.len() > Course::MAX_ID_LEN {
+            return Err(format!("course_id must be 1-{} bytes", Course::MAX_ID_LEN));
+        }
+        if self.course_name.len() > Course::MAX_NAME_LEN {
+            return Err(format!("name longer than {} bytes", Course::MAX_NAME_LEN));
+        }
+        if self.required_credits == 0 {
+            return Err("required_credits must be greater than 0".to_string());
+        }
+        if let Some(credit_hours) = self.credit_hours {
+            if credit_hours == 0 || credit_hours > MAX_CREDIT_HOURS {
+                return Err(format!("credit_hours must be 1-{MAX_CREDIT_HOURS}"));
+            }
+        }
+        if let Some(max_attempts) = self.max_attempts {
+            if max_attempts == 0 || max_attempts > MAX_COURSE_ATTEMPTS {
+                return Err(format!("max_attempts must be 1-{MAX_COURSE_ATTEMPTS}"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fields that differ from `course`; blank optional columns never differ
+    fn update_for(&self, course: &Course) -> CourseUpdate {
+        fn changed<T: PartialEq + Clone>(wanted: Option<&T>, current: &T) -> Option<T> {
+            wanted.filter(|wanted| *wanted != current).cloned()
+        }
+
+        CourseUpdate {
+            course_name: changed(Some(&self.course_name), &course.course_name),
+            instructor: changed(Some(&self.instructor), &course.instructor),
+            required_credits: changed(Some(&self.required_credits), &course.required_credits),
+            credit_hours: changed(self.credit_hours.as_ref(), &course.credit_hours),
+            max_attempts: changed(self.max_attempts.as_ref(), &course.max_attempts),
+            is_active: changed(self.is_active.as_ref(), &course.is_active),
+        }
+    }
+}
+
+/// A catalog row that failed validation; `row` is 1-based
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[error("row {row} ({course_id}): {reason}")]
+pub struct CatalogError {
+    pub row: usize,
+    pub course_id: String,
+    pub reason: String,
+}
+
+/// Validate every row and reject duplicate course IDs
+pub fn validate(entries: &[CatalogEntry]) -> Result<(), Vec<CatalogError>> {
+    let mut seen = HashSet::new();
+    let errors: Vec<CatalogError> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| {
+            let reason = match entry.validate() {
+                Err(reason) => reason,
+                Ok(()) if !seen.insert(entry.course_id.as_str()) => "duplicate course_id".to_string(),
+                Ok(()) => return None,
+            };
+            Some(CatalogError {
+                row: index + 1,
+                course_id: entry.course_id.clone(),
+                reason,
+            })
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// A change needed to bring one on-chain course in line with the catalog
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CatalogChange {
+    Create(CatalogEntry),
+    Update { course_id: String, update: CourseUpdate },
+}
+
+impl CatalogChange {
+    pub fn course_id(&self) -> &str {
+        match self {
+            CatalogChange::Create(entry) => &entry.course_id,
+            CatalogChange::Update { course_id, .. } => course_id,
+        }
+    }
+
+    /// Instructions for this change, signed by the institution `authority`
+    /// or a CourseManager passing its assignment as `authority_role`
+    pub fn instructions(
+        &self,
+        institution: &Pubkey,
+        authority: &Pubkey,
+        authority_role: Option<Pubkey>,
+    ) -> Vec<anchor_lang::solana_program::instruction::Instruction> {
+        match self {
+            CatalogChange::Create(entry) => {
+                let mut ixs = vec![instructions::create_course(
+                    institution,
+                    authority,
+                    authority_role,
+                    &entry.course_id,
+                    &entry.course_name,
+                    &entry.instructor,
+                    entry.required_credits,
+                    entry.credit_hours.unwrap_or(DEFAULT_CREDIT_HOURS),
+                    entry.max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS),
+                )];
+                // Courses are created active
+                if entry.is_active == Some(false) {
+                    ixs.push(instructions::update_course(
+                        institution,
+                        authority,
+                        authority_role,
+                        &entry.course_id,
+                        CourseUpdate { is_active: Some(false), ..CourseUpdate::default() },
+                    ));
+                }
+                ixs
+            }
+            CatalogChange::Update { course_id, update } => {
+                vec![instructions::update_course(institution, authority, authority_role, course_id, update.clone())]
+            }
+        }
+    }
+}
+
+/// Result of diffing a catalog against the chain
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CatalogPlan {
+    pub changes: Vec<CatalogChange>,
+    /// Catalog courses already matching the chain
+    pub unchanged: Vec<String>,
+    /// On-chain courses missing from the catalog; left untouched
+    pub unlisted: Vec<String>,
+}
+
+/// Diff validated `entries` against `on_chain` courses
+pub fn plan(entries: &[CatalogEntry], on_chain: &[Course]) -> CatalogPlan {
+    let existing: BTreeMap<&str, &Course> = on_chain
+        .iter()
+        .map(|course| (course.course_id.as_str(), course))
+        .collect();
+
+    let mut plan = CatalogPlan::default();
+    for entry in entries {
+        match existing.get(entry.course_id.as_str()) {
+            None => plan.changes.push(CatalogChange::Create(entry.clone())),
+            Some(course) => {
+                let update = entry.update_for(course);
+                if update == CourseUpdate::default() {
+                    plan.unchanged.push(entry.course_id.clone());
+                } else {
+                    plan.changes.push(CatalogChange::Update {
+                        course_id: entry.course_id.clone(),
+                        update,
+                    });
+                }
+            }
+        }
+    }
+
+    let listed: HashSet<&str> = entries.iter().map(|entry| entry.course_id.as_str()).collect();
+    plan.unlisted = existing
+        .keys()
+        .filter(|course_id| !listed.contains(*course_id))
+        .map(|course_id| course_id.to_string())
+        .collect();
+
+    plan
+}
+
+/// Names of the fields an update changes
+pub fn changed_fields(update: &CourseUpdate) -> Vec<&'static str> {
+    [
+        ("name", update.course_name.is_some()),
+        ("instructor", update.instructor.is_some()),
+        ("required_credits", update.required_credits.is_some()),
+        ("credit_hours", update.credit_hours.is_some()),
+        ("max_attempts", update.max_attempts.is_some()),
+        ("is_active", update.is_active.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, changed)| changed.then_some(name))
+    .collect()
+}
+
+// Catalog files carry addresses as base58 strings
+mod pubkey_string {
+    use anchor_lang::prelude::Pubkey;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|_| D::Error::custom(format!("invalid address `{value}`")))
+    }
+}
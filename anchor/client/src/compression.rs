@@ -0,0 +1,463 @@
+//! Compressed course certificates.
+//!
+//! Instead of a mint, a student can have their certificate appended as a leaf
+//! to an spl-account-compression concurrent Merkle tree owned by a
+//! `CertificateTree` PDA. The leaf is
+//! `academic_chain::utils::compressed_certificate_leaf` of the enrollment, and
+//! the enrollment records the tree, index and leaf hash in place of
+//! `certificate_mint`.
+//!
+//! The tree account only stores its current root, so a proof needs every
+//! leaf. They are rebuilt from the enrollments that record a leaf in the
+//! tree; once one of them is closed its leaf has to come from an indexer
+//! (the `compressed_certificates` table) and `proof_from_leaves` is used
+//! directly.
+
+use academic_chain::constants::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use academic_chain::state::{
+    CompressedCertificate, CompressedCertificateLeaf, CourseEnrollment, EnrollmentRecord, EnrollmentStatus,
+};
+use academic_chain::utils::{compressed_certificate_leaf, empty_tree_node, recompute_tree_root, tree_parent};
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_instruction;
+use serde::{Deserialize, Serialize};
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::accounts::{fetch_all, fetch_enrollment_or_record};
+use crate::error::Result;
+use crate::metadata::{hash_from_hex, hash_to_hex};
+
+// Anchor discriminator of the compression program's `verify_leaf`
+const VERIFY_LEAF: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+/// Account type, version, buffer size, depth, authority, creation slot, padding
+pub const TREE_HEADER_LEN: usize = 1 + 1 + 4 + 4 + 32 + 8 + 6;
+
+/// (max_depth, max_buffer_size) pairs the compression program has a layout for
+pub const TREE_SIZES: &[(u32, u32)] = &[
+    (3, 8),
+    (5, 8),
+    (6, 16),
+    (7, 16),
+    (8, 16),
+    (9, 16),
+    (10, 32),
+    (11, 32),
+    (12, 32),
+    (13, 32),
+    (14, 64),
+    (14, 256),
+    (14, 1024),
+    (14, 2048),
+    (15, 64),
+    (16, 64),
+    (17, 64),
+    (18, 64),
+    (19, 64),
+    (20, 64),
+    (20, 256),
+    (20, 1024),
+    (20, 2048),
+    (24, 64),
+    (24, 256),
+    (24, 512),
+    (24, 1024),
+    (24, 2048),
+    (26, 512),
+    (26, 1024),
+    (26, 2048),
+    (30, 512),
+    (30, 1024),
+    (30, 2048),
+];
+
+pub fn is_supported_tree_size(max_depth: u32, max_buffer_size: u32) -> bool {
+    TREE_SIZES.contains(&(max_depth, max_buffer_size))
+}
+
+fn change_log_len(max_depth: usize) -> usize {
+    // root, path, index, padding
+    32 + max_depth * 32 + 4 + 4
+}
+
+/// Size of a tree account without a canopy
+pub fn merkle_tree_account_size(max_depth: u32, max_buffer_size: u32) -> usize {
+    let depth = max_depth as usize;
+    // sequence number, active index, buffer size
+    let counters = 8 + 8 + 8;
+    // proof, leaf, index, padding
+    let rightmost_proof = depth * 32 + 32 + 4 + 4;
+    TREE_HEADER_LEN + counters + max_buffer_size as usize * change_log_len(depth) + rightmost_proof
+}
+
+/// Create the zeroed tree account, owned by the compression program, that
+/// `instructions::create_certificate_tree` initializes. `lamports` is the
+/// rent exemption for `merkle_tree_account_size`.
+pub fn allocate_tree(
+    payer: &Pubkey,
+    merkle_tree: &Pubkey,
+    lamports: u64,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    system_instruction::create_account(
+        payer,
+        merkle_tree,
+        lamports,
+        merkle_tree_account_size(max_depth, max_buffer_size) as u64,
+        &ACCOUNT_COMPRESSION_PROGRAM_ID,
+    )
+}
+
+/// What a verifier needs from an initialized tree account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeState {
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub authority: Pubkey,
+    pub root: [u8; 32],
+    /// Leaves appended so far
+    pub leaf_count: u32,
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+fn read_hash(data: &[u8], offset: usize) -> Option<[u8; 32]> {
+    data.get(offset..offset + 32)?.try_into().ok()
+}
+
+/// Parse an initialized concurrent Merkle tree account; `None` for anything else
+pub fn parse_tree(data: &[u8]) -> Option<TreeState> {
+    // Account type 1 is a concurrent Merkle tree, header version 0 is V1
+    if data.first() != Some(&1) || data.get(1) != Some(&0) {
+        return None;
+    }
+    let max_buffer_size = read_u32(data, 2)?;
+    let max_depth = read_u32(data, 6)?;
+    let authority = Pubkey::new_from_array(read_hash(data, 10)?);
+    if !is_supported_tree_size(max_depth, max_buffer_size) {
+        return None;
+    }
+
+    let depth = max_depth as usize;
+    let active_index = usize::try_from(read_u64(data, TREE_HEADER_LEN + 8)?).ok()?;
+    if active_index >= max_buffer_size as usize {
+        return None;
+    }
+    let change_logs = TREE_HEADER_LEN + 24;
+    let root = read_hash(data, change_logs + active_index * change_log_len(depth))?;
+    let rightmost_proof = change_logs + max_buffer_size as usize * change_log_len(depth);
+    let leaf_count = read_u32(data, rightmost_proof + depth * 32 + 32)?;
+    Some(TreeState {
+        max_depth,
+        max_buffer_size,
+        authority,
+        root,
+        leaf_count,
+    })
+}
+
+fn tree_levels(leaves: &[[u8; 32]], max_depth: u32) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![leaves.to_vec()];
+    for level in 0..max_depth {
+        let nodes = levels.last().expect("starts with the leaves");
+        let empty = empty_tree_node(level);
+        let parents = nodes
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => tree_parent(left, right),
+                [left] => tree_parent(left, &empty),
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(parents);
+    }
+    levels
+}
+
+/// Root of a tree of `max_depth` holding `leaves` from index 0, the rest empty
+pub fn tree_root(leaves: &[[u8; 32]], max_depth: u32) -> [u8; 32] {
+    if leaves.is_empty() {
+        return empty_tree_node(max_depth);
+    }
+    tree_levels(leaves, max_depth)[max_depth as usize][0]
+}
+
+/// The `max_depth` sibling hashes of the leaf at `index`, leaf level first
+pub fn tree_proof(leaves: &[[u8; 32]], index: usize, max_depth: u32) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let levels = tree_levels(leaves, max_depth);
+    Some(
+        (0..max_depth)
+            .map(|level| {
+                let sibling = (index >> level) ^ 1;
+                levels[level as usize]
+                    .get(sibling)
+                    .copied()
+                    .unwrap_or_else(|| empty_tree_node(level))
+            })
+            .collect(),
+    )
+}
+
+/// The leaf an enrollment at `address` hashes to at `leaf_index` of
+/// `merkle_tree`; `None` unless it is completed with a metadata hash
+pub fn compressed_leaf(
+    address: &Pubkey,
+    enrollment: &CourseEnrollment,
+    merkle_tree: &Pubkey,
+    leaf_index: u32,
+) -> Option<[u8; 32]> {
+    if enrollment.status != EnrollmentStatus::Completed {
+        return None;
+    }
+    Some(compressed_certificate_leaf(&CompressedCertificateLeaf {
+        merkle_tree: *merkle_tree,
+        leaf_index,
+        enrollment: *address,
+        student: enrollment.student,
+        course_id: enrollment.course_id.clone(),
+        grade: enrollment.grade,
+        completion_date: enrollment.completion_date?,
+        metadata_hash: enrollment.metadata_hash?,
+    }))
+}
+
+/// A compressed certificate's leaf with the path to the tree root
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateProof {
+    pub merkle_tree: String,
+    pub enrollment: String,
+    pub leaf_index: u32,
+    pub leaf_hash: String,
+    pub root: String,
+    /// Hex sibling hashes, leaf level first
+    pub proof: Vec<String>,
+}
+
+impl CertificateProof {
+    pub fn leaf(&self) -> std::result::Result<[u8; 32], CompressedFailure> {
+        hash_from_hex(&self.leaf_hash).ok_or_else(|| CompressedFailure::Malformed("leafHash".to_string()))
+    }
+
+    pub fn root(&self) -> std::result::Result<[u8; 32], CompressedFailure> {
+        hash_from_hex(&self.root).ok_or_else(|| CompressedFailure::Malformed("root".to_string()))
+    }
+
+    pub fn nodes(&self) -> std::result::Result<Vec<[u8; 32]>, CompressedFailure> {
+        self.proof
+            .iter()
+            .map(|node| hash_from_hex(node).ok_or_else(|| CompressedFailure::Malformed(format!("proof node {node}"))))
+            .collect()
+    }
+
+    pub fn merkle_tree(&self) -> std::result::Result<Pubkey, CompressedFailure> {
+        self.merkle_tree
+            .parse()
+            .map_err(|_| CompressedFailure::Malformed(format!("merkleTree {}", self.merkle_tree)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CompressedFailure {
+    #[error("malformed proof: {0}")]
+    Malformed(String),
+
+    #[error("enrollment not found")]
+    EnrollmentNotFound,
+
+    #[error("enrollment has no compressed certificate")]
+    NotCompressed,
+
+    #[error("enrollment no longer hashes to the certificate leaf")]
+    LeafMismatch,
+
+    #[error("certificate tree account not found or not initialized")]
+    TreeNotFound,
+
+    #[error("leaf {0} is unknown; its enrollment may be closed")]
+    MissingLeaf(u32),
+
+    #[error("proof does not lead from the leaf to the root")]
+    InvalidProof,
+
+    #[error("root is not the tree's current root")]
+    RootMismatch,
+}
+
+/// Build the proof for `certificate` from every leaf of its tree, in index order
+pub fn proof_from_leaves(
+    enrollment: &Pubkey,
+    certificate: &CompressedCertificate,
+    leaves: &[Option<[u8; 32]>],
+    max_depth: u32,
+) -> std::result::Result<CertificateProof, CompressedFailure> {
+    let leaves = leaves
+        .iter()
+        .enumerate()
+        .map(|(index, leaf)| leaf.ok_or(CompressedFailure::MissingLeaf(index as u32)))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let index = certificate.leaf_index as usize;
+    if leaves.get(index) != Some(&certificate.leaf_hash) {
+        return Err(CompressedFailure::LeafMismatch);
+    }
+    let proof = tree_proof(&leaves, index, max_depth).ok_or(CompressedFailure::MissingLeaf(certificate.leaf_index))?;
+    Ok(CertificateProof {
+        merkle_tree: certificate.merkle_tree.to_string(),
+        enrollment: enrollment.to_string(),
+        leaf_index: certificate.leaf_index,
+        leaf_hash: hash_to_hex(&certificate.leaf_hash),
+        root: hash_to_hex(&tree_root(&leaves, max_depth)),
+        proof: proof.iter().map(hash_to_hex).collect(),
+    })
+}
+
+/// Check that a proof leads from its leaf to its root, offline
+pub fn check_proof(proof: &CertificateProof) -> std::result::Result<(), CompressedFailure> {
+    proof.merkle_tree()?;
+    if recompute_tree_root(&proof.leaf()?, proof.leaf_index, &proof.nodes()?) != proof.root()? {
+        return Err(CompressedFailure::InvalidProof);
+    }
+    Ok(())
+}
+
+/// Check a proof that passed `check_proof` against the tree account
+pub fn check_tree(proof: &CertificateProof, tree: Option<&TreeState>) -> std::result::Result<(), CompressedFailure> {
+    let tree = tree.ok_or(CompressedFailure::TreeNotFound)?;
+    if proof.proof.len() != tree.max_depth as usize || tree.root != proof.root()? {
+        return Err(CompressedFailure::RootMismatch);
+    }
+    Ok(())
+}
+
+/// The compression program's `verify_leaf` for a proof; it fails unless the
+/// leaf is in the tree under `root`, so it can be simulated or prepended to
+/// another transaction
+pub fn verify_leaf_instruction(proof: &CertificateProof) -> std::result::Result<Instruction, CompressedFailure> {
+    let mut data = VERIFY_LEAF.to_vec();
+    data.extend_from_slice(&proof.root()?);
+    data.extend_from_slice(&proof.leaf()?);
+    data.extend_from_slice(&proof.leaf_index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(proof.merkle_tree()?, false)];
+    for node in proof.nodes()? {
+        accounts.push(AccountMeta::new_readonly(Pubkey::new_from_array(node), false));
+    }
+    Ok(Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    })
+}
+
+/// Fetch and parse a tree account, returning `None` when it does not exist or
+/// is not an initialized tree
+pub fn fetch_tree(rpc: &RpcClient, merkle_tree: &Pubkey) -> Result<Option<TreeState>> {
+    let account = rpc.get_account_with_commitment(merkle_tree, rpc.commitment())?.value;
+    Ok(account
+        .filter(|account| account.owner == ACCOUNT_COMPRESSION_PROGRAM_ID)
+        .and_then(|account| parse_tree(&account.data)))
+}
+
+/// The first `leaf_count` leaves of a tree, read from the enrollments that
+/// record them and the records of closed ones; `None` where neither is left.
+/// The leaf sits after variable-length fields, so both are scanned
+/// client-side.
+pub fn fetch_tree_leaves(rpc: &RpcClient, merkle_tree: &Pubkey, leaf_count: u32) -> Result<Vec<Option<[u8; 32]>>> {
+    let mut leaves = vec![None; leaf_count as usize];
+    let open = fetch_all::<CourseEnrollment>(rpc, Vec::new())?
+        .into_iter()
+        .map(|(_, enrollment)| enrollment.compressed_certificate);
+    let closed = fetch_all::<EnrollmentRecord>(rpc, Vec::new())?
+        .into_iter()
+        .map(|(_, record)| record.compressed_certificate);
+    for certificate in open.chain(closed) {
+        let Some(certificate) = certificate else {
+            continue;
+        };
+        if certificate.merkle_tree == *merkle_tree {
+            if let Some(leaf) = leaves.get_mut(certificate.leaf_index as usize) {
+                *leaf = Some(certificate.leaf_hash);
+            }
+        }
+    }
+    Ok(leaves)
+}
+
+/// The outcome of verifying an enrollment's compressed certificate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompressedVerification {
+    pub enrollment: Pubkey,
+    pub compressed_certificate: Option<CompressedCertificate>,
+    pub proof: Option<CertificateProof>,
+    pub failure: Option<CompressedFailure>,
+}
+
+impl CompressedVerification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Check that the enrollment at `address` still hashes to its recorded leaf
+pub fn check_enrollment(
+    address: &Pubkey,
+    enrollment: Option<&CourseEnrollment>,
+) -> std::result::Result<CompressedCertificate, CompressedFailure> {
+    let enrollment = enrollment.ok_or(CompressedFailure::EnrollmentNotFound)?;
+    let certificate = enrollment.compressed_certificate.ok_or(CompressedFailure::NotCompressed)?;
+    let leaf = compressed_leaf(address, enrollment, &certificate.merkle_tree, certificate.leaf_index);
+    if leaf != Some(certificate.leaf_hash) {
+        return Err(CompressedFailure::LeafMismatch);
+    }
+    Ok(certificate)
+}
+
+/// Rebuild the proof of an enrollment's compressed certificate and check it
+/// against the tree's current root. The proof comes back with the outcome so
+/// it can be handed on or passed to `verify_leaf_instruction`.
+pub fn verify_compressed_certificate(rpc: &RpcClient, enrollment: &Pubkey) -> Result<CompressedVerification> {
+    let account = fetch_enrollment_or_record(rpc, enrollment)?;
+    let certificate = match check_enrollment(enrollment, account.as_ref()) {
+        Ok(certificate) => certificate,
+        Err(failure) => {
+            return Ok(CompressedVerification {
+                enrollment: *enrollment,
+                compressed_certificate: account.and_then(|account| account.compressed_certificate),
+                proof: None,
+                failure: Some(failure),
+            })
+        }
+    };
+    let failed = |failure| CompressedVerification {
+        enrollment: *enrollment,
+        compressed_certificate: Some(certificate),
+        proof: None,
+        failure: Some(failure),
+    };
+
+    let Some(tree) = fetch_tree(rpc, &certificate.merkle_tree)? else {
+        return Ok(failed(CompressedFailure::TreeNotFound));
+    };
+    let leaves = fetch_tree_leaves(rpc, &certificate.merkle_tree, tree.leaf_count)?;
+    let proof = match proof_from_leaves(enrollment, &certificate, &leaves, tree.max_depth) {
+        Ok(proof) => proof,
+        Err(failure) => return Ok(failed(failure)),
+    };
+    Ok(CompressedVerification {
+        enrollment: *enrollment,
+        compressed_certificate: Some(certificate),
+        failure: check_proof(&proof).and_then(|()| check_tree(&proof, Some(&tree))).err(),
+        proof: Some(proof),
+    })
+}
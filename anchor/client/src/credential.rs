@@ -0,0 +1,504 @@
+//! W3C Verifiable Credentials 2.0 for completed courses.
+//!
+//! A credential is issued for an enrollment whose certificate verifies. It
+//! carries the enrollment, offering and certificate mint as evidence and is
+//! secured with a Data Integrity proof using the `eddsa-jcs-2022`
+//! cryptosuite: the proof options and the credential are each canonicalized
+//! with JCS (RFC 8785), hashed with SHA-256, and the concatenated hashes are
+//! signed with the issuer's Ed25519 key.
+//!
+//! A credential verifies only when every claim matches the on-chain course
+//! and enrollment, and its issuer is the institution's authority or holds
+//! the Registrar role there.
+//!
+//! Issuers and subjects are identified by `did:key` or `did:sol`. Only the
+//! default key of a `did:sol` (its own address) is resolved; DIDs whose keys
+//! were rotated through the did:sol program are not supported.
+
+use std::fmt;
+use std::str::FromStr;
+
+use academic_chain::state::{Course, CourseEnrollment, Institution, Role, RoleAssignment};
+use academic_chain::ID;
+use anchor_lang::prelude::Pubkey;
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_signature::Signature;
+use solana_signer::Signer;
+
+use crate::accounts::fetch_account_optional;
+use crate::error::Result;
+use crate::metadata::sha256;
+use crate::pda::role_assignment_pda;
+use crate::verify::{check_enrollment, verify_certificate, Verification, VerificationFailure};
+
+pub const CREDENTIALS_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+pub const CREDENTIAL_TYPE: &str = "CourseCompletionCredential";
+pub const PROOF_TYPE: &str = "DataIntegrityProof";
+pub const CRYPTOSUITE: &str = "eddsa-jcs-2022";
+
+// Multicodec prefix of an Ed25519 public key in did:key
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+
+/// How a wallet address is written as a DID
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DidMethod {
+    #[default]
+    Key,
+    /// `did:sol`, with the cluster for anything but mainnet
+    Sol { cluster: Option<String> },
+}
+
+impl DidMethod {
+    pub fn did(&self, key: &Pubkey) -> String {
+        match self {
+            DidMethod::Key => format!("did:key:{}", did_key_identifier(key)),
+            DidMethod::Sol { cluster: None } => format!("did:sol:{key}"),
+            DidMethod::Sol { cluster: Some(cluster) } => format!("did:sol:{cluster}:{key}"),
+        }
+    }
+
+    /// The verification method that signs for `key`
+    pub fn verification_method(&self, key: &Pubkey) -> String {
+        match self {
+            DidMethod::Key => format!("{}#{}", self.did(key), did_key_identifier(key)),
+            DidMethod::Sol { .. } => format!("{}#default", self.did(key)),
+        }
+    }
+}
+
+/// `key`, `sol`, or `sol:<cluster>`
+impl FromStr for DidMethod {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value.split_once(':') {
+            None if value == "key" => Ok(DidMethod::Key),
+            None if value == "sol" => Ok(DidMethod::Sol { cluster: None }),
+            Some(("sol", cluster)) if !cluster.is_empty() => Ok(DidMethod::Sol {
+                cluster: Some(cluster.to_string()),
+            }),
+            _ => Err(format!("unknown DID method `{value}`; use key, sol or sol:<cluster>")),
+        }
+    }
+}
+
+impl fmt::Display for DidMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DidMethod::Key => f.write_str("key"),
+            DidMethod::Sol { cluster: None } => f.write_str("sol"),
+            DidMethod::Sol { cluster: Some(cluster) } => write!(f, "sol:{cluster}"),
+        }
+    }
+}
+
+fn did_key_identifier(key: &Pubkey) -> String {
+    let mut bytes = ED25519_MULTICODEC.to_vec();
+    bytes.extend_from_slice(key.as_ref());
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+/// The Ed25519 key a `did:key` or default `did:sol` stands for
+pub fn resolve_did(did: &str) -> Option<Pubkey> {
+    if let Some(identifier) = did.strip_prefix("did:key:") {
+        let bytes = bs58::decode(identifier.strip_prefix('z')?).into_vec().ok()?;
+        let key = bytes.strip_prefix(&ED25519_MULTICODEC[..])?;
+        return Pubkey::try_from(key).ok();
+    }
+    let identifier = did.strip_prefix("did:sol:")?;
+    identifier.rsplit(':').next()?.parse().ok()
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiableCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub issuer: String,
+    pub valid_from: String,
+    pub credential_subject: CourseCompletion,
+    pub evidence: Vec<Evidence>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<DataIntegrityProof>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseCompletion {
+    /// The student's DID
+    pub id: String,
+    pub course_id: String,
+    pub course_name: String,
+    pub credit_hours: u8,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub completed_at: String,
+}
+
+/// The on-chain accounts backing the claim
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Evidence {
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub program_id: String,
+    pub enrollment: String,
+    pub offering: String,
+    pub certificate_mint: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataIntegrityProof {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub cryptosuite: String,
+    pub created: String,
+    pub verification_method: String,
+    pub proof_purpose: String,
+    pub proof_value: String,
+}
+
+pub(crate) fn timestamp(unix: i64) -> String {
+    DateTime::from_timestamp(unix, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+impl VerifiableCredential {
+    /// Unsigned credential for a course certificate that currently verifies
+    pub fn course_completion(
+        address: &Pubkey,
+        enrollment: &CourseEnrollment,
+        course: &Course,
+        issuer: &str,
+        subject_method: &DidMethod,
+    ) -> std::result::Result<Self, VerificationFailure> {
+        let mint = enrollment.certificate_mint.ok_or(VerificationFailure::NotFound)?;
+        check_enrollment(&mint, address, enrollment)?;
+        let completed_at = timestamp(enrollment.completion_date.unwrap_or_default());
+
+        Ok(Self {
+            context: vec![CREDENTIALS_V2_CONTEXT.to_string()],
+            id: format!("urn:academic-chain:enrollment:{address}"),
+            kind: vec!["VerifiableCredential".to_string(), CREDENTIAL_TYPE.to_string()],
+            issuer: issuer.to_string(),
+            valid_from: completed_at.clone(),
+            credential_subject: CourseCompletion {
+                id: subject_method.did(&enrollment.student),
+                course_id: enrollment.course_id.clone(),
+                course_name: course.course_name.clone(),
+                credit_hours: enrollment.credit_hours,
+                grade: enrollment.grade,
+                letter_grade: enrollment.letter_grade.clone(),
+                completed_at,
+            },
+            evidence: vec![Evidence {
+                kind: vec!["Evidence".to_string(), "SolanaAccount".to_string()],
+                program_id: ID.to_string(),
+                enrollment: address.to_string(),
+                offering: enrollment.offering.to_string(),
+                certificate_mint: mint.to_string(),
+            }],
+            proof: None,
+        })
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).expect("credential serializes")
+    }
+}
+
+/// Issue and sign a course credential; the signer's DID is the issuer
+pub fn issue_course_credential(
+    address: &Pubkey,
+    enrollment: &CourseEnrollment,
+    course: &Course,
+    signer: &dyn Signer,
+    method: &DidMethod,
+    issued_at: i64,
+) -> std::result::Result<VerifiableCredential, VerificationFailure> {
+    let issuer = method.did(&signer.pubkey());
+    let mut credential = VerifiableCredential::course_completion(address, enrollment, course, &issuer, method)?;
+    credential.proof = Some(sign(
+        &credential.to_value(),
+        signer,
+        &method.verification_method(&signer.pubkey()),
+        issued_at,
+    ));
+    Ok(credential)
+}
+
+/// Why a credential does not verify
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CredentialFailure {
+    #[error("malformed credential: {0}")]
+    Malformed(String),
+
+    #[error("unsupported proof; expected {PROOF_TYPE} with {CRYPTOSUITE}")]
+    UnsupportedProof,
+
+    #[error("verification method {0} does not belong to the issuer")]
+    ForeignVerificationMethod(String),
+
+    #[error("cannot resolve {0} to an Ed25519 key")]
+    UnresolvableDid(String),
+
+    #[error("signature does not match the credential")]
+    BadSignature,
+
+    #[error("evidence does not match the on-chain enrollment")]
+    EvidenceMismatch,
+
+    #[error("claims differ from the on-chain course or enrollment")]
+    ClaimsMismatch,
+
+    #[error("issuer {0} is neither the institution authority nor one of its Registrars")]
+    UnauthorizedIssuer(String),
+
+    #[error(transparent)]
+    Certificate(#[from] VerificationFailure),
+}
+
+/// The outcome of verifying one credential
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CredentialVerification {
+    pub issuer: Option<String>,
+    pub failure: Option<CredentialFailure>,
+}
+
+impl CredentialVerification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// RFC 8785 canonical JSON. Values here are strings, booleans and integers,
+/// which serde_json already writes the way JCS requires, so only object
+/// keys need sorting.
+pub fn canonicalize(value: &Value) -> Vec<u8> {
+    fn write(value: &Value, out: &mut String) {
+        match value {
+            Value::Array(items) => {
+                out.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    write(item, out);
+                }
+                out.push(']');
+            }
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+                out.push('{');
+                for (index, (key, item)) in entries.into_iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    out.push_str(&Value::from(key.as_str()).to_string());
+                    out.push(':');
+                    write(item, out);
+                }
+                out.push('}');
+            }
+            scalar => out.push_str(&scalar.to_string()),
+        }
+    }
+
+    let mut out = String::new();
+    write(value, &mut out);
+    out.into_bytes()
+}
+
+// hash(proof options with the document context) || hash(document without proof)
+fn hash_data(document: &Value, options: &Value) -> Vec<u8> {
+    let mut document = document.clone();
+    if let Some(map) = document.as_object_mut() {
+        map.remove("proof");
+    }
+    let mut options = options.clone();
+    if let Some(map) = options.as_object_mut() {
+        map.remove("proofValue");
+        if let Some(context) = document.get("@context") {
+            map.insert("@context".to_string(), context.clone());
+        }
+    }
+
+    let mut data = sha256(&canonicalize(&options)).to_vec();
+    data.extend_from_slice(&sha256(&canonicalize(&document)));
+    data
+}
+
+/// Create an `eddsa-jcs-2022` proof over `document`
+pub fn sign(document: &Value, signer: &dyn Signer, verification_method: &str, created: i64) -> DataIntegrityProof {
+    let mut proof = DataIntegrityProof {
+        kind: PROOF_TYPE.to_string(),
+        cryptosuite: CRYPTOSUITE.to_string(),
+        created: timestamp(created),
+        verification_method: verification_method.to_string(),
+        proof_purpose: "assertionMethod".to_string(),
+        proof_value: String::new(),
+    };
+    let options = serde_json::to_value(&proof).expect("proof serializes");
+    let signature = signer.sign_message(&hash_data(document, &options));
+    proof.proof_value = format!("z{}", bs58::encode(signature.as_ref()).into_string());
+    proof
+}
+
+/// Check the proof on a credential exactly as received and return the
+/// issuer's DID. Fields unknown to this module are covered by the signature
+/// too, since they are part of the received JSON.
+pub fn verify_proof(credential: &Value) -> std::result::Result<String, CredentialFailure> {
+    let issuer = credential
+        .get("issuer")
+        .and_then(|issuer| issuer.as_str().or_else(|| issuer.get("id")?.as_str()))
+        .ok_or_else(|| CredentialFailure::Malformed("missing issuer".to_string()))?;
+    let options = credential
+        .get("proof")
+        .ok_or_else(|| CredentialFailure::Malformed("missing proof".to_string()))?;
+    let proof: DataIntegrityProof = serde_json::from_value(options.clone())
+        .map_err(|err| CredentialFailure::Malformed(format!("proof: {err}")))?;
+    if proof.kind != PROOF_TYPE || proof.cryptosuite != CRYPTOSUITE {
+        return Err(CredentialFailure::UnsupportedProof);
+    }
+
+    let did = proof
+        .verification_method
+        .split_once('#')
+        .map_or(proof.verification_method.as_str(), |(did, _)| did);
+    if did != issuer {
+        return Err(CredentialFailure::ForeignVerificationMethod(proof.verification_method));
+    }
+    let key = resolve_did(did).ok_or_else(|| CredentialFailure::UnresolvableDid(did.to_string()))?;
+
+    let signature = proof
+        .proof_value
+        .strip_prefix('z')
+        .and_then(|value| bs58::decode(value).into_vec().ok())
+        .and_then(|bytes| Signature::try_from(bytes).ok())
+        .ok_or(CredentialFailure::BadSignature)?;
+    if !signature.verify(key.as_ref(), &hash_data(credential, options)) {
+        return Err(CredentialFailure::BadSignature);
+    }
+    Ok(issuer.to_string())
+}
+
+/// Check the credential's evidence and claims against a verified certificate
+pub fn check_record(
+    credential: &VerifiableCredential,
+    verification: &Verification,
+) -> std::result::Result<(), CredentialFailure> {
+    if let Some(failure) = verification.failure {
+        return Err(failure.into());
+    }
+    let (Some((address, enrollment)), Some(course)) = (&verification.enrollment, &verification.course) else {
+        return Err(VerificationFailure::NotFound.into());
+    };
+    let Some(evidence) = credential.evidence.first() else {
+        return Err(CredentialFailure::Malformed("missing evidence".to_string()));
+    };
+    if evidence.program_id != ID.to_string()
+        || evidence.enrollment != address.to_string()
+        || evidence.offering != enrollment.offering.to_string()
+        || evidence.certificate_mint != verification.mint.to_string()
+    {
+        return Err(CredentialFailure::EvidenceMismatch);
+    }
+
+    let subject = &credential.credential_subject;
+    let completed_at = enrollment.completion_date.map(timestamp);
+    if resolve_did(&subject.id) != Some(enrollment.student)
+        || subject.course_id != enrollment.course_id
+        || subject.course_name != course.course_name
+        || subject.credit_hours != enrollment.credit_hours
+        || subject.grade != enrollment.grade
+        || subject.letter_grade != enrollment.letter_grade
+        || completed_at.as_ref() != Some(&subject.completed_at)
+        || completed_at.as_ref() != Some(&credential.valid_from)
+    {
+        return Err(CredentialFailure::ClaimsMismatch);
+    }
+    Ok(())
+}
+
+/// Check that `issuer` is the authority of `institution` or holds the
+/// Registrar role there through `registrar`
+pub fn check_issuer(
+    issuer: &str,
+    institution_address: &Pubkey,
+    institution: &Institution,
+    registrar: Option<&RoleAssignment>,
+) -> std::result::Result<(), CredentialFailure> {
+    let key = resolve_did(issuer).ok_or_else(|| CredentialFailure::UnresolvableDid(issuer.to_string()))?;
+    let is_registrar = registrar.is_some_and(|assignment| {
+        assignment.institution == *institution_address
+            && assignment.holder == key
+            && assignment.role == Role::Registrar
+    });
+    if key != institution.authority && !is_registrar {
+        return Err(CredentialFailure::UnauthorizedIssuer(issuer.to_string()));
+    }
+    Ok(())
+}
+
+/// Verify the proof, then that the certificate in the evidence still
+/// verifies on-chain and backs the credential's claims
+pub fn verify_credential(rpc: &RpcClient, credential: &Value) -> Result<CredentialVerification> {
+    let issuer = match verify_proof(credential) {
+        Ok(issuer) => issuer,
+        Err(failure) => {
+            return Ok(CredentialVerification {
+                issuer: None,
+                failure: Some(failure),
+            })
+        }
+    };
+    let failed = |failure| CredentialVerification {
+        issuer: Some(issuer.clone()),
+        failure: Some(failure),
+    };
+
+    let parsed: VerifiableCredential = match serde_json::from_value(credential.clone()) {
+        Ok(parsed) => parsed,
+        Err(err) => return Ok(failed(CredentialFailure::Malformed(err.to_string()))),
+    };
+    let Some(mint) = parsed
+        .evidence
+        .first()
+        .and_then(|evidence| evidence.certificate_mint.parse::<Pubkey>().ok())
+    else {
+        return Ok(failed(CredentialFailure::Malformed("missing certificate mint".to_string())));
+    };
+
+    let verification = verify_certificate(rpc, &mint)?;
+    if let Err(failure) = check_record(&parsed, &verification) {
+        return Ok(failed(failure));
+    }
+
+    // check_record found the offering through the enrollment, so it exists
+    let Some(offering) = &verification.offering else {
+        return Ok(failed(VerificationFailure::NotFound.into()));
+    };
+    let Some(institution) = fetch_account_optional::<Institution>(rpc, &offering.institution)? else {
+        return Ok(failed(VerificationFailure::NotFound.into()));
+    };
+    let registrar = match resolve_did(&issuer) {
+        Some(key) => fetch_account_optional::<RoleAssignment>(
+            rpc,
+            &role_assignment_pda(&offering.institution, &key, Role::Registrar).0,
+        )?,
+        None => None,
+    };
+    Ok(CredentialVerification {
+        failure: check_issuer(&issuer, &offering.institution, &institution, registrar.as_ref()).err(),
+        issuer: Some(issuer),
+    })
+}
@@ -0,0 +1,126 @@
+//! Client errors and decoding of `AcademicChainError` from failed transactions.
+
+use academic_chain::errors::AcademicChainError;
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::InstructionError;
+use solana_rpc_client_api::client_error::Error as RpcError;
+use solana_transaction_error::TransactionError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Rpc(Box<RpcError>),
+
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+
+    #[error("failed to deserialize account {0}: {1}")]
+    Deserialize(Pubkey, Box<anchor_lang::error::Error>),
+
+    #[error("account {0} is not an address lookup table")]
+    InvalidLookupTable(Pubkey),
+}
+
+impl From<RpcError> for Error {
+    fn from(err: RpcError) -> Self {
+        Error::Rpc(Box::new(err))
+    }
+}
+
+impl Error {
+    /// The program error that made the transaction fail, if any
+    pub fn program_error(&self) -> Option<AcademicChainError> {
+        match self {
+            Error::Rpc(err) => decode_rpc_error(err),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// In declaration order, so that `code - ERROR_CODE_OFFSET` indexes the variant
+const PROGRAM_ERRORS: &[AcademicChainError] = &[
+    AcademicChainError::InsufficientCredits,
+    AcademicChainError::CourseNotActive,
+    AcademicChainError::AlreadyEnrolled,
+    AcademicChainError::CourseNotCompleted,
+    AcademicChainError::InvalidGrade,
+    AcademicChainError::UnauthorizedInstructor,
+    AcademicChainError::CertificateAlreadyMinted,
+    AcademicChainError::RequirementsNotMet,
+    AcademicChainError::InvalidCourseId,
+    AcademicChainError::InvalidCourseName,
+    AcademicChainError::InvalidCredits,
+    AcademicChainError::Unauthorized,
+    AcademicChainError::ArithmeticOverflow,
+    AcademicChainError::InvalidCreditHours,
+    AcademicChainError::InvalidGradeScale,
+    AcademicChainError::CourseAlreadyCompleted,
+    AcademicChainError::InvalidBatchSize,
+    AcademicChainError::InvalidBatchAccounts,
+    AcademicChainError::InvalidTermId,
+    AcademicChainError::InvalidTermName,
+    AcademicChainError::InvalidTermDates,
+    AcademicChainError::InvalidCapacity,
+    AcademicChainError::OfferingFull,
+    AcademicChainError::InvalidDeadlines,
+    AcademicChainError::EnrollmentNotOpen,
+    AcademicChainError::EnrollmentClosed,
+    AcademicChainError::GradingDeadlinePassed,
+    AcademicChainError::GradingDeadlineNotReached,
+    AcademicChainError::EnrollmentNotActive,
+    AcademicChainError::InvalidMaxAttempts,
+    AcademicChainError::RetakeNotAllowed,
+    AcademicChainError::AttemptLimitReached,
+    AcademicChainError::InvalidDisplayName,
+    AcademicChainError::InvalidMetadataUri,
+    AcademicChainError::EnrollmentNotFinished,
+    AcademicChainError::OpenEnrollmentsRemain,
+    AcademicChainError::InvalidCreditPrice,
+    AcademicChainError::InvalidMetadataHash,
+    AcademicChainError::InvalidTranscript,
+    AcademicChainError::InvalidCertificateBatch,
+    AcademicChainError::InvalidMerkleProof,
+    AcademicChainError::InvalidCertificateTree,
+    AcademicChainError::CertificateTreeFull,
+    AcademicChainError::InvalidInstitutionId,
+    AcademicChainError::InvalidInstitutionName,
+    AcademicChainError::InstitutionMismatch,
+    AcademicChainError::SameInstitution,
+    AcademicChainError::TransferNotPassed,
+    AcademicChainError::InvalidGovernance,
+    AcademicChainError::NotGovernanceAdmin,
+    AcademicChainError::EmptyConfigChange,
+    AcademicChainError::AlreadyApproved,
+    AcademicChainError::ProposalNotApproved,
+    AcademicChainError::ProposalTimelocked,
+    AcademicChainError::ConfigChangeGoverned,
+];
+
+/// Map a custom program error code (6000 + n) to `AcademicChainError`
+pub fn decode_error_code(code: u32) -> Option<AcademicChainError> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)?;
+    PROGRAM_ERRORS.get(index as usize).copied()
+}
+
+/// Extract the program error from a failed transaction
+pub fn decode_transaction_error(err: &TransactionError) -> Option<AcademicChainError> {
+    match err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            decode_error_code(*code)
+        }
+        _ => None,
+    }
+}
+
+/// Extract the program error from an RPC error (send or simulate)
+pub fn decode_rpc_error(err: &RpcError) -> Option<AcademicChainError> {
+    decode_transaction_error(&err.get_transaction_error()?)
+}
+
+/// All program errors, in code order
+pub fn program_errors() -> &'static [AcademicChainError] {
+    PROGRAM_ERRORS
+}
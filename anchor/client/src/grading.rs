@@ -0,0 +1,136 @@
+//! Grading a cohort with `complete_course_batch` in v0 transactions.
+//!
+//! Every student adds a writable enrollment and student profile to the
+//! instruction, so a full batch of `MAX_GRADING_BATCH_SIZE` students only
+//! fits a transaction when those accounts are loaded from an address lookup
+//! table. `plan_grading_batches` splits a cohort into batches and groups them
+//! so that each group's accounts fit one table. The caller creates and
+//! extends the table, waits a slot for the new addresses to activate, sends
+//! each batch against it and finally deactivates it.
+
+use academic_chain::constants::MAX_GRADING_BATCH_SIZE;
+use academic_chain::state::StudentGrade;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use solana_address_lookup_table_interface::instruction as lookup_table;
+use solana_address_lookup_table_interface::state::AddressLookupTable;
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_message::AddressLookupTableAccount;
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::error::{Error, Result};
+use crate::instructions;
+use crate::pda::{course_pda, offering_pda};
+
+/// Addresses one lookup table can hold
+pub const MAX_LOOKUP_TABLE_ADDRESSES: usize = 256;
+
+/// Addresses per extend instruction that keep its transaction under the size limit
+pub const LOOKUP_TABLE_EXTEND_CHUNK: usize = 30;
+
+/// Compute budget: fixed overhead plus the cost of grading one student,
+/// including the `CourseCompleted` event CPI
+pub const BASE_COMPUTE_UNITS: u32 = 30_000;
+pub const COMPUTE_UNITS_PER_GRADE: u32 = 32_000;
+
+/// One `complete_course_batch` instruction and the students it grades
+#[derive(Clone, Debug)]
+pub struct GradingBatch {
+    pub grades: Vec<StudentGrade>,
+    pub instruction: Instruction,
+}
+
+/// Batches whose accounts are all resolvable through one lookup table
+#[derive(Clone, Debug)]
+pub struct LookupTablePlan {
+    /// Addresses to store in the table
+    pub addresses: Vec<Pubkey>,
+    pub batches: Vec<GradingBatch>,
+}
+
+/// Split `grades` into batches of `batch_size` students and group them so
+/// that each group's accounts fit a single lookup table.
+///
+/// Panics unless `batch_size` is 1 to `MAX_GRADING_BATCH_SIZE`.
+pub fn plan_grading_batches(
+    institution: &Pubkey,
+    instructor: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    grades: &[StudentGrade],
+    batch_size: usize,
+) -> Vec<LookupTablePlan> {
+    assert!(
+        (1..=MAX_GRADING_BATCH_SIZE).contains(&batch_size),
+        "batch size must be between 1 and {MAX_GRADING_BATCH_SIZE}"
+    );
+
+    // Institution, course and offering are shared by every batch in a table
+    let shared = [
+        *institution,
+        course_pda(institution, course_id).0,
+        offering_pda(institution, course_id, term_id).0,
+    ];
+    let batches_per_table = ((MAX_LOOKUP_TABLE_ADDRESSES - shared.len()) / (batch_size * 2)).max(1);
+
+    let batches: Vec<GradingBatch> = grades
+        .chunks(batch_size)
+        .map(|chunk| GradingBatch {
+            grades: chunk.to_vec(),
+            instruction: instructions::complete_course_batch(institution, instructor, course_id, term_id, chunk.to_vec()),
+        })
+        .collect();
+
+    batches
+        .chunks(batches_per_table)
+        .map(|table_batches| {
+            let mut addresses = shared.to_vec();
+            for batch in table_batches {
+                // The (enrollment, student_profile) pairs follow the named accounts
+                let students = batch.grades.len() * 2;
+                let accounts = &batch.instruction.accounts;
+                addresses.extend(accounts[accounts.len() - students..].iter().map(|meta| meta.pubkey));
+            }
+            LookupTablePlan {
+                addresses,
+                batches: table_batches.to_vec(),
+            }
+        })
+        .collect()
+}
+
+/// Compute unit limit for a batch grading `students`
+pub fn compute_unit_limit(students: usize) -> Instruction {
+    ComputeBudgetInstruction::set_compute_unit_limit(BASE_COMPUTE_UNITS + COMPUTE_UNITS_PER_GRADE * students as u32)
+}
+
+/// Create a lookup table owned by `authority`, derived from a recent finalized slot
+pub fn create_lookup_table(authority: &Pubkey, payer: &Pubkey, recent_slot: u64) -> (Instruction, Pubkey) {
+    lookup_table::create_lookup_table(*authority, *payer, recent_slot)
+}
+
+/// Extend `table` with `addresses`, one instruction per transaction
+pub fn extend_lookup_table(table: &Pubkey, authority: &Pubkey, payer: &Pubkey, addresses: &[Pubkey]) -> Vec<Instruction> {
+    addresses
+        .chunks(LOOKUP_TABLE_EXTEND_CHUNK)
+        .map(|chunk| lookup_table::extend_lookup_table(*table, *authority, Some(*payer), chunk.to_vec()))
+        .collect()
+}
+
+/// Deactivate `table`; its rent can be reclaimed once deactivation completes
+pub fn deactivate_lookup_table(table: &Pubkey, authority: &Pubkey) -> Instruction {
+    lookup_table::deactivate_lookup_table(*table, *authority)
+}
+
+/// Fetch a lookup table for compiling v0 messages
+pub fn fetch_lookup_table(rpc: &RpcClient, address: &Pubkey) -> Result<AddressLookupTableAccount> {
+    let account = rpc
+        .get_account_with_commitment(address, rpc.commitment())?
+        .value
+        .ok_or(Error::AccountNotFound(*address))?;
+    let table = AddressLookupTable::deserialize(&account.data).map_err(|_| Error::InvalidLookupTable(*address))?;
+    Ok(AddressLookupTableAccount {
+        key: *address,
+        addresses: table.addresses.to_vec(),
+    })
+}
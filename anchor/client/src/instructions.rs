@@ -0,0 +1,964 @@
+//! Instruction builders. Each builder derives the PDAs the instruction needs,
+//! so callers only pass signers, arguments and accounts chosen off-chain.
+
+use academic_chain::constants::{ACCOUNT_COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+use academic_chain::state::{CertificateLeaf, ConfigChange, GradeScaleEntry, Role, StudentGrade, TranscriptEntry};
+use academic_chain::{accounts, instruction, ID};
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+
+use crate::pda::*;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Register an institution; `authority` pays and becomes its admin. The
+/// credit mint's mint authority must be `institution_pda(institution_id)`.
+pub fn create_institution(
+    authority: &Pubkey,
+    institution_id: &str,
+    name: &str,
+    treasury: &Pubkey,
+    credit_mint: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::CreateInstitution {
+            authority: *authority,
+            institution: institution_pda(institution_id).0,
+            treasury: *treasury,
+            credit_mint: *credit_mint,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateInstitution {
+            institution_id: institution_id.to_string(),
+            name: name.to_string(),
+        },
+    )
+}
+
+/// Replace the grade scale (authority or Registrar)
+pub fn set_grade_scale(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    grade_scale: Vec<GradeScaleEntry>,
+) -> Instruction {
+    build(
+        accounts::SetGradeScale {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::SetGradeScale { grade_scale },
+    )
+}
+
+/// Config fields to change; `None` keeps the current value
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConfigUpdate {
+    pub new_authority: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub credit_price: Option<u64>,
+}
+
+/// Update the institution config (authority or FinanceAdmin; a new
+/// authority only from the authority itself)
+pub fn update_config(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    update: ConfigUpdate,
+) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::UpdateConfig {
+            new_authority: update.new_authority,
+            treasury: update.treasury,
+            credit_price: update.credit_price,
+        },
+    )
+}
+
+/// Grant `role` to `holder` (authority, or a SuperAdmin for roles other than SuperAdmin)
+pub fn grant_role(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    holder: &Pubkey,
+    role: Role,
+) -> Instruction {
+    build(
+        accounts::GrantRole {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            role_assignment: role_assignment_pda(institution, holder, role).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::GrantRole { holder: *holder, role },
+    )
+}
+
+/// Revoke `role` from `holder`; the assignment's rent goes to the signer
+pub fn revoke_role(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    holder: &Pubkey,
+    role: Role,
+) -> Instruction {
+    build(
+        accounts::RevokeRole {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            role_assignment: role_assignment_pda(institution, holder, role).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::RevokeRole { holder: *holder, role },
+    )
+}
+
+/// Put the institution under `admins`; signed by the institution authority
+pub fn create_config_governance(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    admins: Vec<Pubkey>,
+    threshold: u8,
+    min_delay: i64,
+) -> Instruction {
+    build(
+        accounts::CreateConfigGovernance {
+            authority: *authority,
+            institution: *institution,
+            governance: config_governance_pda(institution).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateConfigGovernance { admins, threshold, min_delay },
+    )
+}
+
+/// Propose `change`; `proposal_id` must be the governance's current `proposal_count`
+pub fn propose_config_change(
+    institution: &Pubkey,
+    proposer: &Pubkey,
+    proposal_id: u64,
+    change: ConfigChange,
+) -> Instruction {
+    build(
+        accounts::ProposeConfigChange {
+            proposer: *proposer,
+            institution: *institution,
+            governance: config_governance_pda(institution).0,
+            proposal: config_proposal_pda(institution, proposal_id).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ProposeConfigChange { change },
+    )
+}
+
+/// Approve a config proposal as a governance admin
+pub fn approve_config_change(institution: &Pubkey, admin: &Pubkey, proposal_id: u64) -> Instruction {
+    build(
+        accounts::ApproveConfigChange {
+            admin: *admin,
+            institution: *institution,
+            governance: config_governance_pda(institution).0,
+            proposal: config_proposal_pda(institution, proposal_id).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ApproveConfigChange { proposal_id },
+    )
+}
+
+/// Execute an approved config proposal after its delay; any signer may.
+/// The proposal's rent goes back to `proposer`.
+pub fn execute_config_change(
+    institution: &Pubkey,
+    executor: &Pubkey,
+    proposer: &Pubkey,
+    proposal_id: u64,
+) -> Instruction {
+    build(
+        accounts::ExecuteConfigChange {
+            executor: *executor,
+            institution: *institution,
+            governance: config_governance_pda(institution).0,
+            proposal: config_proposal_pda(institution, proposal_id).0,
+            proposer: *proposer,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ExecuteConfigChange { proposal_id },
+    )
+}
+
+/// Cancel a config proposal as a governance admin; the rent goes back to `proposer`
+pub fn cancel_config_change(
+    institution: &Pubkey,
+    admin: &Pubkey,
+    proposer: &Pubkey,
+    proposal_id: u64,
+) -> Instruction {
+    build(
+        accounts::CancelConfigChange {
+            admin: *admin,
+            institution: *institution,
+            governance: config_governance_pda(institution).0,
+            proposal: config_proposal_pda(institution, proposal_id).0,
+            proposer: *proposer,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CancelConfigChange { proposal_id },
+    )
+}
+
+/// Create the student's profile, restoring the record of a closed one from its tombstone
+pub fn create_student_profile(
+    institution: &Pubkey,
+    student: &Pubkey,
+    display_name: Option<String>,
+    metadata_uri: Option<String>,
+) -> Instruction {
+    build(
+        accounts::CreateStudentProfile {
+            student: *student,
+            institution: *institution,
+            student_profile: student_profile_pda(institution, student).0,
+            profile_tombstone: profile_tombstone_pda(institution, student).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateStudentProfile { display_name, metadata_uri },
+    )
+}
+
+/// Buy `amount` credits; the student's credit token account must exist
+pub fn purchase_credits(
+    institution: &Pubkey,
+    student: &Pubkey,
+    treasury: &Pubkey,
+    credit_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::PurchaseCredits {
+            student: *student,
+            institution: *institution,
+            treasury: *treasury,
+            credit_mint: *credit_mint,
+            student_credit_account: get_associated_token_address(student, credit_mint),
+            student_profile: student_profile_pda(institution, student).0,
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::PurchaseCredits { amount },
+    )
+}
+
+/// Add a course to the institution's catalog (authority or CourseManager)
+#[allow(clippy::too_many_arguments)]
+pub fn create_course(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    course_id: &str,
+    course_name: &str,
+    instructor: &Pubkey,
+    required_credits: u64,
+    credit_hours: u8,
+    max_attempts: u8,
+) -> Instruction {
+    build(
+        accounts::CreateCourse {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            course: course_pda(institution, course_id).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateCourse {
+            course_id: course_id.to_string(),
+            course_name: course_name.to_string(),
+            instructor: *instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+        },
+    )
+}
+
+/// Course fields to change; `None` keeps the current value
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CourseUpdate {
+    pub course_name: Option<String>,
+    pub instructor: Option<Pubkey>,
+    pub required_credits: Option<u64>,
+    pub credit_hours: Option<u8>,
+    pub max_attempts: Option<u8>,
+    pub is_active: Option<bool>,
+}
+
+/// Update a course (authority or CourseManager)
+pub fn update_course(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    course_id: &str,
+    update: CourseUpdate,
+) -> Instruction {
+    build(
+        accounts::UpdateCourse {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            course: course_pda(institution, course_id).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::UpdateCourse {
+            course_id: course_id.to_string(),
+            course_name: update.course_name,
+            instructor: update.instructor,
+            required_credits: update.required_credits,
+            credit_hours: update.credit_hours,
+            max_attempts: update.max_attempts,
+            is_active: update.is_active,
+        },
+    )
+}
+
+/// Dates of a term, as Unix timestamps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TermDates {
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub registration_opens_at: i64,
+    pub registration_closes_at: i64,
+}
+
+/// Create an academic term (authority or Registrar)
+pub fn create_term(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    term_id: &str,
+    name: &str,
+    dates: TermDates,
+) -> Instruction {
+    build(
+        accounts::CreateTerm {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            term: term_pda(institution, term_id).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateTerm {
+            term_id: term_id.to_string(),
+            name: name.to_string(),
+            starts_at: dates.starts_at,
+            ends_at: dates.ends_at,
+            registration_opens_at: dates.registration_opens_at,
+            registration_closes_at: dates.registration_closes_at,
+        },
+    )
+}
+
+/// Offering deadlines; `None` falls back to the term's dates
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OfferingDeadlines {
+    pub enrollment_opens_at: Option<i64>,
+    pub enrollment_closes_at: Option<i64>,
+    pub grading_deadline: Option<i64>,
+}
+
+/// Offer a course in a term (authority or CourseManager)
+#[allow(clippy::too_many_arguments)]
+pub fn create_offering(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    course_id: &str,
+    term_id: &str,
+    instructor: &Pubkey,
+    capacity: u32,
+    deadlines: OfferingDeadlines,
+) -> Instruction {
+    build(
+        accounts::CreateOffering {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            course: course_pda(institution, course_id).0,
+            term: term_pda(institution, term_id).0,
+            offering: offering_pda(institution, course_id, term_id).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateOffering {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            instructor: *instructor,
+            capacity,
+            enrollment_opens_at: deadlines.enrollment_opens_at,
+            enrollment_closes_at: deadlines.enrollment_closes_at,
+            grading_deadline: deadlines.grading_deadline,
+        },
+    )
+}
+
+/// Register for a course offering, burning the course's credits
+pub fn register_course(
+    institution: &Pubkey,
+    student: &Pubkey,
+    credit_mint: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+) -> Instruction {
+    let course = course_pda(institution, course_id).0;
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::RegisterCourse {
+            student: *student,
+            course,
+            offering,
+            enrollment: enrollment_pda(student, &offering).0,
+            course_attempts: course_attempts_pda(student, &course).0,
+            student_profile: student_profile_pda(institution, student).0,
+            institution: *institution,
+            credit_mint: *credit_mint,
+            student_credit_account: get_associated_token_address(student, credit_mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::RegisterCourse {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+        },
+    )
+}
+
+/// Retake a failed course in the same offering
+pub fn retake_course(
+    institution: &Pubkey,
+    student: &Pubkey,
+    credit_mint: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+) -> Instruction {
+    let course = course_pda(institution, course_id).0;
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::RetakeCourse {
+            student: *student,
+            course,
+            offering,
+            enrollment: enrollment_pda(student, &offering).0,
+            course_attempts: course_attempts_pda(student, &course).0,
+            student_profile: student_profile_pda(institution, student).0,
+            institution: *institution,
+            credit_mint: *credit_mint,
+            student_credit_account: get_associated_token_address(student, credit_mint),
+            token_program: anchor_spl::token::ID,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::RetakeCourse {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+        },
+    )
+}
+
+/// Grade one student (offering instructor only)
+pub fn complete_course(
+    institution: &Pubkey,
+    instructor: &Pubkey,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    grade: u8,
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::CompleteCourse {
+            instructor: *instructor,
+            institution: *institution,
+            course: course_pda(institution, course_id).0,
+            offering,
+            student: *student,
+            enrollment: enrollment_pda(student, &offering).0,
+            student_profile: student_profile_pda(institution, student).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CompleteCourse {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            grade,
+        },
+    )
+}
+
+/// Grade up to `MAX_GRADING_BATCH_SIZE` students in one instruction
+pub fn complete_course_batch(
+    institution: &Pubkey,
+    instructor: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    grades: Vec<StudentGrade>,
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    let mut ix = build(
+        accounts::CompleteCourseBatch {
+            instructor: *instructor,
+            institution: *institution,
+            course: course_pda(institution, course_id).0,
+            offering,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CompleteCourseBatch {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            grades: grades.clone(),
+        },
+    );
+
+    // One writable (enrollment, student_profile) pair per student
+    for entry in &grades {
+        ix.accounts.push(AccountMeta::new(enrollment_pda(&entry.student, &offering).0, false));
+        ix.accounts.push(AccountMeta::new(student_profile_pda(institution, &entry.student).0, false));
+    }
+    ix
+}
+
+/// Change a completed course's grade; signed by the offering instructor, or
+/// by a Registrar passing its assignment as `instructor_role`
+pub fn amend_grade(
+    institution: &Pubkey,
+    instructor: &Pubkey,
+    instructor_role: Option<Pubkey>,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    grade: u8,
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::AmendGrade {
+            instructor: *instructor,
+            institution: *institution,
+            instructor_role,
+            course: course_pda(institution, course_id).0,
+            offering,
+            student: *student,
+            enrollment: enrollment_pda(student, &offering).0,
+            student_profile: student_profile_pda(institution, student).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::AmendGrade {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            grade,
+        },
+    )
+}
+
+/// Mark an ungraded enrollment Incomplete after the grading deadline (permissionless)
+pub fn expire_enrollment(institution: &Pubkey, student: &Pubkey, course_id: &str, term_id: &str) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::ExpireEnrollment {
+            institution: *institution,
+            offering,
+            student: *student,
+            enrollment: enrollment_pda(student, &offering).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ExpireEnrollment {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+        },
+    )
+}
+
+/// Record the certificate for a completed course; `metadata_hash` is the
+/// SHA-256 of the JSON at `metadata_uri` (see `metadata::ContentStore`)
+pub fn mint_certificate(
+    institution: &Pubkey,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    metadata_uri: &str,
+    metadata_hash: [u8; 32],
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    let enrollment = enrollment_pda(student, &offering).0;
+    let certificate_mint = certificate_mint_pda(&enrollment).0;
+    build(
+        accounts::MintCertificate {
+            student: *student,
+            institution: *institution,
+            course: course_pda(institution, course_id).0,
+            offering,
+            enrollment,
+            certificate_mint,
+            certificate_record: certificate_record_pda(&certificate_mint).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::MintCertificate {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            metadata_uri: metadata_uri.to_string(),
+            metadata_hash,
+        },
+    )
+}
+
+/// Claim the graduation diploma
+pub fn claim_graduation(institution: &Pubkey, student: &Pubkey, required_courses: Vec<String>) -> Instruction {
+    build(
+        accounts::ClaimGraduation {
+            student: *student,
+            institution: *institution,
+            student_profile: student_profile_pda(institution, student).0,
+            graduation_mint: graduation_mint_pda(institution, student).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ClaimGraduation { required_courses },
+    )
+}
+
+/// Close a finished enrollment, leaving its `EnrollmentRecord`, and reclaim the rest of its rent
+pub fn close_enrollment(institution: &Pubkey, student: &Pubkey, course_id: &str, term_id: &str) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    let enrollment = enrollment_pda(student, &offering).0;
+    build(
+        accounts::CloseEnrollment {
+            student: *student,
+            institution: *institution,
+            offering,
+            enrollment,
+            student_profile: student_profile_pda(institution, student).0,
+            enrollment_record: enrollment_record_pda(&enrollment).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CloseEnrollment {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+        },
+    )
+}
+
+/// Close a student profile with no open enrollments, leaving its record in a tombstone
+pub fn close_profile(institution: &Pubkey, student: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseProfile {
+            student: *student,
+            institution: *institution,
+            student_profile: student_profile_pda(institution, student).0,
+            profile_tombstone: profile_tombstone_pda(institution, student).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CloseProfile {},
+    )
+}
+
+/// Anchor the Merkle root of a student's transcript; signed by the
+/// institution `authority` or a Registrar
+pub fn anchor_transcript(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    student: &Pubkey,
+    merkle_root: [u8; 32],
+    entry_count: u16,
+) -> Instruction {
+    build(
+        accounts::AnchorTranscript {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            student_profile: student_profile_pda(institution, student).0,
+            transcript_anchor: transcript_anchor_pda(institution, student, &merkle_root).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::AnchorTranscript {
+            merkle_root,
+            entry_count,
+        },
+    )
+}
+
+/// Check a transcript entry proof; the program sets a Borsh `bool` as return data
+pub fn verify_transcript_entry(transcript_anchor: &Pubkey, entry: TranscriptEntry, proof: Vec<[u8; 32]>) -> Instruction {
+    build(
+        accounts::VerifyTranscriptEntry {
+            transcript_anchor: *transcript_anchor,
+        },
+        instruction::VerifyTranscriptEntry { entry, proof },
+    )
+}
+
+/// Post the Merkle root of an offering's certificates; signed by its
+/// instructor, or by a Reviewer passing its assignment as `issuer_role`
+pub fn create_certificate_batch(
+    institution: &Pubkey,
+    issuer: &Pubkey,
+    issuer_role: Option<Pubkey>,
+    course_id: &str,
+    term_id: &str,
+    merkle_root: [u8; 32],
+    certificate_count: u16,
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::CreateCertificateBatch {
+            issuer: *issuer,
+            institution: *institution,
+            issuer_role,
+            offering,
+            certificate_batch: certificate_batch_pda(&offering, &merkle_root).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateCertificateBatch {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            merkle_root,
+            certificate_count,
+        },
+    )
+}
+
+/// Claim a batch certificate, linking the student's enrollment to the batch
+pub fn claim_batch_certificate(
+    institution: &Pubkey,
+    student: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    merkle_root: &[u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::ClaimBatchCertificate {
+            student: *student,
+            institution: *institution,
+            offering,
+            enrollment: enrollment_pda(student, &offering).0,
+            certificate_batch: certificate_batch_pda(&offering, merkle_root).0,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::ClaimBatchCertificate {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            proof,
+        },
+    )
+}
+
+/// Check a batch certificate proof; the program sets a Borsh `bool` as return data
+pub fn verify_batch_certificate(certificate_batch: &Pubkey, leaf: CertificateLeaf, proof: Vec<[u8; 32]>) -> Instruction {
+    build(
+        accounts::VerifyBatchCertificate {
+            certificate_batch: *certificate_batch,
+        },
+        instruction::VerifyBatchCertificate { leaf, proof },
+    )
+}
+
+/// Initialize a compressed certificate tree; `merkle_tree` must already be
+/// allocated to the compression program (see `compression::allocate_tree`).
+/// Signed by the institution `authority` or a Reviewer.
+pub fn create_certificate_tree(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    merkle_tree: &Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    build(
+        accounts::CreateCertificateTree {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            merkle_tree: *merkle_tree,
+            certificate_tree: certificate_tree_pda(merkle_tree).0,
+            compression_program: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            noop_program: NOOP_PROGRAM_ID,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateCertificateTree {
+            max_depth,
+            max_buffer_size,
+        },
+    )
+}
+
+/// Append the certificate for a completed course to a certificate tree
+/// instead of minting it; `metadata_hash` as for `mint_certificate`
+pub fn mint_compressed_certificate(
+    institution: &Pubkey,
+    student: &Pubkey,
+    merkle_tree: &Pubkey,
+    course_id: &str,
+    term_id: &str,
+    metadata_uri: &str,
+    metadata_hash: [u8; 32],
+) -> Instruction {
+    let offering = offering_pda(institution, course_id, term_id).0;
+    build(
+        accounts::MintCompressedCertificate {
+            student: *student,
+            institution: *institution,
+            offering,
+            enrollment: enrollment_pda(student, &offering).0,
+            certificate_tree: certificate_tree_pda(merkle_tree).0,
+            merkle_tree: *merkle_tree,
+            compression_program: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            noop_program: NOOP_PROGRAM_ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::MintCompressedCertificate {
+            course_id: course_id.to_string(),
+            term_id: term_id.to_string(),
+            metadata_uri: metadata_uri.to_string(),
+            metadata_hash,
+        },
+    )
+}
+
+/// Accept `source_course_id` at `source_institution` as `course_id` at
+/// `institution`; signed by the authority or a Registrar of each institution
+#[allow(clippy::too_many_arguments)]
+pub fn create_course_equivalency(
+    institution: &Pubkey,
+    authority: &Pubkey,
+    authority_role: Option<Pubkey>,
+    source_institution: &Pubkey,
+    source_authority: &Pubkey,
+    source_authority_role: Option<Pubkey>,
+    source_course_id: &str,
+    course_id: &str,
+) -> Instruction {
+    let course = course_pda(institution, course_id).0;
+    let source_course = course_pda(source_institution, source_course_id).0;
+    build(
+        accounts::CreateCourseEquivalency {
+            authority: *authority,
+            institution: *institution,
+            authority_role,
+            course,
+            source_authority: *source_authority,
+            source_institution: *source_institution,
+            source_authority_role,
+            source_course,
+            course_equivalency: course_equivalency_pda(&source_course, &course).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::CreateCourseEquivalency {
+            source_course_id: source_course_id.to_string(),
+            course_id: course_id.to_string(),
+        },
+    )
+}
+
+/// Transfer the student's completed `source_course_id` enrollment at
+/// `source_institution` to `course_id` at `institution`, once the source
+/// offering's grading deadline has passed
+pub fn transfer_completion(
+    institution: &Pubkey,
+    source_institution: &Pubkey,
+    student: &Pubkey,
+    source_course_id: &str,
+    source_term_id: &str,
+    course_id: &str,
+) -> Instruction {
+    let course = course_pda(institution, course_id).0;
+    let source_course = course_pda(source_institution, source_course_id).0;
+    let source_offering = offering_pda(source_institution, source_course_id, source_term_id).0;
+    let source_enrollment = enrollment_pda(student, &source_offering).0;
+    build(
+        accounts::TransferCompletion {
+            student: *student,
+            institution: *institution,
+            course,
+            source_institution: *source_institution,
+            source_course,
+            source_offering,
+            source_enrollment,
+            course_equivalency: course_equivalency_pda(&source_course, &course).0,
+            enrollment: transferred_enrollment_pda(institution, &source_enrollment).0,
+            student_profile: student_profile_pda(institution, student).0,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda().0,
+            program: ID,
+        },
+        instruction::TransferCompletion {
+            source_course_id: source_course_id.to_string(),
+            source_term_id: source_term_id.to_string(),
+            course_id: course_id.to_string(),
+        },
+    )
+}
@@ -0,0 +1,26 @@
+//! Rust client for the `academic_chain` program: PDA helpers, instruction
+//! builders, account fetching, program error decoding, catalog diffing,
+//! certificate metadata, certificate verification, W3C Verifiable
+//! Credentials, signed transcripts, Merkle-batched certificates,
+//! compressed certificates and cohort grading through lookup tables.
+
+pub mod accounts;
+pub mod batch;
+pub mod catalog;
+pub mod compression;
+pub mod credential;
+pub mod error;
+pub mod grading;
+pub mod instructions;
+pub mod metadata;
+pub mod pda;
+pub mod pdf;
+pub mod transcript;
+pub mod verify;
+
+pub use academic_chain::errors::AcademicChainError;
+pub use academic_chain::events;
+pub use academic_chain::state;
+pub use academic_chain::ID;
+
+pub use error::{decode_error_code, decode_rpc_error, decode_transaction_error, Error, Result};
@@ -0,0 +1,240 @@
+//! Off-chain certificate metadata and its content-addressed store.
+//!
+//! Certificates follow the Metaplex token metadata JSON standard. The JSON
+//! is serialized once, the SHA-256 of those exact bytes is its address in a
+//! `ContentStore`, and `mint_certificate` records the same hash on the
+//! enrollment. Anyone can then compare a hosted file with the on-chain hash.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering, StudentProfile};
+use anchor_lang::solana_program::hash::hash;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const CERTIFICATE_SYMBOL: &str = "ACERT";
+
+/// Links shared by every certificate a deployment issues
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CertificateLinks {
+    pub image: String,
+    /// e.g. the public verify page
+    pub external_url: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CertificateMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub description: String,
+    pub image: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    pub attributes: Vec<Attribute>,
+    pub properties: Properties,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    pub trait_type: String,
+    pub value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+}
+
+impl Attribute {
+    fn new(trait_type: &str, value: impl Into<Value>) -> Self {
+        Self {
+            trait_type: trait_type.to_string(),
+            value: value.into(),
+            display_type: None,
+        }
+    }
+
+    /// Unix timestamp rendered as a date by marketplaces and wallets
+    fn date(trait_type: &str, timestamp: i64) -> Self {
+        Self {
+            display_type: Some("date".to_string()),
+            ..Self::new(trait_type, timestamp)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Properties {
+    pub files: Vec<MetadataFile>,
+    pub category: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MetadataFile {
+    pub uri: String,
+    #[serde(rename = "type")]
+    pub mime: String,
+}
+
+fn image_properties(image: &str) -> Properties {
+    let extension = image.rsplit('.').next().unwrap_or_default().to_ascii_lowercase();
+    let mime = match extension.as_str() {
+        "svg" => "image/svg+xml",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        _ => "image/png",
+    };
+    Properties {
+        files: vec![MetadataFile {
+            uri: image.to_string(),
+            mime: mime.to_string(),
+        }],
+        category: "image".to_string(),
+    }
+}
+
+/// GPA stored x100, as shown on a transcript
+pub(crate) fn format_gpa(gpa: u16) -> String {
+    format!("{}.{:02}", gpa / 100, gpa % 100)
+}
+
+impl CertificateMetadata {
+    /// Metadata for the certificate of a completed course
+    pub fn course(
+        enrollment: &CourseEnrollment,
+        course: &Course,
+        offering: &CourseOffering,
+        links: &CertificateLinks,
+    ) -> Self {
+        let mut attributes = vec![
+            Attribute::new("course_id", enrollment.course_id.as_str()),
+            Attribute::new("course_name", course.course_name.as_str()),
+            Attribute::new("term_id", offering.term_id.as_str()),
+            Attribute::new("grade", enrollment.grade),
+            Attribute::new("letter_grade", enrollment.letter_grade.as_str()),
+            Attribute::new("credit_hours", course.credit_hours),
+            Attribute::new("instructor", offering.instructor.to_string()),
+            Attribute::new("student", enrollment.student.to_string()),
+        ];
+        if let Some(completed) = enrollment.completion_date {
+            attributes.push(Attribute::date("completion_date", completed));
+        }
+
+        Self {
+            name: format!("{} Certificate", course.course_id),
+            symbol: CERTIFICATE_SYMBOL.to_string(),
+            description: format!(
+                "Certificate of completion for {} ({}), term {}.",
+                course.course_name, course.course_id, offering.term_id
+            ),
+            image: links.image.clone(),
+            external_url: links.external_url.clone(),
+            attributes,
+            properties: image_properties(&links.image),
+        }
+    }
+
+    /// Metadata for a graduation diploma, from the student's final profile
+    pub fn graduation(profile: &StudentProfile, links: &CertificateLinks) -> Self {
+        let mut attributes = vec![
+            Attribute::new("student", profile.student.to_string()),
+            Attribute::new("gpa", format_gpa(profile.gpa)),
+            Attribute::new("courses_completed", profile.courses_completed),
+            Attribute::new("credit_hours_earned", profile.credit_hours_earned),
+        ];
+        if let Some(name) = &profile.display_name {
+            attributes.insert(1, Attribute::new("student_name", name.as_str()));
+        }
+
+        Self {
+            name: "Graduation Diploma".to_string(),
+            symbol: CERTIFICATE_SYMBOL.to_string(),
+            description: format!(
+                "Graduation diploma: {} courses completed with a GPA of {}.",
+                profile.courses_completed,
+                format_gpa(profile.gpa)
+            ),
+            image: links.image.clone(),
+            external_url: links.external_url.clone(),
+            attributes,
+            properties: image_properties(&links.image),
+        }
+    }
+
+    /// The exact bytes that are hashed, stored and hosted
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec_pretty(self).expect("metadata serializes")
+    }
+}
+
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    hash(bytes).to_bytes()
+}
+
+pub fn hash_to_hex(hash: &[u8; 32]) -> String {
+    hash.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+pub fn hash_from_hex(hex: &str) -> Option<[u8; 32]> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(hash)
+}
+
+/// Whether `document` is the metadata the enrollment's certificate was minted with
+pub fn matches_enrollment(document: &[u8], enrollment: &CourseEnrollment) -> bool {
+    enrollment.metadata_hash == Some(sha256(document))
+}
+
+/// Files named by the hex SHA-256 of their contents: `<root>/<hash>.json`
+#[derive(Clone, Debug)]
+pub struct ContentStore {
+    root: PathBuf,
+}
+
+impl ContentStore {
+    pub fn open(root: impl Into<PathBuf>) -> io::Result<Self> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, hash: &[u8; 32]) -> PathBuf {
+        self.root.join(format!("{}.json", hash_to_hex(hash)))
+    }
+
+    /// Store `document` and return its hash; storing it again is a no-op
+    pub fn put(&self, document: &[u8]) -> io::Result<[u8; 32]> {
+        let hash = sha256(document);
+        let path = self.path(&hash);
+        if !path.exists() {
+            // Write then rename so a reader never sees a partial file
+            let partial = path.with_extension("json.partial");
+            fs::write(&partial, document)?;
+            fs::rename(&partial, &path)?;
+        }
+        Ok(hash)
+    }
+
+    /// Read a document back, failing if its contents no longer match the hash
+    pub fn get(&self, hash: &[u8; 32]) -> io::Result<Vec<u8>> {
+        let document = fs::read(self.path(hash))?;
+        if sha256(&document) != *hash {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} does not match its hash", self.path(hash).display()),
+            ));
+        }
+        Ok(document)
+    }
+}
+
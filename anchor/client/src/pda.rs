@@ -0,0 +1,118 @@
+//! PDA derivation, using the seeds from `academic_chain::constants`.
+
+use academic_chain::constants::*;
+use academic_chain::state::Role;
+use academic_chain::ID;
+use anchor_lang::prelude::Pubkey;
+
+/// Institution: `["institution", institution_id]`
+pub fn institution_pda(institution_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[INSTITUTION_SEED, institution_id.as_bytes()], &ID)
+}
+
+/// Course catalog entry: `["course", institution, course_id]`
+pub fn course_pda(institution: &Pubkey, course_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COURSE_SEED, institution.as_ref(), course_id.as_bytes()], &ID)
+}
+
+/// Academic term: `["term", institution, term_id]`
+pub fn term_pda(institution: &Pubkey, term_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TERM_SEED, institution.as_ref(), term_id.as_bytes()], &ID)
+}
+
+/// Course offering: `["offering", institution, course_id, term_id]`
+pub fn offering_pda(institution: &Pubkey, course_id: &str, term_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[OFFERING_SEED, institution.as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        &ID,
+    )
+}
+
+/// Enrollment: `["enrollment", student, offering]`
+pub fn enrollment_pda(student: &Pubkey, offering: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ENROLLMENT_SEED, student.as_ref(), offering.as_ref()], &ID)
+}
+
+/// Completion transferred in from another institution:
+/// `["enrollment", institution, source_enrollment]`
+pub fn transferred_enrollment_pda(institution: &Pubkey, source_enrollment: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ENROLLMENT_SEED, institution.as_ref(), source_enrollment.as_ref()], &ID)
+}
+
+/// What is left of a closed enrollment: `["enrollment_record", enrollment]`
+pub fn enrollment_record_pda(enrollment: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ENROLLMENT_RECORD_SEED, enrollment.as_ref()], &ID)
+}
+
+/// A student's attempts at a course across its offerings: `["course_attempts", student, course]`
+pub fn course_attempts_pda(student: &Pubkey, course: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COURSE_ATTEMPTS_SEED, student.as_ref(), course.as_ref()], &ID)
+}
+
+/// Student profile: `["student_profile", institution, student]`
+pub fn student_profile_pda(institution: &Pubkey, student: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STUDENT_PROFILE_SEED, institution.as_ref(), student.as_ref()], &ID)
+}
+
+/// Record left by a closed student profile: `["profile_tombstone", institution, student]`
+pub fn profile_tombstone_pda(institution: &Pubkey, student: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROFILE_TOMBSTONE_SEED, institution.as_ref(), student.as_ref()], &ID)
+}
+
+/// Course certificate mint: `["certificate_mint", enrollment]`
+pub fn certificate_mint_pda(enrollment: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CERTIFICATE_MINT_SEED, enrollment.as_ref()], &ID)
+}
+
+/// Enrollment behind a certificate mint: `["certificate_record", certificate_mint]`
+pub fn certificate_record_pda(certificate_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CERTIFICATE_RECORD_SEED, certificate_mint.as_ref()], &ID)
+}
+
+/// Graduation diploma mint: `["graduation_mint", institution, student]`
+pub fn graduation_mint_pda(institution: &Pubkey, student: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GRADUATION_MINT_SEED, institution.as_ref(), student.as_ref()], &ID)
+}
+
+/// Anchored transcript root: `["transcript", institution, student, merkle_root]`
+pub fn transcript_anchor_pda(institution: &Pubkey, student: &Pubkey, merkle_root: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRANSCRIPT_ANCHOR_SEED, institution.as_ref(), student.as_ref(), merkle_root], &ID)
+}
+
+/// Certificate batch root: `["certificate_batch", offering, merkle_root]`
+pub fn certificate_batch_pda(offering: &Pubkey, merkle_root: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CERTIFICATE_BATCH_SEED, offering.as_ref(), merkle_root], &ID)
+}
+
+/// Authority of a compressed certificate tree: `["certificate_tree", merkle_tree]`
+pub fn certificate_tree_pda(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CERTIFICATE_TREE_SEED, merkle_tree.as_ref()], &ID)
+}
+
+/// Course equivalency: `["equivalency", source_course, course]`
+pub fn course_equivalency_pda(source_course: &Pubkey, course: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COURSE_EQUIVALENCY_SEED, source_course.as_ref(), course.as_ref()], &ID)
+}
+
+/// Role held by `holder` at an institution: `["role", institution, holder, role as u8]`
+pub fn role_assignment_pda(institution: &Pubkey, holder: &Pubkey, role: Role) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROLE_ASSIGNMENT_SEED, institution.as_ref(), holder.as_ref(), &[role as u8]], &ID)
+}
+
+/// Config governance of an institution: `["governance", institution]`
+pub fn config_governance_pda(institution: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_GOVERNANCE_SEED, institution.as_ref()], &ID)
+}
+
+/// Config proposal: `["config_proposal", institution, proposal_id as le bytes]`
+pub fn config_proposal_pda(institution: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CONFIG_PROPOSAL_SEED, institution.as_ref(), &proposal_id.to_le_bytes()],
+        &ID,
+    )
+}
+
+/// Signer for `emit_cpi!` events: `["__event_authority"]`
+pub fn event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID)
+}
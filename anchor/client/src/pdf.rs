@@ -0,0 +1,214 @@
+//! A minimal PDF writer for text documents with an attached file.
+//!
+//! Enough for transcripts: A4 pages of left-aligned lines in the standard
+//! Helvetica and Courier fonts, flowing onto new pages as needed, plus one
+//! uncompressed embedded file so the signed JSON travels with the PDF.
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+    Mono,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+            Font::Mono => "F3",
+        }
+    }
+}
+
+struct Line {
+    font: Font,
+    size: f32,
+    text: String,
+}
+
+struct Attachment {
+    name: String,
+    mime: String,
+    data: Vec<u8>,
+}
+
+pub struct PdfDocument {
+    title: String,
+    lines: Vec<Line>,
+    attachment: Option<Attachment>,
+}
+
+// Standard fonts use WinAnsiEncoding; anything outside printable ASCII is
+// replaced rather than risk mis-encoding
+fn pdf_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('(');
+    for ch in text.chars() {
+        match ch {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            ' '..='~' => escaped.push(ch),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped.push(')');
+    escaped
+}
+
+impl PdfDocument {
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            lines: Vec::new(),
+            attachment: None,
+        }
+    }
+
+    pub fn line(&mut self, font: Font, size: f32, text: impl Into<String>) -> &mut Self {
+        self.lines.push(Line {
+            font,
+            size,
+            text: text.into(),
+        });
+        self
+    }
+
+    pub fn blank(&mut self) -> &mut Self {
+        self.line(Font::Regular, 10.0, "")
+    }
+
+    /// Embed a file, shown by PDF readers as an attachment
+    pub fn attach(&mut self, name: &str, mime: &str, data: Vec<u8>) -> &mut Self {
+        self.attachment = Some(Attachment {
+            name: name.to_string(),
+            mime: mime.to_string(),
+            data,
+        });
+        self
+    }
+
+    // Content stream of each page
+    fn pages(&self) -> Vec<String> {
+        let mut pages = Vec::new();
+        let mut content = String::new();
+        let mut y = PAGE_HEIGHT - MARGIN;
+        for line in &self.lines {
+            let leading = line.size * 1.4;
+            if y - leading < MARGIN && !content.is_empty() {
+                pages.push(std::mem::take(&mut content));
+                y = PAGE_HEIGHT - MARGIN;
+            }
+            y -= leading;
+            if !line.text.is_empty() {
+                content.push_str(&format!(
+                    "BT /{} {} Tf 1 0 0 1 {MARGIN} {y:.1} Tm {} Tj ET\n",
+                    line.font.resource(),
+                    line.size,
+                    pdf_string(&line.text)
+                ));
+            }
+        }
+        pages.push(content);
+        pages
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let pages = self.pages();
+        // 1 catalog, 2 page tree, 3-5 fonts, 6 info, 7-8 attachment, then a
+        // page and its content stream per page
+        let first_page = 9;
+        let kids: Vec<String> = (0..pages.len())
+            .map(|index| format!("{} 0 R", first_page + 2 * index))
+            .collect();
+
+        let names = match &self.attachment {
+            Some(attachment) => format!(
+                " /Names << /EmbeddedFiles << /Names [{} 8 0 R] >> >>",
+                pdf_string(&attachment.name)
+            ),
+            None => String::new(),
+        };
+        let mut objects: Vec<Vec<u8>> = vec![
+            format!("<< /Type /Catalog /Pages 2 0 R{names} >>").into_bytes(),
+            format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()).into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Courier /Encoding /WinAnsiEncoding >>".to_vec(),
+            format!("<< /Title {} /Producer (academic_chain-client) >>", pdf_string(&self.title)).into_bytes(),
+        ];
+        match &self.attachment {
+            Some(attachment) => {
+                let mut stream = format!(
+                    "<< /Type /EmbeddedFile /Subtype /{} /Length {} >>\nstream\n",
+                    attachment.mime.replace('/', "#2F"),
+                    attachment.data.len()
+                )
+                .into_bytes();
+                stream.extend_from_slice(&attachment.data);
+                stream.extend_from_slice(b"\nendstream");
+                objects.push(stream);
+                let name = pdf_string(&attachment.name);
+                objects.push(
+                    format!("<< /Type /Filespec /F {name} /UF {name} /EF << /F 7 0 R >> >>").into_bytes(),
+                );
+            }
+            None => {
+                objects.push(b"null".to_vec());
+                objects.push(b"null".to_vec());
+            }
+        }
+        for (index, content) in pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> /Contents {} 0 R >>",
+                    first_page + 2 * index + 1
+                )
+                .into_bytes(),
+            );
+            objects.push(format!("<< /Length {} >>\nstream\n{content}endstream", content.len()).into_bytes());
+        }
+
+        let mut pdf = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            pdf.extend_from_slice(object);
+            pdf.extend_from_slice(b"\nendobj\n");
+        }
+        let xref = pdf.len();
+        pdf.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R /Info 6 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .as_bytes(),
+        );
+        pdf
+    }
+}
+
+/// The embedded file of a PDF written by `PdfDocument`
+pub fn extract_attachment(pdf: &[u8]) -> Option<Vec<u8>> {
+    let find = |haystack: &[u8], needle: &[u8]| haystack.windows(needle.len()).position(|window| window == needle);
+
+    let start = find(pdf, b"/Type /EmbeddedFile ")?;
+    let dict = &pdf[start..];
+    let length_at = find(dict, b"/Length ")? + b"/Length ".len();
+    let digits: Vec<u8> = dict[length_at..].iter().take_while(|byte| byte.is_ascii_digit()).copied().collect();
+    let length: usize = std::str::from_utf8(&digits).ok()?.parse().ok()?;
+    let data_at = find(dict, b"stream\n")? + b"stream\n".len();
+    dict.get(data_at..data_at + length).map(<[u8]>::to_vec)
+}
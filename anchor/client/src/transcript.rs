@@ -0,0 +1,607 @@
+//! Signed academic transcripts.
+//!
+//! A transcript lists a student's enrollment accounts with the GPA and
+//! graduation status from their `StudentProfile`. Every entry hashes to a
+//! Merkle leaf (`academic_chain::utils::transcript_leaf`), the root is
+//! anchored on-chain by `anchor_transcript`, and the document is signed by
+//! the institution key with the same `eddsa-jcs-2022` proof as credentials.
+//!
+//! A copy verifies when its proof checks out, its entries hash to its root,
+//! and a `TranscriptAnchor` for its institution, student and root was
+//! written by the signing key. Anchors are never closed, so older copies keep verifying
+//! after grades change; they just no longer match the latest transcript.
+//! Closed enrollments are listed from the `EnrollmentRecord` that
+//! `close_enrollment` leaves, so they stay on later transcripts.
+//!
+//! A completion transferred from another institution counts only while its
+//! `transferred_from` source does. Once the source grade is amended below
+//! passing, new transcripts leave the transfer out and verification rejects
+//! copies that still list it. The receiving profile's GPA is not adjusted.
+//!
+//! A student can disclose a single entry with `Transcript::disclose`: the
+//! entry plus its Merkle proof, checked against the same anchor without
+//! revealing the other entries. The course name is shown but not covered
+//! by the leaf. On-chain, `verify_transcript_entry` checks such a proof.
+
+use std::collections::HashMap;
+
+use academic_chain::state::{
+    Course, CourseEnrollment, CourseOffering, EnrollmentStatus, StudentProfile, TranscriptAnchor, TranscriptEntry,
+};
+use academic_chain::utils::{is_passing_grade, merkle_proof, merkle_root, transcript_leaf, verify_merkle_proof};
+use academic_chain::ID;
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_signer::Signer;
+
+use crate::accounts::{
+    fetch_account, fetch_account_optional, fetch_course, fetch_enrollment_or_record, fetch_student_enrollments,
+};
+use crate::credential::{resolve_did, sign, timestamp, verify_proof, CredentialFailure, DataIntegrityProof, DidMethod};
+use crate::error::Result;
+use crate::metadata::{format_gpa, hash_from_hex, hash_to_hex};
+use crate::pda::transcript_anchor_pda;
+use crate::pdf::{extract_attachment, Font, PdfDocument};
+
+pub const TRANSCRIPT_TYPE: &str = "AcademicTranscript";
+pub const DISCLOSURE_TYPE: &str = "TranscriptEntryDisclosure";
+/// Name of the signed JSON attached to transcript PDFs
+pub const PDF_ATTACHMENT: &str = "transcript.json";
+/// Term shown for completions transferred from another institution
+pub const TRANSFER_TERM_ID: &str = "TRANSFER";
+
+/// An enrollment with the off-account details a transcript shows
+#[derive(Clone)]
+pub struct TranscriptRecord {
+    pub address: Pubkey,
+    pub enrollment: CourseEnrollment,
+    pub term_id: String,
+    pub course_name: String,
+}
+
+/// Every enrollment of `student` at `institution` with its term and course name
+pub fn fetch_transcript_records(
+    rpc: &RpcClient,
+    institution: &Pubkey,
+    student: &Pubkey,
+) -> Result<Vec<TranscriptRecord>> {
+    let mut courses: HashMap<String, Course> = HashMap::new();
+    let mut records = Vec::new();
+    for (address, enrollment) in fetch_student_enrollments(rpc, institution, student)? {
+        let term_id = match enrollment.transferred_from {
+            Some(source) if !transfer_source_counts(fetch_enrollment_or_record(rpc, &source)?.as_ref()) => continue,
+            Some(_) => TRANSFER_TERM_ID.to_string(),
+            None => fetch_account::<CourseOffering>(rpc, &enrollment.offering)?.term_id,
+        };
+        if !courses.contains_key(&enrollment.course_id) {
+            courses.insert(enrollment.course_id.clone(), fetch_course(rpc, institution, &enrollment.course_id)?);
+        }
+        records.push(TranscriptRecord {
+            address,
+            course_name: courses[&enrollment.course_id].course_name.clone(),
+            enrollment,
+            term_id,
+        });
+    }
+    Ok(records)
+}
+
+/// Whether the source of a transferred completion still counts: it exists,
+/// is completed and its grade was not amended below passing
+pub fn transfer_source_counts(source: Option<&CourseEnrollment>) -> bool {
+    source.is_some_and(|source| source.status == EnrollmentStatus::Completed && is_passing_grade(source.grade))
+}
+
+// The first transferred line whose source no longer counts
+fn check_transfers<'a>(
+    rpc: &RpcClient,
+    lines: impl IntoIterator<Item = &'a TranscriptLine>,
+) -> Result<Option<TranscriptFailure>> {
+    for line in lines.into_iter().filter(|line| line.term_id == TRANSFER_TERM_ID) {
+        let Ok(address) = line.enrollment.parse::<Pubkey>() else {
+            return Ok(Some(malformed(format!("entry {}", line.enrollment))));
+        };
+        let Some(source) = fetch_enrollment_or_record(rpc, &address)?.and_then(|enrollment| enrollment.transferred_from)
+        else {
+            continue;
+        };
+        if !transfer_source_counts(fetch_enrollment_or_record(rpc, &source)?.as_ref()) {
+            return Ok(Some(TranscriptFailure::TransferRevoked(line.enrollment.clone())));
+        }
+    }
+    Ok(None)
+}
+
+fn status_name(status: &EnrollmentStatus) -> &'static str {
+    match status {
+        EnrollmentStatus::Enrolled => "enrolled",
+        EnrollmentStatus::Completed => "completed",
+        EnrollmentStatus::Incomplete => "incomplete",
+    }
+}
+
+fn parse_status(name: &str) -> Option<EnrollmentStatus> {
+    match name {
+        "enrolled" => Some(EnrollmentStatus::Enrolled),
+        "completed" => Some(EnrollmentStatus::Completed),
+        "incomplete" => Some(EnrollmentStatus::Incomplete),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptLine {
+    pub enrollment: String,
+    pub course_id: String,
+    pub course_name: String,
+    pub term_id: String,
+    pub status: String,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub credit_hours: u8,
+    /// Unix seconds, as stored on the enrollment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completion_date: Option<i64>,
+}
+
+impl TranscriptLine {
+    fn new(record: &TranscriptRecord) -> Self {
+        let enrollment = &record.enrollment;
+        Self {
+            enrollment: record.address.to_string(),
+            course_id: enrollment.course_id.clone(),
+            course_name: record.course_name.clone(),
+            term_id: record.term_id.clone(),
+            status: status_name(&enrollment.status).to_string(),
+            grade: enrollment.grade,
+            letter_grade: enrollment.letter_grade.clone(),
+            credit_hours: enrollment.credit_hours,
+            completion_date: enrollment.completion_date,
+        }
+    }
+
+    /// The hashed form of this line; `None` if a field does not parse
+    pub fn entry(&self) -> Option<TranscriptEntry> {
+        Some(TranscriptEntry {
+            enrollment: self.enrollment.parse().ok()?,
+            course_id: self.course_id.clone(),
+            term_id: self.term_id.clone(),
+            status: parse_status(&self.status)?,
+            grade: self.grade,
+            letter_grade: self.letter_grade.clone(),
+            credit_hours: self.credit_hours,
+            completion_date: self.completion_date,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptSummary {
+    pub courses_completed: u16,
+    pub credit_hours_attempted: u16,
+    pub credit_hours_earned: u16,
+    pub gpa: String,
+    pub graduated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graduation_mint: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transcript {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub program_id: String,
+    /// The institution account the transcript was issued from
+    pub institution: String,
+    pub student: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub student_name: Option<String>,
+    /// The institution's DID
+    pub issuer: String,
+    pub issued_at: String,
+    pub entries: Vec<TranscriptLine>,
+    pub summary: TranscriptSummary,
+    /// Hex Merkle root of `entries`, as anchored on-chain
+    pub merkle_root: String,
+    pub transcript_anchor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<DataIntegrityProof>,
+}
+
+impl Transcript {
+    /// Unsigned transcript; entries are ordered by enrollment date
+    pub fn new(profile: &StudentProfile, records: &[TranscriptRecord], issuer: String, issued_at: i64) -> Self {
+        let mut records: Vec<&TranscriptRecord> = records.iter().collect();
+        records.sort_by(|a, b| {
+            (a.enrollment.enrollment_date, &a.enrollment.course_id, a.address)
+                .cmp(&(b.enrollment.enrollment_date, &b.enrollment.course_id, b.address))
+        });
+        let entries: Vec<TranscriptLine> = records.into_iter().map(TranscriptLine::new).collect();
+        let leaves: Vec<[u8; 32]> = entries
+            .iter()
+            .map(|line| transcript_leaf(&line.entry().expect("lines built from accounts parse")))
+            .collect();
+        let root = merkle_root(&leaves);
+
+        Self {
+            kind: TRANSCRIPT_TYPE.to_string(),
+            program_id: ID.to_string(),
+            institution: profile.institution.to_string(),
+            student: profile.student.to_string(),
+            student_name: profile.display_name.clone(),
+            issuer,
+            issued_at: timestamp(issued_at),
+            entries,
+            summary: TranscriptSummary {
+                courses_completed: profile.courses_completed,
+                credit_hours_attempted: profile.credit_hours_attempted,
+                credit_hours_earned: profile.credit_hours_earned,
+                gpa: format_gpa(profile.gpa),
+                graduated: profile.graduation_nft.is_some(),
+                graduation_mint: profile.graduation_nft.map(|mint| mint.to_string()),
+            },
+            merkle_root: hash_to_hex(&root),
+            transcript_anchor: transcript_anchor_pda(&profile.institution, &profile.student, &root).0.to_string(),
+            proof: None,
+        }
+    }
+
+    /// Merkle leaves of the entries, in order
+    pub fn leaves(&self) -> std::result::Result<Vec<[u8; 32]>, TranscriptFailure> {
+        self.entries
+            .iter()
+            .map(|line| {
+                line.entry()
+                    .map(|entry| transcript_leaf(&entry))
+                    .ok_or_else(|| malformed(format!("entry {}", line.enrollment)))
+            })
+            .collect()
+    }
+
+    /// The root stated by the transcript
+    pub fn root(&self) -> std::result::Result<[u8; 32], TranscriptFailure> {
+        hash_from_hex(&self.merkle_root).ok_or_else(|| malformed("merkleRoot".to_string()))
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).expect("transcript serializes")
+    }
+
+    /// A printable PDF with this transcript attached as `transcript.json`
+    pub fn to_pdf(&self) -> Vec<u8> {
+        let mut pdf = PdfDocument::new("Academic Transcript");
+        pdf.line(Font::Bold, 18.0, "Academic Transcript").blank();
+        let student = match &self.student_name {
+            Some(name) => format!("Student: {name} ({})", self.student),
+            None => format!("Student: {}", self.student),
+        };
+        pdf.line(Font::Regular, 10.0, student)
+            .line(Font::Regular, 10.0, format!("Issuer: {}", self.issuer))
+            .line(Font::Regular, 10.0, format!("Issued: {}", self.issued_at))
+            .blank();
+
+        pdf.line(
+            Font::Bold,
+            9.0,
+            format!("{:<10} {:<30} {:<10} {:>5} {:<6} {:>3}  Status", "Course", "Name", "Term", "Grade", "Letter", "Cr"),
+        );
+        for line in &self.entries {
+            let name: String = line.course_name.chars().take(30).collect();
+            pdf.line(
+                Font::Mono,
+                9.0,
+                format!(
+                    "{:<10} {:<30} {:<10} {:>5} {:<6} {:>3}  {}",
+                    line.course_id, name, line.term_id, line.grade, line.letter_grade, line.credit_hours, line.status
+                ),
+            );
+        }
+
+        let summary = &self.summary;
+        pdf.blank()
+            .line(Font::Bold, 10.0, format!("GPA {}", summary.gpa))
+            .line(
+                Font::Regular,
+                10.0,
+                format!(
+                    "{} courses completed, {} of {} credit hours earned",
+                    summary.courses_completed, summary.credit_hours_earned, summary.credit_hours_attempted
+                ),
+            )
+            .line(
+                Font::Regular,
+                10.0,
+                match &summary.graduation_mint {
+                    Some(mint) => format!("Graduated (certificate {mint})"),
+                    None => "Not yet graduated".to_string(),
+                },
+            )
+            .blank()
+            .line(Font::Regular, 8.0, format!("Merkle root: {}", self.merkle_root))
+            .line(Font::Regular, 8.0, format!("Anchor: {}", self.transcript_anchor))
+            .line(
+                Font::Regular,
+                8.0,
+                format!("The signed {PDF_ATTACHMENT} is attached; verify it against the anchor on-chain."),
+            );
+
+        let json = serde_json::to_vec_pretty(self).expect("transcript serializes");
+        pdf.attach(PDF_ATTACHMENT, "application/json", json);
+        pdf.to_bytes()
+    }
+
+    /// The entry at `index` with its inclusion proof; `None` if out of range
+    /// or an entry does not parse
+    pub fn disclose(&self, index: usize) -> Option<TranscriptDisclosure> {
+        let leaves = self.leaves().ok()?;
+        let proof = merkle_proof(&leaves, index)?;
+        Some(TranscriptDisclosure {
+            kind: DISCLOSURE_TYPE.to_string(),
+            program_id: self.program_id.clone(),
+            institution: self.institution.clone(),
+            student: self.student.clone(),
+            issuer: self.issuer.clone(),
+            merkle_root: self.merkle_root.clone(),
+            transcript_anchor: self.transcript_anchor.clone(),
+            entry: self.entries[index].clone(),
+            proof: proof.iter().map(hash_to_hex).collect(),
+        })
+    }
+}
+
+/// One transcript entry with the sibling hashes linking it to the anchored root
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptDisclosure {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub program_id: String,
+    pub institution: String,
+    pub student: String,
+    /// The DID that signed the full transcript
+    pub issuer: String,
+    pub merkle_root: String,
+    pub transcript_anchor: String,
+    pub entry: TranscriptLine,
+    /// Hex sibling hashes, leaf level first
+    pub proof: Vec<String>,
+}
+
+impl TranscriptDisclosure {
+    pub fn root(&self) -> std::result::Result<[u8; 32], TranscriptFailure> {
+        hash_from_hex(&self.merkle_root).ok_or_else(|| malformed("merkleRoot".to_string()))
+    }
+
+    /// The entry and proof as `verify_transcript_entry` takes them
+    pub fn instruction_args(&self) -> std::result::Result<(TranscriptEntry, Vec<[u8; 32]>), TranscriptFailure> {
+        let entry = self
+            .entry
+            .entry()
+            .ok_or_else(|| malformed(format!("entry {}", self.entry.enrollment)))?;
+        let proof = self
+            .proof
+            .iter()
+            .map(|node| hash_from_hex(node).ok_or_else(|| malformed(format!("proof node {node}"))))
+            .collect::<std::result::Result<_, _>>()?;
+        Ok((entry, proof))
+    }
+}
+
+/// A transcript signed by `signer`, identified by `method`
+pub fn issue_transcript(
+    profile: &StudentProfile,
+    records: &[TranscriptRecord],
+    signer: &dyn Signer,
+    method: &DidMethod,
+    issued_at: i64,
+) -> Transcript {
+    let mut transcript = Transcript::new(profile, records, method.did(&signer.pubkey()), issued_at);
+    transcript.proof = Some(sign(
+        &transcript.to_value(),
+        signer,
+        &method.verification_method(&signer.pubkey()),
+        issued_at,
+    ));
+    transcript
+}
+
+/// Why a transcript does not verify
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TranscriptFailure {
+    #[error(transparent)]
+    Proof(#[from] CredentialFailure),
+
+    #[error("entries do not hash to the stated Merkle root")]
+    RootMismatch,
+
+    #[error("Merkle root is not anchored on-chain")]
+    NotAnchored,
+
+    #[error("on-chain anchor does not match the transcript's institution, student, issuer or entry count")]
+    AnchorMismatch,
+
+    #[error("Merkle proof does not lead from the entry to the root")]
+    InvalidProof,
+
+    #[error("transferred entry {0} no longer counts; its source grade was revoked")]
+    TransferRevoked(String),
+}
+
+fn malformed(what: String) -> TranscriptFailure {
+    CredentialFailure::Malformed(what).into()
+}
+
+/// The outcome of verifying one transcript
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TranscriptVerification {
+    pub issuer: Option<String>,
+    pub anchor: Option<Pubkey>,
+    pub failure: Option<TranscriptFailure>,
+}
+
+impl TranscriptVerification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// The transcript JSON in `bytes`, which hold either the JSON itself or a
+/// PDF with it attached
+pub fn read_transcript(bytes: &[u8]) -> std::result::Result<Value, TranscriptFailure> {
+    let json = if bytes.starts_with(b"%PDF-") {
+        extract_attachment(bytes).ok_or_else(|| malformed(format!("PDF has no {PDF_ATTACHMENT}")))?
+    } else {
+        bytes.to_vec()
+    };
+    serde_json::from_slice(&json).map_err(|err| malformed(err.to_string()))
+}
+
+/// Check the proof and that the entries hash to the stated root, offline
+pub fn check_transcript(document: &Value) -> std::result::Result<Transcript, TranscriptFailure> {
+    verify_proof(document)?;
+    let transcript: Transcript = serde_json::from_value(document.clone()).map_err(|err| malformed(err.to_string()))?;
+    if transcript.kind != TRANSCRIPT_TYPE {
+        return Err(malformed(format!("type {}", transcript.kind)));
+    }
+    if merkle_root(&transcript.leaves()?) != transcript.root()? {
+        return Err(TranscriptFailure::RootMismatch);
+    }
+    Ok(transcript)
+}
+
+// Institution, student, root, issuer and anchor address shared by transcripts and disclosures
+fn check_anchor_fields(
+    institution: &str,
+    student: &str,
+    root: &[u8; 32],
+    issuer: &str,
+    transcript_anchor: &str,
+    anchor: Option<&TranscriptAnchor>,
+) -> std::result::Result<(), TranscriptFailure> {
+    let anchor = anchor.ok_or(TranscriptFailure::NotAnchored)?;
+    let institution: Pubkey = institution.parse().map_err(|_| malformed("institution".to_string()))?;
+    let student: Pubkey = student.parse().map_err(|_| malformed("student".to_string()))?;
+    if anchor.institution != institution
+        || anchor.student != student
+        || anchor.merkle_root != *root
+        || resolve_did(issuer) != Some(anchor.issuer)
+        || transcript_anchor != transcript_anchor_pda(&institution, &student, root).0.to_string()
+    {
+        return Err(TranscriptFailure::AnchorMismatch);
+    }
+    Ok(())
+}
+
+/// Check a transcript that passed `check_transcript` against the anchor
+/// stored for its institution, student and root
+pub fn check_anchor(
+    transcript: &Transcript,
+    anchor: Option<&TranscriptAnchor>,
+) -> std::result::Result<(), TranscriptFailure> {
+    check_anchor_fields(
+        &transcript.institution,
+        &transcript.student,
+        &transcript.root()?,
+        &transcript.issuer,
+        &transcript.transcript_anchor,
+        anchor,
+    )?;
+    if anchor.is_some_and(|anchor| usize::from(anchor.entry_count) != transcript.entries.len()) {
+        return Err(TranscriptFailure::AnchorMismatch);
+    }
+    Ok(())
+}
+
+/// Check that the disclosed entry's proof leads to the stated root, offline
+pub fn check_disclosure(disclosure: &TranscriptDisclosure) -> std::result::Result<(), TranscriptFailure> {
+    if disclosure.kind != DISCLOSURE_TYPE {
+        return Err(malformed(format!("type {}", disclosure.kind)));
+    }
+    let (entry, proof) = disclosure.instruction_args()?;
+    if !verify_merkle_proof(&transcript_leaf(&entry), &proof, &disclosure.root()?) {
+        return Err(TranscriptFailure::InvalidProof);
+    }
+    Ok(())
+}
+
+/// Check a disclosure that passed `check_disclosure` against the anchor
+/// stored for its institution, student and root
+pub fn check_disclosure_anchor(
+    disclosure: &TranscriptDisclosure,
+    anchor: Option<&TranscriptAnchor>,
+) -> std::result::Result<(), TranscriptFailure> {
+    check_anchor_fields(
+        &disclosure.institution,
+        &disclosure.student,
+        &disclosure.root()?,
+        &disclosure.issuer,
+        &disclosure.transcript_anchor,
+        anchor,
+    )
+}
+
+/// Verify the proof and root, that the root is anchored on-chain by the
+/// signing issuer, and that every transferred entry's source still counts
+pub fn verify_transcript(rpc: &RpcClient, document: &Value) -> Result<TranscriptVerification> {
+    let transcript = match check_transcript(document) {
+        Ok(transcript) => transcript,
+        Err(failure) => {
+            return Ok(TranscriptVerification {
+                issuer: None,
+                anchor: None,
+                failure: Some(failure),
+            })
+        }
+    };
+    let issuer = Some(transcript.issuer.clone());
+    let parsed = (transcript.institution.parse::<Pubkey>(), transcript.student.parse::<Pubkey>());
+    let (Ok(institution), Ok(student)) = parsed else {
+        return Ok(TranscriptVerification {
+            issuer,
+            anchor: None,
+            failure: Some(malformed("institution or student".to_string())),
+        });
+    };
+    let address = transcript_anchor_pda(&institution, &student, &transcript.root().expect("checked")).0;
+    let anchor: Option<TranscriptAnchor> = fetch_account_optional(rpc, &address)?;
+    let failure = match check_anchor(&transcript, anchor.as_ref()) {
+        Ok(()) => check_transfers(rpc, &transcript.entries)?,
+        Err(failure) => Some(failure),
+    };
+    Ok(TranscriptVerification {
+        issuer,
+        anchor: anchor.as_ref().map(|_| address),
+        failure,
+    })
+}
+
+/// Verify a disclosed entry's proof, that its root is anchored on-chain by
+/// the stated issuer, and for a transferred entry that its source still counts
+pub fn verify_disclosure(rpc: &RpcClient, disclosure: &TranscriptDisclosure) -> Result<TranscriptVerification> {
+    let failed = |failure| TranscriptVerification {
+        issuer: None,
+        anchor: None,
+        failure: Some(failure),
+    };
+    if let Err(failure) = check_disclosure(disclosure) {
+        return Ok(failed(failure));
+    }
+    let Ok(address) = disclosure.transcript_anchor.parse::<Pubkey>() else {
+        return Ok(failed(malformed("transcriptAnchor".to_string())));
+    };
+    let anchor: Option<TranscriptAnchor> = fetch_account_optional(rpc, &address)?;
+    let failure = match check_disclosure_anchor(disclosure, anchor.as_ref()) {
+        Ok(()) => check_transfers(rpc, [&disclosure.entry])?,
+        Err(failure) => Some(failure),
+    };
+    Ok(TranscriptVerification {
+        issuer: Some(disclosure.issuer.clone()),
+        anchor: anchor.as_ref().map(|_| address),
+        failure,
+    })
+}
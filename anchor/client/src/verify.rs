@@ -0,0 +1,113 @@
+//! Certificate verification.
+//!
+//! A course certificate mint is valid when it is the `certificate_mint` PDA
+//! of an enrollment that records it, and that enrollment is completed with a
+//! passing grade.
+//!
+//! There is no separate revocation instruction: a certificate is revoked
+//! when `amend_grade` lowers the enrollment's grade below `PASSING_GRADE`.
+//! The mint and its `CertificateRecord` stay on-chain, but verification
+//! reports `Revoked` from then on. Amending the grade back to passing
+//! reinstates it.
+
+use academic_chain::state::{CertificateRecord, Course, CourseEnrollment, CourseOffering, EnrollmentStatus};
+use academic_chain::utils::is_passing_grade;
+use anchor_lang::prelude::Pubkey;
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::accounts::{fetch_account_optional, fetch_enrollment_or_record};
+use crate::error::Result;
+use crate::pda::{certificate_mint_pda, certificate_record_pda, course_pda};
+
+/// Why a mint does not verify
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum VerificationFailure {
+    #[error("no enrollment references this mint")]
+    NotFound,
+
+    #[error("mint is not the certificate PDA of its enrollment")]
+    NotProgramMint,
+
+    #[error("enrollment is not completed")]
+    NotCompleted,
+
+    /// The grade was amended below passing after the certificate was minted
+    #[error("grade is below passing; the certificate has been revoked")]
+    Revoked,
+}
+
+/// The outcome of verifying one mint, with the accounts behind it
+#[derive(Clone)]
+pub struct Verification {
+    pub mint: Pubkey,
+    pub enrollment: Option<(Pubkey, CourseEnrollment)>,
+    pub offering: Option<CourseOffering>,
+    pub course: Option<Course>,
+    pub failure: Option<VerificationFailure>,
+}
+
+impl Verification {
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Check that `enrollment` at `address` backs certificate `mint`
+pub fn check_enrollment(
+    mint: &Pubkey,
+    address: &Pubkey,
+    enrollment: &CourseEnrollment,
+) -> std::result::Result<(), VerificationFailure> {
+    if enrollment.certificate_mint != Some(*mint) {
+        return Err(VerificationFailure::NotFound);
+    }
+    if certificate_mint_pda(address).0 != *mint {
+        return Err(VerificationFailure::NotProgramMint);
+    }
+    if enrollment.status != EnrollmentStatus::Completed {
+        return Err(VerificationFailure::NotCompleted);
+    }
+    if !is_passing_grade(enrollment.grade) {
+        return Err(VerificationFailure::Revoked);
+    }
+    Ok(())
+}
+
+/// Find the enrollment recording `mint` through its `CertificateRecord`,
+/// falling back to its `EnrollmentRecord` once it is closed
+pub fn find_certificate_enrollment(
+    rpc: &RpcClient,
+    mint: &Pubkey,
+) -> Result<Option<(Pubkey, CourseEnrollment)>> {
+    let record: Option<CertificateRecord> = fetch_account_optional(rpc, &certificate_record_pda(mint).0)?;
+    let Some(CertificateRecord { enrollment: address, .. }) = record else {
+        return Ok(None);
+    };
+    Ok(fetch_enrollment_or_record(rpc, &address)?.map(|enrollment| (address, enrollment)))
+}
+
+/// Resolve `mint` to its enrollment, offering and course and check it
+pub fn verify_certificate(rpc: &RpcClient, mint: &Pubkey) -> Result<Verification> {
+    let Some((address, enrollment)) = find_certificate_enrollment(rpc, mint)? else {
+        return Ok(Verification {
+            mint: *mint,
+            enrollment: None,
+            offering: None,
+            course: None,
+            failure: Some(VerificationFailure::NotFound),
+        });
+    };
+
+    let offering = fetch_account_optional::<CourseOffering>(rpc, &enrollment.offering)?;
+    let course = match &offering {
+        Some(offering) => fetch_account_optional::<Course>(rpc, &course_pda(&offering.institution, &enrollment.course_id).0)?,
+        None => None,
+    };
+    Ok(Verification {
+        mint: *mint,
+        failure: check_enrollment(mint, &address, &enrollment).err(),
+        enrollment: Some((address, enrollment)),
+        offering,
+        course,
+    })
+}
@@ -0,0 +1,105 @@
+use academic_chain::state::{CertificateBatch, CourseEnrollment, EnrollmentStatus};
+use academic_chain::utils::{certificate_leaf, verify_merkle_proof};
+use academic_chain_client::batch::*;
+use academic_chain_client::pda::{enrollment_pda, institution_pda, offering_pda};
+use anchor_lang::prelude::Pubkey;
+
+const COMPLETED_AT: i64 = 1_760_100_000;
+
+fn institution() -> Pubkey {
+    institution_pda("EXU").0
+}
+
+fn enrollment(grade: u8, status: EnrollmentStatus) -> (Pubkey, CourseEnrollment) {
+    let student = Pubkey::new_unique();
+    let offering = offering_pda(&institution(), "SOL101", "2025FA").0;
+    let enrollment = CourseEnrollment {
+        student,
+        course_id: "SOL101".to_string(),
+        offering,
+        credits_paid: 5,
+        enrollment_date: 1_760_000_000,
+        completion_date: (status == EnrollmentStatus::Completed).then_some(COMPLETED_AT),
+        status,
+        grade,
+        letter_grade: "B".to_string(),
+        grade_points: 300,
+        credit_hours: 3,
+        certificate_mint: None,
+        metadata_hash: None,
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    (enrollment_pda(&student, &offering).0, enrollment)
+}
+
+fn cohort() -> Vec<(Pubkey, CourseEnrollment)> {
+    let mut enrollments: Vec<_> = (0..5).map(|i| enrollment(80 + i, EnrollmentStatus::Completed)).collect();
+    enrollments.push(enrollment(0, EnrollmentStatus::Enrolled));
+    let (address, mut minted) = enrollment(95, EnrollmentStatus::Completed);
+    minted.certificate_mint = Some(Pubkey::new_unique());
+    enrollments.push((address, minted));
+    enrollments
+}
+
+fn stored(manifest: &BatchManifest) -> CertificateBatch {
+    CertificateBatch {
+        offering: offering_pda(&institution(), &manifest.course_id, &manifest.term_id).0,
+        issuer: Pubkey::new_unique(),
+        merkle_root: manifest.root().unwrap(),
+        certificate_count: manifest.certificates.len() as u16,
+        claimed_count: 0,
+        created_at: COMPLETED_AT,
+        bump: 255,
+    }
+}
+
+#[test]
+fn manifests_cover_completions_without_a_certificate() {
+    let enrollments = cohort();
+    let manifest = BatchManifest::new(&institution(), "SOL101", "2025FA", &enrollments);
+
+    assert_eq!(manifest.certificates.len(), 5);
+    assert!(manifest.certificates.windows(2).all(|pair| pair[0].student < pair[1].student));
+    assert_eq!(check_manifest(&manifest), Ok(()));
+
+    // The order enrollments are fetched in does not change the root
+    let reversed: Vec<_> = enrollments.iter().rev().cloned().collect();
+    assert_eq!(BatchManifest::new(&institution(), "SOL101", "2025FA", &reversed), manifest);
+
+    let mut edited = manifest.clone();
+    edited.certificates[2].grade = 99;
+    assert_eq!(check_manifest(&edited), Err(BatchFailure::RootMismatch));
+}
+
+#[test]
+fn certificates_prove_their_enrollment_against_the_batch() {
+    let enrollments = cohort();
+    let manifest = BatchManifest::new(&institution(), "SOL101", "2025FA", &enrollments);
+    let batch = stored(&manifest);
+
+    for (_, enrollment) in enrollments.iter().filter(|(_, enrollment)| batch_leaf(enrollment).is_some()) {
+        let certificate = manifest.certificate(&enrollment.student).unwrap();
+        assert_eq!(check_certificate(&certificate), Ok(()));
+        assert_eq!(check_batch(&certificate, Some(&batch)), Ok(()));
+
+        // The program rebuilds the same leaf from the enrollment when claiming
+        let (_, proof) = certificate.instruction_args().unwrap();
+        let leaf = certificate_leaf(&batch_leaf(enrollment).unwrap());
+        assert!(verify_merkle_proof(&leaf, &proof, &batch.merkle_root));
+    }
+    assert_eq!(manifest.certificate(&Pubkey::new_unique()), None);
+
+    let mut raised = manifest.certificate(&enrollments[0].1.student).unwrap();
+    raised.certificate.grade = 100;
+    assert_eq!(check_certificate(&raised), Err(BatchFailure::InvalidProof));
+
+    let certificate = manifest.certificate(&enrollments[1].1.student).unwrap();
+    assert_eq!(check_batch(&certificate, None), Err(BatchFailure::NotCreated));
+    let mut other = stored(&manifest);
+    other.merkle_root = [9; 32];
+    assert_eq!(check_batch(&certificate, Some(&other)), Err(BatchFailure::BatchMismatch));
+}
@@ -0,0 +1,310 @@
+use academic_chain::constants::{MAX_CONFIG_DELAY, MIN_CONFIG_DELAY};
+use academic_chain::state::{ConfigChange, ConfigGovernance, Course, Role, StudentGrade};
+use academic_chain::utils::{validate_governance, validate_governance_change};
+use academic_chain_client::error::program_errors;
+use academic_chain_client::instructions::*;
+use academic_chain_client::pda::*;
+use academic_chain_client::{accounts, decode_error_code, decode_transaction_error, Error};
+use academic_chain_client::{AcademicChainError, ID};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::InstructionError;
+use anchor_lang::{AccountSerialize, Discriminator};
+use solana_transaction_error::TransactionError;
+
+#[test]
+fn register_course_derives_offering_scoped_accounts() {
+    let student = Pubkey::new_unique();
+    let credit_mint = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let ix = register_course(&institution, &student, &credit_mint, "SOL101", "2025FA");
+
+    let offering = offering_pda(&institution, "SOL101", "2025FA").0;
+    let enrollment = enrollment_pda(&student, &offering).0;
+
+    assert_eq!(ix.program_id, ID);
+    assert!(ix.data.starts_with(academic_chain::instruction::RegisterCourse::DISCRIMINATOR));
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    assert_eq!(ix.accounts[0].pubkey, student);
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == enrollment && meta.is_writable));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == event_authority_pda().0));
+    assert_ne!(offering, offering_pda(&institution_pda("OTH").0, "SOL101", "2025FA").0);
+
+    // Attempts are counted per course, shared by every term's offering
+    let course = course_pda(&institution, "SOL101").0;
+    let attempts = course_attempts_pda(&student, &course).0;
+    let retake = retake_course(&institution, &student, &credit_mint, "SOL101", "2026SP");
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == attempts && meta.is_writable));
+    assert!(retake.accounts.iter().any(|meta| meta.pubkey == attempts && meta.is_writable));
+}
+
+#[test]
+fn complete_course_batch_appends_enrollment_and_profile_pairs() {
+    let instructor = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let grades: Vec<StudentGrade> = (0..3)
+        .map(|i| StudentGrade { student: Pubkey::new_unique(), grade: 70 + i })
+        .collect();
+
+    let single = complete_course(&institution, &instructor, &grades[0].student, "SOL101", "2025FA", 70);
+    let batch = complete_course_batch(&institution, &instructor, "SOL101", "2025FA", grades.clone());
+
+    // Fixed accounts: instructor, institution, course, offering, event authority, program
+    let fixed = 6;
+    assert_eq!(batch.accounts.len(), fixed + grades.len() * 2);
+
+    let offering = offering_pda(&institution, "SOL101", "2025FA").0;
+    for (entry, pair) in grades.iter().zip(batch.accounts[fixed..].chunks(2)) {
+        assert_eq!(pair[0].pubkey, enrollment_pda(&entry.student, &offering).0);
+        assert_eq!(pair[1].pubkey, student_profile_pda(&institution, &entry.student).0);
+        assert!(pair[0].is_writable && pair[1].is_writable);
+    }
+
+    // The single-student builder targets the same enrollment
+    assert!(single.accounts.iter().any(|meta| meta.pubkey == batch.accounts[fixed].pubkey));
+}
+
+#[test]
+fn certificate_mint_is_derived_from_the_enrollment() {
+    let student = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let ix = mint_certificate(&institution, &student, "SOL101", "2025FA", "https://example.com/cert.json", [7; 32]);
+
+    let enrollment = enrollment_pda(&student, &offering_pda(&institution, "SOL101", "2025FA").0).0;
+    let mint = certificate_mint_pda(&enrollment).0;
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == mint));
+
+    // The record verifiers resolve the mint through is created alongside it
+    let record = certificate_record_pda(&mint).0;
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == record && meta.is_writable));
+}
+
+#[test]
+fn transcript_anchor_is_keyed_by_institution_student_and_root() {
+    let authority = Pubkey::new_unique();
+    let student = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let ix = anchor_transcript(&institution, &authority, None, &student, [9; 32], 4);
+
+    let anchor = transcript_anchor_pda(&institution, &student, &[9; 32]).0;
+    assert_ne!(anchor, transcript_anchor_pda(&institution, &student, &[8; 32]).0);
+    assert_ne!(anchor, transcript_anchor_pda(&institution_pda("OTHER").0, &student, &[9; 32]).0);
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == anchor && meta.is_writable));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == student_profile_pda(&institution, &student).0));
+}
+
+#[test]
+fn transcript_entries_are_verified_read_only() {
+    let anchor = Pubkey::new_unique();
+    let entry = academic_chain::state::TranscriptEntry {
+        enrollment: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        status: academic_chain::state::EnrollmentStatus::Completed,
+        grade: 90,
+        letter_grade: "A".to_string(),
+        credit_hours: 3,
+        completion_date: Some(1_760_000_000),
+    };
+    let ix = verify_transcript_entry(&anchor, entry, vec![[1; 32], [2; 32]]);
+
+    assert!(ix.data.starts_with(academic_chain::instruction::VerifyTranscriptEntry::DISCRIMINATOR));
+    assert_eq!(ix.accounts.len(), 1);
+    assert_eq!(ix.accounts[0].pubkey, anchor);
+    assert!(!ix.accounts[0].is_writable && !ix.accounts[0].is_signer);
+}
+
+#[test]
+fn closing_an_enrollment_creates_its_record() {
+    let student = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let ix = close_enrollment(&institution, &student, "SOL101", "2025FA");
+
+    let enrollment = enrollment_pda(&student, &offering_pda(&institution, "SOL101", "2025FA").0).0;
+    let record = enrollment_record_pda(&enrollment).0;
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == enrollment && meta.is_writable));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == record && meta.is_writable));
+}
+
+#[test]
+fn batch_claims_link_the_students_enrollment() {
+    let student = Pubkey::new_unique();
+    let root = [7; 32];
+    let institution = institution_pda("EXU").0;
+    let ix = claim_batch_certificate(&institution, &student, "SOL101", "2025FA", &root, vec![[1; 32]]);
+
+    let offering = offering_pda(&institution, "SOL101", "2025FA").0;
+    assert!(ix.data.starts_with(academic_chain::instruction::ClaimBatchCertificate::DISCRIMINATOR));
+    assert_eq!(ix.accounts[0].pubkey, student);
+    assert!(ix.accounts[0].is_signer);
+    assert_eq!(ix.accounts[1].pubkey, institution);
+    assert_eq!(ix.accounts[3].pubkey, enrollment_pda(&student, &offering).0);
+    assert!(ix.accounts[3].is_writable);
+    assert_eq!(ix.accounts[4].pubkey, certificate_batch_pda(&offering, &root).0);
+    assert!(ix.accounts[4].is_writable);
+}
+
+#[test]
+fn compressed_certificates_are_appended_by_the_tree_pda() {
+    let student = Pubkey::new_unique();
+    let merkle_tree = Pubkey::new_unique();
+    let institution = institution_pda("EXU").0;
+    let ix =
+        mint_compressed_certificate(&institution, &student, &merkle_tree, "SOL101", "2025FA", "ipfs://cert", [1; 32]);
+
+    let offering = offering_pda(&institution, "SOL101", "2025FA").0;
+    assert!(ix.data.starts_with(academic_chain::instruction::MintCompressedCertificate::DISCRIMINATOR));
+    assert_eq!(ix.accounts[3].pubkey, enrollment_pda(&student, &offering).0);
+    assert_eq!(ix.accounts[4].pubkey, certificate_tree_pda(&merkle_tree).0);
+    assert!(ix.accounts[4].is_writable);
+    assert_eq!(ix.accounts[5].pubkey, merkle_tree);
+    assert!(ix.accounts[5].is_writable && !ix.accounts[5].is_signer);
+    assert_eq!(ix.accounts[6].pubkey, academic_chain::constants::ACCOUNT_COMPRESSION_PROGRAM_ID);
+    assert_eq!(ix.accounts[7].pubkey, academic_chain::constants::NOOP_PROGRAM_ID);
+}
+
+#[test]
+fn transfers_are_keyed_by_the_source_enrollment() {
+    let student = Pubkey::new_unique();
+    let source = institution_pda("EXU").0;
+    let partner = institution_pda("PTU").0;
+    let (authority, source_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let equivalency = course_equivalency_pda(&course_pda(&source, "SOL101").0, &course_pda(&partner, "BC200").0).0;
+    let create =
+        create_course_equivalency(&partner, &authority, None, &source, &source_authority, None, "SOL101", "BC200");
+    assert!(create.data.starts_with(academic_chain::instruction::CreateCourseEquivalency::DISCRIMINATOR));
+    let signers: Vec<_> = create.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+    assert_eq!(signers, vec![authority, source_authority]);
+    assert!(create.accounts.iter().any(|meta| meta.pubkey == equivalency && meta.is_writable));
+
+    let ix = transfer_completion(&partner, &source, &student, "SOL101", "2025FA", "BC200");
+    let source_enrollment = enrollment_pda(&student, &offering_pda(&source, "SOL101", "2025FA").0).0;
+    assert!(ix.data.starts_with(academic_chain::instruction::TransferCompletion::DISCRIMINATOR));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == source_enrollment && !meta.is_writable));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == equivalency && !meta.is_writable));
+    let writable = |address: Pubkey| ix.accounts.iter().any(|meta| meta.pubkey == address && meta.is_writable);
+    assert!(writable(transferred_enrollment_pda(&partner, &source_enrollment).0));
+    assert!(writable(student_profile_pda(&partner, &student).0));
+    assert!(!ix.accounts.iter().any(|meta| meta.pubkey == student_profile_pda(&source, &student).0));
+}
+
+#[test]
+fn role_assignments_are_passed_only_when_given() {
+    let institution = institution_pda("EXU").0;
+    let (registrar, holder) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let assignment = role_assignment_pda(&institution, &registrar, Role::Registrar).0;
+    assert_ne!(assignment, role_assignment_pda(&institution, &registrar, Role::Reviewer).0);
+
+    let student = Pubkey::new_unique();
+    let with_role = anchor_transcript(&institution, &registrar, Some(assignment), &student, [9; 32], 4);
+    let without = anchor_transcript(&institution, &registrar, None, &student, [9; 32], 4);
+    assert!(with_role.accounts.iter().any(|meta| meta.pubkey == assignment && !meta.is_writable));
+    // Anchor marks a missing optional account with the program ID
+    assert!(without.accounts.iter().any(|meta| meta.pubkey == ID));
+    assert_eq!(with_role.accounts.len(), without.accounts.len());
+
+    let grant = grant_role(&institution, &registrar, None, &holder, Role::CourseManager);
+    assert!(grant.data.starts_with(academic_chain::instruction::GrantRole::DISCRIMINATOR));
+    let granted = role_assignment_pda(&institution, &holder, Role::CourseManager).0;
+    assert!(grant.accounts.iter().any(|meta| meta.pubkey == granted && meta.is_writable));
+}
+
+#[test]
+fn config_proposals_are_keyed_by_their_id() {
+    let institution = institution_pda("EXU").0;
+    let (admin, proposer) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let proposal = config_proposal_pda(&institution, 3).0;
+    assert_ne!(proposal, config_proposal_pda(&institution, 4).0);
+    assert_ne!(proposal, config_proposal_pda(&institution_pda("PTU").0, 3).0);
+
+    let change = ConfigChange { treasury: Some(Pubkey::new_unique()), ..Default::default() };
+    let propose = propose_config_change(&institution, &proposer, 3, change);
+    assert!(propose.data.starts_with(academic_chain::instruction::ProposeConfigChange::DISCRIMINATOR));
+    assert!(propose.accounts.iter().any(|meta| meta.pubkey == proposal && meta.is_writable));
+    let governance = config_governance_pda(&institution).0;
+    assert!(propose.accounts.iter().any(|meta| meta.pubkey == governance && meta.is_writable));
+
+    // Executing refunds the proposer, who does not need to sign
+    let execute = execute_config_change(&institution, &admin, &proposer, 3);
+    let signers: Vec<_> = execute.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+    assert_eq!(signers, vec![admin]);
+    assert!(execute.accounts.iter().any(|meta| meta.pubkey == proposer && meta.is_writable));
+}
+
+#[test]
+fn governance_delays_stay_within_the_bounds() {
+    let admins = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    assert!(validate_governance(&admins, 2, MIN_CONFIG_DELAY).is_ok());
+    assert!(validate_governance(&admins, 2, MAX_CONFIG_DELAY).is_ok());
+    assert!(validate_governance(&admins, 2, 0).is_err());
+    assert!(validate_governance(&admins, 2, MAX_CONFIG_DELAY + 1).is_err());
+
+    // A proposal can't lower the delay below the floor either
+    let governance = ConfigGovernance {
+        institution: institution_pda("EXU").0,
+        admins,
+        threshold: 2,
+        min_delay: 24 * 60 * 60,
+        proposal_count: 0,
+        bump: 255,
+    };
+    let change = |min_delay| ConfigChange { min_delay: Some(min_delay), ..Default::default() };
+    assert!(validate_governance_change(&governance, &change(MIN_CONFIG_DELAY)).is_ok());
+    assert!(validate_governance_change(&governance, &change(MIN_CONFIG_DELAY - 1)).is_err());
+    assert!(validate_governance_change(&governance, &change(0)).is_err());
+}
+
+#[test]
+fn error_codes_round_trip() {
+    for error in program_errors() {
+        let code = u32::from(*error);
+        let decoded = decode_error_code(code).expect("known error code");
+        assert_eq!(u32::from(decoded), code);
+        assert_eq!(decoded.to_string(), error.to_string());
+    }
+
+    assert!(decode_error_code(5999).is_none());
+    assert!(decode_error_code(6000 + program_errors().len() as u32).is_none());
+}
+
+#[test]
+fn transaction_errors_are_decoded() {
+    let failed = TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(AcademicChainError::OfferingFull.into()),
+    );
+    assert_eq!(
+        decode_transaction_error(&failed).map(u32::from),
+        Some(u32::from(AcademicChainError::OfferingFull))
+    );
+
+    let other = TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature);
+    assert!(decode_transaction_error(&other).is_none());
+}
+
+#[test]
+fn accounts_are_deserialized_with_discriminator_check() {
+    let course = Course {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        course_name: "Solana Blockchain Fundamentals".to_string(),
+        instructor: Pubkey::new_unique(),
+        required_credits: 5,
+        credit_hours: 3,
+        max_attempts: 2,
+        is_active: true,
+        created_at: 1_700_000_000,
+        bump: 255,
+    };
+    let mut data = Vec::new();
+    course.try_serialize(&mut data).unwrap();
+
+    let address = course_pda(&institution_pda("EXU").0, "SOL101").0;
+    let decoded: Course = accounts::deserialize_account(&address, &data).unwrap();
+    assert_eq!(decoded.course_id, course.course_id);
+    assert_eq!(decoded.max_attempts, 2);
+
+    data[0] ^= 0xff;
+    let result = accounts::deserialize_account::<Course>(&address, &data);
+    assert!(matches!(result, Err(Error::Deserialize(key, _)) if key == address));
+}
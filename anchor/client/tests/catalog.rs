@@ -0,0 +1,134 @@
+use academic_chain::state::Course;
+use academic_chain_client::catalog::*;
+use academic_chain_client::instructions::CourseUpdate;
+use anchor_lang::prelude::Pubkey;
+
+fn course(course_id: &str, instructor: Pubkey) -> Course {
+    Course {
+        institution: Pubkey::default(),
+        course_id: course_id.to_string(),
+        course_name: format!("{course_id} name"),
+        instructor,
+        required_credits: 5,
+        credit_hours: 3,
+        max_attempts: 3,
+        is_active: true,
+        created_at: 1_700_000_000,
+        bump: 255,
+    }
+}
+
+fn entry(course_id: &str, instructor: Pubkey) -> CatalogEntry {
+    CatalogEntry {
+        course_id: course_id.to_string(),
+        course_name: format!("{course_id} name"),
+        instructor,
+        required_credits: 5,
+        credit_hours: None,
+        max_attempts: None,
+        is_active: None,
+    }
+}
+
+#[test]
+fn plan_sends_only_needed_changes() {
+    let instructor = Pubkey::new_unique();
+    let on_chain = vec![
+        course("SOL101", instructor),
+        course("SOL102", instructor),
+        course("RUST201", instructor),
+    ];
+
+    let mut changed = entry("SOL102", instructor);
+    changed.required_credits = 8;
+    changed.is_active = Some(false);
+    let entries = vec![entry("SOL101", instructor), changed, entry("WEB301", instructor)];
+
+    let plan = plan(&entries, &on_chain);
+
+    assert_eq!(plan.unchanged, vec!["SOL101".to_string()]);
+    assert_eq!(plan.unlisted, vec!["RUST201".to_string()]);
+    assert_eq!(
+        plan.changes,
+        vec![
+            CatalogChange::Update {
+                course_id: "SOL102".to_string(),
+                update: CourseUpdate {
+                    required_credits: Some(8),
+                    is_active: Some(false),
+                    ..CourseUpdate::default()
+                },
+            },
+            CatalogChange::Create(entry("WEB301", instructor)),
+        ]
+    );
+}
+
+#[test]
+fn blank_optional_columns_keep_on_chain_values() {
+    let instructor = Pubkey::new_unique();
+    let mut on_chain = course("SOL101", instructor);
+    on_chain.credit_hours = 4;
+    on_chain.max_attempts = 1;
+    on_chain.is_active = false;
+
+    let plan = plan(&[entry("SOL101", instructor)], &[on_chain]);
+    assert!(plan.changes.is_empty());
+}
+
+#[test]
+fn exported_courses_round_trip_without_changes() {
+    let instructor = Pubkey::new_unique();
+    let on_chain = vec![course("SOL101", instructor), course("SOL102", instructor)];
+    let exported: Vec<CatalogEntry> = on_chain.iter().map(CatalogEntry::from_course).collect();
+
+    let plan = plan(&exported, &on_chain);
+    assert!(plan.changes.is_empty());
+    assert_eq!(plan.unchanged.len(), 2);
+}
+
+#[test]
+fn inactive_new_course_is_created_then_deactivated() {
+    let authority = Pubkey::new_unique();
+    let mut inactive = entry("SOL101", Pubkey::new_unique());
+    inactive.is_active = Some(false);
+
+    let institution = Pubkey::new_unique();
+    assert_eq!(CatalogChange::Create(inactive).instructions(&institution, &authority, None).len(), 2);
+    assert_eq!(CatalogChange::Create(entry("SOL102", authority)).instructions(&institution, &authority, None).len(), 1);
+}
+
+#[test]
+fn validate_reports_every_bad_row() {
+    let instructor = Pubkey::new_unique();
+    let mut long_id = entry(&"X".repeat(Course::MAX_ID_LEN + 1), instructor);
+    long_id.course_name = "Long".to_string();
+    let mut long_name = entry("SOL102", instructor);
+    long_name.course_name = "N".repeat(Course::MAX_NAME_LEN + 1);
+    let mut bad_hours = entry("SOL103", instructor);
+    bad_hours.credit_hours = Some(0);
+
+    let entries = vec![
+        entry("SOL101", instructor),
+        long_id,
+        long_name,
+        bad_hours,
+        entry("SOL101", instructor),
+    ];
+
+    let errors = validate(&entries).unwrap_err();
+    let rows: Vec<usize> = errors.iter().map(|error| error.row).collect();
+    assert_eq!(rows, vec![2, 3, 4, 5]);
+    assert_eq!(errors[3].reason, "duplicate course_id");
+    assert!(validate(&entries[..1]).is_ok());
+}
+
+#[test]
+fn changed_fields_lists_set_fields() {
+    let update = CourseUpdate {
+        course_name: Some("New".to_string()),
+        max_attempts: Some(2),
+        ..CourseUpdate::default()
+    };
+    assert_eq!(changed_fields(&update), vec!["name", "max_attempts"]);
+}
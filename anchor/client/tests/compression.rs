@@ -0,0 +1,158 @@
+use academic_chain::constants::ACCOUNT_COMPRESSION_PROGRAM_ID;
+use academic_chain::state::{CompressedCertificate, CourseEnrollment, EnrollmentStatus};
+use academic_chain::utils::{empty_tree_node, recompute_tree_root};
+use academic_chain_client::compression::*;
+use academic_chain_client::metadata::hash_to_hex;
+use academic_chain_client::pda::{enrollment_pda, institution_pda, offering_pda};
+use anchor_lang::prelude::Pubkey;
+
+const DEPTH: u32 = 3;
+
+fn compressed_enrollment(merkle_tree: &Pubkey, leaf_index: u32) -> (Pubkey, CourseEnrollment) {
+    let student = Pubkey::new_unique();
+    let offering = offering_pda(&institution_pda("EXU").0, "SOL101", "2025FA").0;
+    let address = enrollment_pda(&student, &offering).0;
+    let mut enrollment = CourseEnrollment {
+        student,
+        course_id: "SOL101".to_string(),
+        offering,
+        credits_paid: 5,
+        enrollment_date: 1_760_000_000,
+        completion_date: Some(1_760_100_000),
+        status: EnrollmentStatus::Completed,
+        grade: 85,
+        letter_grade: "B".to_string(),
+        grade_points: 300,
+        credit_hours: 3,
+        certificate_mint: None,
+        metadata_hash: Some([leaf_index as u8 + 1; 32]),
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    let leaf_hash = compressed_leaf(&address, &enrollment, merkle_tree, leaf_index).unwrap();
+    enrollment.compressed_certificate = Some(CompressedCertificate {
+        merkle_tree: *merkle_tree,
+        leaf_index,
+        leaf_hash,
+    });
+    (address, enrollment)
+}
+
+/// Tree account bytes as the compression program lays them out
+fn tree_account(max_depth: u32, max_buffer_size: u32, root: [u8; 32], leaf_count: u32) -> Vec<u8> {
+    let mut data = vec![0; merkle_tree_account_size(max_depth, max_buffer_size)];
+    data[0] = 1;
+    data[2..6].copy_from_slice(&max_buffer_size.to_le_bytes());
+    data[6..10].copy_from_slice(&max_depth.to_le_bytes());
+    data[10..42].copy_from_slice(&[4; 32]);
+
+    let active_index = 3usize;
+    let change_log_len = 32 + max_depth as usize * 32 + 8;
+    data[TREE_HEADER_LEN + 8..TREE_HEADER_LEN + 16].copy_from_slice(&(active_index as u64).to_le_bytes());
+    let root_at = TREE_HEADER_LEN + 24 + active_index * change_log_len;
+    data[root_at..root_at + 32].copy_from_slice(&root);
+    let index_at = TREE_HEADER_LEN + 24 + max_buffer_size as usize * change_log_len + max_depth as usize * 32 + 32;
+    data[index_at..index_at + 4].copy_from_slice(&leaf_count.to_le_bytes());
+    data
+}
+
+#[test]
+fn proofs_lead_every_leaf_to_the_tree_root() {
+    assert_eq!(tree_root(&[], DEPTH), empty_tree_node(DEPTH));
+
+    for count in 1..=1 << DEPTH {
+        let leaves: Vec<[u8; 32]> = (0..count).map(|i| [i as u8 + 1; 32]).collect();
+        let root = tree_root(&leaves, DEPTH);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree_proof(&leaves, index, DEPTH).unwrap();
+            assert_eq!(proof.len(), DEPTH as usize);
+            assert_eq!(recompute_tree_root(leaf, index as u32, &proof), root);
+        }
+        assert_eq!(tree_proof(&leaves, count, DEPTH), None);
+    }
+
+    // Appending a leaf changes the root, as it does on-chain
+    assert_ne!(tree_root(&[[1; 32]], DEPTH), tree_root(&[[1; 32], [2; 32]], DEPTH));
+}
+
+#[test]
+fn tree_accounts_are_sized_and_parsed_like_the_compression_program() {
+    // spl-account-compression's getConcurrentMerkleTreeAccountSize(14, 64)
+    assert_eq!(merkle_tree_account_size(14, 64), 31_800);
+    assert!(is_supported_tree_size(14, 64));
+    assert!(!is_supported_tree_size(14, 100));
+
+    let data = tree_account(DEPTH, 8, [7; 32], 5);
+    let tree = parse_tree(&data).unwrap();
+    assert_eq!(tree.max_depth, DEPTH);
+    assert_eq!(tree.max_buffer_size, 8);
+    assert_eq!(tree.authority, Pubkey::new_from_array([4; 32]));
+    assert_eq!(tree.root, [7; 32]);
+    assert_eq!(tree.leaf_count, 5);
+
+    let mut uninitialized = data.clone();
+    uninitialized[0] = 0;
+    assert_eq!(parse_tree(&uninitialized), None);
+    assert_eq!(parse_tree(&data[..100]), None);
+}
+
+#[test]
+fn enrollments_prove_their_compressed_certificate() {
+    let merkle_tree = Pubkey::new_unique();
+    let enrollments: Vec<_> = (0..5).map(|index| compressed_enrollment(&merkle_tree, index)).collect();
+    let leaves: Vec<_> = enrollments
+        .iter()
+        .map(|(_, enrollment)| Some(enrollment.compressed_certificate.unwrap().leaf_hash))
+        .collect();
+
+    let (address, enrollment) = &enrollments[2];
+    let certificate = check_enrollment(address, Some(enrollment)).unwrap();
+    let proof = proof_from_leaves(address, &certificate, &leaves, DEPTH).unwrap();
+    assert_eq!(check_proof(&proof), Ok(()));
+
+    let root = proof.root().unwrap();
+    let tree = parse_tree(&tree_account(DEPTH, 8, root, 5)).unwrap();
+    assert_eq!(check_tree(&proof, Some(&tree)), Ok(()));
+    let moved_on = parse_tree(&tree_account(DEPTH, 8, [9; 32], 6)).unwrap();
+    assert_eq!(check_tree(&proof, Some(&moved_on)), Err(CompressedFailure::RootMismatch));
+    assert_eq!(check_tree(&proof, None), Err(CompressedFailure::TreeNotFound));
+
+    let ix = verify_leaf_instruction(&proof).unwrap();
+    assert_eq!(ix.program_id, ACCOUNT_COMPRESSION_PROGRAM_ID);
+    assert_eq!(ix.data.len(), 8 + 32 + 32 + 4);
+    assert_eq!(ix.accounts.len(), 1 + DEPTH as usize);
+    assert_eq!(ix.accounts[0].pubkey, merkle_tree);
+
+    let mut forged = proof.clone();
+    forged.leaf_hash = hash_to_hex(&[1; 32]);
+    assert_eq!(check_proof(&forged), Err(CompressedFailure::InvalidProof));
+
+    // A closed enrollment's leaf has to come from elsewhere
+    let mut closed = leaves.clone();
+    closed[0] = None;
+    assert_eq!(
+        proof_from_leaves(address, &certificate, &closed, DEPTH),
+        Err(CompressedFailure::MissingLeaf(0))
+    );
+}
+
+#[test]
+fn amended_enrollments_no_longer_match_their_leaf() {
+    let merkle_tree = Pubkey::new_unique();
+    let (address, enrollment) = compressed_enrollment(&merkle_tree, 0);
+    assert!(check_enrollment(&address, Some(&enrollment)).is_ok());
+
+    let amended = CourseEnrollment { grade: 40, ..enrollment.clone() };
+    assert_eq!(check_enrollment(&address, Some(&amended)), Err(CompressedFailure::LeafMismatch));
+
+    // The leaf commits to the enrollment address
+    let other = Pubkey::new_unique();
+    assert_eq!(check_enrollment(&other, Some(&enrollment)), Err(CompressedFailure::LeafMismatch));
+
+    let minted = CourseEnrollment { compressed_certificate: None, ..enrollment };
+    assert_eq!(check_enrollment(&address, Some(&minted)), Err(CompressedFailure::NotCompressed));
+    assert_eq!(check_enrollment(&address, None), Err(CompressedFailure::EnrollmentNotFound));
+}
@@ -0,0 +1,218 @@
+use academic_chain::state::{Course, CourseEnrollment, EnrollmentStatus, Institution, Role, RoleAssignment};
+use academic_chain_client::credential::*;
+use academic_chain_client::pda::certificate_mint_pda;
+use academic_chain_client::verify::{Verification, VerificationFailure};
+use anchor_lang::prelude::Pubkey;
+use serde_json::Value;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+const ISSUED_AT: i64 = 1_760_200_000;
+
+fn certified() -> (Pubkey, CourseEnrollment, Course) {
+    let address = Pubkey::new_unique();
+    let enrollment = CourseEnrollment {
+        student: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        offering: Pubkey::new_unique(),
+        credits_paid: 5,
+        enrollment_date: 1_760_000_000,
+        completion_date: Some(1_760_100_000),
+        status: EnrollmentStatus::Completed,
+        grade: 85,
+        letter_grade: "B".to_string(),
+        grade_points: 300,
+        credit_hours: 3,
+        certificate_mint: Some(certificate_mint_pda(&address).0),
+        metadata_hash: Some([0x42; 32]),
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    let course = Course {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        course_name: "Intro to Solana".to_string(),
+        instructor: Pubkey::new_unique(),
+        required_credits: 5,
+        credit_hours: 3,
+        max_attempts: 3,
+        is_active: true,
+        created_at: 1_700_000_000,
+        bump: 255,
+    };
+    (address, enrollment, course)
+}
+
+fn verification(address: &Pubkey, enrollment: &CourseEnrollment, course: &Course) -> Verification {
+    Verification {
+        mint: enrollment.certificate_mint.unwrap(),
+        enrollment: Some((*address, enrollment.clone())),
+        offering: None,
+        course: Some(course.clone()),
+        failure: None,
+    }
+}
+
+fn institution(authority: Pubkey) -> Institution {
+    Institution {
+        institution_id: "EXU".to_string(),
+        name: "Example University".to_string(),
+        authority,
+        credit_mint: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        credit_price: 1_000_000,
+        grade_scale: Vec::new(),
+        governed: false,
+        bump: 255,
+    }
+}
+
+#[test]
+fn dids_resolve_to_the_signing_key() {
+    let key = Keypair::new().pubkey();
+    for method in ["key", "sol", "sol:devnet"] {
+        let method: DidMethod = method.parse().unwrap();
+        let did = method.did(&key);
+        assert_eq!(resolve_did(&did), Some(key), "{did}");
+        assert!(method.verification_method(&key).starts_with(&format!("{did}#")));
+    }
+    assert!(DidMethod::Key.did(&key).starts_with("did:key:z6Mk"));
+    assert!("web".parse::<DidMethod>().is_err());
+}
+
+#[test]
+fn canonical_json_sorts_keys_without_whitespace() {
+    let value: Value = serde_json::from_str(r#"{"b": [1, {"z": true, "a": null}], "a": "é\n"}"#).unwrap();
+    assert_eq!(
+        String::from_utf8(canonicalize(&value)).unwrap(),
+        r#"{"a":"é\n","b":[1,{"a":null,"z":true}]}"#
+    );
+}
+
+#[test]
+fn signed_credentials_verify_and_tampering_is_detected() {
+    let (address, enrollment, course) = certified();
+    let issuer = Keypair::new();
+    let credential =
+        issue_course_credential(&address, &enrollment, &course, &issuer, &DidMethod::Key, ISSUED_AT).unwrap();
+    let document = credential.to_value();
+
+    assert_eq!(document["@context"][0], CREDENTIALS_V2_CONTEXT);
+    assert_eq!(document["type"][1], CREDENTIAL_TYPE);
+    assert_eq!(document["validFrom"], "2025-10-10T12:40:00Z");
+    assert_eq!(document["evidence"][0]["enrollment"], address.to_string());
+    assert_eq!(document["evidence"][0]["offering"], enrollment.offering.to_string());
+    assert_eq!(document["proof"]["cryptosuite"], CRYPTOSUITE);
+    assert_eq!(verify_proof(&document), Ok(DidMethod::Key.did(&issuer.pubkey())));
+
+    let mut regraded = document.clone();
+    regraded["credentialSubject"]["grade"] = 100.into();
+    assert_eq!(verify_proof(&regraded), Err(CredentialFailure::BadSignature));
+
+    // Fields this module does not know about are signed too
+    let mut extended = document.clone();
+    extended["credentialSubject"]["honors"] = true.into();
+    assert_eq!(verify_proof(&extended), Err(CredentialFailure::BadSignature));
+
+    // Re-signing with another key does not make it the original issuer's
+    let mut forged = document.clone();
+    let impostor = Keypair::new();
+    let proof = sign(&document, &impostor, &DidMethod::Key.verification_method(&impostor.pubkey()), ISSUED_AT);
+    forged["proof"] = serde_json::to_value(proof).unwrap();
+    assert!(matches!(verify_proof(&forged), Err(CredentialFailure::ForeignVerificationMethod(_))));
+}
+
+#[test]
+fn records_are_checked_against_the_chain() {
+    let (address, enrollment, course) = certified();
+    let issuer = Keypair::new();
+    let method = DidMethod::Sol { cluster: Some("devnet".to_string()) };
+    let credential = issue_course_credential(&address, &enrollment, &course, &issuer, &method, ISSUED_AT).unwrap();
+    assert_eq!(check_record(&credential, &verification(&address, &enrollment, &course)), Ok(()));
+
+    let amended = CourseEnrollment { grade: 40, ..enrollment.clone() };
+    let mut revoked = verification(&address, &amended, &course);
+    revoked.failure = Some(VerificationFailure::Revoked);
+    assert_eq!(
+        check_record(&credential, &revoked),
+        Err(CredentialFailure::Certificate(VerificationFailure::Revoked))
+    );
+
+    // Every claim is compared with the chain, not just the grade
+    let regraded = CourseEnrollment { grade: 70, ..enrollment.clone() };
+    let relettered = CourseEnrollment { letter_grade: "A".to_string(), ..enrollment.clone() };
+    let rehoured = CourseEnrollment { credit_hours: 4, ..enrollment.clone() };
+    let redated = CourseEnrollment { completion_date: Some(1_760_100_001), ..enrollment.clone() };
+    for changed in [regraded, relettered, rehoured, redated] {
+        assert_eq!(
+            check_record(&credential, &verification(&address, &changed, &course)),
+            Err(CredentialFailure::ClaimsMismatch)
+        );
+    }
+    let renamed = Course { course_name: "Advanced Solana".to_string(), ..course.clone() };
+    assert_eq!(
+        check_record(&credential, &verification(&address, &enrollment, &renamed)),
+        Err(CredentialFailure::ClaimsMismatch)
+    );
+    let mut backdated = credential.clone();
+    backdated.valid_from = "2020-01-01T00:00:00Z".to_string();
+    assert_eq!(
+        check_record(&backdated, &verification(&address, &enrollment, &course)),
+        Err(CredentialFailure::ClaimsMismatch)
+    );
+
+    let elsewhere = verification(&Pubkey::new_unique(), &enrollment, &course);
+    assert_eq!(check_record(&credential, &elsewhere), Err(CredentialFailure::EvidenceMismatch));
+    let moved = CourseEnrollment { offering: Pubkey::new_unique(), ..enrollment.clone() };
+    assert_eq!(
+        check_record(&credential, &verification(&address, &moved, &course)),
+        Err(CredentialFailure::EvidenceMismatch)
+    );
+
+    // Nothing is issued for a certificate that does not verify
+    assert_eq!(
+        issue_course_credential(&address, &amended, &course, &issuer, &method, ISSUED_AT),
+        Err(VerificationFailure::Revoked)
+    );
+}
+
+#[test]
+fn issuers_must_be_the_authority_or_a_registrar() {
+    let address = Pubkey::new_unique();
+    let authority = Keypair::new().pubkey();
+    let registrar = Keypair::new().pubkey();
+    let institution = institution(authority);
+    let assignment = |holder, role| RoleAssignment {
+        institution: address,
+        holder,
+        role,
+        granted_by: authority,
+        granted_at: ISSUED_AT,
+        bump: 255,
+    };
+
+    let did = |key| DidMethod::Key.did(&key);
+    assert_eq!(check_issuer(&did(authority), &address, &institution, None), Ok(()));
+    let granted = assignment(registrar, Role::Registrar);
+    assert_eq!(check_issuer(&did(registrar), &address, &institution, Some(&granted)), Ok(()));
+
+    // A self-signed credential from anyone else is rejected
+    let stranger = did(Keypair::new().pubkey());
+    assert_eq!(
+        check_issuer(&stranger, &address, &institution, None),
+        Err(CredentialFailure::UnauthorizedIssuer(stranger.clone()))
+    );
+    let reviewer = assignment(registrar, Role::Reviewer);
+    assert_eq!(
+        check_issuer(&did(registrar), &address, &institution, Some(&reviewer)),
+        Err(CredentialFailure::UnauthorizedIssuer(did(registrar)))
+    );
+    let foreign = RoleAssignment { institution: Pubkey::new_unique(), ..granted };
+    assert_eq!(
+        check_issuer(&did(registrar), &address, &institution, Some(&foreign)),
+        Err(CredentialFailure::UnauthorizedIssuer(did(registrar)))
+    );
+}
@@ -0,0 +1,86 @@
+use academic_chain::constants::MAX_GRADING_BATCH_SIZE;
+use academic_chain::state::StudentGrade;
+use academic_chain_client::grading::*;
+use academic_chain_client::pda::{course_pda, enrollment_pda, institution_pda, offering_pda, student_profile_pda};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use solana_message::{legacy, v0, AddressLookupTableAccount, VersionedMessage};
+
+// Largest serialized transaction, signatures included
+const PACKET_DATA_SIZE: usize = 1232;
+
+fn cohort(students: usize) -> Vec<StudentGrade> {
+    (0..students)
+        .map(|_| StudentGrade {
+            student: Pubkey::new_unique(),
+            grade: 80,
+        })
+        .collect()
+}
+
+/// One signature plus its compact-array length prefix
+fn signed_size(message: usize) -> usize {
+    1 + 64 + message
+}
+
+#[test]
+fn cohorts_are_split_into_batches_that_fit_lookup_tables() {
+    let institution = institution_pda("EXU").0;
+    let instructor = Pubkey::new_unique();
+    let grades = cohort(200);
+
+    let plans = plan_grading_batches(&institution, &instructor, "SOL101", "2025FA", &grades, MAX_GRADING_BATCH_SIZE);
+    let batches: Vec<_> = plans.iter().flat_map(|plan| &plan.batches).collect();
+    assert_eq!(batches.len(), 200_usize.div_ceil(MAX_GRADING_BATCH_SIZE));
+    assert_eq!(batches.iter().map(|batch| batch.grades.len()).sum::<usize>(), 200);
+    assert!(plans.len() > 1);
+
+    let offering = offering_pda(&institution, "SOL101", "2025FA").0;
+    for plan in &plans {
+        assert!(plan.addresses.len() <= MAX_LOOKUP_TABLE_ADDRESSES);
+        assert_eq!(plan.addresses[..3], [institution, course_pda(&institution, "SOL101").0, offering]);
+
+        for batch in &plan.batches {
+            for entry in &batch.grades {
+                assert!(plan.addresses.contains(&enrollment_pda(&entry.student, &offering).0));
+                assert!(plan.addresses.contains(&student_profile_pda(&institution, &entry.student).0));
+            }
+        }
+    }
+}
+
+#[test]
+fn a_full_batch_only_fits_a_v0_transaction() {
+    let institution = institution_pda("EXU").0;
+    let instructor = Pubkey::new_unique();
+    let grades = cohort(MAX_GRADING_BATCH_SIZE);
+
+    let [plan] = &plan_grading_batches(&institution, &instructor, "SOL101", "2025FA", &grades, MAX_GRADING_BATCH_SIZE)[..]
+    else {
+        panic!("one batch should need one lookup table");
+    };
+    let batch = &plan.batches[0];
+    let instructions = [compute_unit_limit(batch.grades.len()), batch.instruction.clone()];
+
+    let legacy = legacy::Message::new(&instructions, Some(&instructor));
+    assert!(signed_size(legacy.serialize().len()) > PACKET_DATA_SIZE);
+
+    let table = AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses: plan.addresses.clone(),
+    };
+    let message = v0::Message::try_compile(&instructor, &instructions, &[table], Hash::default()).unwrap();
+    let message = VersionedMessage::V0(message);
+    assert!(signed_size(message.serialize().len()) <= PACKET_DATA_SIZE);
+}
+
+#[test]
+fn lookup_tables_are_extended_in_chunks() {
+    let authority = Pubkey::new_unique();
+    let (_, table) = create_lookup_table(&authority, &authority, 42);
+    let addresses: Vec<_> = (0..MAX_LOOKUP_TABLE_ADDRESSES).map(|_| Pubkey::new_unique()).collect();
+
+    let extends = extend_lookup_table(&table, &authority, &authority, &addresses);
+    assert_eq!(extends.len(), MAX_LOOKUP_TABLE_ADDRESSES.div_ceil(LOOKUP_TABLE_EXTEND_CHUNK));
+    assert!(extends.iter().all(|ix| ix.accounts[0].pubkey == table));
+}
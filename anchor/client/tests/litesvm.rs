@@ -0,0 +1,568 @@
+//! End-to-end flow against the compiled program.
+//! Run `anchor build` first, then `cargo test -p academic_chain-client -- --include-ignored`.
+//! Compressed certificate tests also load spl-account-compression and spl-noop
+//! from `tests/fixtures`; dump them with `scripts/dump-fixtures.sh`.
+//!
+//! `register_and_complete_a_course` runs by default as a smoke test. It is
+//! skipped while the program is not built, except under CI, where the
+//! Anchor workflow builds it and runs every test here.
+
+use academic_chain::constants::{ACCOUNT_COMPRESSION_PROGRAM_ID, MIN_CONFIG_DELAY, NOOP_PROGRAM_ID};
+use academic_chain::state::*;
+use academic_chain_client::accounts::deserialize_account;
+use academic_chain_client::compression::{
+    allocate_tree, merkle_tree_account_size, parse_tree, proof_from_leaves, verify_leaf_instruction,
+};
+use academic_chain_client::instructions::*;
+use academic_chain_client::pda::*;
+use academic_chain_client::{decode_transaction_error, AcademicChainError, ID};
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::AccountDeserialize;
+use anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account;
+use anchor_spl::token::spl_token;
+use std::path::Path;
+use litesvm::types::FailedTransactionMetadata;
+use litesvm::LiteSVM;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+const PROGRAM_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../target/deploy/academic_chain.so");
+const COMPRESSION_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/fixtures/spl_account_compression.so");
+const NOOP_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/fixtures/spl_noop.so");
+const NOW: i64 = 1_760_000_000;
+const DAY: i64 = 24 * 60 * 60;
+const INSTITUTION_ID: &str = "EXU";
+
+struct TestContext {
+    svm: LiteSVM,
+    authority: Keypair,
+    institution: Pubkey,
+    treasury: Pubkey,
+    credit_mint: Pubkey,
+}
+
+impl TestContext {
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+        svm.add_program_from_file(ID, PROGRAM_PATH)
+            .expect("program binary missing, run `anchor build`");
+
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = NOW;
+        svm.set_sysvar(&clock);
+
+        let authority = Keypair::new();
+        svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+
+        // Credit mint whose mint authority is the institution PDA
+        let institution = institution_pda(INSTITUTION_ID).0;
+        let mint = Keypair::new();
+        let rent = svm.minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN);
+        let create_mint = [
+            system_instruction::create_account(
+                &authority.pubkey(),
+                &mint.pubkey(),
+                rent,
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &institution,
+                None,
+                0,
+            )
+            .unwrap(),
+        ];
+
+        let mut ctx = Self {
+            svm,
+            authority,
+            institution,
+            treasury: Pubkey::new_unique(),
+            credit_mint: mint.pubkey(),
+        };
+        ctx.send(&create_mint, &[&mint]).unwrap();
+        ctx
+    }
+
+    fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), AcademicChainError> {
+        self.try_send(instructions, signers).map_err(|failed| {
+            decode_transaction_error(&failed.err)
+                .unwrap_or_else(|| panic!("unexpected failure: {:?}\n{:#?}", failed.err, failed.meta.logs))
+        })
+    }
+
+    // Like `send`, but also for failures outside the program, such as an
+    // account that already exists
+    fn try_send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), Box<FailedTransactionMetadata>> {
+        let mut all_signers = vec![&self.authority];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.authority.pubkey()),
+            &all_signers,
+            self.svm.latest_blockhash(),
+        );
+        self.svm.expire_blockhash();
+        self.svm.send_transaction(tx).map(|_| ()).map_err(Box::new)
+    }
+
+    fn set_time(&mut self, unix_timestamp: i64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+
+    fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.svm.get_account(address).expect("account exists");
+        deserialize_account(address, &account.data).unwrap()
+    }
+
+    fn new_student(&mut self, credits: u64) -> Keypair {
+        let student = Keypair::new();
+        self.svm.airdrop(&student.pubkey(), 1_000_000_000).unwrap();
+        let ixs = [
+            create_associated_token_account(
+                &self.authority.pubkey(),
+                &student.pubkey(),
+                &self.credit_mint,
+                &spl_token::ID,
+            ),
+            create_student_profile(&self.institution, &student.pubkey(), Some("Student".to_string()), None),
+            purchase_credits(&self.institution, &student.pubkey(), &self.treasury, &self.credit_mint, credits),
+        ];
+        self.send(&ixs, &[&student]).unwrap();
+        student
+    }
+}
+
+// Whether the program binary exists; missing it fails the test under CI
+fn program_built() -> bool {
+    if Path::new(PROGRAM_PATH).exists() {
+        return true;
+    }
+    assert!(std::env::var_os("CI").is_none(), "{PROGRAM_PATH} is missing, run `anchor build`");
+    eprintln!("skipping: {PROGRAM_PATH} is missing, run `anchor build`");
+    false
+}
+
+fn setup_course(ctx: &mut TestContext, instructor: &Pubkey) {
+    let (authority, institution) = (ctx.authority.pubkey(), ctx.institution);
+    let ixs = [
+        create_institution(&authority, INSTITUTION_ID, "Example University", &ctx.treasury, &ctx.credit_mint),
+        create_course(&institution, &authority, None, "SOL101", "Solana Fundamentals", instructor, 5, 3, 2),
+        create_term(
+            &institution,
+            &authority,
+            None,
+            "2025FA",
+            "Fall 2025",
+            TermDates {
+                starts_at: NOW - DAY,
+                ends_at: NOW + 90 * DAY,
+                registration_opens_at: NOW - 7 * DAY,
+                registration_closes_at: NOW + 7 * DAY,
+            },
+        ),
+        create_offering(
+            &institution,
+            &authority,
+            None,
+            "SOL101",
+            "2025FA",
+            instructor,
+            30,
+            OfferingDeadlines::default(),
+        ),
+    ];
+    ctx.send(&ixs, &[]).unwrap();
+}
+
+#[test]
+fn register_and_complete_a_course() {
+    if !program_built() {
+        return;
+    }
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+
+    let student = ctx.new_student(10);
+    let ix = register_course(&ctx.institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+    ctx.send(&[ix], &[&student]).unwrap();
+
+    let ix = complete_course(&ctx.institution, &instructor.pubkey(), &student.pubkey(), "SOL101", "2025FA", 85);
+    ctx.send(&[ix], &[&instructor]).unwrap();
+
+    let offering = offering_pda(&ctx.institution, "SOL101", "2025FA").0;
+    let enrollment: CourseEnrollment = ctx.fetch(&enrollment_pda(&student.pubkey(), &offering).0);
+    assert_eq!(enrollment.status, EnrollmentStatus::Completed);
+    assert_eq!(enrollment.letter_grade, "B");
+
+    let profile: StudentProfile = ctx.fetch(&student_profile_pda(&ctx.institution, &student.pubkey()).0);
+    assert_eq!(profile.courses_completed, 1);
+    assert_eq!(profile.gpa, 300);
+    assert_eq!(profile.display_name.as_deref(), Some("Student"));
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn program_errors_are_decoded() {
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+
+    let student = ctx.new_student(2);
+    let ix = register_course(&ctx.institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+    let err = ctx.send(&[ix], &[&student]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::InsufficientCredits));
+
+    let impostor = Keypair::new();
+    let ix = complete_course(&ctx.institution, &impostor.pubkey(), &student.pubkey(), "SOL101", "2025FA", 90);
+    let err = ctx.send(&[ix], &[&impostor]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::UnauthorizedInstructor));
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn attempts_carry_over_to_the_next_term() {
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+    let (authority, institution) = (ctx.authority.pubkey(), ctx.institution);
+
+    // SOL101 allows two attempts: fail the first try and the retake in 2025FA
+    let student = ctx.new_student(20);
+    let ix = register_course(&institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+    ctx.send(&[ix], &[&student]).unwrap();
+    let ix = complete_course(&institution, &instructor.pubkey(), &student.pubkey(), "SOL101", "2025FA", 30);
+    ctx.send(&[ix], &[&instructor]).unwrap();
+    let ix = retake_course(&institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+    ctx.send(&[ix], &[&student]).unwrap();
+    let ix = complete_course(&institution, &instructor.pubkey(), &student.pubkey(), "SOL101", "2025FA", 40);
+    ctx.send(&[ix], &[&instructor]).unwrap();
+
+    let ixs = [
+        create_term(
+            &institution,
+            &authority,
+            None,
+            "2026SP",
+            "Spring 2026",
+            TermDates {
+                starts_at: NOW + 100 * DAY,
+                ends_at: NOW + 190 * DAY,
+                registration_opens_at: NOW - DAY,
+                registration_closes_at: NOW + 100 * DAY,
+            },
+        ),
+        create_offering(
+            &institution,
+            &authority,
+            None,
+            "SOL101",
+            "2026SP",
+            &instructor.pubkey(),
+            30,
+            OfferingDeadlines::default(),
+        ),
+    ];
+    ctx.send(&ixs, &[]).unwrap();
+
+    // A fresh offering does not reset the attempts used in 2025FA
+    let ix = register_course(&institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2026SP");
+    let err = ctx.send(&[ix], &[&student]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::AttemptLimitReached));
+
+    let course = course_pda(&institution, "SOL101").0;
+    let attempts: CourseAttempts = ctx.fetch(&course_attempts_pda(&student.pubkey(), &course).0);
+    assert_eq!(attempts.attempts, 2);
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn institutions_are_administered_separately() {
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+
+    let other_authority = Keypair::new();
+    ctx.svm.airdrop(&other_authority.pubkey(), 1_000_000_000).unwrap();
+    let other = institution_pda("OTH").0;
+    let ix = create_institution(&other_authority.pubkey(), "OTH", "Other College", &ctx.treasury, &ctx.credit_mint);
+    ctx.send(&[ix], &[&other_authority]).unwrap();
+
+    // The same course ID is free at the other institution, but only its own authority can create it
+    let ix = create_course(&other, &ctx.authority.pubkey(), None, "SOL101", "Solana", &instructor.pubkey(), 5, 3, 2);
+    let err = ctx.send(&[ix], &[]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::Unauthorized));
+
+    let ix = create_course(&other, &other_authority.pubkey(), None, "SOL101", "Solana", &instructor.pubkey(), 5, 3, 2);
+    ctx.send(&[ix], &[&other_authority]).unwrap();
+    let course: Course = ctx.fetch(&course_pda(&other, "SOL101").0);
+    assert_eq!(course.institution, other);
+    assert_ne!(course_pda(&other, "SOL101").0, course_pda(&ctx.institution, "SOL101").0);
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn roles_delegate_only_their_own_instructions() {
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+    let (authority, institution) = (ctx.authority.pubkey(), ctx.institution);
+
+    let manager = Keypair::new();
+    ctx.svm.airdrop(&manager.pubkey(), 1_000_000_000).unwrap();
+    let ix = grant_role(&institution, &authority, None, &manager.pubkey(), Role::CourseManager);
+    ctx.send(&[ix], &[]).unwrap();
+    let assignment = role_assignment_pda(&institution, &manager.pubkey(), Role::CourseManager).0;
+    let role: RoleAssignment = ctx.fetch(&assignment);
+    assert_eq!((role.holder, role.role, role.granted_by), (manager.pubkey(), Role::CourseManager, authority));
+
+    let manager_key = manager.pubkey();
+    let instructor = instructor.pubkey();
+    let ix = create_course(&institution, &manager_key, Some(assignment), "SOL201", "DeFi", &instructor, 5, 3, 2);
+    ctx.send(&[ix], &[&manager]).unwrap();
+
+    // A course manager's assignment does not stand in for the finance role
+    let update = ConfigUpdate { credit_price: Some(1), ..Default::default() };
+    let ix = update_config(&institution, &manager_key, Some(assignment), update);
+    let err = ctx.send(&[ix], &[&manager]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::Unauthorized));
+
+    let ix = revoke_role(&institution, &authority, None, &manager_key, Role::CourseManager);
+    ctx.send(&[ix], &[]).unwrap();
+    assert!(ctx.svm.get_account(&assignment).is_none_or(|account| account.lamports == 0));
+    let ix = create_course(&institution, &manager_key, None, "SOL202", "NFTs", &instructor, 5, 3, 2);
+    let err = ctx.send(&[ix], &[&manager]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::Unauthorized));
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn treasury_changes_wait_for_approvals_and_the_delay() {
+    let mut ctx = TestContext::new();
+    setup_course(&mut ctx, &Pubkey::new_unique());
+    let (authority, institution) = (ctx.authority.pubkey(), ctx.institution);
+    let admins = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let admin_keys: Vec<Pubkey> = admins.iter().map(|admin| admin.pubkey()).collect();
+
+    let ix = create_config_governance(&institution, &authority, admin_keys.clone(), 2, DAY);
+    ctx.send(&[ix], &[]).unwrap();
+
+    // The authority alone can no longer move the treasury
+    let new_treasury = Pubkey::new_unique();
+    let update = ConfigUpdate { treasury: Some(new_treasury), ..Default::default() };
+    let err = ctx.send(&[update_config(&institution, &authority, None, update)], &[]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::ConfigChangeGoverned));
+
+    // The proposer pays the proposal's rent and gets it back when it closes
+    ctx.svm.airdrop(&admin_keys[0], 1_000_000_000).unwrap();
+    let change = ConfigChange { treasury: Some(new_treasury), ..Default::default() };
+    ctx.send(&[propose_config_change(&institution, &admin_keys[0], 0, change)], &[&admins[0]]).unwrap();
+
+    let execute = execute_config_change(&institution, &authority, &admin_keys[0], 0);
+    let err = ctx.send(std::slice::from_ref(&execute), &[]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::ProposalNotApproved));
+
+    ctx.send(&[approve_config_change(&institution, &admin_keys[1], 0)], &[&admins[1]]).unwrap();
+    let err = ctx.send(std::slice::from_ref(&execute), &[]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::ProposalTimelocked));
+
+    ctx.set_time(NOW + DAY);
+    ctx.send(&[execute], &[]).unwrap();
+    let config: Institution = ctx.fetch(&institution);
+    assert_eq!(config.treasury, new_treasury);
+    assert!(ctx.svm.get_account(&config_proposal_pda(&institution, 0).0).is_none_or(|account| account.lamports == 0));
+
+    // The delay can't be lowered below the floor through a proposal
+    let change = ConfigChange { min_delay: Some(MIN_CONFIG_DELAY - 1), ..Default::default() };
+    let err = ctx.send(&[propose_config_change(&institution, &admin_keys[0], 1, change)], &[&admins[0]]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::InvalidGovernance));
+
+    // Any one admin can cancel a queued change
+    let change = ConfigChange { authority: Some(admin_keys[2]), ..Default::default() };
+    ctx.send(&[propose_config_change(&institution, &admin_keys[0], 1, change)], &[&admins[0]]).unwrap();
+    ctx.send(&[cancel_config_change(&institution, &admin_keys[2], &admin_keys[0], 1)], &[&admins[2]]).unwrap();
+    let config: Institution = ctx.fetch(&institution);
+    assert_eq!(config.authority, authority);
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so from `anchor build`"]
+fn completions_transfer_through_a_course_equivalency() {
+    let mut ctx = TestContext::new();
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+
+    let student = ctx.new_student(10);
+    let ix = register_course(&ctx.institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+    ctx.send(&[ix], &[&student]).unwrap();
+    let ix = complete_course(&ctx.institution, &instructor.pubkey(), &student.pubkey(), "SOL101", "2025FA", 85);
+    ctx.send(&[ix], &[&instructor]).unwrap();
+
+    let partner_authority = Keypair::new();
+    ctx.svm.airdrop(&partner_authority.pubkey(), 1_000_000_000).unwrap();
+    let partner = institution_pda("PTU").0;
+    let ixs = [
+        create_institution(&partner_authority.pubkey(), "PTU", "Partner University", &ctx.treasury, &ctx.credit_mint),
+        create_course(
+            &partner,
+            &partner_authority.pubkey(),
+            None,
+            "BC200",
+            "Blockchains",
+            &instructor.pubkey(),
+            5,
+            4,
+            2,
+        ),
+        create_student_profile(&partner, &student.pubkey(), None, None),
+    ];
+    ctx.send(&ixs, &[&partner_authority, &student]).unwrap();
+
+    // The receiving institution alone cannot vouch for the other's course
+    let (authority, source) = (ctx.authority.pubkey(), ctx.institution);
+    let (partner_signer, forged) = (partner_authority.pubkey(), Keypair::new());
+    let forged_key = forged.pubkey();
+    let ix = create_course_equivalency(&partner, &partner_signer, None, &source, &forged_key, None, "SOL101", "BC200");
+    let err = ctx.send(&[ix], &[&partner_authority, &forged]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::Unauthorized));
+
+    let ix = create_course_equivalency(&partner, &partner_signer, None, &source, &authority, None, "SOL101", "BC200");
+    ctx.send(&[ix], &[&partner_authority]).unwrap();
+
+    // The source grade can still change until the offering's grading deadline
+    let ix = transfer_completion(&partner, &source, &student.pubkey(), "SOL101", "2025FA", "BC200");
+    let err = ctx.send(&[ix], &[&student]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::GradingDeadlineNotReached));
+
+    let offering = offering_pda(&source, "SOL101", "2025FA").0;
+    let grading_deadline = ctx.fetch::<CourseOffering>(&offering).grading_deadline;
+    ctx.set_time(grading_deadline + 1);
+    let ix = transfer_completion(&partner, &source, &student.pubkey(), "SOL101", "2025FA", "BC200");
+    ctx.send(&[ix], &[&student]).unwrap();
+
+    let source_enrollment = enrollment_pda(&student.pubkey(), &offering).0;
+    let enrollment: CourseEnrollment = ctx.fetch(&transferred_enrollment_pda(&partner, &source_enrollment).0);
+    let equivalency = course_equivalency_pda(&course_pda(&source, "SOL101").0, &course_pda(&partner, "BC200").0).0;
+    assert_eq!(enrollment.offering, equivalency);
+    assert_eq!(enrollment.transferred_from, Some(source_enrollment));
+    assert_eq!(enrollment.status, EnrollmentStatus::Completed);
+    assert_eq!((enrollment.grade, enrollment.credit_hours, enrollment.credits_paid), (85, 4, 0));
+
+    let profile: StudentProfile = ctx.fetch(&student_profile_pda(&partner, &student.pubkey()).0);
+    assert_eq!(profile.courses_completed, 1);
+    assert_eq!(profile.credit_hours_earned, 4);
+    assert_eq!(profile.open_enrollments, 0);
+
+    // A completion transfers once per receiving institution, even to another
+    // equivalent course
+    let ixs = [
+        create_course(
+            &partner,
+            &partner_signer,
+            None,
+            "BC201",
+            "Blockchains II",
+            &instructor.pubkey(),
+            5,
+            4,
+            2,
+        ),
+        create_course_equivalency(&partner, &partner_signer, None, &source, &authority, None, "SOL101", "BC201"),
+    ];
+    ctx.send(&ixs, &[&partner_authority]).unwrap();
+    for course_id in ["BC200", "BC201"] {
+        let ix = transfer_completion(&partner, &source, &student.pubkey(), "SOL101", "2025FA", course_id);
+        assert!(ctx.try_send(&[ix], &[&student]).is_err());
+    }
+    let profile: StudentProfile = ctx.fetch(&student_profile_pda(&partner, &student.pubkey()).0);
+    assert_eq!(profile.courses_completed, 1);
+}
+
+#[test]
+#[ignore = "requires target/deploy/academic_chain.so and the tests/fixtures compression programs"]
+fn compressed_certificates_prove_against_the_tree() {
+    let mut ctx = TestContext::new();
+    ctx.svm
+        .add_program_from_file(ACCOUNT_COMPRESSION_PROGRAM_ID, COMPRESSION_PATH)
+        .expect("run scripts/dump-fixtures.sh for spl_account_compression.so");
+    ctx.svm
+        .add_program_from_file(NOOP_PROGRAM_ID, NOOP_PATH)
+        .expect("run scripts/dump-fixtures.sh for spl_noop.so");
+    let instructor = Keypair::new();
+    setup_course(&mut ctx, &instructor.pubkey());
+
+    let (authority, institution) = (ctx.authority.pubkey(), ctx.institution);
+    let merkle_tree = Keypair::new();
+    let rent = ctx.svm.minimum_balance_for_rent_exemption(merkle_tree_account_size(3, 8));
+    let ixs = [
+        allocate_tree(&authority, &merkle_tree.pubkey(), rent, 3, 8),
+        create_certificate_tree(&institution, &authority, None, &merkle_tree.pubkey(), 3, 8),
+    ];
+    ctx.send(&ixs, &[&merkle_tree]).unwrap();
+
+    const URI: &str = "ipfs://certificate";
+    let offering = offering_pda(&ctx.institution, "SOL101", "2025FA").0;
+    let mut students = Vec::new();
+    let mut enrollments = Vec::new();
+    for grade in [91, 78] {
+        let student = ctx.new_student(10);
+        let ix = register_course(&ctx.institution, &student.pubkey(), &ctx.credit_mint, "SOL101", "2025FA");
+        ctx.send(&[ix], &[&student]).unwrap();
+        let ix = complete_course(&ctx.institution, &instructor.pubkey(), &student.pubkey(), "SOL101", "2025FA", grade);
+        ctx.send(&[ix], &[&instructor]).unwrap();
+        let ix = mint_compressed_certificate(
+            &institution,
+            &student.pubkey(),
+            &merkle_tree.pubkey(),
+            "SOL101",
+            "2025FA",
+            URI,
+            [7; 32],
+        );
+        ctx.send(&[ix], &[&student]).unwrap();
+
+        let address = enrollment_pda(&student.pubkey(), &offering).0;
+        let enrollment: CourseEnrollment = ctx.fetch(&address);
+        enrollments.push((address, enrollment));
+        students.push(student);
+    }
+
+    let account = ctx.svm.get_account(&merkle_tree.pubkey()).unwrap();
+    let tree = parse_tree(&account.data).unwrap();
+    assert_eq!(tree.authority, certificate_tree_pda(&merkle_tree.pubkey()).0);
+    assert_eq!(tree.leaf_count, 2);
+
+    let leaves: Vec<_> = enrollments
+        .iter()
+        .map(|(_, enrollment)| enrollment.compressed_certificate.map(|leaf| leaf.leaf_hash))
+        .collect();
+    let (address, enrollment) = &enrollments[1];
+    let certificate = enrollment.compressed_certificate.unwrap();
+    assert_eq!(certificate.leaf_index, 1);
+    let proof = proof_from_leaves(address, &certificate, &leaves, tree.max_depth).unwrap();
+    assert_eq!(proof.root().unwrap(), tree.root);
+
+    ctx.send(&[verify_leaf_instruction(&proof).unwrap()], &[]).unwrap();
+
+    let ix = mint_compressed_certificate(
+        &institution,
+        &students[0].pubkey(),
+        &merkle_tree.pubkey(),
+        "SOL101",
+        "2025FA",
+        URI,
+        [7; 32],
+    );
+    let err = ctx.send(&[ix], &[&students[0]]).unwrap_err();
+    assert_eq!(u32::from(err), u32::from(AcademicChainError::CertificateAlreadyMinted));
+}
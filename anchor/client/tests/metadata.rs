@@ -0,0 +1,111 @@
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering, EnrollmentStatus};
+use academic_chain_client::metadata::*;
+use anchor_lang::prelude::Pubkey;
+
+fn completed() -> (CourseEnrollment, Course, CourseOffering) {
+    let instructor = Pubkey::new_unique();
+    let course = Course {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        course_name: "Intro to Solana".to_string(),
+        instructor,
+        required_credits: 5,
+        credit_hours: 3,
+        max_attempts: 3,
+        is_active: true,
+        created_at: 1_700_000_000,
+        bump: 255,
+    };
+    let offering = CourseOffering {
+        institution: Pubkey::default(),
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        instructor,
+        capacity: 30,
+        enrollment_count: 1,
+        enrollment_opens_at: 1_750_000_000,
+        enrollment_closes_at: 1_755_000_000,
+        grading_deadline: 1_765_000_000,
+        created_at: 1_750_000_000,
+        bump: 255,
+    };
+    let enrollment = CourseEnrollment {
+        student: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        offering: Pubkey::new_unique(),
+        credits_paid: 5,
+        enrollment_date: 1_752_000_000,
+        completion_date: Some(1_760_000_000),
+        status: EnrollmentStatus::Completed,
+        grade: 85,
+        letter_grade: "B".to_string(),
+        grade_points: 300,
+        credit_hours: 3,
+        certificate_mint: None,
+        metadata_hash: None,
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    (enrollment, course, offering)
+}
+
+fn links() -> CertificateLinks {
+    CertificateLinks {
+        image: "https://example.edu/certificate.svg".to_string(),
+        external_url: Some("https://verify.example.edu/V/ABC".to_string()),
+    }
+}
+
+#[test]
+fn course_metadata_follows_the_metaplex_shape() {
+    let (enrollment, course, offering) = completed();
+    let metadata = CertificateMetadata::course(&enrollment, &course, &offering, &links());
+    let json: serde_json::Value = serde_json::from_slice(&metadata.to_bytes()).unwrap();
+
+    assert_eq!(json["name"], "SOL101 Certificate");
+    assert_eq!(json["symbol"], CERTIFICATE_SYMBOL);
+    assert_eq!(json["external_url"], "https://verify.example.edu/V/ABC");
+    assert_eq!(json["properties"]["files"][0]["type"], "image/svg+xml");
+    assert_eq!(json["properties"]["category"], "image");
+
+    let attribute = |trait_type: &str| {
+        json["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|attribute| attribute["trait_type"] == trait_type)
+            .cloned()
+            .unwrap()
+    };
+    assert_eq!(attribute("term_id")["value"], "2025FA");
+    assert_eq!(attribute("grade")["value"], 85);
+    assert_eq!(attribute("completion_date")["display_type"], "date");
+}
+
+#[test]
+fn stored_documents_are_addressed_by_their_hash() {
+    let (mut enrollment, course, offering) = completed();
+    let document = CertificateMetadata::course(&enrollment, &course, &offering, &links()).to_bytes();
+
+    let root = std::env::temp_dir().join(format!("academic-metadata-{}", std::process::id()));
+    let store = ContentStore::open(&root).unwrap();
+    let hash = store.put(&document).unwrap();
+    assert_eq!(hash, sha256(&document));
+    assert_eq!(store.path(&hash), root.join(format!("{}.json", hash_to_hex(&hash))));
+    assert_eq!(store.get(&hash).unwrap(), document);
+    assert_eq!(hash_from_hex(&hash_to_hex(&hash)), Some(hash));
+
+    enrollment.metadata_hash = Some(hash);
+    assert!(matches_enrollment(&document, &enrollment));
+    let mut edited = document.clone();
+    edited.push(b'\n');
+    assert!(!matches_enrollment(&edited, &enrollment));
+
+    // A file changed after storing no longer reads back
+    std::fs::write(store.path(&hash), &edited).unwrap();
+    assert!(store.get(&hash).is_err());
+    std::fs::remove_dir_all(root).unwrap();
+}
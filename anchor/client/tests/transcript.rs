@@ -0,0 +1,233 @@
+use academic_chain::state::{CourseEnrollment, EnrollmentStatus, StudentProfile, TranscriptAnchor};
+use academic_chain::utils::{merkle_proof, merkle_root, verify_merkle_proof};
+use academic_chain_client::credential::{sign, CredentialFailure, DidMethod};
+use academic_chain_client::metadata::hash_to_hex;
+use academic_chain_client::transcript::*;
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+const ISSUED_AT: i64 = 1_760_300_000;
+
+fn record(student: Pubkey, course_id: &str, enrolled_at: i64, grade: u8, letter: &str) -> TranscriptRecord {
+    TranscriptRecord {
+        address: Pubkey::new_unique(),
+        enrollment: CourseEnrollment {
+            student,
+            course_id: course_id.to_string(),
+            offering: Pubkey::new_unique(),
+            credits_paid: 5,
+            enrollment_date: enrolled_at,
+            completion_date: Some(enrolled_at + 100_000),
+            status: EnrollmentStatus::Completed,
+            grade,
+            letter_grade: letter.to_string(),
+            grade_points: 300,
+            credit_hours: 3,
+            certificate_mint: None,
+            metadata_hash: None,
+            certificate_batch: None,
+            compressed_certificate: None,
+            transferred_from: None,
+            attempts: Vec::new(),
+            bump: 255,
+        },
+        term_id: "2025FA".to_string(),
+        course_name: format!("{course_id} (Solana)"),
+    }
+}
+
+fn student() -> (StudentProfile, Vec<TranscriptRecord>) {
+    let student = Pubkey::new_unique();
+    let profile = StudentProfile {
+        institution: Pubkey::new_unique(),
+        student,
+        total_credits_purchased: 20,
+        total_credits_spent: 15,
+        courses_completed: 3,
+        open_enrollments: 3,
+        credit_hours_attempted: 9,
+        credit_hours_earned: 9,
+        quality_points: 3150,
+        gpa: 350,
+        graduation_nft: Some(Pubkey::new_unique()),
+        display_name: Some("Ada".to_string()),
+        metadata_uri: None,
+        created_at: 1_700_000_000,
+        bump: 255,
+    };
+    // Out of order on purpose; transcripts sort by enrollment date
+    let records = vec![
+        record(student, "SOL201", 1_760_000_300, 91, "A"),
+        record(student, "SOL101", 1_760_000_100, 85, "B"),
+        record(student, "RUST101", 1_760_000_200, 78, "C"),
+    ];
+    (profile, records)
+}
+
+fn anchor(transcript: &Transcript, issuer: Pubkey) -> TranscriptAnchor {
+    TranscriptAnchor {
+        institution: transcript.institution.parse().unwrap(),
+        student: transcript.student.parse().unwrap(),
+        issuer,
+        merkle_root: transcript.root().unwrap(),
+        entry_count: transcript.entries.len() as u16,
+        issued_at: ISSUED_AT,
+        bump: 255,
+    }
+}
+
+#[test]
+fn signed_transcripts_verify_against_their_anchor() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+
+    let courses: Vec<&str> = transcript.entries.iter().map(|line| line.course_id.as_str()).collect();
+    assert_eq!(courses, ["SOL101", "RUST101", "SOL201"]);
+    assert_eq!(transcript.summary.gpa, "3.50");
+    assert!(transcript.summary.graduated);
+    assert_eq!(transcript.merkle_root, hash_to_hex(&merkle_root(&transcript.leaves().unwrap())));
+
+    // The same enrollments always produce the same root and anchor
+    let reissued = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT + 60);
+    assert_eq!(reissued.merkle_root, transcript.merkle_root);
+    assert_eq!(reissued.transcript_anchor, transcript.transcript_anchor);
+
+    let checked = check_transcript(&transcript.to_value()).unwrap();
+    assert_eq!(checked, transcript);
+    assert_eq!(check_anchor(&checked, Some(&anchor(&checked, issuer.pubkey()))), Ok(()));
+}
+
+#[test]
+fn edited_transcripts_are_rejected() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+
+    let mut document = transcript.to_value();
+    document["entries"][1]["grade"] = 99.into();
+    assert_eq!(
+        check_transcript(&document),
+        Err(TranscriptFailure::Proof(CredentialFailure::BadSignature))
+    );
+
+    // Re-signing an edited entry does not change the stated root
+    let mut forged = transcript.clone();
+    forged.entries[1].grade = 99;
+    forged.proof = None;
+    let method = DidMethod::Key.verification_method(&issuer.pubkey());
+    forged.proof = Some(sign(&forged.to_value(), &issuer, &method, ISSUED_AT));
+    assert_eq!(check_transcript(&forged.to_value()), Err(TranscriptFailure::RootMismatch));
+}
+
+#[test]
+fn anchors_must_come_from_the_signing_issuer() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+
+    assert_eq!(check_anchor(&transcript, None), Err(TranscriptFailure::NotAnchored));
+    assert_eq!(
+        check_anchor(&transcript, Some(&anchor(&transcript, Pubkey::new_unique()))),
+        Err(TranscriptFailure::AnchorMismatch)
+    );
+    let mut short = anchor(&transcript, issuer.pubkey());
+    short.entry_count -= 1;
+    assert_eq!(check_anchor(&transcript, Some(&short)), Err(TranscriptFailure::AnchorMismatch));
+}
+
+#[test]
+fn anchors_must_belong_to_the_claimed_institution() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+    assert_eq!(transcript.institution, profile.institution.to_string());
+
+    let mut elsewhere = anchor(&transcript, issuer.pubkey());
+    elsewhere.institution = Pubkey::new_unique();
+    assert_eq!(check_anchor(&transcript, Some(&elsewhere)), Err(TranscriptFailure::AnchorMismatch));
+    let disclosure = transcript.disclose(0).unwrap();
+    assert_eq!(check_disclosure_anchor(&disclosure, Some(&elsewhere)), Err(TranscriptFailure::AnchorMismatch));
+
+    // Claiming another institution changes the anchor address the copy must name
+    let mut moved = transcript.clone();
+    moved.institution = Pubkey::new_unique().to_string();
+    let stored = anchor(&moved, issuer.pubkey());
+    assert_eq!(check_anchor(&moved, Some(&stored)), Err(TranscriptFailure::AnchorMismatch));
+}
+
+#[test]
+fn pdf_transcripts_carry_the_signed_json() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+
+    let pdf = transcript.to_pdf();
+    assert!(pdf.starts_with(b"%PDF-1.7"));
+    assert!(pdf.windows(7).any(|window| window == b"RUST101"));
+
+    let document = read_transcript(&pdf).unwrap();
+    assert_eq!(check_transcript(&document).unwrap(), transcript);
+    assert_eq!(read_transcript(&serde_json::to_vec(&transcript).unwrap()).unwrap(), document);
+}
+
+#[test]
+fn merkle_proofs_cover_every_leaf_of_uneven_trees() {
+    for size in 1..=9u8 {
+        let leaves: Vec<[u8; 32]> = (0..size).map(|i| [i + 1; 32]).collect();
+        let root = merkle_root(&leaves);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = merkle_proof(&leaves, index).unwrap();
+            assert!(verify_merkle_proof(leaf, &proof, &root), "leaf {index} of {size}");
+            assert!(!verify_merkle_proof(&[0xff; 32], &proof, &root));
+        }
+        assert_eq!(merkle_proof(&leaves, leaves.len()), None);
+    }
+}
+
+#[test]
+fn disclosures_prove_one_entry_against_the_anchor() {
+    let (profile, records) = student();
+    let issuer = Keypair::new();
+    let transcript = issue_transcript(&profile, &records, &issuer, &DidMethod::Key, ISSUED_AT);
+    let stored = anchor(&transcript, issuer.pubkey());
+
+    for index in 0..transcript.entries.len() {
+        let disclosure = transcript.disclose(index).unwrap();
+        assert_eq!(disclosure.entry, transcript.entries[index]);
+        assert_eq!(check_disclosure(&disclosure), Ok(()));
+        assert_eq!(check_disclosure_anchor(&disclosure, Some(&stored)), Ok(()));
+    }
+    assert_eq!(transcript.disclose(transcript.entries.len()), None);
+
+    let mut raised = transcript.disclose(1).unwrap();
+    raised.entry.grade = 99;
+    assert_eq!(check_disclosure(&raised), Err(TranscriptFailure::InvalidProof));
+
+    // A valid proof for one entry does not carry over to another
+    let mut swapped = transcript.disclose(0).unwrap();
+    swapped.entry = transcript.entries[2].clone();
+    assert_eq!(check_disclosure(&swapped), Err(TranscriptFailure::InvalidProof));
+
+    let disclosure = transcript.disclose(0).unwrap();
+    assert_eq!(
+        check_disclosure_anchor(&disclosure, Some(&anchor(&transcript, Pubkey::new_unique()))),
+        Err(TranscriptFailure::AnchorMismatch)
+    );
+}
+
+#[test]
+fn transfers_count_only_while_their_source_passes() {
+    let (_, records) = student();
+    let mut source = records[1].enrollment.clone();
+    assert!(transfer_source_counts(Some(&source)));
+
+    // Amending the source grade below passing revokes the transfer
+    source.grade = 40;
+    assert!(!transfer_source_counts(Some(&source)));
+    source.grade = 85;
+    source.status = EnrollmentStatus::Incomplete;
+    assert!(!transfer_source_counts(Some(&source)));
+    assert!(!transfer_source_counts(None));
+}
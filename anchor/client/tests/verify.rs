@@ -0,0 +1,101 @@
+use academic_chain::state::{CourseEnrollment, EnrollmentRecord, EnrollmentStatus, TranscriptEntry};
+use academic_chain_client::accounts::recorded_enrollment;
+use academic_chain_client::pda::certificate_mint_pda;
+use academic_chain_client::verify::{check_enrollment, VerificationFailure};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+
+fn certified(address: &Pubkey, grade: u8) -> (Pubkey, CourseEnrollment) {
+    let mint = certificate_mint_pda(address).0;
+    let enrollment = CourseEnrollment {
+        student: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        offering: Pubkey::new_unique(),
+        credits_paid: 5,
+        enrollment_date: 1_760_000_000,
+        completion_date: Some(1_760_100_000),
+        status: EnrollmentStatus::Completed,
+        grade,
+        letter_grade: "B".to_string(),
+        grade_points: 300,
+        credit_hours: 3,
+        certificate_mint: Some(mint),
+        metadata_hash: Some([0x42; 32]),
+        certificate_batch: None,
+        compressed_certificate: None,
+        transferred_from: None,
+        attempts: Vec::new(),
+        bump: 255,
+    };
+    (mint, enrollment)
+}
+
+#[test]
+fn completed_passing_enrollment_verifies() {
+    let address = Pubkey::new_unique();
+    let (mint, enrollment) = certified(&address, 85);
+    assert_eq!(check_enrollment(&mint, &address, &enrollment), Ok(()));
+}
+
+#[test]
+fn each_failure_is_reported() {
+    let address = Pubkey::new_unique();
+    let (mint, enrollment) = certified(&address, 85);
+
+    assert_eq!(
+        check_enrollment(&Pubkey::new_unique(), &address, &enrollment),
+        Err(VerificationFailure::NotFound)
+    );
+
+    // A recorded mint that is not this enrollment's PDA
+    let other = Pubkey::new_unique();
+    let forged = CourseEnrollment { certificate_mint: Some(other), ..enrollment.clone() };
+    assert_eq!(check_enrollment(&other, &address, &forged), Err(VerificationFailure::NotProgramMint));
+
+    let retaken = CourseEnrollment { status: EnrollmentStatus::Enrolled, ..enrollment.clone() };
+    assert_eq!(check_enrollment(&mint, &address, &retaken), Err(VerificationFailure::NotCompleted));
+
+    let amended = CourseEnrollment { grade: 40, ..enrollment };
+    assert_eq!(check_enrollment(&mint, &address, &amended), Err(VerificationFailure::Revoked));
+}
+
+#[test]
+fn closed_enrollments_verify_from_their_record() {
+    let address = Pubkey::new_unique();
+    let (mint, enrollment) = certified(&address, 85);
+    let record = EnrollmentRecord {
+        student: enrollment.student,
+        offering: enrollment.offering,
+        entry: TranscriptEntry {
+            enrollment: address,
+            course_id: enrollment.course_id.clone(),
+            term_id: "2025FA".to_string(),
+            status: enrollment.status,
+            grade: enrollment.grade,
+            letter_grade: enrollment.letter_grade.clone(),
+            credit_hours: enrollment.credit_hours,
+            completion_date: enrollment.completion_date,
+        },
+        enrollment_date: enrollment.enrollment_date,
+        grade_points: enrollment.grade_points,
+        certificate_mint: enrollment.certificate_mint,
+        metadata_hash: enrollment.metadata_hash,
+        certificate_batch: None,
+        compressed_certificate: None,
+        closed_at: 1_760_200_000,
+        bump: 255,
+    };
+
+    // Sized exactly, and smaller than the enrollment it replaces
+    let mut data = Vec::new();
+    record.try_serialize(&mut data).unwrap();
+    let space = EnrollmentRecord::space("SOL101", "2025FA", "B");
+    assert!(data.len() <= space && space < CourseEnrollment::LEN);
+
+    let rebuilt = recorded_enrollment(&record);
+    assert_eq!(check_enrollment(&mint, &address, &rebuilt), Ok(()));
+    assert_eq!(
+        (rebuilt.grade, rebuilt.letter_grade.as_str(), rebuilt.completion_date, rebuilt.metadata_hash),
+        (enrollment.grade, "B", enrollment.completion_date, enrollment.metadata_hash)
+    );
+}
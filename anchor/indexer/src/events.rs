@@ -0,0 +1,125 @@
+//! Decoding of `academic_chain` instructions and `emit_cpi!` events.
+
+use academic_chain::events::*;
+use academic_chain::instruction;
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+
+macro_rules! program_events {
+    ($($event:ident),* $(,)?) => {
+        /// Any event emitted by the program
+        pub enum ProgramEvent {
+            $($event($event),)*
+        }
+
+        impl ProgramEvent {
+            /// Decode event bytes (discriminator followed by the Borsh payload)
+            pub fn decode(data: &[u8]) -> Option<Self> {
+                $(
+                    if let Some(mut payload) = data.strip_prefix($event::DISCRIMINATOR) {
+                        return $event::deserialize(&mut payload).ok().map(Self::$event);
+                    }
+                )*
+                None
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$event(_) => stringify!($event),)*
+                }
+            }
+        }
+    };
+}
+
+program_events! {
+    InstitutionCreated,
+    GradeScaleUpdated,
+    ConfigUpdated,
+    RoleGranted,
+    RoleRevoked,
+    ConfigGovernanceUpdated,
+    ConfigChangeProposed,
+    ConfigChangeApproved,
+    ConfigChangeExecuted,
+    ConfigChangeCancelled,
+    StudentProfileCreated,
+    StudentProfileClosed,
+    CreditsPurchased,
+    CourseCreated,
+    CourseUpdated,
+    TermCreated,
+    OfferingCreated,
+    StudentRegistered,
+    CourseRetaken,
+    CourseCompleted,
+    GradeAmended,
+    EnrollmentExpired,
+    CertificateMinted,
+    GraduationClaimed,
+    EnrollmentClosed,
+    TranscriptAnchored,
+    CertificateBatchCreated,
+    BatchCertificateClaimed,
+    CertificateTreeCreated,
+    CompressedCertificateMinted,
+    CourseEquivalencyCreated,
+    CompletionTransferred,
+}
+
+/// Event bytes carried by a self-CPI from `emit_cpi!`, without the event tag
+pub fn event_cpi_data(ix_data: &[u8]) -> Option<&[u8]> {
+    ix_data.strip_prefix(EVENT_IX_TAG_LE)
+}
+
+macro_rules! instruction_names {
+    ($($ix:ident => $name:literal),* $(,)?) => {
+        /// Snake case name of a top-level program instruction
+        pub fn instruction_name(data: &[u8]) -> Option<&'static str> {
+            $(
+                if data.starts_with(instruction::$ix::DISCRIMINATOR) {
+                    return Some($name);
+                }
+            )*
+            None
+        }
+    };
+}
+
+instruction_names! {
+    CreateInstitution => "create_institution",
+    SetGradeScale => "set_grade_scale",
+    UpdateConfig => "update_config",
+    GrantRole => "grant_role",
+    RevokeRole => "revoke_role",
+    CreateConfigGovernance => "create_config_governance",
+    ProposeConfigChange => "propose_config_change",
+    ApproveConfigChange => "approve_config_change",
+    ExecuteConfigChange => "execute_config_change",
+    CancelConfigChange => "cancel_config_change",
+    CreateStudentProfile => "create_student_profile",
+    PurchaseCredits => "purchase_credits",
+    CreateCourse => "create_course",
+    UpdateCourse => "update_course",
+    CreateTerm => "create_term",
+    CreateOffering => "create_offering",
+    RegisterCourse => "register_course",
+    RetakeCourse => "retake_course",
+    CompleteCourse => "complete_course",
+    CompleteCourseBatch => "complete_course_batch",
+    AmendGrade => "amend_grade",
+    ExpireEnrollment => "expire_enrollment",
+    MintCertificate => "mint_certificate",
+    ClaimGraduation => "claim_graduation",
+    CloseEnrollment => "close_enrollment",
+    CloseProfile => "close_profile",
+    AnchorTranscript => "anchor_transcript",
+    VerifyTranscriptEntry => "verify_transcript_entry",
+    CreateCertificateBatch => "create_certificate_batch",
+    ClaimBatchCertificate => "claim_batch_certificate",
+    VerifyBatchCertificate => "verify_batch_certificate",
+    CreateCertificateTree => "create_certificate_tree",
+    MintCompressedCertificate => "mint_compressed_certificate",
+    CreateCourseEquivalency => "create_course_equivalency",
+    TransferCompletion => "transfer_completion",
+}
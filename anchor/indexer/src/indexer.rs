@@ -0,0 +1,155 @@
+//! Follow program transactions over RPC at `confirmed` commitment.
+//!
+//! Each poll first settles earlier transactions: finalized ones are marked
+//! as such and any that disappeared with a dropped fork are rolled back. It
+//! then fetches signatures newer than the checkpoint and stores them oldest
+//! first, one SQLite transaction each, so a crash resumes where it stopped.
+
+use std::str::FromStr;
+
+use academic_chain::ID;
+use anyhow::{Context as _, Result};
+use solana_commitment_config::CommitmentConfig;
+use solana_rpc_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_signature::Signature;
+use solana_transaction_status_client_types::{TransactionConfirmationStatus, UiTransactionEncoding};
+
+use crate::parse::parse_transaction;
+use crate::store::Store;
+
+// Page size of getSignaturesForAddress and maximum batch of getSignatureStatuses
+const SIGNATURE_PAGE: usize = 1000;
+const STATUS_BATCH: usize = 256;
+
+/// What one poll did
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PollSummary {
+    pub indexed: usize,
+    pub finalized: usize,
+    pub rolled_back: usize,
+}
+
+pub struct Indexer {
+    rpc: RpcClient,
+    store: Store,
+}
+
+impl Indexer {
+    pub fn new(rpc_url: String, store: Store) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(rpc_url, CommitmentConfig::confirmed()),
+            store,
+        }
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn poll(&mut self) -> Result<PollSummary> {
+        let (finalized, rolled_back) = self.settle()?;
+        let indexed = self.index_new()?;
+        Ok(PollSummary {
+            indexed,
+            finalized,
+            rolled_back,
+        })
+    }
+
+    /// Mark finalized transactions and roll back those no longer on any fork
+    fn settle(&mut self) -> Result<(usize, usize)> {
+        let pending = self.store.unfinalized()?;
+        let mut finalized = Vec::new();
+        let mut dropped = Vec::new();
+
+        for chunk in pending.chunks(STATUS_BATCH) {
+            let signatures = chunk
+                .iter()
+                .map(|signature| Signature::from_str(signature))
+                .collect::<Result<Vec<_>, _>>()?;
+            let statuses = self
+                .rpc
+                .get_signature_statuses_with_history(&signatures)
+                .context("failed to fetch signature statuses")?
+                .value;
+
+            for (signature, status) in chunk.iter().zip(statuses) {
+                match status.and_then(|status| status.confirmation_status) {
+                    Some(TransactionConfirmationStatus::Finalized) => finalized.push(signature.clone()),
+                    Some(_) => {}
+                    None => dropped.push(signature.clone()),
+                }
+            }
+        }
+
+        self.store.mark_finalized(&finalized)?;
+        if !dropped.is_empty() {
+            log::warn!("rolling back {} transactions dropped with their fork", dropped.len());
+            self.store.rollback(&dropped)?;
+        }
+        Ok((finalized.len(), dropped.len()))
+    }
+
+    fn index_new(&mut self) -> Result<usize> {
+        let until = self
+            .store
+            .checkpoint()?
+            .map(|checkpoint| Signature::from_str(&checkpoint.signature))
+            .transpose()?;
+
+        // Newest first, page by page back to the checkpoint
+        let mut pending = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(
+                    &ID,
+                    GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until,
+                        limit: Some(SIGNATURE_PAGE),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                    },
+                )
+                .context("failed to fetch program signatures")?;
+            let full = page.len() == SIGNATURE_PAGE;
+            before = page
+                .last()
+                .map(|status| Signature::from_str(&status.signature))
+                .transpose()?;
+            pending.extend(page);
+            if !full {
+                break;
+            }
+        }
+
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
+        let count = pending.len();
+        for status in pending.into_iter().rev() {
+            let signature = Signature::from_str(&status.signature)?;
+            let encoded = self
+                .rpc
+                .get_transaction_with_config(&signature, config)
+                .with_context(|| format!("failed to fetch transaction {signature}"))?;
+
+            let mut transaction = parse_transaction(&status.signature, &encoded)?;
+            transaction.finalized = status.confirmation_status == Some(TransactionConfirmationStatus::Finalized);
+            self.store.record(&transaction)?;
+            log::info!(
+                "{} slot {} [{}] {} events",
+                transaction.signature,
+                transaction.slot,
+                transaction.instructions.join(", "),
+                transaction.events.len(),
+            );
+        }
+
+        Ok(count)
+    }
+}
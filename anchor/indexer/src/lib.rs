@@ -0,0 +1,13 @@
+//! Indexer for the `academic_chain` program: follows its transactions over
+//! RPC, decodes instructions and `emit_cpi!` events, and materializes courses,
+//! enrollments, profiles, certificates and purchases into SQLite.
+
+pub mod events;
+pub mod indexer;
+pub mod parse;
+pub mod projection;
+pub mod store;
+
+pub use indexer::{Indexer, PollSummary};
+pub use parse::{parse_transaction, IndexedTransaction, RawEvent};
+pub use store::{Checkpoint, Store};
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use academic_indexer::{Indexer, Store};
+use anyhow::{Context as _, Result};
+use clap::Parser;
+
+#[derive(Parser)]
+#[command(name = "academic-indexer", version, about = "Index academic_chain into SQLite")]
+struct Args {
+    /// RPC endpoint to follow
+    #[arg(short = 'u', long, default_value = "http://127.0.0.1:8899", env = "ACADEMIC_RPC_URL")]
+    rpc_url: String,
+
+    /// SQLite database file
+    #[arg(long, default_value = "academic_chain.db", env = "ACADEMIC_DB")]
+    db: PathBuf,
+
+    /// Seconds between polls
+    #[arg(long, default_value_t = 5)]
+    interval: u64,
+
+    /// Poll once and exit
+    #[arg(long)]
+    once: bool,
+}
+
+fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
+
+    let store = Store::open(&args.db).with_context(|| format!("failed to open {}", args.db.display()))?;
+    if let Some(checkpoint) = store.checkpoint()? {
+        log::info!("resuming after {} (slot {})", checkpoint.signature, checkpoint.slot);
+    }
+    let mut indexer = Indexer::new(args.rpc_url, store);
+
+    loop {
+        match indexer.poll() {
+            Ok(summary) if summary != Default::default() => log::info!(
+                "indexed {}, finalized {}, rolled back {}",
+                summary.indexed,
+                summary.finalized,
+                summary.rolled_back,
+            ),
+            Ok(_) => {}
+            // Transient RPC errors are retried on the next poll from the same checkpoint
+            Err(err) if !args.once => log::error!("{err:#}"),
+            Err(err) => return Err(err),
+        }
+
+        if args.once {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_secs(args.interval));
+    }
+}
@@ -0,0 +1,123 @@
+//! Turn an RPC transaction into the instruction names and events to store.
+
+use academic_chain::ID;
+use anchor_lang::prelude::Pubkey;
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, UiInstruction, UiLoadedAddresses,
+};
+
+use crate::events::{event_cpi_data, instruction_name, ProgramEvent};
+
+/// A program transaction ready to be stored
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub success: bool,
+    pub fee: u64,
+    pub signer: String,
+    /// Names of the top-level program instructions, in order
+    pub instructions: Vec<&'static str>,
+    pub events: Vec<RawEvent>,
+    pub finalized: bool,
+}
+
+/// Event bytes as emitted: discriminator followed by the Borsh payload
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawEvent {
+    pub name: &'static str,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("transaction {0} could not be decoded; request base64 encoding")]
+    Undecodable(String),
+
+    #[error("transaction {0} has no status meta")]
+    MissingMeta(String),
+
+    #[error("transaction {0} references account index {1} out of range")]
+    AccountIndex(String, u8),
+}
+
+pub fn parse_transaction(
+    signature: &str,
+    encoded: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<IndexedTransaction, ParseError> {
+    let transaction = encoded
+        .transaction
+        .transaction
+        .decode()
+        .ok_or_else(|| ParseError::Undecodable(signature.to_string()))?;
+    let meta = encoded
+        .transaction
+        .meta
+        .as_ref()
+        .ok_or_else(|| ParseError::MissingMeta(signature.to_string()))?;
+
+    // Static keys first, then keys loaded from lookup tables (writable, readonly)
+    let mut account_keys = transaction.message.static_account_keys().to_vec();
+    if let Some(loaded) = Option::<&UiLoadedAddresses>::from(meta.loaded_addresses.as_ref()) {
+        account_keys.extend(
+            loaded
+                .writable
+                .iter()
+                .chain(&loaded.readonly)
+                .filter_map(|key| key.parse::<Pubkey>().ok()),
+        );
+    }
+    let program_at = |index: u8| {
+        account_keys
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| ParseError::AccountIndex(signature.to_string(), index))
+    };
+
+    let mut instructions = Vec::new();
+    for ix in transaction.message.instructions() {
+        if program_at(ix.program_id_index)? == ID {
+            instructions.extend(instruction_name(&ix.data));
+        }
+    }
+
+    // A failed transaction changed nothing, whatever its inner instructions say
+    let success = meta.err.is_none();
+    let mut events = Vec::new();
+    if success {
+        let inner = Option::<&Vec<_>>::from(meta.inner_instructions.as_ref());
+        for ui_ix in inner.into_iter().flatten().flat_map(|inner| &inner.instructions) {
+            let UiInstruction::Compiled(ix) = ui_ix else {
+                continue;
+            };
+            if program_at(ix.program_id_index)? != ID {
+                continue;
+            }
+            let Ok(data) = bs58::decode(&ix.data).into_vec() else {
+                continue;
+            };
+            if let Some(event_data) = event_cpi_data(&data) {
+                match ProgramEvent::decode(event_data) {
+                    Some(event) => events.push(RawEvent {
+                        name: event.name(),
+                        data: event_data.to_vec(),
+                    }),
+                    None => log::warn!("{signature}: skipping unknown event"),
+                }
+            }
+        }
+    }
+
+    Ok(IndexedTransaction {
+        signature: signature.to_string(),
+        slot: encoded.slot,
+        block_time: encoded.block_time,
+        success,
+        fee: meta.fee,
+        signer: account_keys.first().map(ToString::to_string).unwrap_or_default(),
+        instructions,
+        events,
+        finalized: false,
+    })
+}
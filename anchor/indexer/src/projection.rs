@@ -0,0 +1,759 @@
+//! Fold program events into the projection tables.
+
+use academic_chain::state::{ConfigChange, EnrollmentStatus, GradeScaleEntry, Role};
+use anchor_lang::prelude::Pubkey;
+use rusqlite::{params, OptionalExtension, Transaction};
+
+use crate::events::ProgramEvent;
+
+pub fn status_name(status: &EnrollmentStatus) -> &'static str {
+    match status {
+        EnrollmentStatus::Enrolled => "enrolled",
+        EnrollmentStatus::Completed => "completed",
+        EnrollmentStatus::Incomplete => "incomplete",
+    }
+}
+
+pub fn role_name(role: Role) -> &'static str {
+    match role {
+        Role::SuperAdmin => "super_admin",
+        Role::Registrar => "registrar",
+        Role::FinanceAdmin => "finance_admin",
+        Role::CourseManager => "course_manager",
+        Role::Reviewer => "reviewer",
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn grade_scale_json(grade_scale: &[GradeScaleEntry]) -> String {
+    let rows: Vec<_> = grade_scale
+        .iter()
+        .map(|entry| {
+            serde_json::json!({
+                "min_score": entry.min_score,
+                "letter": entry.letter,
+                "grade_points": entry.grade_points,
+            })
+        })
+        .collect();
+    serde_json::Value::Array(rows).to_string()
+}
+
+fn admins_json(admins: &[Pubkey]) -> String {
+    serde_json::Value::from(admins.iter().map(ToString::to_string).collect::<Vec<_>>()).to_string()
+}
+
+// Only the fields the proposal sets
+fn config_change_json(change: &ConfigChange) -> String {
+    let mut fields = serde_json::Map::new();
+    let keys = [("authority", change.authority), ("treasury", change.treasury), ("credit_mint", change.credit_mint)];
+    for (name, key) in keys {
+        if let Some(key) = key {
+            fields.insert(name.into(), key.to_string().into());
+        }
+    }
+    if let Some(admins) = &change.admins {
+        fields.insert("admins".into(), admins.iter().map(ToString::to_string).collect::<Vec<_>>().into());
+    }
+    if let Some(threshold) = change.threshold {
+        fields.insert("threshold".into(), threshold.into());
+    }
+    if let Some(min_delay) = change.min_delay {
+        fields.insert("min_delay".into(), min_delay.into());
+    }
+    serde_json::Value::Object(fields).to_string()
+}
+
+/// Apply one event; `signature` and `index` identify it in the event log
+pub fn apply(tx: &Transaction, signature: &str, index: usize, event: &ProgramEvent) -> rusqlite::Result<()> {
+    match event {
+        ProgramEvent::InstitutionCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO institutions
+                 (address, institution_id, name, authority, treasury, credit_mint, credit_price, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    e.institution.to_string(),
+                    e.institution_id,
+                    e.name,
+                    e.authority.to_string(),
+                    e.treasury.to_string(),
+                    e.credit_mint.to_string(),
+                    e.credit_price,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::ConfigUpdated(e) => {
+            tx.execute(
+                "UPDATE institutions SET authority = ?1, treasury = ?2, credit_price = ?3, updated_at = ?4
+                 WHERE address = ?5",
+                params![
+                    e.authority.to_string(),
+                    e.treasury.to_string(),
+                    e.credit_price,
+                    e.timestamp,
+                    e.institution.to_string(),
+                ],
+            )?;
+        }
+        ProgramEvent::RoleGranted(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO role_assignments
+                 (address, institution, holder, role, granted_by, granted_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    e.role_assignment.to_string(),
+                    e.institution.to_string(),
+                    e.holder.to_string(),
+                    role_name(e.role),
+                    e.granted_by.to_string(),
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::RoleRevoked(e) => {
+            tx.execute(
+                "DELETE FROM role_assignments WHERE address = ?1",
+                params![e.role_assignment.to_string()],
+            )?;
+        }
+        ProgramEvent::ConfigGovernanceUpdated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO config_governance (institution, address, admins, threshold, min_delay, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    e.institution.to_string(),
+                    e.governance.to_string(),
+                    admins_json(&e.admins),
+                    e.threshold,
+                    e.min_delay,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::ConfigChangeProposed(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO config_proposals
+                 (address, institution, proposal_id, proposer, change, approvals, executable_at, status, created_at,
+                  updated_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, 'pending', ?7, ?7, ?8)",
+                params![
+                    e.proposal.to_string(),
+                    e.institution.to_string(),
+                    e.proposal_id,
+                    e.proposer.to_string(),
+                    config_change_json(&e.change),
+                    e.executable_at,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::ConfigChangeApproved(e) => {
+            tx.execute(
+                "UPDATE config_proposals SET approvals = ?1, updated_at = ?2 WHERE address = ?3",
+                params![e.approvals, e.timestamp, e.proposal.to_string()],
+            )?;
+        }
+        ProgramEvent::ConfigChangeExecuted(e) => {
+            tx.execute(
+                "UPDATE config_proposals SET status = 'executed', updated_at = ?1 WHERE address = ?2",
+                params![e.timestamp, e.proposal.to_string()],
+            )?;
+            tx.execute(
+                "UPDATE institutions SET authority = ?1, treasury = ?2, credit_mint = ?3, updated_at = ?4
+                 WHERE address = ?5",
+                params![
+                    e.authority.to_string(),
+                    e.treasury.to_string(),
+                    e.credit_mint.to_string(),
+                    e.timestamp,
+                    e.institution.to_string(),
+                ],
+            )?;
+            tx.execute(
+                "UPDATE config_governance SET admins = ?1, threshold = ?2, min_delay = ?3, updated_at = ?4
+                 WHERE institution = ?5",
+                params![admins_json(&e.admins), e.threshold, e.min_delay, e.timestamp, e.institution.to_string()],
+            )?;
+        }
+        ProgramEvent::ConfigChangeCancelled(e) => {
+            tx.execute(
+                "UPDATE config_proposals SET status = 'cancelled', updated_at = ?1 WHERE address = ?2",
+                params![e.timestamp, e.proposal.to_string()],
+            )?;
+        }
+        ProgramEvent::GradeScaleUpdated(e) => {
+            tx.execute(
+                "UPDATE institutions SET grade_scale = ?1, updated_at = ?2 WHERE address = ?3",
+                params![grade_scale_json(&e.grade_scale), e.timestamp, e.institution.to_string()],
+            )?;
+        }
+        ProgramEvent::StudentProfileCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO profiles
+                 (address, student, institution, display_name, metadata_uri, total_credits_purchased, total_credits_spent,
+                  created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 0, 0, ?6)",
+                params![
+                    e.student_profile.to_string(),
+                    e.student.to_string(),
+                    e.institution.to_string(),
+                    e.display_name,
+                    e.metadata_uri,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::StudentProfileClosed(e) => {
+            tx.execute(
+                "UPDATE profiles SET closed_at = ?1 WHERE address = ?2",
+                params![e.timestamp, e.student_profile.to_string()],
+            )?;
+        }
+        ProgramEvent::CreditsPurchased(e) => {
+            tx.execute(
+                "UPDATE profiles SET total_credits_purchased = ?1 WHERE student = ?2 AND institution = ?3",
+                params![e.total_credits_purchased, e.student.to_string(), e.institution.to_string()],
+            )?;
+            tx.execute(
+                "INSERT OR REPLACE INTO purchases
+                 (signature, event_index, student, institution, amount, total_cost, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    index,
+                    e.student.to_string(),
+                    e.institution.to_string(),
+                    e.amount,
+                    e.total_cost,
+                    e.timestamp,
+                ],
+            )?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "credit_purchase",
+                    student: e.student.to_string(),
+                    institution: Some(e.institution.to_string()),
+                    course_id: None,
+                    amount: e.amount,
+                    details: serde_json::json!({
+                        "totalCost": e.total_cost,
+                        "creditsReceived": e.amount,
+                        "totalCreditsPurchased": e.total_credits_purchased,
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::CourseCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO courses
+                 (address, institution, course_id, name, instructor, required_credits, credit_hours, max_attempts,
+                  is_active, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 1, ?9, ?9)",
+                params![
+                    e.course.to_string(),
+                    e.institution.to_string(),
+                    e.course_id,
+                    e.course_name,
+                    e.instructor.to_string(),
+                    e.required_credits,
+                    e.credit_hours,
+                    e.max_attempts,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::CourseUpdated(e) => {
+            tx.execute(
+                "UPDATE courses SET name = ?1, instructor = ?2, required_credits = ?3, credit_hours = ?4,
+                 max_attempts = ?5, is_active = ?6, updated_at = ?7 WHERE address = ?8",
+                params![
+                    e.course_name,
+                    e.instructor.to_string(),
+                    e.required_credits,
+                    e.credit_hours,
+                    e.max_attempts,
+                    e.is_active,
+                    e.timestamp,
+                    e.course.to_string(),
+                ],
+            )?;
+        }
+        ProgramEvent::TermCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO terms
+                 (address, institution, term_id, name, starts_at, ends_at, registration_opens_at, registration_closes_at,
+                  created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    e.term.to_string(),
+                    e.institution.to_string(),
+                    e.term_id,
+                    e.name,
+                    e.starts_at,
+                    e.ends_at,
+                    e.registration_opens_at,
+                    e.registration_closes_at,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::OfferingCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO offerings
+                 (address, institution, course_id, term_id, instructor, capacity, enrollment_count,
+                  enrollment_opens_at, enrollment_closes_at, grading_deadline, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7, ?8, ?9, ?10)",
+                params![
+                    e.offering.to_string(),
+                    e.institution.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.instructor.to_string(),
+                    e.capacity,
+                    e.enrollment_opens_at,
+                    e.enrollment_closes_at,
+                    e.grading_deadline,
+                    e.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::StudentRegistered(e) => {
+            let institution = offering_institution(tx, &e.offering.to_string())?;
+            tx.execute(
+                "INSERT OR REPLACE INTO enrollments
+                 (address, student, institution, offering, course_id, term_id, status, credits_paid, attempt, enrolled_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'enrolled', ?7, 1, ?8)",
+                params![
+                    e.enrollment.to_string(),
+                    e.student.to_string(),
+                    institution,
+                    e.offering.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.credits_paid,
+                    e.timestamp,
+                ],
+            )?;
+            tx.execute(
+                "UPDATE offerings SET enrollment_count = enrollment_count + 1 WHERE address = ?1",
+                params![e.offering.to_string()],
+            )?;
+            add_credits_spent(tx, &e.student.to_string(), institution.as_deref(), e.credits_paid)?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "course_registration",
+                    student: e.student.to_string(),
+                    institution,
+                    course_id: Some(&e.course_id),
+                    amount: e.credits_paid,
+                    details: serde_json::json!({
+                        "courseId": e.course_id,
+                        "termId": e.term_id,
+                        "creditsBurned": e.credits_paid,
+                        "attempt": 1,
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::CourseRetaken(e) => {
+            tx.execute(
+                "UPDATE enrollments SET status = 'enrolled', attempt = ?1, credits_paid = credits_paid + ?2,
+                 grade = NULL, letter_grade = NULL, grade_points = NULL, credit_hours = NULL, completed_at = NULL
+                 WHERE address = ?3",
+                params![e.attempt, e.credits_paid, e.enrollment.to_string()],
+            )?;
+            let institution = offering_institution(tx, &e.offering.to_string())?;
+            add_credits_spent(tx, &e.student.to_string(), institution.as_deref(), e.credits_paid)?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "course_registration",
+                    student: e.student.to_string(),
+                    institution,
+                    course_id: Some(&e.course_id),
+                    amount: e.credits_paid,
+                    details: serde_json::json!({
+                        "courseId": e.course_id,
+                        "termId": e.term_id,
+                        "creditsBurned": e.credits_paid,
+                        "attempt": e.attempt,
+                        "previousGrade": e.previous_grade,
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::CourseCompleted(e) => {
+            tx.execute(
+                "UPDATE enrollments SET status = 'completed', grade = ?1, letter_grade = ?2, grade_points = ?3,
+                 credit_hours = ?4, completed_at = ?5 WHERE address = ?6",
+                params![
+                    e.grade,
+                    e.letter_grade,
+                    e.grade_points,
+                    e.credit_hours,
+                    e.timestamp,
+                    e.enrollment.to_string(),
+                ],
+            )?;
+        }
+        ProgramEvent::GradeAmended(e) => {
+            tx.execute(
+                "UPDATE enrollments SET grade = ?1, letter_grade = ?2, grade_points = ?3, credit_hours = ?4
+                 WHERE address = ?5",
+                params![
+                    e.grade,
+                    e.letter_grade,
+                    e.grade_points,
+                    e.credit_hours,
+                    e.enrollment.to_string(),
+                ],
+            )?;
+        }
+        ProgramEvent::EnrollmentExpired(e) => {
+            tx.execute(
+                "UPDATE enrollments SET status = 'incomplete' WHERE address = ?1",
+                params![e.enrollment.to_string()],
+            )?;
+        }
+        ProgramEvent::CertificateMinted(e) => {
+            tx.execute(
+                "UPDATE enrollments SET certificate_mint = ?1 WHERE address = ?2",
+                params![e.certificate_mint.to_string(), e.enrollment.to_string()],
+            )?;
+            let institution = enrollment_institution(tx, &e.enrollment.to_string())?;
+            tx.execute(
+                "INSERT OR REPLACE INTO certificates
+                 (mint, kind, student, institution, enrollment, course_id, term_id, grade, metadata_uri, metadata_hash,
+                  issued_at, signature)
+                 VALUES (?1, 'course', ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    e.certificate_mint.to_string(),
+                    e.student.to_string(),
+                    institution,
+                    e.enrollment.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.grade,
+                    e.metadata_uri,
+                    hex(&e.metadata_hash),
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "certificate_mint",
+                    student: e.student.to_string(),
+                    institution,
+                    course_id: Some(&e.course_id),
+                    amount: 1,
+                    details: serde_json::json!({
+                        "courseId": e.course_id,
+                        "termId": e.term_id,
+                        "grade": e.grade,
+                        "certificateId": e.certificate_mint.to_string(),
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::GraduationClaimed(e) => {
+            tx.execute(
+                "UPDATE profiles SET graduation_mint = ?1 WHERE student = ?2 AND institution = ?3",
+                params![e.graduation_mint.to_string(), e.student.to_string(), e.institution.to_string()],
+            )?;
+            tx.execute(
+                "INSERT OR REPLACE INTO certificates (mint, kind, student, institution, gpa, issued_at, signature)
+                 VALUES (?1, 'graduation', ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    e.graduation_mint.to_string(),
+                    e.student.to_string(),
+                    e.institution.to_string(),
+                    e.gpa,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "certificate_mint",
+                    student: e.student.to_string(),
+                    institution: Some(e.institution.to_string()),
+                    course_id: None,
+                    amount: 1,
+                    details: serde_json::json!({
+                        "gpa": e.gpa,
+                        "certificateId": e.graduation_mint.to_string(),
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::EnrollmentClosed(e) => {
+            // The closing snapshot is authoritative for the final record
+            tx.execute(
+                "UPDATE enrollments SET status = ?1, grade = ?2, letter_grade = ?3, grade_points = ?4,
+                 credit_hours = ?5, credits_paid = ?6, completed_at = ?7, certificate_mint = ?8,
+                 attempt = ?9, closed_at = ?10 WHERE address = ?11",
+                params![
+                    status_name(&e.status),
+                    e.grade,
+                    e.letter_grade,
+                    e.grade_points,
+                    e.credit_hours,
+                    e.credits_paid,
+                    e.completion_date,
+                    e.certificate_mint.map(|mint| mint.to_string()),
+                    e.attempts,
+                    e.timestamp,
+                    e.enrollment.to_string(),
+                ],
+            )?;
+        }
+        ProgramEvent::TranscriptAnchored(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO transcripts
+                 (anchor, student, institution, issuer, merkle_root, entry_count, issued_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    e.transcript_anchor.to_string(),
+                    e.student.to_string(),
+                    e.institution.to_string(),
+                    e.issuer.to_string(),
+                    hex(&e.merkle_root),
+                    e.entry_count,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::CertificateBatchCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO certificate_batches
+                 (address, offering, course_id, term_id, issuer, merkle_root, certificate_count, claimed_count, created_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, ?8, ?9)",
+                params![
+                    e.certificate_batch.to_string(),
+                    e.offering.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.issuer.to_string(),
+                    hex(&e.merkle_root),
+                    e.certificate_count,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::BatchCertificateClaimed(e) => {
+            tx.execute(
+                "UPDATE certificate_batches SET claimed_count = claimed_count + 1 WHERE address = ?1",
+                params![e.certificate_batch.to_string()],
+            )?;
+            tx.execute(
+                "INSERT OR REPLACE INTO batch_claims
+                 (enrollment, certificate_batch, student, course_id, term_id, grade, claimed_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    e.enrollment.to_string(),
+                    e.certificate_batch.to_string(),
+                    e.student.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.grade,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::CertificateTreeCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO certificate_trees
+                 (merkle_tree, certificate_tree, institution, authority, max_depth, max_buffer_size, minted, created_at,
+                  signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0, ?7, ?8)",
+                params![
+                    e.merkle_tree.to_string(),
+                    e.certificate_tree.to_string(),
+                    e.institution.to_string(),
+                    e.authority.to_string(),
+                    e.max_depth,
+                    e.max_buffer_size,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::CompressedCertificateMinted(e) => {
+            tx.execute(
+                "UPDATE certificate_trees SET minted = minted + 1 WHERE merkle_tree = ?1",
+                params![e.merkle_tree.to_string()],
+            )?;
+            tx.execute(
+                "INSERT OR REPLACE INTO compressed_certificates
+                 (merkle_tree, leaf_index, leaf_hash, enrollment, student, course_id, term_id, grade,
+                  metadata_uri, metadata_hash, minted_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                params![
+                    e.merkle_tree.to_string(),
+                    e.leaf_index,
+                    hex(&e.leaf_hash),
+                    e.enrollment.to_string(),
+                    e.student.to_string(),
+                    e.course_id,
+                    e.term_id,
+                    e.grade,
+                    e.metadata_uri,
+                    hex(&e.metadata_hash),
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+            record_activity(
+                tx,
+                signature,
+                index,
+                Activity {
+                    kind: "certificate_mint",
+                    student: e.student.to_string(),
+                    institution: enrollment_institution(tx, &e.enrollment.to_string())?,
+                    course_id: Some(&e.course_id),
+                    amount: 1,
+                    details: serde_json::json!({
+                        "courseId": e.course_id,
+                        "termId": e.term_id,
+                        "grade": e.grade,
+                        "merkleTree": e.merkle_tree.to_string(),
+                        "leafIndex": e.leaf_index,
+                    }),
+                    timestamp: e.timestamp,
+                },
+            )?;
+        }
+        ProgramEvent::CourseEquivalencyCreated(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO course_equivalencies
+                 (address, institution, course_id, source_institution, source_course_id, created_at, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    e.course_equivalency.to_string(),
+                    e.institution.to_string(),
+                    e.course_id,
+                    e.source_institution.to_string(),
+                    e.source_course_id,
+                    e.timestamp,
+                    signature,
+                ],
+            )?;
+        }
+        ProgramEvent::CompletionTransferred(e) => {
+            tx.execute(
+                "INSERT OR REPLACE INTO enrollments
+                 (address, student, institution, offering, course_id, term_id, status, grade, letter_grade,
+                  grade_points, credit_hours, credits_paid, attempt, enrolled_at, completed_at, transferred_from)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'completed', ?7, ?8, ?9, ?10, 0, 1, ?11, ?12, ?13)",
+                params![
+                    e.enrollment.to_string(),
+                    e.student.to_string(),
+                    e.institution.to_string(),
+                    e.course_equivalency.to_string(),
+                    e.course_id,
+                    e.source_term_id,
+                    e.grade,
+                    e.letter_grade,
+                    e.grade_points,
+                    e.credit_hours,
+                    e.timestamp,
+                    e.completion_date,
+                    e.source_enrollment.to_string(),
+                ],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Institution of an offering; `None` if the log never created it
+fn offering_institution(tx: &Transaction, offering: &str) -> rusqlite::Result<Option<String>> {
+    tx.query_row(
+        "SELECT institution FROM offerings WHERE address = ?1",
+        params![offering],
+        |row| row.get(0),
+    )
+    .optional()
+}
+
+/// Institution of an enrollment, copied from its offering at registration
+fn enrollment_institution(tx: &Transaction, enrollment: &str) -> rusqlite::Result<Option<String>> {
+    Ok(tx
+        .query_row(
+            "SELECT institution FROM enrollments WHERE address = ?1",
+            params![enrollment],
+            |row| row.get(0),
+        )
+        .optional()?
+        .flatten())
+}
+
+fn add_credits_spent(tx: &Transaction, student: &str, institution: Option<&str>, credits: u64) -> rusqlite::Result<()> {
+    tx.execute(
+        "UPDATE profiles SET total_credits_spent = total_credits_spent + ?1 WHERE student = ?2 AND institution = ?3",
+        params![credits, student, institution],
+    )?;
+    Ok(())
+}
+
+/// A row of the `activity` feed
+struct Activity<'a> {
+    kind: &'static str,
+    student: String,
+    institution: Option<String>,
+    course_id: Option<&'a str>,
+    amount: u64,
+    details: serde_json::Value,
+    timestamp: i64,
+}
+
+fn record_activity(tx: &Transaction, signature: &str, index: usize, activity: Activity) -> rusqlite::Result<()> {
+    tx.execute(
+        "INSERT OR REPLACE INTO activity
+         (signature, event_index, kind, student, institution, course_id, amount, details, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            signature,
+            index,
+            activity.kind,
+            activity.student,
+            activity.institution,
+            activity.course_id,
+            activity.amount,
+            activity.details.to_string(),
+            activity.timestamp,
+        ],
+    )?;
+    Ok(())
+}
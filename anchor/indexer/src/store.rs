@@ -0,0 +1,467 @@
+//! SQLite storage: the raw event log plus projections derived from it.
+//!
+//! `transactions` and `events` are the source of truth. Every other table is
+//! a projection rebuilt by folding events in order, which is what makes
+//! rollbacks simple: drop the rolled back transactions and replay the log.
+
+use std::path::Path;
+
+use academic_chain::constants::PASSING_GRADE;
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::events::ProgramEvent;
+use crate::parse::IndexedTransaction;
+use crate::projection;
+
+const SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS transactions (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    signature TEXT NOT NULL UNIQUE,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    success INTEGER NOT NULL,
+    fee INTEGER NOT NULL,
+    signer TEXT NOT NULL,
+    instructions TEXT NOT NULL,
+    finalized INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE IF NOT EXISTS events (
+    signature TEXT NOT NULL REFERENCES transactions (signature) ON DELETE CASCADE,
+    event_index INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    data BLOB NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+CREATE TABLE IF NOT EXISTS institutions (
+    address TEXT PRIMARY KEY,
+    institution_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    treasury TEXT NOT NULL,
+    credit_mint TEXT NOT NULL,
+    credit_price INTEGER NOT NULL,
+    grade_scale TEXT,
+    updated_at INTEGER NOT NULL
+);
+
+-- Course and term ids are only unique within their institution
+CREATE TABLE IF NOT EXISTS courses (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    instructor TEXT NOT NULL,
+    required_credits INTEGER NOT NULL,
+    credit_hours INTEGER NOT NULL,
+    max_attempts INTEGER NOT NULL,
+    is_active INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    UNIQUE (institution, course_id)
+);
+
+CREATE TABLE IF NOT EXISTS terms (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    starts_at INTEGER NOT NULL,
+    ends_at INTEGER NOT NULL,
+    registration_opens_at INTEGER NOT NULL,
+    registration_closes_at INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    UNIQUE (institution, term_id)
+);
+
+CREATE TABLE IF NOT EXISTS offerings (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    instructor TEXT NOT NULL,
+    capacity INTEGER NOT NULL,
+    enrollment_count INTEGER NOT NULL,
+    enrollment_opens_at INTEGER NOT NULL,
+    enrollment_closes_at INTEGER NOT NULL,
+    grading_deadline INTEGER NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+-- One profile per student and institution
+CREATE TABLE IF NOT EXISTS profiles (
+    address TEXT PRIMARY KEY,
+    student TEXT NOT NULL,
+    institution TEXT NOT NULL,
+    display_name TEXT,
+    metadata_uri TEXT,
+    total_credits_purchased INTEGER NOT NULL,
+    total_credits_spent INTEGER NOT NULL,
+    graduation_mint TEXT,
+    created_at INTEGER NOT NULL,
+    closed_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS profiles_student ON profiles (student);
+
+-- `institution` is copied from the offering. Completions transferred from
+-- another institution have the course equivalency as `offering`, the source
+-- enrollment in `transferred_from` and the source term as `term_id`.
+CREATE TABLE IF NOT EXISTS enrollments (
+    address TEXT PRIMARY KEY,
+    student TEXT NOT NULL,
+    institution TEXT,
+    offering TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    grade INTEGER,
+    letter_grade TEXT,
+    grade_points INTEGER,
+    credit_hours INTEGER,
+    credits_paid INTEGER NOT NULL,
+    attempt INTEGER NOT NULL,
+    enrolled_at INTEGER NOT NULL,
+    completed_at INTEGER,
+    certificate_mint TEXT,
+    transferred_from TEXT,
+    closed_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS enrollments_student ON enrollments (student);
+CREATE INDEX IF NOT EXISTS enrollments_course ON enrollments (course_id);
+
+CREATE TABLE IF NOT EXISTS certificates (
+    mint TEXT PRIMARY KEY,
+    kind TEXT NOT NULL,
+    student TEXT NOT NULL,
+    institution TEXT,
+    enrollment TEXT,
+    course_id TEXT,
+    term_id TEXT,
+    grade INTEGER,
+    gpa INTEGER,
+    metadata_uri TEXT,
+    metadata_hash TEXT,
+    issued_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS certificates_student ON certificates (student);
+
+CREATE TABLE IF NOT EXISTS transcripts (
+    anchor TEXT PRIMARY KEY,
+    student TEXT NOT NULL,
+    institution TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    merkle_root TEXT NOT NULL,
+    entry_count INTEGER NOT NULL,
+    issued_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS transcripts_student ON transcripts (student);
+
+CREATE TABLE IF NOT EXISTS certificate_batches (
+    address TEXT PRIMARY KEY,
+    offering TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    merkle_root TEXT NOT NULL,
+    certificate_count INTEGER NOT NULL,
+    claimed_count INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+
+-- Enrollments linked to a batch by `claim_batch_certificate`
+CREATE TABLE IF NOT EXISTS batch_claims (
+    enrollment TEXT PRIMARY KEY,
+    certificate_batch TEXT NOT NULL,
+    student TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    grade INTEGER NOT NULL,
+    claimed_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS batch_claims_student ON batch_claims (student);
+
+CREATE TABLE IF NOT EXISTS certificate_trees (
+    merkle_tree TEXT PRIMARY KEY,
+    certificate_tree TEXT NOT NULL,
+    institution TEXT NOT NULL,
+    authority TEXT NOT NULL,
+    max_depth INTEGER NOT NULL,
+    max_buffer_size INTEGER NOT NULL,
+    minted INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+
+-- Roles currently held; revoking deletes the row
+CREATE TABLE IF NOT EXISTS role_assignments (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    holder TEXT NOT NULL,
+    role TEXT NOT NULL,
+    granted_by TEXT NOT NULL,
+    granted_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS role_assignments_holder ON role_assignments (holder);
+
+-- Admins, as a JSON array, who approve config proposals of an institution
+CREATE TABLE IF NOT EXISTS config_governance (
+    institution TEXT PRIMARY KEY,
+    address TEXT NOT NULL,
+    admins TEXT NOT NULL,
+    threshold INTEGER NOT NULL,
+    min_delay INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+-- Every config proposal; `status` is pending, executed or cancelled
+CREATE TABLE IF NOT EXISTS config_proposals (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    proposal_id INTEGER NOT NULL,
+    proposer TEXT NOT NULL,
+    change TEXT NOT NULL,
+    approvals INTEGER NOT NULL,
+    executable_at INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS config_proposals_institution ON config_proposals (institution);
+
+CREATE TABLE IF NOT EXISTS course_equivalencies (
+    address TEXT PRIMARY KEY,
+    institution TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    source_institution TEXT NOT NULL,
+    source_course_id TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+
+-- Every leaf appended by `mint_compressed_certificate`. Proofs need all the
+-- leaves of a tree, and these outlive closed enrollments.
+CREATE TABLE IF NOT EXISTS compressed_certificates (
+    merkle_tree TEXT NOT NULL,
+    leaf_index INTEGER NOT NULL,
+    leaf_hash TEXT NOT NULL,
+    enrollment TEXT NOT NULL,
+    student TEXT NOT NULL,
+    course_id TEXT NOT NULL,
+    term_id TEXT NOT NULL,
+    grade INTEGER NOT NULL,
+    metadata_uri TEXT NOT NULL,
+    metadata_hash TEXT NOT NULL,
+    minted_at INTEGER NOT NULL,
+    signature TEXT NOT NULL,
+    PRIMARY KEY (merkle_tree, leaf_index)
+);
+CREATE INDEX IF NOT EXISTS compressed_certificates_student ON compressed_certificates (student);
+
+CREATE TABLE IF NOT EXISTS purchases (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    student TEXT NOT NULL,
+    institution TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    total_cost INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+
+-- Student-facing feed of purchases, registrations and certificate mints
+CREATE TABLE IF NOT EXISTS activity (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    student TEXT NOT NULL,
+    institution TEXT,
+    course_id TEXT,
+    amount INTEGER NOT NULL,
+    details TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS activity_student ON activity (student);
+
+-- Academic totals mirror the on-chain profile: completed enrollments count,
+-- passing ones earn credit hours, and GPA is x100 rounded to nearest.
+-- Enrollments from before a profile was closed and recreated are excluded.
+CREATE VIEW IF NOT EXISTS student_stats AS
+SELECT
+    p.student,
+    p.institution,
+    COALESCE(SUM(e.status = 'completed'), 0) AS courses_completed,
+    COALESCE(SUM(CASE WHEN e.status = 'completed' THEN e.credit_hours END), 0) AS credit_hours_attempted,
+    COALESCE(SUM(CASE WHEN e.status = 'completed' AND e.grade >= {passing_grade} THEN e.credit_hours END), 0) AS credit_hours_earned,
+    COALESCE(
+        (SUM(CASE WHEN e.status = 'completed' THEN e.grade_points * e.credit_hours END)
+            + SUM(CASE WHEN e.status = 'completed' THEN e.credit_hours END) / 2)
+        / NULLIF(SUM(CASE WHEN e.status = 'completed' THEN e.credit_hours END), 0),
+        0
+    ) AS gpa
+FROM profiles p
+LEFT JOIN enrollments e
+    ON e.student = p.student AND e.institution = p.institution AND e.enrolled_at >= p.created_at
+GROUP BY p.address;
+"#;
+
+/// Projection tables, cleared before a replay
+const PROJECTIONS: &[&str] = &[
+    "institutions",
+    "courses",
+    "terms",
+    "offerings",
+    "profiles",
+    "enrollments",
+    "certificates",
+    "transcripts",
+    "certificate_batches",
+    "batch_claims",
+    "certificate_trees",
+    "compressed_certificates",
+    "course_equivalencies",
+    "role_assignments",
+    "config_governance",
+    "config_proposals",
+    "purchases",
+    "activity",
+];
+
+/// The newest stored transaction; indexing resumes after it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub signature: String,
+    pub slot: u64,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        conn.pragma_update(None, "journal_mode", "wal")?;
+        conn.execute_batch(&SCHEMA.replace("{passing_grade}", &PASSING_GRADE.to_string()))?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    pub fn checkpoint(&self) -> rusqlite::Result<Option<Checkpoint>> {
+        self.conn
+            .query_row(
+                "SELECT signature, slot FROM transactions ORDER BY seq DESC LIMIT 1",
+                [],
+                |row| {
+                    Ok(Checkpoint {
+                        signature: row.get(0)?,
+                        slot: row.get(1)?,
+                    })
+                },
+            )
+            .optional()
+    }
+
+    /// Store a transaction and apply its events in one SQLite transaction,
+    /// so the checkpoint never runs ahead of the projections
+    pub fn record(&mut self, transaction: &IndexedTransaction) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO transactions (signature, slot, block_time, success, fee, signer, instructions, finalized)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                transaction.signature,
+                transaction.slot,
+                transaction.block_time,
+                transaction.success,
+                transaction.fee,
+                transaction.signer,
+                transaction.instructions.join(","),
+                transaction.finalized,
+            ],
+        )?;
+
+        for (index, raw) in transaction.events.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO events (signature, event_index, name, data) VALUES (?1, ?2, ?3, ?4)",
+                params![transaction.signature, index, raw.name, raw.data],
+            )?;
+            if let Some(event) = ProgramEvent::decode(&raw.data) {
+                projection::apply(&tx, &transaction.signature, index, &event)?;
+            }
+        }
+
+        tx.commit()
+    }
+
+    /// Confirmed but not yet finalized transactions, oldest first
+    pub fn unfinalized(&self) -> rusqlite::Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT signature FROM transactions WHERE finalized = 0 ORDER BY seq")?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        rows.collect()
+    }
+
+    pub fn mark_finalized(&mut self, signatures: &[String]) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+        for signature in signatures {
+            tx.execute(
+                "UPDATE transactions SET finalized = 1 WHERE signature = ?1",
+                params![signature],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// Forget transactions dropped with their fork and rebuild every
+    /// projection from the remaining events
+    pub fn rollback(&mut self, signatures: &[String]) -> rusqlite::Result<()> {
+        let tx = self.conn.transaction()?;
+
+        for signature in signatures {
+            tx.execute("DELETE FROM transactions WHERE signature = ?1", params![signature])?;
+        }
+        for table in PROJECTIONS {
+            tx.execute(&format!("DELETE FROM {table}"), [])?;
+        }
+
+        {
+            let mut stmt = tx.prepare(
+                "SELECT e.signature, e.event_index, e.data FROM events e
+                 JOIN transactions t ON t.signature = e.signature
+                 ORDER BY t.seq, e.event_index",
+            )?;
+            let mut rows = stmt.query([])?;
+            while let Some(row) = rows.next()? {
+                let signature: String = row.get(0)?;
+                let index: usize = row.get(1)?;
+                let data: Vec<u8> = row.get(2)?;
+                if let Some(event) = ProgramEvent::decode(&data) {
+                    projection::apply(&tx, &signature, index, &event)?;
+                }
+            }
+        }
+
+        tx.commit()
+    }
+}
@@ -0,0 +1,110 @@
+use academic_chain::events::StudentRegistered;
+use academic_chain::ID;
+use academic_indexer::events::ProgramEvent;
+use academic_indexer::parse_transaction;
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{Event, InstructionData};
+use base64::Engine;
+use solana_message::Message;
+use solana_transaction::Transaction;
+use solana_transaction_status_client_types::EncodedConfirmedTransactionWithStatusMeta;
+
+fn registered(student: Pubkey) -> StudentRegistered {
+    StudentRegistered {
+        student,
+        enrollment: Pubkey::new_unique(),
+        offering: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        credits_paid: 5,
+        timestamp: 1_760_000_000,
+    }
+}
+
+/// An RPC `getTransaction` response (base64 encoding) for a register_course
+/// call whose event self-CPI is listed in the inner instructions
+fn rpc_response(student: Pubkey, event: &StudentRegistered, err: Option<&str>) -> EncodedConfirmedTransactionWithStatusMeta {
+    let ix = Instruction {
+        program_id: ID,
+        accounts: vec![AccountMeta::new(student, true)],
+        data: academic_chain::instruction::RegisterCourse {
+            course_id: "SOL101".to_string(),
+            term_id: "2025FA".to_string(),
+        }
+        .data(),
+    };
+    let message = Message::new(&[ix], Some(&student));
+    let program_index = message.account_keys.iter().position(|key| *key == ID).unwrap();
+    let transaction = Transaction::new_unsigned(message);
+    let encoded = base64::engine::general_purpose::STANDARD.encode(bincode::serialize(&transaction).unwrap());
+
+    let event_data = [EVENT_IX_TAG_LE, &event.data()].concat();
+    let (err, status) = match err {
+        Some(err) => (serde_json::json!(err), serde_json::json!({ "Err": err })),
+        None => (serde_json::Value::Null, serde_json::json!({ "Ok": null })),
+    };
+
+    serde_json::from_value(serde_json::json!({
+        "slot": 42,
+        "blockTime": 1_760_000_000,
+        "version": "legacy",
+        "transaction": [encoded, "base64"],
+        "meta": {
+            "err": err,
+            "status": status,
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "innerInstructions": [{
+                "index": 0,
+                "instructions": [{
+                    "programIdIndex": program_index,
+                    "accounts": [],
+                    "data": bs58::encode(event_data).into_string(),
+                    "stackHeight": 2
+                }]
+            }],
+            "logMessages": [],
+            "preTokenBalances": [],
+            "postTokenBalances": [],
+            "rewards": [],
+            "loadedAddresses": { "writable": [], "readonly": [] }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn decodes_instructions_and_cpi_events() {
+    let student = Pubkey::new_unique();
+    let event = registered(student);
+    let parsed = parse_transaction("sig", &rpc_response(student, &event, None)).unwrap();
+
+    assert!(parsed.success);
+    assert_eq!(parsed.slot, 42);
+    assert_eq!(parsed.fee, 5000);
+    assert_eq!(parsed.signer, student.to_string());
+    assert_eq!(parsed.instructions, vec!["register_course"]);
+    assert_eq!(parsed.events.len(), 1);
+    assert_eq!(parsed.events[0].name, "StudentRegistered");
+
+    match ProgramEvent::decode(&parsed.events[0].data) {
+        Some(ProgramEvent::StudentRegistered(decoded)) => {
+            assert_eq!(decoded.enrollment, event.enrollment);
+            assert_eq!(decoded.credits_paid, 5);
+        }
+        _ => panic!("expected StudentRegistered"),
+    }
+}
+
+#[test]
+fn failed_transactions_carry_no_events() {
+    let student = Pubkey::new_unique();
+    let parsed = parse_transaction("sig", &rpc_response(student, &registered(student), Some("AccountInUse"))).unwrap();
+
+    assert!(!parsed.success);
+    assert_eq!(parsed.instructions, vec!["register_course"]);
+    assert!(parsed.events.is_empty());
+}
@@ -0,0 +1,663 @@
+use academic_chain::events::*;
+use academic_chain::state::{ConfigChange, Role};
+use academic_indexer::{Checkpoint, IndexedTransaction, RawEvent, Store};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Event;
+use rusqlite::params;
+
+const NOW: i64 = 1_760_000_000;
+
+struct Fixture {
+    institution: Pubkey,
+    student: Pubkey,
+    offering: Pubkey,
+    enrollment: Pubkey,
+    instructor: Pubkey,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            institution: Pubkey::new_unique(),
+            student: Pubkey::new_unique(),
+            offering: Pubkey::new_unique(),
+            enrollment: Pubkey::new_unique(),
+            instructor: Pubkey::new_unique(),
+        }
+    }
+
+    fn registered(&self) -> StudentRegistered {
+        StudentRegistered {
+            student: self.student,
+            enrollment: self.enrollment,
+            offering: self.offering,
+            course_id: "SOL101".to_string(),
+            term_id: "2025FA".to_string(),
+            credits_paid: 5,
+            timestamp: NOW + 10,
+        }
+    }
+
+    fn completed(&self, grade: u8, letter: &str, grade_points: u16) -> CourseCompleted {
+        CourseCompleted {
+            student: self.student,
+            enrollment: self.enrollment,
+            offering: self.offering,
+            instructor: self.instructor,
+            course_id: "SOL101".to_string(),
+            term_id: "2025FA".to_string(),
+            grade,
+            letter_grade: letter.to_string(),
+            grade_points,
+            credit_hours: 3,
+            gpa: grade_points,
+            timestamp: NOW + 20,
+        }
+    }
+}
+
+fn event<E: Event>(name: &'static str, event: E) -> RawEvent {
+    RawEvent { name, data: event.data() }
+}
+
+fn transaction(signature: &str, slot: u64, events: Vec<RawEvent>) -> IndexedTransaction {
+    IndexedTransaction {
+        signature: signature.to_string(),
+        slot,
+        block_time: Some(NOW),
+        success: true,
+        fee: 5000,
+        signer: Pubkey::new_unique().to_string(),
+        instructions: vec![],
+        events,
+        finalized: false,
+    }
+}
+
+/// Institution, catalog and a student with 10 purchased credits
+fn seeded(fixture: &Fixture) -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    let authority = Pubkey::new_unique();
+
+    store
+        .record(&transaction(
+            "setup",
+            1,
+            vec![
+                event(
+                    "InstitutionCreated",
+                    InstitutionCreated {
+                        institution: fixture.institution,
+                        institution_id: "EXU".to_string(),
+                        name: "Example University".to_string(),
+                        authority,
+                        treasury: authority,
+                        credit_mint: Pubkey::new_unique(),
+                        credit_price: 1_000_000,
+                        timestamp: NOW,
+                    },
+                ),
+                event(
+                    "CourseCreated",
+                    CourseCreated {
+                        institution: fixture.institution,
+                        course: Pubkey::new_unique(),
+                        course_id: "SOL101".to_string(),
+                        course_name: "Solana Fundamentals".to_string(),
+                        instructor: fixture.instructor,
+                        required_credits: 5,
+                        credit_hours: 3,
+                        max_attempts: 3,
+                        timestamp: NOW,
+                    },
+                ),
+                event(
+                    "OfferingCreated",
+                    OfferingCreated {
+                        institution: fixture.institution,
+                        offering: fixture.offering,
+                        course_id: "SOL101".to_string(),
+                        term_id: "2025FA".to_string(),
+                        instructor: fixture.instructor,
+                        capacity: 30,
+                        enrollment_opens_at: NOW,
+                        enrollment_closes_at: NOW + 100,
+                        grading_deadline: NOW + 1000,
+                        timestamp: NOW,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+    store
+        .record(&transaction(
+            "onboard",
+            2,
+            vec![
+                event(
+                    "StudentProfileCreated",
+                    StudentProfileCreated {
+                        institution: fixture.institution,
+                        student: fixture.student,
+                        student_profile: Pubkey::new_unique(),
+                        display_name: Some("Alice".to_string()),
+                        metadata_uri: None,
+                        timestamp: NOW,
+                    },
+                ),
+                event(
+                    "CreditsPurchased",
+                    CreditsPurchased {
+                        institution: fixture.institution,
+                        student: fixture.student,
+                        amount: 10,
+                        total_cost: 10_000_000,
+                        total_credits_purchased: 10,
+                        timestamp: NOW,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+    store
+}
+
+fn query<T: rusqlite::types::FromSql>(store: &Store, sql: &str) -> T {
+    store.connection().query_row(sql, [], |row| row.get(0)).unwrap()
+}
+
+#[test]
+fn events_are_projected() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    store.record(&transaction("register", 3, vec![event("StudentRegistered", fixture.registered())])).unwrap();
+    store.record(&transaction("grade", 4, vec![event("CourseCompleted", fixture.completed(85, "B", 300))])).unwrap();
+
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM courses"), 1);
+    assert_eq!(query::<i64>(&store, "SELECT enrollment_count FROM offerings"), 1);
+    assert_eq!(query::<String>(&store, "SELECT status FROM enrollments"), "completed");
+    assert_eq!(query::<String>(&store, "SELECT letter_grade FROM enrollments"), "B");
+    assert_eq!(query::<String>(&store, "SELECT institution FROM enrollments"), fixture.institution.to_string());
+    assert_eq!(query::<i64>(&store, "SELECT total_credits_purchased FROM profiles"), 10);
+    assert_eq!(query::<i64>(&store, "SELECT total_credits_spent FROM profiles"), 5);
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM purchases"), 1);
+    assert_eq!(
+        query::<String>(&store, "SELECT group_concat(kind) FROM activity ORDER BY timestamp"),
+        "credit_purchase,course_registration"
+    );
+    assert_eq!(query::<i64>(&store, "SELECT courses_completed FROM student_stats"), 1);
+    assert_eq!(query::<i64>(&store, "SELECT credit_hours_earned FROM student_stats"), 3);
+    assert_eq!(query::<i64>(&store, "SELECT gpa FROM student_stats"), 300);
+    assert_eq!(
+        store.checkpoint().unwrap(),
+        Some(Checkpoint { signature: "grade".to_string(), slot: 4 })
+    );
+}
+
+#[test]
+fn institutions_keep_separate_catalogs_and_profiles() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let other = Pubkey::new_unique();
+    let offering = Pubkey::new_unique();
+    store
+        .record(&transaction(
+            "other",
+            3,
+            vec![
+                event(
+                    "CourseCreated",
+                    CourseCreated {
+                        institution: other,
+                        course: Pubkey::new_unique(),
+                        course_id: "SOL101".to_string(),
+                        course_name: "Intro to Solana".to_string(),
+                        instructor: fixture.instructor,
+                        required_credits: 7,
+                        credit_hours: 4,
+                        max_attempts: 3,
+                        timestamp: NOW,
+                    },
+                ),
+                event(
+                    "OfferingCreated",
+                    OfferingCreated {
+                        institution: other,
+                        offering,
+                        course_id: "SOL101".to_string(),
+                        term_id: "2025FA".to_string(),
+                        instructor: fixture.instructor,
+                        capacity: 30,
+                        enrollment_opens_at: NOW,
+                        enrollment_closes_at: NOW + 100,
+                        grading_deadline: NOW + 1000,
+                        timestamp: NOW,
+                    },
+                ),
+                event(
+                    "StudentProfileCreated",
+                    StudentProfileCreated {
+                        institution: other,
+                        student: fixture.student,
+                        student_profile: Pubkey::new_unique(),
+                        display_name: None,
+                        metadata_uri: None,
+                        timestamp: NOW,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+    let registered = StudentRegistered { offering, credits_paid: 7, ..fixture.registered() };
+    store.record(&transaction("register", 4, vec![event("StudentRegistered", registered)])).unwrap();
+
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM courses WHERE course_id = 'SOL101'"), 2);
+    let spent = |institution: &Pubkey| -> i64 {
+        store
+            .connection()
+            .query_row(
+                "SELECT total_credits_spent FROM profiles WHERE institution = ?1",
+                params![institution.to_string()],
+                |row| row.get(0),
+            )
+            .unwrap()
+    };
+    assert_eq!(spent(&fixture.institution), 0);
+    assert_eq!(spent(&other), 7);
+}
+
+#[test]
+fn transferred_completions_count_at_the_receiving_institution() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    store.record(&transaction("register", 3, vec![event("StudentRegistered", fixture.registered())])).unwrap();
+    store.record(&transaction("grade", 4, vec![event("CourseCompleted", fixture.completed(85, "B", 300))])).unwrap();
+
+    let partner = Pubkey::new_unique();
+    let equivalency = Pubkey::new_unique();
+    let transferred = Pubkey::new_unique();
+    store
+        .record(&transaction(
+            "transfer",
+            5,
+            vec![
+                event(
+                    "StudentProfileCreated",
+                    StudentProfileCreated {
+                        institution: partner,
+                        student: fixture.student,
+                        student_profile: Pubkey::new_unique(),
+                        display_name: None,
+                        metadata_uri: None,
+                        timestamp: NOW + 30,
+                    },
+                ),
+                event(
+                    "CourseEquivalencyCreated",
+                    CourseEquivalencyCreated {
+                        course_equivalency: equivalency,
+                        institution: partner,
+                        course_id: "BC200".to_string(),
+                        source_institution: fixture.institution,
+                        source_course_id: "SOL101".to_string(),
+                        authority: Pubkey::new_unique(),
+                        source_authority: Pubkey::new_unique(),
+                        timestamp: NOW + 30,
+                    },
+                ),
+                event(
+                    "CompletionTransferred",
+                    CompletionTransferred {
+                        institution: partner,
+                        student: fixture.student,
+                        enrollment: transferred,
+                        course_equivalency: equivalency,
+                        course_id: "BC200".to_string(),
+                        source_enrollment: fixture.enrollment,
+                        source_course_id: "SOL101".to_string(),
+                        source_term_id: "2025FA".to_string(),
+                        grade: 85,
+                        letter_grade: "B".to_string(),
+                        grade_points: 300,
+                        credit_hours: 4,
+                        gpa: 300,
+                        completion_date: NOW + 20,
+                        timestamp: NOW + 40,
+                    },
+                ),
+            ],
+        ))
+        .unwrap();
+
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM course_equivalencies"), 1);
+    let (offering, source, credits_paid): (String, String, i64) = store
+        .connection()
+        .query_row(
+            "SELECT offering, transferred_from, credits_paid FROM enrollments WHERE address = ?1",
+            params![transferred.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(offering, equivalency.to_string());
+    assert_eq!(source, fixture.enrollment.to_string());
+    assert_eq!(credits_paid, 0);
+
+    // Both institutions count the course; the partner with its own credit hours
+    let stats = |institution: &Pubkey| -> (i64, i64) {
+        store
+            .connection()
+            .query_row(
+                "SELECT courses_completed, credit_hours_earned FROM student_stats WHERE institution = ?1",
+                params![institution.to_string()],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+    };
+    assert_eq!(stats(&fixture.institution), (1, 3));
+    assert_eq!(stats(&partner), (1, 4));
+}
+
+#[test]
+fn revoked_roles_leave_the_role_table() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let (authority, registrar, finance) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let (registrar_role, finance_role) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let granted = |role_assignment: Pubkey, holder: Pubkey, role: Role| RoleGranted {
+        institution: fixture.institution,
+        role_assignment,
+        holder,
+        role,
+        granted_by: authority,
+        timestamp: NOW + 5,
+    };
+    store
+        .record(&transaction(
+            "grant",
+            3,
+            vec![
+                event("RoleGranted", granted(registrar_role, registrar, Role::Registrar)),
+                event("RoleGranted", granted(finance_role, finance, Role::FinanceAdmin)),
+            ],
+        ))
+        .unwrap();
+    store
+        .record(&transaction(
+            "revoke",
+            4,
+            vec![event(
+                "RoleRevoked",
+                RoleRevoked {
+                    institution: fixture.institution,
+                    role_assignment: finance_role,
+                    holder: finance,
+                    role: Role::FinanceAdmin,
+                    revoked_by: authority,
+                    timestamp: NOW + 6,
+                },
+            )],
+        ))
+        .unwrap();
+
+    let (holder, role): (String, String) = store
+        .connection()
+        .query_row("SELECT holder, role FROM role_assignments", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    assert_eq!(holder, registrar.to_string());
+    assert_eq!(role, "registrar");
+
+    // Rolling back the revoke brings the role back
+    store.rollback(&["revoke".to_string()]).unwrap();
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM role_assignments"), 2);
+}
+
+#[test]
+fn executed_proposals_update_the_institution() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let (admin, treasury, proposal) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let change = ConfigChange { treasury: Some(treasury), ..Default::default() };
+    store
+        .record(&transaction(
+            "propose",
+            3,
+            vec![event(
+                "ConfigChangeProposed",
+                ConfigChangeProposed {
+                    institution: fixture.institution,
+                    proposal,
+                    proposal_id: 0,
+                    proposer: admin,
+                    change,
+                    executable_at: NOW + 86_400,
+                    timestamp: NOW + 5,
+                },
+            )],
+        ))
+        .unwrap();
+    let (status, change): (String, String) = store
+        .connection()
+        .query_row("SELECT status, change FROM config_proposals", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    assert_eq!(status, "pending");
+    assert_eq!(change, format!(r#"{{"treasury":"{treasury}"}}"#));
+
+    let institution: (String, String) = store
+        .connection()
+        .query_row("SELECT authority, credit_mint FROM institutions", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    let executed = ConfigChangeExecuted {
+        institution: fixture.institution,
+        proposal,
+        proposal_id: 0,
+        executor: admin,
+        authority: institution.0.parse().unwrap(),
+        treasury,
+        credit_mint: institution.1.parse().unwrap(),
+        admins: vec![admin],
+        threshold: 1,
+        min_delay: 86_400,
+        timestamp: NOW + 86_400,
+    };
+    store.record(&transaction("execute", 4, vec![event("ConfigChangeExecuted", executed)])).unwrap();
+
+    assert_eq!(query::<String>(&store, "SELECT status FROM config_proposals"), "executed");
+    assert_eq!(query::<String>(&store, "SELECT treasury FROM institutions"), treasury.to_string());
+}
+
+#[test]
+fn rollback_replays_surviving_events() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    store.record(&transaction("register", 3, vec![event("StudentRegistered", fixture.registered())])).unwrap();
+    store.record(&transaction("grade", 4, vec![event("CourseCompleted", fixture.completed(85, "B", 300))])).unwrap();
+
+    store.rollback(&["grade".to_string()]).unwrap();
+
+    assert_eq!(query::<String>(&store, "SELECT status FROM enrollments"), "enrolled");
+    assert_eq!(query::<i64>(&store, "SELECT gpa FROM student_stats"), 0);
+    // Replay rebuilds counters from scratch instead of applying them twice
+    assert_eq!(query::<i64>(&store, "SELECT total_credits_spent FROM profiles"), 5);
+    assert_eq!(query::<i64>(&store, "SELECT enrollment_count FROM offerings"), 1);
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM events WHERE signature = 'grade'"), 0);
+    assert_eq!(store.checkpoint().unwrap().unwrap().signature, "register");
+}
+
+#[test]
+fn retake_reverts_a_failed_completion() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    store.record(&transaction("register", 3, vec![event("StudentRegistered", fixture.registered())])).unwrap();
+    store.record(&transaction("fail", 4, vec![event("CourseCompleted", fixture.completed(40, "F", 0))])).unwrap();
+    assert_eq!(query::<i64>(&store, "SELECT courses_completed FROM student_stats"), 1);
+    assert_eq!(query::<i64>(&store, "SELECT credit_hours_earned FROM student_stats"), 0);
+
+    let retake = CourseRetaken {
+        student: fixture.student,
+        enrollment: fixture.enrollment,
+        offering: fixture.offering,
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        attempt: 2,
+        previous_grade: 40,
+        credits_paid: 5,
+        timestamp: NOW + 30,
+    };
+    store.record(&transaction("retake", 5, vec![event("CourseRetaken", retake)])).unwrap();
+
+    assert_eq!(query::<String>(&store, "SELECT status FROM enrollments"), "enrolled");
+    assert_eq!(query::<i64>(&store, "SELECT attempt FROM enrollments"), 2);
+    assert_eq!(query::<i64>(&store, "SELECT credits_paid FROM enrollments"), 10);
+    assert_eq!(query::<i64>(&store, "SELECT total_credits_spent FROM profiles"), 10);
+    assert_eq!(query::<i64>(&store, "SELECT courses_completed FROM student_stats"), 0);
+}
+
+#[test]
+fn certificates_are_recorded() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    store.record(&transaction("register", 3, vec![event("StudentRegistered", fixture.registered())])).unwrap();
+    store.record(&transaction("grade", 4, vec![event("CourseCompleted", fixture.completed(95, "A", 400))])).unwrap();
+
+    let mint = Pubkey::new_unique();
+    let minted = CertificateMinted {
+        student: fixture.student,
+        enrollment: fixture.enrollment,
+        certificate_mint: mint,
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        grade: 95,
+        metadata_uri: "https://example.com/cert.json".to_string(),
+        metadata_hash: [0xab; 32],
+        timestamp: NOW + 40,
+    };
+    store.record(&transaction("mint", 5, vec![event("CertificateMinted", minted)])).unwrap();
+
+    let (kind, signature, hash): (String, String, String) = store
+        .connection()
+        .query_row(
+            "SELECT kind, signature, metadata_hash FROM certificates WHERE mint = ?1",
+            params![mint.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(kind, "course");
+    assert_eq!(signature, "mint");
+    assert_eq!(hash, "ab".repeat(32));
+    assert_eq!(query::<String>(&store, "SELECT certificate_mint FROM enrollments"), mint.to_string());
+}
+
+#[test]
+fn transcripts_are_recorded() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let anchored = TranscriptAnchored {
+        institution: fixture.institution,
+        student: fixture.student,
+        transcript_anchor: Pubkey::new_unique(),
+        issuer: Pubkey::new_unique(),
+        merkle_root: [0xcd; 32],
+        entry_count: 4,
+        timestamp: NOW + 50,
+    };
+    store.record(&transaction("transcript", 3, vec![event("TranscriptAnchored", anchored)])).unwrap();
+
+    assert_eq!(query::<String>(&store, "SELECT merkle_root FROM transcripts"), "cd".repeat(32));
+    assert_eq!(query::<i64>(&store, "SELECT entry_count FROM transcripts"), 4);
+    assert_eq!(query::<String>(&store, "SELECT student FROM transcripts"), fixture.student.to_string());
+}
+
+#[test]
+fn batch_claims_count_against_their_batch() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let batch = Pubkey::new_unique();
+    let created = CertificateBatchCreated {
+        certificate_batch: batch,
+        offering: fixture.offering,
+        issuer: Pubkey::new_unique(),
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        merkle_root: [0xab; 32],
+        certificate_count: 40,
+        timestamp: NOW + 50,
+    };
+    let claimed = BatchCertificateClaimed {
+        student: fixture.student,
+        enrollment: fixture.enrollment,
+        certificate_batch: batch,
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        grade: 88,
+        timestamp: NOW + 60,
+    };
+    store.record(&transaction("batch", 3, vec![event("CertificateBatchCreated", created)])).unwrap();
+    store.record(&transaction("claim", 4, vec![event("BatchCertificateClaimed", claimed)])).unwrap();
+
+    assert_eq!(query::<i64>(&store, "SELECT certificate_count FROM certificate_batches"), 40);
+    assert_eq!(query::<i64>(&store, "SELECT claimed_count FROM certificate_batches"), 1);
+    assert_eq!(query::<String>(&store, "SELECT certificate_batch FROM batch_claims"), batch.to_string());
+}
+
+#[test]
+fn compressed_certificates_keep_every_leaf_of_their_tree() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    let merkle_tree = Pubkey::new_unique();
+    let created = CertificateTreeCreated {
+        institution: fixture.institution,
+        certificate_tree: Pubkey::new_unique(),
+        merkle_tree,
+        authority: Pubkey::new_unique(),
+        max_depth: 14,
+        max_buffer_size: 64,
+        timestamp: NOW + 50,
+    };
+    let minted = CompressedCertificateMinted {
+        student: fixture.student,
+        enrollment: fixture.enrollment,
+        merkle_tree,
+        leaf_index: 0,
+        leaf_hash: [0xcd; 32],
+        course_id: "SOL101".to_string(),
+        term_id: "2025FA".to_string(),
+        grade: 88,
+        metadata_uri: "ipfs://certificate".to_string(),
+        metadata_hash: [0xef; 32],
+        timestamp: NOW + 60,
+    };
+    store.record(&transaction("tree", 3, vec![event("CertificateTreeCreated", created)])).unwrap();
+    store.record(&transaction("leaf", 4, vec![event("CompressedCertificateMinted", minted)])).unwrap();
+
+    assert_eq!(query::<i64>(&store, "SELECT minted FROM certificate_trees"), 1);
+    assert_eq!(
+        query::<String>(&store, "SELECT leaf_hash FROM compressed_certificates WHERE leaf_index = 0"),
+        "cd".repeat(32)
+    );
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM activity WHERE kind = 'certificate_mint'"), 1);
+}
+
+#[test]
+fn failed_transactions_advance_the_checkpoint_only() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+
+    let mut failed = transaction("failed", 3, vec![]);
+    failed.success = false;
+    store.record(&failed).unwrap();
+
+    assert_eq!(store.checkpoint().unwrap().unwrap().signature, "failed");
+    assert_eq!(query::<i64>(&store, "SELECT COUNT(*) FROM enrollments"), 0);
+}
+
+#[test]
+fn finalized_transactions_leave_the_pending_set() {
+    let fixture = Fixture::new();
+    let mut store = seeded(&fixture);
+    assert_eq!(store.unfinalized().unwrap(), vec!["setup".to_string(), "onboard".to_string()]);
+
+    store.mark_finalized(&["setup".to_string()]).unwrap();
+    assert_eq!(store.unfinalized().unwrap(), vec!["onboard".to_string()]);
+}
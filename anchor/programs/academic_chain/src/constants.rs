@@ -1,30 +1,97 @@
 use anchor_lang::prelude::*;
 
 #[constant]
-pub const PROGRAM_CONFIG_SEED: &[u8] = b"config";
+pub const INSTITUTION_SEED: &[u8] = b"institution";
 
 #[constant]
 pub const COURSE_SEED: &[u8] = b"course";
 
+#[constant]
+pub const TERM_SEED: &[u8] = b"term";
+
+#[constant]
+pub const OFFERING_SEED: &[u8] = b"offering";
+
 #[constant]
 pub const ENROLLMENT_SEED: &[u8] = b"enrollment";
 
+#[constant]
+pub const ENROLLMENT_RECORD_SEED: &[u8] = b"enrollment_record";
+
+#[constant]
+pub const COURSE_ATTEMPTS_SEED: &[u8] = b"course_attempts";
+
 #[constant]
 pub const STUDENT_PROFILE_SEED: &[u8] = b"student_profile";
 
+#[constant]
+pub const PROFILE_TOMBSTONE_SEED: &[u8] = b"profile_tombstone";
+
 #[constant]
 pub const CERTIFICATE_MINT_SEED: &[u8] = b"certificate_mint";
 
+#[constant]
+pub const CERTIFICATE_RECORD_SEED: &[u8] = b"certificate_record";
+
 #[constant]
 pub const GRADUATION_MINT_SEED: &[u8] = b"graduation_mint";
 
+#[constant]
+pub const TRANSCRIPT_ANCHOR_SEED: &[u8] = b"transcript";
+
+#[constant]
+pub const CERTIFICATE_BATCH_SEED: &[u8] = b"certificate_batch";
+
+#[constant]
+pub const CERTIFICATE_TREE_SEED: &[u8] = b"certificate_tree";
+
+#[constant]
+pub const COURSE_EQUIVALENCY_SEED: &[u8] = b"equivalency";
+
+#[constant]
+pub const ROLE_ASSIGNMENT_SEED: &[u8] = b"role";
+
+#[constant]
+pub const CONFIG_GOVERNANCE_SEED: &[u8] = b"governance";
+
+#[constant]
+pub const CONFIG_PROPOSAL_SEED: &[u8] = b"config_proposal";
+
+// State compression programs the certificate trees live in
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey = pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+// Credit price of a new institution: 0.001 SOL per credit
+pub const DEFAULT_CREDIT_PRICE: u64 = 1_000_000;
+
 // Token metadata constants
 pub const NFT_NAME_PREFIX: &str = "AcademicChain Certificate - ";
 pub const NFT_SYMBOL: &str = "ACADNFT";
 pub const GRADUATION_NFT_NAME: &str = "AcademicChain Graduation";
 pub const GRADUATION_NFT_SYMBOL: &str = "GRADNFT";
 
+// Shortest timelock a governance can require: 1 hour, so admins have time
+// to cancel a proposal before it can be executed
+pub const MIN_CONFIG_DELAY: i64 = 60 * 60;
+
+// Longest timelock a governance can require: 30 days
+pub const MAX_CONFIG_DELAY: i64 = 30 * 24 * 60 * 60;
+
 // Business logic constants
 pub const MIN_GRADE: u8 = 0;
 pub const MAX_GRADE: u8 = 100;
 pub const PASSING_GRADE: u8 = 50;
+pub const MAX_CREDIT_HOURS: u8 = 12;
+
+// Upper bound for Course::max_attempts, which also bounds the attempts history
+pub const MAX_COURSE_ATTEMPTS: u8 = 5;
+
+// Batch grading: (enrollment, student_profile) pairs per transaction
+pub const MAX_GRADING_BATCH_SIZE: usize = 20;
+
+// Transcript entry_count and batch certificate_count are u16, so proofs
+// have at most 16 siblings
+pub const MAX_MERKLE_PROOF_LEN: usize = 16;
+
+// spl-account-compression supports trees up to depth 30
+pub const MAX_CERTIFICATE_TREE_DEPTH: u32 = 30;
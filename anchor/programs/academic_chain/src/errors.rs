@@ -35,9 +35,135 @@ pub enum AcademicChainError {
     #[msg("Invalid credits amount")]
     InvalidCredits,
     
-    #[msg("Unauthorized: Only program authority can perform this action")]
+    #[msg("Unauthorized: Signer is not the institution authority and lacks the required role")]
     Unauthorized,
     
     #[msg("Arithmetic overflow")]
     ArithmeticOverflow,
+
+    #[msg("Invalid credit hours")]
+    InvalidCreditHours,
+
+    #[msg("Invalid grade scale")]
+    InvalidGradeScale,
+
+    #[msg("Course already completed")]
+    CourseAlreadyCompleted,
+
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+
+    #[msg("Batch accounts do not match the student list")]
+    InvalidBatchAccounts,
+
+    #[msg("Invalid term ID format")]
+    InvalidTermId,
+
+    #[msg("Invalid term name")]
+    InvalidTermName,
+
+    #[msg("Invalid term dates")]
+    InvalidTermDates,
+
+    #[msg("Invalid offering capacity")]
+    InvalidCapacity,
+
+    #[msg("Course offering is full")]
+    OfferingFull,
+
+    #[msg("Invalid enrollment or grading deadlines")]
+    InvalidDeadlines,
+
+    #[msg("Enrollment has not opened yet")]
+    EnrollmentNotOpen,
+
+    #[msg("Enrollment is closed")]
+    EnrollmentClosed,
+
+    #[msg("Grading deadline has passed")]
+    GradingDeadlinePassed,
+
+    #[msg("Grading deadline has not passed yet")]
+    GradingDeadlineNotReached,
+
+    #[msg("Enrollment is not awaiting a grade")]
+    EnrollmentNotActive,
+
+    #[msg("Invalid maximum attempts")]
+    InvalidMaxAttempts,
+
+    #[msg("Only a failed course can be retaken")]
+    RetakeNotAllowed,
+
+    #[msg("Maximum attempts for this course reached")]
+    AttemptLimitReached,
+
+    #[msg("Invalid display name")]
+    InvalidDisplayName,
+
+    #[msg("Invalid metadata URI")]
+    InvalidMetadataUri,
+
+    #[msg("Enrollment is not finished")]
+    EnrollmentNotFinished,
+
+    #[msg("Student still has open enrollments")]
+    OpenEnrollmentsRemain,
+
+    #[msg("Invalid credit price")]
+    InvalidCreditPrice,
+
+    #[msg("Invalid metadata hash")]
+    InvalidMetadataHash,
+
+    #[msg("Invalid transcript Merkle root or entry count")]
+    InvalidTranscript,
+
+    #[msg("Invalid certificate batch root or count")]
+    InvalidCertificateBatch,
+
+    #[msg("Merkle proof does not match the certificate batch")]
+    InvalidMerkleProof,
+
+    #[msg("Invalid certificate tree depth or buffer size")]
+    InvalidCertificateTree,
+
+    #[msg("Certificate tree is full")]
+    CertificateTreeFull,
+
+    #[msg("Invalid institution ID")]
+    InvalidInstitutionId,
+
+    #[msg("Invalid institution name")]
+    InvalidInstitutionName,
+
+    #[msg("Account belongs to a different institution")]
+    InstitutionMismatch,
+
+    #[msg("Course equivalency must link two different institutions")]
+    SameInstitution,
+
+    #[msg("Only a passed course can be transferred")]
+    TransferNotPassed,
+
+    #[msg("Invalid governance admins, threshold or delay")]
+    InvalidGovernance,
+
+    #[msg("Signer is not a governance admin")]
+    NotGovernanceAdmin,
+
+    #[msg("Config change changes nothing")]
+    EmptyConfigChange,
+
+    #[msg("Admin already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal does not have enough approvals")]
+    ProposalNotApproved,
+
+    #[msg("Proposal delay has not elapsed")]
+    ProposalTimelocked,
+
+    #[msg("Authority, treasury and credit mint changes need a config proposal")]
+    ConfigChangeGoverned,
 }
@@ -0,0 +1,396 @@
+use anchor_lang::prelude::*;
+use crate::state::{ConfigChange, EnrollmentStatus, GradeScaleEntry, Role};
+
+// Every state-changing instruction emits one of these through `emit_cpi!`,
+// so indexers can read them from inner instructions instead of parsing logs.
+
+#[event]
+pub struct InstitutionCreated {
+    pub institution: Pubkey,
+    pub institution_id: String,
+    pub name: String,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub credit_mint: Pubkey,
+    pub credit_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GradeScaleUpdated {
+    pub institution: Pubkey,
+    pub authority: Pubkey,
+    pub grade_scale: Vec<GradeScaleEntry>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub institution: Pubkey,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub credit_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub institution: Pubkey,
+    pub role_assignment: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub institution: Pubkey,
+    pub role_assignment: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigGovernanceUpdated {
+    pub institution: Pubkey,
+    pub governance: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub threshold: u8,
+    pub min_delay: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeProposed {
+    pub institution: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeApproved {
+    pub institution: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub admin: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+// Carries the resulting institution and governance values
+#[event]
+pub struct ConfigChangeExecuted {
+    pub institution: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub credit_mint: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub threshold: u8,
+    pub min_delay: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub institution: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StudentProfileCreated {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub student_profile: Pubkey,
+    pub display_name: Option<String>,
+    pub metadata_uri: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StudentProfileClosed {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub student_profile: Pubkey,
+    pub courses_completed: u16,
+    pub gpa: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsPurchased {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub amount: u64,
+    pub total_cost: u64,  // Lamports paid to the treasury
+    pub total_credits_purchased: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseCreated {
+    pub institution: Pubkey,
+    pub course: Pubkey,
+    pub course_id: String,
+    pub course_name: String,
+    pub instructor: Pubkey,
+    pub required_credits: u64,
+    pub credit_hours: u8,
+    pub max_attempts: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseUpdated {
+    pub institution: Pubkey,
+    pub course: Pubkey,
+    pub course_id: String,
+    pub course_name: String,
+    pub instructor: Pubkey,
+    pub required_credits: u64,
+    pub credit_hours: u8,
+    pub max_attempts: u8,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TermCreated {
+    pub institution: Pubkey,
+    pub term: Pubkey,
+    pub term_id: String,
+    pub name: String,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub registration_opens_at: i64,
+    pub registration_closes_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferingCreated {
+    pub institution: Pubkey,
+    pub offering: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub instructor: Pubkey,
+    pub capacity: u32,
+    pub enrollment_opens_at: i64,
+    pub enrollment_closes_at: i64,
+    pub grading_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StudentRegistered {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub offering: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub credits_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseRetaken {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub offering: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub attempt: u8,  // Number of the attempt that starts now
+    pub previous_grade: u8,
+    pub credits_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseCompleted {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub offering: Pubkey,
+    pub instructor: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,
+    pub credit_hours: u8,
+    pub gpa: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GradeAmended {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub offering: Pubkey,
+    pub instructor: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub previous_grade: u8,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,
+    pub credit_hours: u8,
+    pub gpa: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EnrollmentExpired {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub offering: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateMinted {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub certificate_mint: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub grade: u8,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GraduationClaimed {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub graduation_mint: Pubkey,
+    pub courses_completed: u16,
+    pub credit_hours_earned: u16,
+    pub gpa: u16,
+    pub timestamp: i64,
+}
+
+/// Compressed transcript record emitted when an enrollment account is closed
+#[event]
+pub struct EnrollmentClosed {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub course_id: String,
+    pub offering: Pubkey,
+    pub status: EnrollmentStatus,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,
+    pub credit_hours: u8,
+    pub credits_paid: u64,
+    pub enrollment_date: i64,
+    pub completion_date: Option<i64>,
+    pub certificate_mint: Option<Pubkey>,
+    pub attempts: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TranscriptAnchored {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub transcript_anchor: Pubkey,
+    pub issuer: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub entry_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateBatchCreated {
+    pub certificate_batch: Pubkey,
+    pub offering: Pubkey,
+    pub issuer: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub merkle_root: [u8; 32],
+    pub certificate_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchCertificateClaimed {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub certificate_batch: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub grade: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CertificateTreeCreated {
+    pub institution: Pubkey,
+    pub certificate_tree: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub authority: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompressedCertificateMinted {
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u32,
+    pub leaf_hash: [u8; 32],
+    pub course_id: String,
+    pub term_id: String,
+    pub grade: u8,
+    pub metadata_uri: String,
+    pub metadata_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseEquivalencyCreated {
+    pub course_equivalency: Pubkey,
+    pub institution: Pubkey,
+    pub course_id: String,
+    pub source_institution: Pubkey,
+    pub source_course_id: String,
+    pub authority: Pubkey,
+    pub source_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompletionTransferred {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub enrollment: Pubkey,
+    pub course_equivalency: Pubkey,
+    pub course_id: String,
+    pub source_enrollment: Pubkey,
+    pub source_course_id: String,
+    pub source_term_id: String,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,
+    pub credit_hours: u8,
+    pub gpa: u16,
+    pub completion_date: i64,
+    pub timestamp: i64,
+}
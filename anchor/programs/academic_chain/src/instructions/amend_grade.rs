@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct AmendGrade<'info> {
+    // Offering instructor, or a Registrar overriding the grade
+    pub instructor: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Registrar assignment of the signer; not needed for the instructor or institution authority
+    pub instructor_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    /// CHECK: Student public key
+    pub student: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.student == student.key(),
+        constraint = enrollment.status == EnrollmentStatus::Completed @ AcademicChainError::CourseNotCompleted,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<AmendGrade>,
+    course_id: String,
+    term_id: String,
+    grade: u8,
+) -> Result<()> {
+    let instructor = ctx.accounts.instructor.key();
+    require!(
+        ctx.accounts.offering.instructor == instructor
+            || has_role(&ctx.accounts.institution, &instructor, ctx.accounts.instructor_role.as_ref(), Role::Registrar),
+        AcademicChainError::UnauthorizedInstructor
+    );
+
+    validate_grade(grade)?;
+
+    let entry = lookup_grade(&ctx.accounts.institution.grade_scale, grade)?;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let profile = &mut ctx.accounts.student_profile;
+    let previous_grade = enrollment.grade;
+
+    // Swap the old grade's GPA contribution for the new one
+    revert_grade(profile, enrollment)?;
+    apply_grade(profile, enrollment, entry, grade, ctx.accounts.course.credit_hours)?;
+
+    emit_cpi!(GradeAmended {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        offering: enrollment.offering,
+        instructor,
+        course_id,
+        term_id,
+        previous_grade,
+        grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        grade_points: enrollment.grade_points,
+        credit_hours: enrollment.credit_hours,
+        gpa: profile.gpa,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Grade amended");
+    msg!("Student: {}", enrollment.student);
+    msg!("Grade: {} -> {} ({})", previous_grade, grade, enrollment.letter_grade);
+    msg!("GPA: {}", profile.gpa);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct AnchorTranscript<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Registrar assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"student_profile", institution.key().as_ref(), student_profile.student.as_ref()],
+        bump = student_profile.bump,
+        constraint = student_profile.institution == institution.key() @ AcademicChainError::InstitutionMismatch,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    // Keyed by root so every issued copy stays verifiable after re-issues
+    #[account(
+        init,
+        payer = authority,
+        space = TranscriptAnchor::LEN,
+        seeds = [b"transcript", institution.key().as_ref(), student_profile.student.as_ref(), merkle_root.as_ref()],
+        bump,
+    )]
+    pub transcript_anchor: Account<'info, TranscriptAnchor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AnchorTranscript>, merkle_root: [u8; 32], entry_count: u16) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::Registrar,
+    )?;
+
+    require!(
+        merkle_root != [0; 32] && entry_count > 0,
+        AcademicChainError::InvalidTranscript
+    );
+
+    let clock = Clock::get()?;
+    let anchor = &mut ctx.accounts.transcript_anchor;
+    anchor.institution = ctx.accounts.institution.key();
+    anchor.student = ctx.accounts.student_profile.student;
+    anchor.issuer = ctx.accounts.authority.key();
+    anchor.merkle_root = merkle_root;
+    anchor.entry_count = entry_count;
+    anchor.issued_at = clock.unix_timestamp;
+    anchor.bump = ctx.bumps.transcript_anchor;
+
+    emit_cpi!(TranscriptAnchored {
+        institution: anchor.institution,
+        student: anchor.student,
+        transcript_anchor: anchor.key(),
+        issuer: anchor.issuer,
+        merkle_root,
+        entry_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("✅ Transcript anchored for {}", anchor.student);
+    msg!("Entries: {}", entry_count);
+
+    Ok(())
+}
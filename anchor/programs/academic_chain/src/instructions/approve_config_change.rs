@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveConfigChange<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"governance", institution.key().as_ref()],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, ConfigGovernance>,
+
+    #[account(
+        mut,
+        seeds = [b"config_proposal", institution.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+}
+
+pub(crate) fn handler(ctx: Context<ApproveConfigChange>, proposal_id: u64) -> Result<()> {
+    let admin = ctx.accounts.admin.key();
+    require!(ctx.accounts.governance.is_admin(&admin), AcademicChainError::NotGovernanceAdmin);
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.approvals.contains(&admin), AcademicChainError::AlreadyApproved);
+    // Drop approvals of admins removed since, so the list stays within MAX_ADMINS
+    let governance = &ctx.accounts.governance;
+    proposal.approvals.retain(|approver| governance.is_admin(approver));
+    proposal.approvals.push(admin);
+
+    emit_cpi!(ConfigChangeApproved {
+        institution: proposal.institution,
+        proposal: proposal.key(),
+        proposal_id,
+        admin,
+        approvals: proposal.approvals.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Config change #{} approved by {}", proposal_id, admin);
+    msg!("Approvals: {}", proposal.approvals.len());
+
+    Ok(())
+}
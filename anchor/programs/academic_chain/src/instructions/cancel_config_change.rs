@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelConfigChange<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"governance", institution.key().as_ref()],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, ConfigGovernance>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"config_proposal", institution.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal's rent; checked against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelConfigChange>, proposal_id: u64) -> Result<()> {
+    // Any single admin can stop a change; executing one takes the threshold
+    let admin = ctx.accounts.admin.key();
+    require!(ctx.accounts.governance.is_admin(&admin), AcademicChainError::NotGovernanceAdmin);
+
+    emit_cpi!(ConfigChangeCancelled {
+        institution: ctx.accounts.institution.key(),
+        proposal: ctx.accounts.proposal.key(),
+        proposal_id,
+        admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Config change #{} cancelled by {}", proposal_id, admin);
+
+    Ok(())
+}
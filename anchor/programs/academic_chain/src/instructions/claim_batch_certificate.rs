@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct ClaimBatchCertificate<'info> {
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.student == student.key(),
+        constraint = enrollment.status == EnrollmentStatus::Completed @ AcademicChainError::CourseNotCompleted,
+        constraint = enrollment.certificate_mint.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.certificate_batch.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.compressed_certificate.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate_batch", offering.key().as_ref(), certificate_batch.merkle_root.as_ref()],
+        bump = certificate_batch.bump,
+    )]
+    pub certificate_batch: Account<'info, CertificateBatch>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ClaimBatchCertificate>,
+    course_id: String,
+    term_id: String,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let enrollment = &mut ctx.accounts.enrollment;
+    let batch = &mut ctx.accounts.certificate_batch;
+
+    // The leaf is rebuilt from the enrollment, so only the grade on record can be claimed
+    let leaf = CertificateLeaf {
+        student: enrollment.student,
+        course_id: enrollment.course_id.clone(),
+        grade: enrollment.grade,
+        completion_date: enrollment.completion_date.ok_or(AcademicChainError::CourseNotCompleted)?,
+    };
+    require!(
+        proof.len() <= MAX_MERKLE_PROOF_LEN
+            && verify_merkle_proof(&certificate_leaf(&leaf), &proof, &batch.merkle_root),
+        AcademicChainError::InvalidMerkleProof
+    );
+    require!(
+        batch.claimed_count < batch.certificate_count,
+        AcademicChainError::InvalidCertificateBatch
+    );
+
+    batch.claimed_count += 1;
+    enrollment.certificate_batch = Some(batch.key());
+
+    emit_cpi!(BatchCertificateClaimed {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        certificate_batch: batch.key(),
+        course_id,
+        term_id,
+        grade: enrollment.grade,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Batch certificate claimed");
+    msg!("Student: {}", enrollment.student);
+    msg!("Grade: {}", enrollment.grade);
+
+    Ok(())
+}
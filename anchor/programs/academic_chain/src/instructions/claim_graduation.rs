@@ -1,15 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimGraduation<'info> {
     #[account(mut)]
     pub student: Signer<'info>,
 
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
     #[account(
         mut,
-        seeds = [b"student_profile", student.key().as_ref()],
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
         bump = student_profile.bump,
         constraint = student_profile.graduation_nft.is_none() @ AcademicChainError::CertificateAlreadyMinted,
     )]
@@ -18,7 +26,7 @@ pub struct ClaimGraduation<'info> {
     /// CHECK: Graduation NFT mint to be created
     #[account(
         mut,
-        seeds = [b"graduation_mint", student.key().as_ref()],
+        seeds = [b"graduation_mint", institution.key().as_ref(), student.key().as_ref()],
         bump,
     )]
     pub graduation_mint: AccountInfo<'info>,
@@ -26,7 +34,7 @@ pub struct ClaimGraduation<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<ClaimGraduation>,
     required_courses: Vec<String>,
 ) -> Result<()> {
@@ -41,6 +49,16 @@ pub fn handler(
     // Store graduation NFT mint
     profile.graduation_nft = Some(ctx.accounts.graduation_mint.key());
 
+    emit_cpi!(GraduationClaimed {
+        institution: profile.institution,
+        student: profile.student,
+        graduation_mint: ctx.accounts.graduation_mint.key(),
+        courses_completed: profile.courses_completed,
+        credit_hours_earned: profile.credit_hours_earned,
+        gpa: profile.gpa,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("✅ Graduation NFT claimed");
     msg!("Student: {}", ctx.accounts.student.key());
     msg!("Total courses completed: {}", profile.courses_completed);
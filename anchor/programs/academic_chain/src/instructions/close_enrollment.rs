@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct CloseEnrollment<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    #[account(
+        mut,
+        close = student,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.student == student.key(),
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    #[account(
+        init,
+        payer = student,
+        space = EnrollmentRecord::space(&enrollment.course_id, &offering.term_id, &enrollment.letter_grade),
+        seeds = [b"enrollment_record", enrollment.key().as_ref()],
+        bump,
+    )]
+    pub enrollment_record: Account<'info, EnrollmentRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseEnrollment>, _course_id: String, _term_id: String) -> Result<()> {
+    let enrollment = &ctx.accounts.enrollment;
+
+    require!(
+        enrollment.status != EnrollmentStatus::Enrolled,
+        AcademicChainError::EnrollmentNotFinished
+    );
+
+    // Once grading is over the offering can no longer be re-registered or retaken
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now > ctx.accounts.offering.grading_deadline,
+        AcademicChainError::GradingDeadlineNotReached
+    );
+
+    // The transcript entry and certificate outlive the enrollment, so the
+    // completion stays verifiable and on later transcripts
+    let record = &mut ctx.accounts.enrollment_record;
+    record.student = enrollment.student;
+    record.offering = enrollment.offering;
+    record.entry = TranscriptEntry {
+        enrollment: enrollment.key(),
+        course_id: enrollment.course_id.clone(),
+        term_id: ctx.accounts.offering.term_id.clone(),
+        status: enrollment.status,
+        grade: enrollment.grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        credit_hours: enrollment.credit_hours,
+        completion_date: enrollment.completion_date,
+    };
+    record.enrollment_date = enrollment.enrollment_date;
+    record.grade_points = enrollment.grade_points;
+    record.certificate_mint = enrollment.certificate_mint;
+    record.metadata_hash = enrollment.metadata_hash;
+    record.certificate_batch = enrollment.certificate_batch;
+    record.compressed_certificate = enrollment.compressed_certificate;
+    record.closed_at = now;
+    record.bump = ctx.bumps.enrollment_record;
+
+    let profile = &mut ctx.accounts.student_profile;
+    profile.open_enrollments = profile.open_enrollments
+        .checked_sub(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
+    emit_cpi!(EnrollmentClosed {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        course_id: enrollment.course_id.clone(),
+        offering: enrollment.offering,
+        status: enrollment.status,
+        grade: enrollment.grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        grade_points: enrollment.grade_points,
+        credit_hours: enrollment.credit_hours,
+        credits_paid: enrollment.credits_paid,
+        enrollment_date: enrollment.enrollment_date,
+        completion_date: enrollment.completion_date,
+        certificate_mint: enrollment.certificate_mint,
+        attempts: (enrollment.attempts.len() + 1) as u8,
+        timestamp: now,
+    });
+
+    msg!("✅ Enrollment closed: {}", enrollment.course_id);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        mut,
+        close = student,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
+        constraint = student_profile.open_enrollments == 0 @ AcademicChainError::OpenEnrollmentsRemain,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    #[account(
+        init,
+        payer = student,
+        space = ProfileTombstone::LEN,
+        seeds = [b"profile_tombstone", institution.key().as_ref(), student.key().as_ref()],
+        bump,
+    )]
+    pub profile_tombstone: Account<'info, ProfileTombstone>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CloseProfile>) -> Result<()> {
+    let profile = &ctx.accounts.student_profile;
+    let now = Clock::get()?.unix_timestamp;
+
+    // The academic record outlives the profile and is restored if it is re-created
+    let tombstone = &mut ctx.accounts.profile_tombstone;
+    tombstone.institution = profile.institution;
+    tombstone.student = profile.student;
+    tombstone.courses_completed = profile.courses_completed;
+    tombstone.credit_hours_attempted = profile.credit_hours_attempted;
+    tombstone.credit_hours_earned = profile.credit_hours_earned;
+    tombstone.quality_points = profile.quality_points;
+    tombstone.gpa = profile.gpa;
+    tombstone.graduation_nft = profile.graduation_nft;
+    tombstone.closed_at = now;
+    tombstone.bump = ctx.bumps.profile_tombstone;
+
+    emit_cpi!(StudentProfileClosed {
+        institution: profile.institution,
+        student: profile.student,
+        student_profile: profile.key(),
+        courses_completed: profile.courses_completed,
+        gpa: profile.gpa,
+        timestamp: now,
+    });
+
+    msg!("✅ Student profile closed for {}", ctx.accounts.student.key());
+
+    Ok(())
+}
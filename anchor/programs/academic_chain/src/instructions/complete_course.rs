@@ -1,27 +1,41 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
-use crate::constants::*;
+use crate::events::*;
+use crate::utils::*;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(course_id: String)]
+#[instruction(course_id: String, term_id: String)]
 pub struct CompleteCourse<'info> {
     #[account(mut)]
     pub instructor: Signer<'info>,
 
     #[account(
-        seeds = [b"course", course_id.as_bytes()],
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
         bump = course.bump,
-        constraint = course.instructor == instructor.key() @ AcademicChainError::UnauthorizedInstructor
     )]
     pub course: Account<'info, Course>,
 
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+        constraint = offering.instructor == instructor.key() @ AcademicChainError::UnauthorizedInstructor
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
     /// CHECK: Student public key
     pub student: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [b"enrollment", student.key().as_ref(), course_id.as_bytes()],
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
         bump = enrollment.bump,
         constraint = enrollment.student == student.key(),
     )]
@@ -29,35 +43,51 @@ pub struct CompleteCourse<'info> {
 
     #[account(
         mut,
-        seeds = [b"student_profile", student.key().as_ref()],
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
         bump = student_profile.bump,
     )]
     pub student_profile: Account<'info, StudentProfile>,
 }
 
-pub fn handler(ctx: Context<CompleteCourse>, _course_id: String, grade: u8) -> Result<()> {
-    // Validate grade
-    require!(
-        grade >= MIN_GRADE && grade <= MAX_GRADE,
-        AcademicChainError::InvalidGrade
-    );
-
+pub(crate) fn handler(
+    ctx: Context<CompleteCourse>,
+    course_id: String,
+    term_id: String,
+    grade: u8,
+) -> Result<()> {
     let enrollment = &mut ctx.accounts.enrollment;
-    
-    // Mark course as completed
-    enrollment.is_completed = true;
-    enrollment.grade = grade;
-    enrollment.completion_date = Some(Clock::get()?.unix_timestamp);
-
-    // Update student profile
     let profile = &mut ctx.accounts.student_profile;
-    profile.courses_completed = profile.courses_completed
-        .checked_add(1)
-        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    let completed_at = Clock::get()?.unix_timestamp;
+
+    complete_enrollment(
+        &ctx.accounts.institution,
+        &ctx.accounts.course,
+        &ctx.accounts.offering,
+        enrollment,
+        profile,
+        grade,
+        completed_at,
+    )?;
+
+    emit_cpi!(CourseCompleted {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        offering: enrollment.offering,
+        instructor: ctx.accounts.instructor.key(),
+        course_id,
+        term_id,
+        grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        grade_points: enrollment.grade_points,
+        credit_hours: enrollment.credit_hours,
+        gpa: profile.gpa,
+        timestamp: completed_at,
+    });
 
     msg!("✅ Course completed");
     msg!("Student: {}", enrollment.student);
-    msg!("Grade: {}", grade);
+    msg!("Grade: {} ({})", grade, enrollment.letter_grade);
+    msg!("GPA: {}", profile.gpa);
 
     Ok(())
 }
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+/// Remaining accounts: one writable `(enrollment, student_profile)` pair per
+/// entry in `grades`, in the same order.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct CompleteCourseBatch<'info> {
+    #[account(mut)]
+    pub instructor: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+        constraint = offering.instructor == instructor.key() @ AcademicChainError::UnauthorizedInstructor
+    )]
+    pub offering: Account<'info, CourseOffering>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CompleteCourseBatch<'info>>,
+    course_id: String,
+    term_id: String,
+    grades: Vec<StudentGrade>,
+) -> Result<()> {
+    require!(
+        !grades.is_empty() && grades.len() <= MAX_GRADING_BATCH_SIZE,
+        AcademicChainError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == grades.len() * 2,
+        AcademicChainError::InvalidBatchAccounts
+    );
+
+    let institution_key = ctx.accounts.institution.key();
+    let offering_key = ctx.accounts.offering.key();
+    let completed_at = Clock::get()?.unix_timestamp;
+
+    for (entry, accounts) in grades.iter().zip(ctx.remaining_accounts.chunks_exact(2)) {
+        let (enrollment_info, profile_info) = (&accounts[0], &accounts[1]);
+        require!(
+            enrollment_info.is_writable && profile_info.is_writable,
+            AcademicChainError::InvalidBatchAccounts
+        );
+
+        // Owner and discriminator are checked on deserialization
+        let mut enrollment = Account::<CourseEnrollment>::try_from(enrollment_info)?;
+        let mut profile = Account::<StudentProfile>::try_from(profile_info)?;
+
+        // Same seeds as `CompleteCourse`, re-derived from the stored bumps
+        let enrollment_key = Pubkey::create_program_address(
+            &[
+                ENROLLMENT_SEED,
+                entry.student.as_ref(),
+                offering_key.as_ref(),
+                &[enrollment.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| AcademicChainError::InvalidBatchAccounts)?;
+        let profile_key = Pubkey::create_program_address(
+            &[STUDENT_PROFILE_SEED, institution_key.as_ref(), entry.student.as_ref(), &[profile.bump]],
+            ctx.program_id,
+        )
+        .map_err(|_| AcademicChainError::InvalidBatchAccounts)?;
+
+        require_keys_eq!(enrollment_info.key(), enrollment_key, AcademicChainError::InvalidBatchAccounts);
+        require_keys_eq!(profile_info.key(), profile_key, AcademicChainError::InvalidBatchAccounts);
+        require_keys_eq!(enrollment.student, entry.student, AcademicChainError::InvalidBatchAccounts);
+
+        complete_enrollment(
+            &ctx.accounts.institution,
+            &ctx.accounts.course,
+            &ctx.accounts.offering,
+            &mut enrollment,
+            &mut profile,
+            entry.grade,
+            completed_at,
+        )?;
+
+        // Persist now so a duplicate student later in the batch sees the completion
+        enrollment.exit(ctx.program_id)?;
+        profile.exit(ctx.program_id)?;
+
+        emit_cpi!(CourseCompleted {
+            student: entry.student,
+            enrollment: enrollment_key,
+            offering: offering_key,
+            instructor: ctx.accounts.instructor.key(),
+            course_id: course_id.clone(),
+            term_id: term_id.clone(),
+            grade: entry.grade,
+            letter_grade: enrollment.letter_grade.clone(),
+            grade_points: enrollment.grade_points,
+            credit_hours: enrollment.credit_hours,
+            gpa: profile.gpa,
+            timestamp: completed_at,
+        });
+    }
+
+    msg!("✅ Batch completed: {} students in {} ({})", grades.len(), course_id, term_id);
+
+    Ok(())
+}
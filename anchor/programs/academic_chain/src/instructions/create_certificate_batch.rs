@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String, merkle_root: [u8; 32])]
+pub struct CreateCertificateBatch<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Reviewer assignment of the signer; not needed for the instructor or institution authority
+    pub issuer_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    // Keyed by root so an offering can be certified in several batches
+    #[account(
+        init,
+        payer = issuer,
+        space = CertificateBatch::LEN,
+        seeds = [b"certificate_batch", offering.key().as_ref(), merkle_root.as_ref()],
+        bump,
+    )]
+    pub certificate_batch: Account<'info, CertificateBatch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateCertificateBatch>,
+    course_id: String,
+    term_id: String,
+    merkle_root: [u8; 32],
+    certificate_count: u16,
+) -> Result<()> {
+    let issuer = ctx.accounts.issuer.key();
+    require!(
+        ctx.accounts.offering.instructor == issuer
+            || has_role(&ctx.accounts.institution, &issuer, ctx.accounts.issuer_role.as_ref(), Role::Reviewer),
+        AcademicChainError::UnauthorizedInstructor
+    );
+
+    require!(
+        merkle_root != [0; 32] && certificate_count > 0,
+        AcademicChainError::InvalidCertificateBatch
+    );
+
+    let clock = Clock::get()?;
+    let batch = &mut ctx.accounts.certificate_batch;
+    batch.offering = ctx.accounts.offering.key();
+    batch.issuer = issuer;
+    batch.merkle_root = merkle_root;
+    batch.certificate_count = certificate_count;
+    batch.claimed_count = 0;
+    batch.created_at = clock.unix_timestamp;
+    batch.bump = ctx.bumps.certificate_batch;
+
+    emit_cpi!(CertificateBatchCreated {
+        certificate_batch: batch.key(),
+        offering: batch.offering,
+        issuer: batch.issuer,
+        course_id: course_id.clone(),
+        term_id: term_id.clone(),
+        merkle_root,
+        certificate_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("✅ Certificate batch created: {} {}", course_id, term_id);
+    msg!("Certificates: {}", certificate_count);
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateCertificateTree<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Reviewer assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    /// CHECK: Allocated and assigned to the account compression program earlier
+    /// in the transaction; that program checks it is zeroed and sized for the tree
+    #[account(mut, owner = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CertificateTree::LEN,
+        seeds = [b"certificate_tree", merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub certificate_tree: Account<'info, CertificateTree>,
+
+    /// CHECK: spl-account-compression
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: spl-noop, which the compression program logs changes through
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateCertificateTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::Reviewer,
+    )?;
+
+    // The compression program rejects depth and buffer pairs it has no layout for
+    require!(
+        max_depth > 0 && max_depth <= MAX_CERTIFICATE_TREE_DEPTH && max_buffer_size.is_power_of_two(),
+        AcademicChainError::InvalidCertificateTree
+    );
+
+    let clock = Clock::get()?;
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let tree = &mut ctx.accounts.certificate_tree;
+    tree.merkle_tree = merkle_tree;
+    tree.institution = ctx.accounts.institution.key();
+    tree.authority = ctx.accounts.authority.key();
+    tree.max_depth = max_depth;
+    tree.max_buffer_size = max_buffer_size;
+    tree.minted = 0;
+    tree.created_at = clock.unix_timestamp;
+    tree.bump = ctx.bumps.certificate_tree;
+
+    let bump = [tree.bump];
+    let seeds: &[&[u8]] = &[CERTIFICATE_TREE_SEED, merkle_tree.as_ref(), &bump];
+    init_empty_merkle_tree(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.certificate_tree.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        seeds,
+        max_depth,
+        max_buffer_size,
+    )?;
+
+    emit_cpi!(CertificateTreeCreated {
+        institution: ctx.accounts.institution.key(),
+        certificate_tree: ctx.accounts.certificate_tree.key(),
+        merkle_tree,
+        authority: ctx.accounts.authority.key(),
+        max_depth,
+        max_buffer_size,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("✅ Certificate tree created: {}", merkle_tree);
+    msg!("Capacity: 2^{}", max_depth);
+
+    Ok(())
+}
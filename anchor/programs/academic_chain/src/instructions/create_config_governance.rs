@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateConfigGovernance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Putting the institution under governance is never delegated to a role
+    #[account(
+        mut,
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+        has_one = authority @ AcademicChainError::Unauthorized,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ConfigGovernance::LEN,
+        seeds = [b"governance", institution.key().as_ref()],
+        bump,
+    )]
+    pub governance: Account<'info, ConfigGovernance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateConfigGovernance>,
+    admins: Vec<Pubkey>,
+    threshold: u8,
+    min_delay: i64,
+) -> Result<()> {
+    validate_governance(&admins, threshold, min_delay)?;
+
+    let institution = &mut ctx.accounts.institution;
+    institution.governed = true;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.institution = institution.key();
+    governance.admins = admins;
+    governance.threshold = threshold;
+    governance.min_delay = min_delay;
+    governance.proposal_count = 0;
+    governance.bump = ctx.bumps.governance;
+
+    emit_cpi!(ConfigGovernanceUpdated {
+        institution: governance.institution,
+        governance: governance.key(),
+        admins: governance.admins.clone(),
+        threshold,
+        min_delay,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Config governance created: {}-of-{}", threshold, governance.admins.len());
+    msg!("Minimum Delay: {}s", min_delay);
+
+    Ok(())
+}
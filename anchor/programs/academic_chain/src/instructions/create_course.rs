@@ -1,18 +1,31 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(course_id: String, course_name: String)]
 pub struct CreateCourse<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // CourseManager assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
     #[account(
         init,
         payer = authority,
         space = Course::LEN,
-        seeds = [b"course", course_id.as_bytes()],
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
         bump,
     )]
     pub course: Account<'info, Course>,
@@ -20,13 +33,22 @@ pub struct CreateCourse<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<CreateCourse>,
     course_id: String,
     course_name: String,
     instructor: Pubkey,
     required_credits: u64,
+    credit_hours: u8,
+    max_attempts: u8,
 ) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::CourseManager,
+    )?;
+
     require!(
         course_id.len() <= Course::MAX_ID_LEN,
         AcademicChainError::InvalidCourseId
@@ -42,15 +64,40 @@ pub fn handler(
         AcademicChainError::InvalidCredits
     );
 
+    require!(
+        credit_hours > 0 && credit_hours <= MAX_CREDIT_HOURS,
+        AcademicChainError::InvalidCreditHours
+    );
+
+    require!(
+        max_attempts > 0 && max_attempts <= MAX_COURSE_ATTEMPTS,
+        AcademicChainError::InvalidMaxAttempts
+    );
+
     let course = &mut ctx.accounts.course;
+    course.institution = ctx.accounts.institution.key();
     course.course_id = course_id;
     course.course_name = course_name;
     course.instructor = instructor;
     course.required_credits = required_credits;
+    course.credit_hours = credit_hours;
+    course.max_attempts = max_attempts;
     course.is_active = true;
     course.created_at = Clock::get()?.unix_timestamp;
     course.bump = ctx.bumps.course;
 
+    emit_cpi!(CourseCreated {
+        institution: course.institution,
+        course: course.key(),
+        course_id: course.course_id.clone(),
+        course_name: course.course_name.clone(),
+        instructor: course.instructor,
+        required_credits: course.required_credits,
+        credit_hours: course.credit_hours,
+        max_attempts: course.max_attempts,
+        timestamp: course.created_at,
+    });
+
     msg!("Course created: {}", course.course_id);
     Ok(())
 }
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(source_course_id: String, course_id: String)]
+pub struct CreateCourseEquivalency<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Registrar assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    pub source_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", source_institution.institution_id.as_bytes()],
+        bump = source_institution.bump,
+    )]
+    pub source_institution: Account<'info, Institution>,
+
+    // Registrar assignment of the source signer at the source institution
+    pub source_authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"course", source_institution.key().as_ref(), source_course_id.as_bytes()],
+        bump = source_course.bump,
+    )]
+    pub source_course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CourseEquivalency::LEN,
+        seeds = [b"equivalency", source_course.key().as_ref(), course.key().as_ref()],
+        bump,
+    )]
+    pub course_equivalency: Account<'info, CourseEquivalency>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateCourseEquivalency>, source_course_id: String, course_id: String) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::Registrar,
+    )?;
+    require_role(
+        &ctx.accounts.source_institution,
+        &ctx.accounts.source_authority.key(),
+        ctx.accounts.source_authority_role.as_ref(),
+        Role::Registrar,
+    )?;
+
+    require_keys_neq!(
+        ctx.accounts.institution.key(),
+        ctx.accounts.source_institution.key(),
+        AcademicChainError::SameInstitution
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let equivalency = &mut ctx.accounts.course_equivalency;
+    equivalency.institution = ctx.accounts.institution.key();
+    equivalency.course = ctx.accounts.course.key();
+    equivalency.course_id = course_id;
+    equivalency.source_institution = ctx.accounts.source_institution.key();
+    equivalency.source_course = ctx.accounts.source_course.key();
+    equivalency.source_course_id = source_course_id;
+    equivalency.created_at = now;
+    equivalency.bump = ctx.bumps.course_equivalency;
+
+    emit_cpi!(CourseEquivalencyCreated {
+        course_equivalency: equivalency.key(),
+        institution: equivalency.institution,
+        course_id: equivalency.course_id.clone(),
+        source_institution: equivalency.source_institution,
+        source_course_id: equivalency.source_course_id.clone(),
+        authority: ctx.accounts.authority.key(),
+        source_authority: ctx.accounts.source_authority.key(),
+        timestamp: now,
+    });
+
+    msg!(
+        "✅ Course equivalency created: {} ({}) -> {} ({})",
+        equivalency.source_course_id,
+        ctx.accounts.source_institution.institution_id,
+        equivalency.course_id,
+        ctx.accounts.institution.institution_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(institution_id: String)]
+pub struct CreateInstitution<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Institution::LEN,
+        seeds = [b"institution", institution_id.as_bytes()],
+        bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    /// CHECK: Treasury account for receiving payments
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Credit token mint; its mint authority must be the institution PDA
+    pub credit_mint: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateInstitution>, institution_id: String, name: String) -> Result<()> {
+    require!(
+        !institution_id.is_empty() && institution_id.len() <= Institution::MAX_ID_LEN,
+        AcademicChainError::InvalidInstitutionId
+    );
+
+    require!(
+        !name.is_empty() && name.len() <= Institution::MAX_NAME_LEN,
+        AcademicChainError::InvalidInstitutionName
+    );
+
+    let institution = &mut ctx.accounts.institution;
+    institution.institution_id = institution_id;
+    institution.name = name;
+    institution.authority = ctx.accounts.authority.key();
+    institution.treasury = ctx.accounts.treasury.key();
+    institution.credit_mint = ctx.accounts.credit_mint.key();
+    institution.credit_price = DEFAULT_CREDIT_PRICE;
+    institution.grade_scale = default_grade_scale();
+    institution.governed = false;
+    institution.bump = ctx.bumps.institution;
+
+    emit_cpi!(InstitutionCreated {
+        institution: institution.key(),
+        institution_id: institution.institution_id.clone(),
+        name: institution.name.clone(),
+        authority: institution.authority,
+        treasury: institution.treasury,
+        credit_mint: institution.credit_mint,
+        credit_price: institution.credit_price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Institution created: {} ({})", institution.name, institution.institution_id);
+    msg!("Authority: {}", institution.authority);
+    msg!("Treasury: {}", institution.treasury);
+    msg!("Credit Mint: {}", institution.credit_mint);
+
+    Ok(())
+}
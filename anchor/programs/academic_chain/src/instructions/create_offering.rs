@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct CreateOffering<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // CourseManager assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"term", institution.key().as_ref(), term_id.as_bytes()],
+        bump = term.bump,
+    )]
+    pub term: Account<'info, Term>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CourseOffering::LEN,
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handler(
+    ctx: Context<CreateOffering>,
+    course_id: String,
+    term_id: String,
+    instructor: Pubkey,
+    capacity: u32,
+    enrollment_opens_at: Option<i64>,
+    enrollment_closes_at: Option<i64>,
+    grading_deadline: Option<i64>,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::CourseManager,
+    )?;
+
+    require!(ctx.accounts.course.is_active, AcademicChainError::CourseNotActive);
+    require!(capacity > 0, AcademicChainError::InvalidCapacity);
+
+    // Deadlines default to the term's registration window and end date
+    let term = &ctx.accounts.term;
+    let enrollment_opens_at = enrollment_opens_at.unwrap_or(term.registration_opens_at);
+    let enrollment_closes_at = enrollment_closes_at.unwrap_or(term.registration_closes_at);
+    let grading_deadline = grading_deadline.unwrap_or(term.ends_at);
+    require!(
+        enrollment_opens_at < enrollment_closes_at && enrollment_closes_at <= grading_deadline,
+        AcademicChainError::InvalidDeadlines
+    );
+
+    let offering = &mut ctx.accounts.offering;
+    offering.institution = ctx.accounts.institution.key();
+    offering.course_id = course_id;
+    offering.term_id = term_id;
+    offering.instructor = instructor;
+    offering.capacity = capacity;
+    offering.enrollment_count = 0;
+    offering.enrollment_opens_at = enrollment_opens_at;
+    offering.enrollment_closes_at = enrollment_closes_at;
+    offering.grading_deadline = grading_deadline;
+    offering.created_at = Clock::get()?.unix_timestamp;
+    offering.bump = ctx.bumps.offering;
+
+    emit_cpi!(OfferingCreated {
+        institution: offering.institution,
+        offering: offering.key(),
+        course_id: offering.course_id.clone(),
+        term_id: offering.term_id.clone(),
+        instructor: offering.instructor,
+        capacity: offering.capacity,
+        enrollment_opens_at: offering.enrollment_opens_at,
+        enrollment_closes_at: offering.enrollment_closes_at,
+        grading_deadline: offering.grading_deadline,
+        timestamp: offering.created_at,
+    });
+
+    msg!("Offering created: {} in {}", offering.course_id, offering.term_id);
+    msg!("Instructor: {}", offering.instructor);
+    msg!("Capacity: {}", offering.capacity);
+    Ok(())
+}
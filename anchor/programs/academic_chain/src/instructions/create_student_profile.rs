@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateStudentProfile<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        init,
+        payer = student,
+        space = StudentProfile::LEN,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    /// CHECK: Tombstone of an earlier profile, restored and closed when it exists
+    #[account(
+        mut,
+        seeds = [b"profile_tombstone", institution.key().as_ref(), student.key().as_ref()],
+        bump,
+    )]
+    pub profile_tombstone: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateStudentProfile>,
+    display_name: Option<String>,
+    metadata_uri: Option<String>,
+) -> Result<()> {
+    if let Some(name) = &display_name {
+        require!(
+            !name.is_empty() && name.len() <= StudentProfile::MAX_DISPLAY_NAME_LEN,
+            AcademicChainError::InvalidDisplayName
+        );
+    }
+
+    if let Some(uri) = &metadata_uri {
+        require!(
+            !uri.is_empty() && uri.len() <= StudentProfile::MAX_METADATA_URI_LEN,
+            AcademicChainError::InvalidMetadataUri
+        );
+    }
+
+    let profile = &mut ctx.accounts.student_profile;
+    profile.student = ctx.accounts.student.key();
+    profile.institution = ctx.accounts.institution.key();
+    profile.total_credits_purchased = 0;
+    profile.total_credits_spent = 0;
+    profile.courses_completed = 0;
+    profile.open_enrollments = 0;
+    profile.credit_hours_attempted = 0;
+    profile.credit_hours_earned = 0;
+    profile.quality_points = 0;
+    profile.gpa = 0;
+    profile.graduation_nft = None;
+    profile.display_name = display_name;
+    profile.metadata_uri = metadata_uri;
+    profile.created_at = Clock::get()?.unix_timestamp;
+    profile.bump = ctx.bumps.student_profile;
+
+    // A re-created profile picks up the academic record of the closed one
+    let info = ctx.accounts.profile_tombstone.to_account_info();
+    if !info.data_is_empty() {
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        let tombstone = ProfileTombstone::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        profile.courses_completed = tombstone.courses_completed;
+        profile.credit_hours_attempted = tombstone.credit_hours_attempted;
+        profile.credit_hours_earned = tombstone.credit_hours_earned;
+        profile.quality_points = tombstone.quality_points;
+        profile.gpa = tombstone.gpa;
+        profile.graduation_nft = tombstone.graduation_nft;
+
+        // Close the tombstone, refunding its rent to the student
+        let student = ctx.accounts.student.to_account_info();
+        **student.try_borrow_mut_lamports()? += info.lamports();
+        **info.try_borrow_mut_lamports()? = 0;
+        info.assign(&System::id());
+        info.realloc(0, false)?;
+    }
+
+    emit_cpi!(StudentProfileCreated {
+        institution: profile.institution,
+        student: profile.student,
+        student_profile: profile.key(),
+        display_name: profile.display_name.clone(),
+        metadata_uri: profile.metadata_uri.clone(),
+        timestamp: profile.created_at,
+    });
+
+    msg!("✅ Student profile created for {}", profile.student);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(term_id: String)]
+pub struct CreateTerm<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Registrar assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Term::LEN,
+        seeds = [b"term", institution.key().as_ref(), term_id.as_bytes()],
+        bump,
+    )]
+    pub term: Account<'info, Term>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateTerm>,
+    term_id: String,
+    name: String,
+    starts_at: i64,
+    ends_at: i64,
+    registration_opens_at: i64,
+    registration_closes_at: i64,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::Registrar,
+    )?;
+
+    require!(
+        !term_id.is_empty() && term_id.len() <= Term::MAX_ID_LEN,
+        AcademicChainError::InvalidTermId
+    );
+
+    require!(
+        !name.is_empty() && name.len() <= Term::MAX_NAME_LEN,
+        AcademicChainError::InvalidTermName
+    );
+
+    require!(
+        starts_at < ends_at
+            && registration_opens_at < registration_closes_at
+            && registration_closes_at <= ends_at,
+        AcademicChainError::InvalidTermDates
+    );
+
+    let term = &mut ctx.accounts.term;
+    term.institution = ctx.accounts.institution.key();
+    term.term_id = term_id;
+    term.name = name;
+    term.starts_at = starts_at;
+    term.ends_at = ends_at;
+    term.registration_opens_at = registration_opens_at;
+    term.registration_closes_at = registration_closes_at;
+    term.created_at = Clock::get()?.unix_timestamp;
+    term.bump = ctx.bumps.term;
+
+    emit_cpi!(TermCreated {
+        institution: term.institution,
+        term: term.key(),
+        term_id: term.term_id.clone(),
+        name: term.name.clone(),
+        starts_at: term.starts_at,
+        ends_at: term.ends_at,
+        registration_opens_at: term.registration_opens_at,
+        registration_closes_at: term.registration_closes_at,
+        timestamp: term.created_at,
+    });
+
+    msg!("Term created: {} ({})", term.term_id, term.name);
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteConfigChange<'info> {
+    // Anyone may execute an approved proposal once its delay has passed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        mut,
+        seeds = [b"governance", institution.key().as_ref()],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, ConfigGovernance>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"config_proposal", institution.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Receives the proposal's rent; checked against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ExecuteConfigChange>, proposal_id: u64) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        counted_approvals(governance, proposal) >= governance.threshold as usize,
+        AcademicChainError::ProposalNotApproved
+    );
+    require!(now >= proposal.executable_at, AcademicChainError::ProposalTimelocked);
+    // The admins may have changed since the proposal was made
+    validate_governance_change(governance, &proposal.change)?;
+
+    let change = &proposal.change;
+    let institution = &mut ctx.accounts.institution;
+    if let Some(authority) = change.authority {
+        institution.authority = authority;
+    }
+    if let Some(treasury) = change.treasury {
+        institution.treasury = treasury;
+    }
+    if let Some(credit_mint) = change.credit_mint {
+        institution.credit_mint = credit_mint;
+    }
+    if let Some(admins) = &change.admins {
+        governance.admins = admins.clone();
+    }
+    if let Some(threshold) = change.threshold {
+        governance.threshold = threshold;
+    }
+    if let Some(min_delay) = change.min_delay {
+        governance.min_delay = min_delay;
+    }
+
+    emit_cpi!(ConfigChangeExecuted {
+        institution: institution.key(),
+        proposal: proposal.key(),
+        proposal_id,
+        executor: ctx.accounts.executor.key(),
+        authority: institution.authority,
+        treasury: institution.treasury,
+        credit_mint: institution.credit_mint,
+        admins: governance.admins.clone(),
+        threshold: governance.threshold,
+        min_delay: governance.min_delay,
+        timestamp: now,
+    });
+
+    msg!("✅ Config change #{} executed", proposal_id);
+    msg!("Authority: {}", institution.authority);
+    msg!("Treasury: {}", institution.treasury);
+    msg!("Credit Mint: {}", institution.credit_mint);
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Permissionless crank: anyone can expire an ungraded enrollment once the
+/// offering's grading deadline has passed.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct ExpireEnrollment<'info> {
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    /// CHECK: Student public key
+    pub student: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.student == student.key(),
+        constraint = enrollment.status == EnrollmentStatus::Enrolled @ AcademicChainError::EnrollmentNotActive,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+}
+
+pub(crate) fn handler(ctx: Context<ExpireEnrollment>, course_id: String, term_id: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now > ctx.accounts.offering.grading_deadline,
+        AcademicChainError::GradingDeadlineNotReached
+    );
+
+    let enrollment = &mut ctx.accounts.enrollment;
+    enrollment.status = EnrollmentStatus::Incomplete;
+
+    emit_cpi!(EnrollmentExpired {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        offering: enrollment.offering,
+        course_id,
+        term_id,
+        timestamp: now,
+    });
+
+    msg!("Enrollment expired as incomplete");
+    msg!("Student: {}", enrollment.student);
+    msg!("Course: {}", enrollment.course_id);
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(holder: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // SuperAdmin assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RoleAssignment::LEN,
+        seeds = [b"role", institution.key().as_ref(), holder.as_ref(), &[role as u8]],
+        bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<GrantRole>, holder: Pubkey, role: Role) -> Result<()> {
+    let institution = &ctx.accounts.institution;
+    let authority = ctx.accounts.authority.key();
+
+    // SuperAdmins manage the other roles; only the authority appoints SuperAdmins
+    let required = if role == Role::SuperAdmin { None } else { ctx.accounts.authority_role.as_ref() };
+    require!(
+        has_role(institution, &authority, required, Role::SuperAdmin),
+        AcademicChainError::Unauthorized
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let assignment = &mut ctx.accounts.role_assignment;
+    assignment.institution = institution.key();
+    assignment.holder = holder;
+    assignment.role = role;
+    assignment.granted_by = authority;
+    assignment.granted_at = now;
+    assignment.bump = ctx.bumps.role_assignment;
+
+    emit_cpi!(RoleGranted {
+        institution: assignment.institution,
+        role_assignment: assignment.key(),
+        holder,
+        role,
+        granted_by: authority,
+        timestamp: now,
+    });
+
+    msg!("✅ Role {:?} granted to {}", role, holder);
+
+    Ok(())
+}
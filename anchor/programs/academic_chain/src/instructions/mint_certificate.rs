@@ -1,49 +1,95 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(course_id: String)]
+#[instruction(course_id: String, term_id: String)]
 pub struct MintCertificate<'info> {
     #[account(mut)]
     pub student: Signer<'info>,
 
     #[account(
-        seeds = [b"course", course_id.as_bytes()],
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
 
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
     #[account(
         mut,
-        seeds = [b"enrollment", student.key().as_ref(), course_id.as_bytes()],
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
         bump = enrollment.bump,
         constraint = enrollment.student == student.key(),
-        constraint = enrollment.is_completed @ AcademicChainError::CourseNotCompleted,
+        constraint = enrollment.status == EnrollmentStatus::Completed @ AcademicChainError::CourseNotCompleted,
         constraint = enrollment.certificate_mint.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.certificate_batch.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.compressed_certificate.is_none() @ AcademicChainError::CertificateAlreadyMinted,
     )]
     pub enrollment: Account<'info, CourseEnrollment>,
 
     /// CHECK: Certificate mint to be created
     #[account(
         mut,
-        seeds = [b"certificate_mint", student.key().as_ref(), course_id.as_bytes()],
+        seeds = [b"certificate_mint", enrollment.key().as_ref()],
         bump,
     )]
     pub certificate_mint: AccountInfo<'info>,
 
+    #[account(
+        init,
+        payer = student,
+        space = CertificateRecord::LEN,
+        seeds = [b"certificate_record", certificate_mint.key().as_ref()],
+        bump,
+    )]
+    pub certificate_record: Account<'info, CertificateRecord>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
+pub(crate) fn handler(
     ctx: Context<MintCertificate>,
-    _course_id: String,
-    _metadata_uri: String,
+    course_id: String,
+    term_id: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
 ) -> Result<()> {
+    require!(metadata_hash != [0u8; 32], AcademicChainError::InvalidMetadataHash);
+
     let enrollment = &mut ctx.accounts.enrollment;
     
-    // Store certificate mint address
+    // Store certificate mint address and the hash of its off-chain metadata
     enrollment.certificate_mint = Some(ctx.accounts.certificate_mint.key());
+    enrollment.metadata_hash = Some(metadata_hash);
+
+    let record = &mut ctx.accounts.certificate_record;
+    record.enrollment = enrollment.key();
+    record.bump = ctx.bumps.certificate_record;
+
+    emit_cpi!(CertificateMinted {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        certificate_mint: ctx.accounts.certificate_mint.key(),
+        course_id,
+        term_id,
+        grade: enrollment.grade,
+        metadata_uri,
+        metadata_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("✅ Certificate NFT minted");
     msg!("Student: {}", enrollment.student);
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct MintCompressedCertificate<'info> {
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    #[account(
+        mut,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        constraint = enrollment.student == student.key(),
+        constraint = enrollment.status == EnrollmentStatus::Completed @ AcademicChainError::CourseNotCompleted,
+        constraint = enrollment.certificate_mint.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.certificate_batch.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+        constraint = enrollment.compressed_certificate.is_none() @ AcademicChainError::CertificateAlreadyMinted,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"certificate_tree", merkle_tree.key().as_ref()],
+        bump = certificate_tree.bump,
+        has_one = institution @ AcademicChainError::InstitutionMismatch,
+    )]
+    pub certificate_tree: Account<'info, CertificateTree>,
+
+    /// CHECK: Tree of `certificate_tree`, written by the compression program
+    #[account(mut, owner = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: spl-account-compression
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: spl-noop, which the compression program logs changes through
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<MintCompressedCertificate>,
+    course_id: String,
+    term_id: String,
+    metadata_uri: String,
+    metadata_hash: [u8; 32],
+) -> Result<()> {
+    require!(metadata_hash != [0u8; 32], AcademicChainError::InvalidMetadataHash);
+
+    let tree = &ctx.accounts.certificate_tree;
+    require!(
+        tree.minted < 1u64 << tree.max_depth,
+        AcademicChainError::CertificateTreeFull
+    );
+    let merkle_tree = tree.merkle_tree;
+    let leaf_index = tree.minted as u32;
+
+    let enrollment = &ctx.accounts.enrollment;
+    let leaf_hash = compressed_certificate_leaf(&CompressedCertificateLeaf {
+        merkle_tree,
+        leaf_index,
+        enrollment: enrollment.key(),
+        student: enrollment.student,
+        course_id: enrollment.course_id.clone(),
+        grade: enrollment.grade,
+        completion_date: enrollment.completion_date.ok_or(AcademicChainError::CourseNotCompleted)?,
+        metadata_hash,
+    });
+
+    let bump = [tree.bump];
+    let seeds: &[&[u8]] = &[CERTIFICATE_TREE_SEED, merkle_tree.as_ref(), &bump];
+    append_leaf(
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.certificate_tree.to_account_info(),
+        &ctx.accounts.noop_program.to_account_info(),
+        seeds,
+        leaf_hash,
+    )?;
+    ctx.accounts.certificate_tree.minted += 1;
+
+    // The leaf takes the place of a certificate mint
+    let enrollment = &mut ctx.accounts.enrollment;
+    enrollment.compressed_certificate = Some(CompressedCertificate {
+        merkle_tree,
+        leaf_index,
+        leaf_hash,
+    });
+    enrollment.metadata_hash = Some(metadata_hash);
+
+    emit_cpi!(CompressedCertificateMinted {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        merkle_tree,
+        leaf_index,
+        leaf_hash,
+        course_id,
+        term_id,
+        grade: enrollment.grade,
+        metadata_uri,
+        metadata_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Compressed certificate minted");
+    msg!("Student: {}", enrollment.student);
+    msg!("Tree: {} leaf {}", merkle_tree, leaf_index);
+
+    Ok(())
+}
@@ -1,15 +1,71 @@
-pub mod initialize;
+pub mod create_institution;
+pub mod set_grade_scale;
+pub mod update_config;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod create_config_governance;
+pub mod propose_config_change;
+pub mod approve_config_change;
+pub mod execute_config_change;
+pub mod cancel_config_change;
+pub mod create_student_profile;
 pub mod purchase_credits;
 pub mod create_course;
+pub mod update_course;
+pub mod create_term;
+pub mod create_offering;
 pub mod register_course;
+pub mod retake_course;
 pub mod complete_course;
+pub mod complete_course_batch;
+pub mod amend_grade;
+pub mod expire_enrollment;
 pub mod mint_certificate;
 pub mod claim_graduation;
+pub mod close_enrollment;
+pub mod close_profile;
+pub mod anchor_transcript;
+pub mod verify_transcript_entry;
+pub mod create_certificate_batch;
+pub mod claim_batch_certificate;
+pub mod verify_batch_certificate;
+pub mod create_certificate_tree;
+pub mod mint_compressed_certificate;
+pub mod create_course_equivalency;
+pub mod transfer_completion;
 
-pub use initialize::*;
+pub use create_institution::*;
+pub use set_grade_scale::*;
+pub use update_config::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use create_config_governance::*;
+pub use propose_config_change::*;
+pub use approve_config_change::*;
+pub use execute_config_change::*;
+pub use cancel_config_change::*;
+pub use create_student_profile::*;
 pub use purchase_credits::*;
 pub use create_course::*;
+pub use update_course::*;
+pub use create_term::*;
+pub use create_offering::*;
 pub use register_course::*;
+pub use retake_course::*;
 pub use complete_course::*;
+pub use complete_course_batch::*;
+pub use amend_grade::*;
+pub use expire_enrollment::*;
 pub use mint_certificate::*;
 pub use claim_graduation::*;
+pub use close_enrollment::*;
+pub use close_profile::*;
+pub use anchor_transcript::*;
+pub use verify_transcript_entry::*;
+pub use create_certificate_batch::*;
+pub use claim_batch_certificate::*;
+pub use verify_batch_certificate::*;
+pub use create_certificate_tree::*;
+pub use mint_compressed_certificate::*;
+pub use create_course_equivalency::*;
+pub use transfer_completion::*;
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeConfigChange<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        mut,
+        seeds = [b"governance", institution.key().as_ref()],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, ConfigGovernance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = ConfigProposal::LEN,
+        seeds = [
+            b"config_proposal",
+            institution.key().as_ref(),
+            &governance.proposal_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ProposeConfigChange>, change: ConfigChange) -> Result<()> {
+    let governance = &mut ctx.accounts.governance;
+    let proposer = ctx.accounts.proposer.key();
+
+    require!(governance.is_admin(&proposer), AcademicChainError::NotGovernanceAdmin);
+    require!(!change.is_empty(), AcademicChainError::EmptyConfigChange);
+    validate_governance_change(governance, &change)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.institution = governance.institution;
+    proposal.proposal_id = governance.proposal_count;
+    proposal.proposer = proposer;
+    proposal.change = change;
+    // Proposing counts as the proposer's approval
+    proposal.approvals = vec![proposer];
+    proposal.created_at = now;
+    proposal.executable_at = now
+        .checked_add(governance.min_delay)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance
+        .proposal_count
+        .checked_add(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
+    emit_cpi!(ConfigChangeProposed {
+        institution: proposal.institution,
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        proposer,
+        change: proposal.change.clone(),
+        executable_at: proposal.executable_at,
+        timestamp: now,
+    });
+
+    msg!("✅ Config change #{} proposed by {}", proposal.proposal_id, proposer);
+    msg!("Executable At: {}", proposal.executable_at);
+
+    Ok(())
+}
@@ -2,28 +2,30 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PurchaseCredits<'info> {
     #[account(mut)]
     pub student: Signer<'info>,
 
     #[account(
-        seeds = [b"config"],
-        bump = config.bump,
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
     )]
-    pub config: Account<'info, ProgramConfig>,
+    pub institution: Account<'info, Institution>,
 
     #[account(
         mut,
-        address = config.treasury
+        address = institution.treasury
     )]
     /// CHECK: Treasury account validated by address constraint
     pub treasury: AccountInfo<'info>,
 
     #[account(
         mut,
-        address = config.credit_mint
+        address = institution.credit_mint
     )]
     pub credit_mint: Account<'info, Mint>,
 
@@ -35,11 +37,9 @@ pub struct PurchaseCredits<'info> {
     pub student_credit_account: Account<'info, TokenAccount>,
 
     #[account(
-        init_if_needed,
-        payer = student,
-        space = StudentProfile::LEN,
-        seeds = [b"student_profile", student.key().as_ref()],
-        bump,
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
     )]
     pub student_profile: Account<'info, StudentProfile>,
 
@@ -47,11 +47,11 @@ pub struct PurchaseCredits<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<PurchaseCredits>, amount: u64) -> Result<()> {
-    let config = &ctx.accounts.config;
+pub(crate) fn handler(ctx: Context<PurchaseCredits>, amount: u64) -> Result<()> {
+    let institution = &ctx.accounts.institution;
     
     // Calculate total cost
-    let total_cost = config.credit_price
+    let total_cost = institution.credit_price
         .checked_mul(amount)
         .ok_or(AcademicChainError::ArithmeticOverflow)?;
 
@@ -72,13 +72,13 @@ pub fn handler(ctx: Context<PurchaseCredits>, amount: u64) -> Result<()> {
     )?;
 
     // Mint credit tokens to student
-    let seeds: &[&[u8]] = &[b"config", &[config.bump]];
-    let signer = &[&seeds[..]];
+    let seeds: &[&[u8]] = &[b"institution", institution.institution_id.as_bytes(), &[institution.bump]];
+    let signer = &[seeds];
 
     let cpi_accounts = MintTo {
         mint: ctx.accounts.credit_mint.to_account_info(),
         to: ctx.accounts.student_credit_account.to_account_info(),
-        authority: config.to_account_info(),
+        authority: institution.to_account_info(),
     };
 
     let cpi_program = ctx.accounts.token_program.to_account_info();
@@ -88,16 +88,19 @@ pub fn handler(ctx: Context<PurchaseCredits>, amount: u64) -> Result<()> {
 
     // Update student profile
     let profile = &mut ctx.accounts.student_profile;
-    if profile.student == Pubkey::default() {
-        profile.student = ctx.accounts.student.key();
-        profile.created_at = Clock::get()?.unix_timestamp;
-        profile.bump = ctx.bumps.student_profile;
-    }
-    
     profile.total_credits_purchased = profile.total_credits_purchased
         .checked_add(amount)
         .ok_or(AcademicChainError::ArithmeticOverflow)?;
 
+    emit_cpi!(CreditsPurchased {
+        institution: profile.institution,
+        student: profile.student,
+        amount,
+        total_cost,
+        total_credits_purchased: profile.total_credits_purchased,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("✅ Purchased {} credits for {} lamports", amount, total_cost);
 
     Ok(())
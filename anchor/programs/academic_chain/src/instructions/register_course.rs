@@ -2,44 +2,63 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Burn, Mint};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(course_id: String)]
+#[instruction(course_id: String, term_id: String)]
 pub struct RegisterCourse<'info> {
     #[account(mut)]
     pub student: Signer<'info>,
 
     #[account(
-        seeds = [b"course", course_id.as_bytes()],
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
 
+    #[account(
+        mut,
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
     #[account(
         init,
         payer = student,
         space = CourseEnrollment::LEN,
-        seeds = [b"enrollment", student.key().as_ref(), course_id.as_bytes()],
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
         bump,
     )]
     pub enrollment: Account<'info, CourseEnrollment>,
 
+    // Created on the student's first registration for the course
     #[account(
-        mut,
-        seeds = [b"student_profile", student.key().as_ref()],
-        bump = student_profile.bump,
+        init_if_needed,
+        payer = student,
+        space = CourseAttempts::LEN,
+        seeds = [b"course_attempts", student.key().as_ref(), course.key().as_ref()],
+        bump,
     )]
-    pub student_profile: Account<'info, StudentProfile>,
+    pub course_attempts: Account<'info, CourseAttempts>,
 
     #[account(
-        seeds = [b"config"],
-        bump = config.bump,
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
     )]
-    pub config: Account<'info, ProgramConfig>,
+    pub student_profile: Account<'info, StudentProfile>,
 
     #[account(
         mut,
-        address = config.credit_mint
+        address = institution.credit_mint
     )]
     pub credit_mint: Account<'info, Mint>,
 
@@ -54,12 +73,36 @@ pub struct RegisterCourse<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<RegisterCourse>, course_id: String) -> Result<()> {
+pub(crate) fn handler(ctx: Context<RegisterCourse>, course_id: String, term_id: String) -> Result<()> {
     let course = &ctx.accounts.course;
     
     // Validate course is active
     require!(course.is_active, AcademicChainError::CourseNotActive);
 
+    // Attempts count per course, so registering in another term does not reset them
+    let course_attempts = &mut ctx.accounts.course_attempts;
+    require!(
+        course_attempts.attempts < course.max_attempts,
+        AcademicChainError::AttemptLimitReached
+    );
+    course_attempts.student = ctx.accounts.student.key();
+    course_attempts.course = course.key();
+    course_attempts.attempts += 1;
+    course_attempts.bump = ctx.bumps.course_attempts;
+
+    // Check the enrollment window and that the offering has a free seat
+    let now = Clock::get()?.unix_timestamp;
+    let offering = &mut ctx.accounts.offering;
+    require!(now >= offering.enrollment_opens_at, AcademicChainError::EnrollmentNotOpen);
+    require!(now < offering.enrollment_closes_at, AcademicChainError::EnrollmentClosed);
+    require!(
+        offering.enrollment_count < offering.capacity,
+        AcademicChainError::OfferingFull
+    );
+    offering.enrollment_count = offering.enrollment_count
+        .checked_add(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
     // Check student has enough credits
     require!(
         ctx.accounts.student_credit_account.amount >= course.required_credits,
@@ -82,12 +125,21 @@ pub fn handler(ctx: Context<RegisterCourse>, course_id: String) -> Result<()> {
     let enrollment = &mut ctx.accounts.enrollment;
     enrollment.student = ctx.accounts.student.key();
     enrollment.course_id = course_id;
+    enrollment.offering = offering.key();
     enrollment.credits_paid = course.required_credits;
-    enrollment.enrollment_date = Clock::get()?.unix_timestamp;
+    enrollment.enrollment_date = now;
     enrollment.completion_date = None;
-    enrollment.is_completed = false;
+    enrollment.status = EnrollmentStatus::Enrolled;
     enrollment.grade = 0;
+    enrollment.letter_grade = String::new();
+    enrollment.grade_points = 0;
+    enrollment.credit_hours = 0;
     enrollment.certificate_mint = None;
+    enrollment.metadata_hash = None;
+    enrollment.certificate_batch = None;
+    enrollment.compressed_certificate = None;
+    enrollment.transferred_from = None;
+    enrollment.attempts = Vec::new();
     enrollment.bump = ctx.bumps.enrollment;
 
     // Update student profile
@@ -95,8 +147,21 @@ pub fn handler(ctx: Context<RegisterCourse>, course_id: String) -> Result<()> {
     profile.total_credits_spent = profile.total_credits_spent
         .checked_add(course.required_credits)
         .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    profile.open_enrollments = profile.open_enrollments
+        .checked_add(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
 
-    msg!("✅ Registered for course: {}", course.course_name);
+    emit_cpi!(StudentRegistered {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        offering: enrollment.offering,
+        course_id: enrollment.course_id.clone(),
+        term_id,
+        credits_paid: enrollment.credits_paid,
+        timestamp: now,
+    });
+
+    msg!("✅ Registered for course: {} ({})", course.course_name, offering.term_id);
     msg!("Credits spent: {}", course.required_credits);
 
     Ok(())
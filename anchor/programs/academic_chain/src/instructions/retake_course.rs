@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Burn, Mint};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{is_passing_grade, revert_grade};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String, term_id: String)]
+pub struct RetakeCourse<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"offering", institution.key().as_ref(), course_id.as_bytes(), term_id.as_bytes()],
+        bump = offering.bump,
+    )]
+    pub offering: Account<'info, CourseOffering>,
+
+    // Grow the account by one history entry for the attempt being archived
+    #[account(
+        mut,
+        seeds = [b"enrollment", student.key().as_ref(), offering.key().as_ref()],
+        bump = enrollment.bump,
+        realloc = CourseEnrollment::space(enrollment.attempts.len() + 1),
+        realloc::payer = student,
+        realloc::zero = false,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"course_attempts", student.key().as_ref(), course.key().as_ref()],
+        bump = course_attempts.bump,
+    )]
+    pub course_attempts: Account<'info, CourseAttempts>,
+
+    #[account(
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    #[account(
+        mut,
+        address = institution.credit_mint
+    )]
+    pub credit_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = credit_mint,
+        associated_token::authority = student,
+    )]
+    pub student_credit_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RetakeCourse>, course_id: String, term_id: String) -> Result<()> {
+    let course = &ctx.accounts.course;
+    require!(course.is_active, AcademicChainError::CourseNotActive);
+
+    // Only a graded, failed attempt can be retaken, and grades must still be accepted
+    let enrollment = &mut ctx.accounts.enrollment;
+    require!(
+        enrollment.status == EnrollmentStatus::Completed && !is_passing_grade(enrollment.grade),
+        AcademicChainError::RetakeNotAllowed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now <= ctx.accounts.offering.grading_deadline,
+        AcademicChainError::GradingDeadlinePassed
+    );
+
+    // The new attempt must fit the course limit, counting every earlier offering
+    let course_attempts = &mut ctx.accounts.course_attempts;
+    require!(
+        course_attempts.attempts < course.max_attempts,
+        AcademicChainError::AttemptLimitReached
+    );
+    course_attempts.attempts += 1;
+    let attempt = (enrollment.attempts.len() + 1) as u8;
+
+    // Check student has enough credits
+    require!(
+        ctx.accounts.student_credit_account.amount >= course.required_credits,
+        AcademicChainError::InsufficientCredits
+    );
+
+    // Burn credits from student account
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.credit_mint.to_account_info(),
+        from: ctx.accounts.student_credit_account.to_account_info(),
+        authority: ctx.accounts.student.to_account_info(),
+    };
+
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+    token::burn(cpi_ctx, course.required_credits)?;
+
+    // The failed grade no longer counts towards the GPA
+    let profile = &mut ctx.accounts.student_profile;
+    revert_grade(profile, enrollment)?;
+    profile.courses_completed = profile.courses_completed
+        .checked_sub(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    profile.total_credits_spent = profile.total_credits_spent
+        .checked_add(course.required_credits)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
+    // Archive the failed attempt and reset the enrollment
+    let previous_grade = enrollment.grade;
+    let previous = EnrollmentAttempt {
+        attempt,
+        grade: previous_grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        enrolled_at: enrollment.enrollment_date,
+        completed_at: enrollment.completion_date,
+    };
+    enrollment.attempts.push(previous);
+    enrollment.credits_paid = enrollment.credits_paid
+        .checked_add(course.required_credits)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    enrollment.enrollment_date = now;
+    enrollment.completion_date = None;
+    enrollment.status = EnrollmentStatus::Enrolled;
+    enrollment.grade = 0;
+    enrollment.letter_grade = String::new();
+    enrollment.grade_points = 0;
+    enrollment.credit_hours = 0;
+
+    emit_cpi!(CourseRetaken {
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        offering: enrollment.offering,
+        course_id,
+        term_id,
+        attempt: attempt + 1,
+        previous_grade,
+        credits_paid: course.required_credits,
+        timestamp: now,
+    });
+
+    msg!("✅ Retaking course: {} (attempt {})", course.course_name, attempt + 1);
+    msg!("Credits spent: {}", course.required_credits);
+
+    Ok(())
+}
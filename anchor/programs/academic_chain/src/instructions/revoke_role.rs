@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(holder: Pubkey, role: Role)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // SuperAdmin assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", institution.key().as_ref(), holder.as_ref(), &[role as u8]],
+        bump = role_assignment.bump,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+}
+
+pub(crate) fn handler(ctx: Context<RevokeRole>, holder: Pubkey, role: Role) -> Result<()> {
+    let institution = &ctx.accounts.institution;
+    let authority = ctx.accounts.authority.key();
+
+    // Same rule as granting: a SuperAdmin can't remove another SuperAdmin
+    let required = if role == Role::SuperAdmin { None } else { ctx.accounts.authority_role.as_ref() };
+    require!(
+        has_role(institution, &authority, required, Role::SuperAdmin),
+        AcademicChainError::Unauthorized
+    );
+
+    emit_cpi!(RoleRevoked {
+        institution: institution.key(),
+        role_assignment: ctx.accounts.role_assignment.key(),
+        holder,
+        role,
+        revoked_by: authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Role {:?} revoked from {}", role, holder);
+
+    Ok(())
+}
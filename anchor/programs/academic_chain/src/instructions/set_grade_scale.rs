@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGradeScale<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // Registrar assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+}
+
+pub(crate) fn handler(ctx: Context<SetGradeScale>, grade_scale: Vec<GradeScaleEntry>) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::Registrar,
+    )?;
+
+    validate_grade_scale(&grade_scale)?;
+
+    let institution = &mut ctx.accounts.institution;
+    institution.grade_scale = grade_scale;
+
+    emit_cpi!(GradeScaleUpdated {
+        institution: institution.key(),
+        authority: ctx.accounts.authority.key(),
+        grade_scale: institution.grade_scale.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Grade scale updated ({} grades)", institution.grade_scale.len());
+
+    Ok(())
+}
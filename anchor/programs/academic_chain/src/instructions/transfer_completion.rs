@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(source_course_id: String, source_term_id: String, course_id: String)]
+pub struct TransferCompletion<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [b"institution", source_institution.institution_id.as_bytes()],
+        bump = source_institution.bump,
+    )]
+    pub source_institution: Account<'info, Institution>,
+
+    #[account(
+        seeds = [b"course", source_institution.key().as_ref(), source_course_id.as_bytes()],
+        bump = source_course.bump,
+    )]
+    pub source_course: Account<'info, Course>,
+
+    #[account(
+        seeds = [
+            b"offering",
+            source_institution.key().as_ref(),
+            source_course_id.as_bytes(),
+            source_term_id.as_bytes(),
+        ],
+        bump = source_offering.bump,
+    )]
+    pub source_offering: Account<'info, CourseOffering>,
+
+    #[account(
+        seeds = [b"enrollment", student.key().as_ref(), source_offering.key().as_ref()],
+        bump = source_enrollment.bump,
+        constraint = source_enrollment.student == student.key(),
+        constraint = source_enrollment.status == EnrollmentStatus::Completed @ AcademicChainError::CourseNotCompleted,
+    )]
+    pub source_enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        seeds = [b"equivalency", source_course.key().as_ref(), course.key().as_ref()],
+        bump = course_equivalency.bump,
+    )]
+    pub course_equivalency: Account<'info, CourseEquivalency>,
+
+    /// One transferred enrollment per source enrollment and receiving
+    /// institution, so a completion can't be carried over to two equivalent
+    /// courses. The equivalency stands in for the offering in `offering`.
+    #[account(
+        init,
+        payer = student,
+        space = CourseEnrollment::LEN,
+        seeds = [b"enrollment", institution.key().as_ref(), source_enrollment.key().as_ref()],
+        bump,
+    )]
+    pub enrollment: Account<'info, CourseEnrollment>,
+
+    #[account(
+        mut,
+        seeds = [b"student_profile", institution.key().as_ref(), student.key().as_ref()],
+        bump = student_profile.bump,
+    )]
+    pub student_profile: Account<'info, StudentProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The grade carries over and is mapped through the receiving institution's
+/// grade scale and the equivalent course's credit hours. No credits are paid,
+/// and since the enrollment has no offering it is never closed, so it is not
+/// counted in `open_enrollments`.
+pub(crate) fn handler(
+    ctx: Context<TransferCompletion>,
+    source_course_id: String,
+    source_term_id: String,
+    course_id: String,
+) -> Result<()> {
+    // Only carry over grades from a closed gradebook. A later amendment of the
+    // source grade is caught by verifiers through `transferred_from`.
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now > ctx.accounts.source_offering.grading_deadline,
+        AcademicChainError::GradingDeadlineNotReached
+    );
+
+    let source = &ctx.accounts.source_enrollment;
+    require!(is_passing_grade(source.grade), AcademicChainError::TransferNotPassed);
+
+    let entry = lookup_grade(&ctx.accounts.institution.grade_scale, source.grade)?;
+    let completion_date = source.completion_date.unwrap_or(now);
+
+    let enrollment = &mut ctx.accounts.enrollment;
+    enrollment.student = ctx.accounts.student.key();
+    enrollment.course_id = course_id;
+    enrollment.offering = ctx.accounts.course_equivalency.key();
+    enrollment.credits_paid = 0;
+    enrollment.enrollment_date = now;
+    enrollment.completion_date = Some(completion_date);
+    enrollment.status = EnrollmentStatus::Completed;
+    enrollment.certificate_mint = None;
+    enrollment.metadata_hash = None;
+    enrollment.certificate_batch = None;
+    enrollment.compressed_certificate = None;
+    enrollment.transferred_from = Some(source.key());
+    enrollment.attempts = Vec::new();
+    enrollment.bump = ctx.bumps.enrollment;
+
+    let profile = &mut ctx.accounts.student_profile;
+    apply_grade(profile, enrollment, entry, source.grade, ctx.accounts.course.credit_hours)?;
+    profile.courses_completed = profile.courses_completed
+        .checked_add(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
+    emit_cpi!(CompletionTransferred {
+        institution: ctx.accounts.institution.key(),
+        student: enrollment.student,
+        enrollment: enrollment.key(),
+        course_equivalency: enrollment.offering,
+        course_id: enrollment.course_id.clone(),
+        source_enrollment: source.key(),
+        source_course_id,
+        source_term_id,
+        grade: enrollment.grade,
+        letter_grade: enrollment.letter_grade.clone(),
+        grade_points: enrollment.grade_points,
+        credit_hours: enrollment.credit_hours,
+        gpa: profile.gpa,
+        completion_date,
+        timestamp: now,
+    });
+
+    msg!("✅ Completion transferred: {} -> {}", source.course_id, enrollment.course_id);
+    msg!("Grade: {} ({})", enrollment.grade, enrollment.letter_grade);
+    msg!("GPA: {}", profile.gpa);
+
+    Ok(())
+}
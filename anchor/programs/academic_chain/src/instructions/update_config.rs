@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // FinanceAdmin assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateConfig>,
+    new_authority: Option<Pubkey>,
+    treasury: Option<Pubkey>,
+    credit_price: Option<u64>,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::FinanceAdmin,
+    )?;
+
+    let institution = &mut ctx.accounts.institution;
+
+    // Once governed, moving funds or control takes approved, timelocked proposals
+    require!(
+        !institution.governed || (treasury.is_none() && new_authority.is_none()),
+        AcademicChainError::ConfigChangeGoverned
+    );
+
+    if let Some(credit_price) = credit_price {
+        require!(credit_price > 0, AcademicChainError::InvalidCreditPrice);
+        institution.credit_price = credit_price;
+    }
+
+    if let Some(treasury) = treasury {
+        institution.treasury = treasury;
+    }
+
+    if let Some(new_authority) = new_authority {
+        // Handing over the institution is never delegated to a role
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            institution.authority,
+            AcademicChainError::Unauthorized
+        );
+        institution.authority = new_authority;
+    }
+
+    emit_cpi!(ConfigUpdated {
+        institution: institution.key(),
+        authority: institution.authority,
+        treasury: institution.treasury,
+        credit_price: institution.credit_price,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Institution config updated: {}", institution.institution_id);
+    msg!("Authority: {}", institution.authority);
+    msg!("Treasury: {}", institution.treasury);
+    msg!("Credit Price: {}", institution.credit_price);
+
+    Ok(())
+}
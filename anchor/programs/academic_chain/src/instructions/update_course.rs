@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String)]
+pub struct UpdateCourse<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"institution", institution.institution_id.as_bytes()],
+        bump = institution.bump,
+    )]
+    pub institution: Account<'info, Institution>,
+
+    // CourseManager assignment of the signer; not needed for the institution authority
+    pub authority_role: Option<Account<'info, RoleAssignment>>,
+
+    #[account(
+        mut,
+        seeds = [b"course", institution.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+}
+
+/// Fields left as `None` keep their current value. Changes apply to future
+/// registrations and grades; existing enrollments keep what they were charged
+/// and the credit hours already applied to the GPA.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handler(
+    ctx: Context<UpdateCourse>,
+    _course_id: String,
+    course_name: Option<String>,
+    instructor: Option<Pubkey>,
+    required_credits: Option<u64>,
+    credit_hours: Option<u8>,
+    max_attempts: Option<u8>,
+    is_active: Option<bool>,
+) -> Result<()> {
+    require_role(
+        &ctx.accounts.institution,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.authority_role.as_ref(),
+        Role::CourseManager,
+    )?;
+
+    let course = &mut ctx.accounts.course;
+
+    if let Some(course_name) = course_name {
+        require!(
+            course_name.len() <= Course::MAX_NAME_LEN,
+            AcademicChainError::InvalidCourseName
+        );
+        course.course_name = course_name;
+    }
+
+    if let Some(instructor) = instructor {
+        course.instructor = instructor;
+    }
+
+    if let Some(required_credits) = required_credits {
+        require!(
+            required_credits > 0,
+            AcademicChainError::InvalidCredits
+        );
+        course.required_credits = required_credits;
+    }
+
+    if let Some(credit_hours) = credit_hours {
+        require!(
+            credit_hours > 0 && credit_hours <= MAX_CREDIT_HOURS,
+            AcademicChainError::InvalidCreditHours
+        );
+        course.credit_hours = credit_hours;
+    }
+
+    if let Some(max_attempts) = max_attempts {
+        require!(
+            max_attempts > 0 && max_attempts <= MAX_COURSE_ATTEMPTS,
+            AcademicChainError::InvalidMaxAttempts
+        );
+        course.max_attempts = max_attempts;
+    }
+
+    if let Some(is_active) = is_active {
+        course.is_active = is_active;
+    }
+
+    emit_cpi!(CourseUpdated {
+        institution: course.institution,
+        course: course.key(),
+        course_id: course.course_id.clone(),
+        course_name: course.course_name.clone(),
+        instructor: course.instructor,
+        required_credits: course.required_credits,
+        credit_hours: course.credit_hours,
+        max_attempts: course.max_attempts,
+        is_active: course.is_active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("✅ Course updated: {}", course.course_id);
+    Ok(())
+}
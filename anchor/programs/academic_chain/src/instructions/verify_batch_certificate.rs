@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct VerifyBatchCertificate<'info> {
+    #[account(
+        seeds = [b"certificate_batch", certificate_batch.offering.as_ref(), certificate_batch.merkle_root.as_ref()],
+        bump = certificate_batch.bump,
+    )]
+    pub certificate_batch: Account<'info, CertificateBatch>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<VerifyBatchCertificate>,
+    leaf: CertificateLeaf,
+    proof: Vec<[u8; 32]>,
+) -> Result<bool> {
+    let batch = &ctx.accounts.certificate_batch;
+    let valid = proof.len() <= MAX_MERKLE_PROOF_LEN
+        && verify_merkle_proof(&certificate_leaf(&leaf), &proof, &batch.merkle_root);
+
+    if valid {
+        msg!("✅ Batch certificate verified: {} {}", leaf.student, leaf.course_id);
+    } else {
+        msg!("Certificate not in batch: {} {}", leaf.student, leaf.course_id);
+    }
+
+    Ok(valid)
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::utils::*;
+
+#[derive(Accounts)]
+pub struct VerifyTranscriptEntry<'info> {
+    #[account(
+        seeds = [
+            b"transcript",
+            transcript_anchor.institution.as_ref(),
+            transcript_anchor.student.as_ref(),
+            transcript_anchor.merkle_root.as_ref(),
+        ],
+        bump = transcript_anchor.bump,
+    )]
+    pub transcript_anchor: Account<'info, TranscriptAnchor>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<VerifyTranscriptEntry>,
+    entry: TranscriptEntry,
+    proof: Vec<[u8; 32]>,
+) -> Result<bool> {
+    let anchor = &ctx.accounts.transcript_anchor;
+    let valid = proof.len() <= MAX_MERKLE_PROOF_LEN
+        && verify_merkle_proof(&transcript_leaf(&entry), &proof, &anchor.merkle_root);
+
+    if valid {
+        msg!("✅ Transcript entry verified: {} {}", entry.course_id, entry.term_id);
+    } else {
+        msg!("Transcript entry not in anchored transcript: {} {}", entry.course_id, entry.term_id);
+    }
+
+    Ok(valid)
+}
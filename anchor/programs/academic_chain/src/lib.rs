@@ -1,12 +1,16 @@
+#![allow(clippy::result_large_err)]
+
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod constants;
 pub mod utils;
 
 use instructions::*;
+use state::{CertificateLeaf, ConfigChange, GradeScaleEntry, Role, StudentGrade, TranscriptEntry};
 
 declare_id!("9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F");
 
@@ -14,9 +18,80 @@ declare_id!("9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F");
 pub mod academic_chain {
     use super::*;
 
-    /// Initialize the program
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::handler(ctx)
+    /// Register an institution with its own authority, treasury and credit mint
+    pub fn create_institution(
+        ctx: Context<CreateInstitution>,
+        institution_id: String,
+        name: String,
+    ) -> Result<()> {
+        instructions::create_institution::handler(ctx, institution_id, name)
+    }
+
+    /// Replace the grade scale used for letter grades and GPA
+    pub fn set_grade_scale(
+        ctx: Context<SetGradeScale>,
+        grade_scale: Vec<GradeScaleEntry>,
+    ) -> Result<()> {
+        instructions::set_grade_scale::handler(ctx, grade_scale)
+    }
+
+    /// Update the credit price, or the authority and treasury of an ungoverned institution
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_authority: Option<Pubkey>,
+        treasury: Option<Pubkey>,
+        credit_price: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, new_authority, treasury, credit_price)
+    }
+
+    /// Grant an administrative role at the institution (authority or SuperAdmin)
+    pub fn grant_role(ctx: Context<GrantRole>, holder: Pubkey, role: Role) -> Result<()> {
+        instructions::grant_role::handler(ctx, holder, role)
+    }
+
+    /// Revoke an administrative role and reclaim its rent (authority or SuperAdmin)
+    pub fn revoke_role(ctx: Context<RevokeRole>, holder: Pubkey, role: Role) -> Result<()> {
+        instructions::revoke_role::handler(ctx, holder, role)
+    }
+
+    /// Require M-of-N admin approval and a delay for authority, treasury and credit mint changes
+    pub fn create_config_governance(
+        ctx: Context<CreateConfigGovernance>,
+        admins: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        instructions::create_config_governance::handler(ctx, admins, threshold, min_delay)
+    }
+
+    /// Queue a config change, approved by the proposing admin
+    pub fn propose_config_change(ctx: Context<ProposeConfigChange>, change: ConfigChange) -> Result<()> {
+        instructions::propose_config_change::handler(ctx, change)
+    }
+
+    /// Add a governance admin's approval to a queued config change
+    pub fn approve_config_change(ctx: Context<ApproveConfigChange>, proposal_id: u64) -> Result<()> {
+        instructions::approve_config_change::handler(ctx, proposal_id)
+    }
+
+    /// Apply an approved config change once its delay has passed
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>, proposal_id: u64) -> Result<()> {
+        instructions::execute_config_change::handler(ctx, proposal_id)
+    }
+
+    /// Drop a queued config change (any governance admin)
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>, proposal_id: u64) -> Result<()> {
+        instructions::cancel_config_change::handler(ctx, proposal_id)
+    }
+
+    /// Create a student profile
+    pub fn create_student_profile(
+        ctx: Context<CreateStudentProfile>,
+        display_name: Option<String>,
+        metadata_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::create_student_profile::handler(ctx, display_name, metadata_uri)
     }
 
     /// Purchase credit tokens
@@ -34,34 +109,155 @@ pub mod academic_chain {
         course_name: String,
         instructor: Pubkey,
         required_credits: u64,
+        credit_hours: u8,
+        max_attempts: u8,
+    ) -> Result<()> {
+        instructions::create_course::handler(
+            ctx,
+            course_id,
+            course_name,
+            instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+        )
+    }
+
+    /// Update an existing course
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_course(
+        ctx: Context<UpdateCourse>,
+        course_id: String,
+        course_name: Option<String>,
+        instructor: Option<Pubkey>,
+        required_credits: Option<u64>,
+        credit_hours: Option<u8>,
+        max_attempts: Option<u8>,
+        is_active: Option<bool>,
+    ) -> Result<()> {
+        instructions::update_course::handler(
+            ctx,
+            course_id,
+            course_name,
+            instructor,
+            required_credits,
+            credit_hours,
+            max_attempts,
+            is_active,
+        )
+    }
+
+    /// Create an academic term
+    pub fn create_term(
+        ctx: Context<CreateTerm>,
+        term_id: String,
+        name: String,
+        starts_at: i64,
+        ends_at: i64,
+        registration_opens_at: i64,
+        registration_closes_at: i64,
     ) -> Result<()> {
-        instructions::create_course::handler(ctx, course_id, course_name, instructor, required_credits)
+        instructions::create_term::handler(
+            ctx,
+            term_id,
+            name,
+            starts_at,
+            ends_at,
+            registration_opens_at,
+            registration_closes_at,
+        )
     }
 
-    /// Register for a course
+    /// Offer a course in a term
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_offering(
+        ctx: Context<CreateOffering>,
+        course_id: String,
+        term_id: String,
+        instructor: Pubkey,
+        capacity: u32,
+        enrollment_opens_at: Option<i64>,
+        enrollment_closes_at: Option<i64>,
+        grading_deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::create_offering::handler(
+            ctx,
+            course_id,
+            term_id,
+            instructor,
+            capacity,
+            enrollment_opens_at,
+            enrollment_closes_at,
+            grading_deadline,
+        )
+    }
+
+    /// Register for a course offering
     pub fn register_course(
         ctx: Context<RegisterCourse>,
         course_id: String,
+        term_id: String,
     ) -> Result<()> {
-        instructions::register_course::handler(ctx, course_id)
+        instructions::register_course::handler(ctx, course_id, term_id)
+    }
+
+    /// Retake a failed course in the same offering
+    pub fn retake_course(
+        ctx: Context<RetakeCourse>,
+        course_id: String,
+        term_id: String,
+    ) -> Result<()> {
+        instructions::retake_course::handler(ctx, course_id, term_id)
     }
 
     /// Mark course as completed
     pub fn complete_course(
         ctx: Context<CompleteCourse>,
         course_id: String,
+        term_id: String,
+        grade: u8,
+    ) -> Result<()> {
+        instructions::complete_course::handler(ctx, course_id, term_id, grade)
+    }
+
+    /// Mark a course as completed for many students at once
+    pub fn complete_course_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CompleteCourseBatch<'info>>,
+        course_id: String,
+        term_id: String,
+        grades: Vec<StudentGrade>,
+    ) -> Result<()> {
+        instructions::complete_course_batch::handler(ctx, course_id, term_id, grades)
+    }
+
+    /// Amend the grade of a completed course (offering instructor or Registrar)
+    pub fn amend_grade(
+        ctx: Context<AmendGrade>,
+        course_id: String,
+        term_id: String,
         grade: u8,
     ) -> Result<()> {
-        instructions::complete_course::handler(ctx, course_id, grade)
+        instructions::amend_grade::handler(ctx, course_id, term_id, grade)
+    }
+
+    /// Mark an enrollment left ungraded past the grading deadline as incomplete
+    pub fn expire_enrollment(
+        ctx: Context<ExpireEnrollment>,
+        course_id: String,
+        term_id: String,
+    ) -> Result<()> {
+        instructions::expire_enrollment::handler(ctx, course_id, term_id)
     }
 
     /// Mint NFT certificate
     pub fn mint_certificate(
         ctx: Context<MintCertificate>,
         course_id: String,
+        term_id: String,
         metadata_uri: String,
+        metadata_hash: [u8; 32],
     ) -> Result<()> {
-        instructions::mint_certificate::handler(ctx, course_id, metadata_uri)
+        instructions::mint_certificate::handler(ctx, course_id, term_id, metadata_uri, metadata_hash)
     }
 
     /// Claim graduation NFT
@@ -71,4 +267,105 @@ pub mod academic_chain {
     ) -> Result<()> {
         instructions::claim_graduation::handler(ctx, required_courses)
     }
+
+    /// Close a finished enrollment and reclaim its rent
+    pub fn close_enrollment(
+        ctx: Context<CloseEnrollment>,
+        course_id: String,
+        term_id: String,
+    ) -> Result<()> {
+        instructions::close_enrollment::handler(ctx, course_id, term_id)
+    }
+
+    /// Close a student profile with no open enrollments
+    pub fn close_profile(ctx: Context<CloseProfile>) -> Result<()> {
+        instructions::close_profile::handler(ctx)
+    }
+
+    /// Anchor the Merkle root of a student's signed transcript
+    pub fn anchor_transcript(
+        ctx: Context<AnchorTranscript>,
+        merkle_root: [u8; 32],
+        entry_count: u16,
+    ) -> Result<()> {
+        instructions::anchor_transcript::handler(ctx, merkle_root, entry_count)
+    }
+
+    /// Check a Merkle proof of one transcript entry against an anchored root
+    pub fn verify_transcript_entry(
+        ctx: Context<VerifyTranscriptEntry>,
+        entry: TranscriptEntry,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<bool> {
+        instructions::verify_transcript_entry::handler(ctx, entry, proof)
+    }
+
+    /// Post a Merkle root over an offering's certificates; signed by the instructor or a Reviewer
+    pub fn create_certificate_batch(
+        ctx: Context<CreateCertificateBatch>,
+        course_id: String,
+        term_id: String,
+        merkle_root: [u8; 32],
+        certificate_count: u16,
+    ) -> Result<()> {
+        instructions::create_certificate_batch::handler(ctx, course_id, term_id, merkle_root, certificate_count)
+    }
+
+    /// Claim a certificate from a batch with a Merkle proof, linking it to the enrollment
+    pub fn claim_batch_certificate(
+        ctx: Context<ClaimBatchCertificate>,
+        course_id: String,
+        term_id: String,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_batch_certificate::handler(ctx, course_id, term_id, proof)
+    }
+
+    /// Check a Merkle proof of one certificate against a batch root
+    pub fn verify_batch_certificate(
+        ctx: Context<VerifyBatchCertificate>,
+        leaf: CertificateLeaf,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<bool> {
+        instructions::verify_batch_certificate::handler(ctx, leaf, proof)
+    }
+
+    /// Create a concurrent Merkle tree for compressed certificates (Reviewer)
+    pub fn create_certificate_tree(
+        ctx: Context<CreateCertificateTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::create_certificate_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Append a course certificate to a certificate tree instead of minting it
+    pub fn mint_compressed_certificate(
+        ctx: Context<MintCompressedCertificate>,
+        course_id: String,
+        term_id: String,
+        metadata_uri: String,
+        metadata_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::mint_compressed_certificate::handler(ctx, course_id, term_id, metadata_uri, metadata_hash)
+    }
+
+    /// Accept a course at another institution as one of ours (signed by a Registrar of each)
+    pub fn create_course_equivalency(
+        ctx: Context<CreateCourseEquivalency>,
+        source_course_id: String,
+        course_id: String,
+    ) -> Result<()> {
+        instructions::create_course_equivalency::handler(ctx, source_course_id, course_id)
+    }
+
+    /// Carry a passed course over from another institution through a course equivalency
+    pub fn transfer_completion(
+        ctx: Context<TransferCompletion>,
+        source_course_id: String,
+        source_term_id: String,
+        course_id: String,
+    ) -> Result<()> {
+        instructions::transfer_completion::handler(ctx, source_course_id, source_term_id, course_id)
+    }
 }
@@ -1,26 +1,146 @@
 use anchor_lang::prelude::*;
 
-/// Program configuration
+/// A school on the program. Everything it runs (courses, terms, offerings,
+/// student profiles) is derived from this account's address.
 #[account]
-pub struct ProgramConfig {
+pub struct Institution {
+    pub institution_id: String,
+    pub name: String,
     pub authority: Pubkey,
     pub credit_mint: Pubkey,
     pub treasury: Pubkey,
     pub credit_price: u64,  // Price in lamports per credit
+    pub grade_scale: Vec<GradeScaleEntry>,  // Ordered by descending min_score
+    pub governed: bool,  // Authority, treasury and credit mint change only through config proposals
     pub bump: u8,
 }
 
-impl ProgramConfig {
-    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+impl Institution {
+    pub const MAX_ID_LEN: usize = 16;
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_GRADE_SCALE_ENTRIES: usize = 13;
+    pub const LEN: usize = 8 + 4 + Self::MAX_ID_LEN + 4 + Self::MAX_NAME_LEN + 32 + 32 + 32 + 8
+        + 4 + Self::MAX_GRADE_SCALE_ENTRIES * GradeScaleEntry::LEN
+        + 1 + 1;
+}
+
+/// Admin keys that approve sensitive config changes of an institution:
+/// `["governance", institution]`
+#[account]
+pub struct ConfigGovernance {
+    pub institution: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub threshold: u8,  // Approvals needed to execute a proposal
+    pub min_delay: i64,  // Seconds between proposing and executing
+    pub proposal_count: u64,  // Next proposal ID
+    pub bump: u8,
+}
+
+impl ConfigGovernance {
+    pub const MAX_ADMINS: usize = 10;
+    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_ADMINS * 32 + 1 + 8 + 8 + 1;
+
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.admins.contains(key)
+    }
+}
+
+/// Institution and governance values a proposal sets; `None` keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub authority: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub credit_mint: Option<Pubkey>,
+    pub admins: Option<Vec<Pubkey>>,
+    pub threshold: Option<u8>,
+    pub min_delay: Option<i64>,
+}
+
+impl ConfigChange {
+    pub const MAX_LEN: usize = 3 * (1 + 32) + 1 + 4 + ConfigGovernance::MAX_ADMINS * 32 + (1 + 1) + (1 + 8);
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Queued config change: `["config_proposal", institution, proposal_id as le bytes]`.
+/// Closed to the proposer when executed or cancelled.
+#[account]
+pub struct ConfigProposal {
+    pub institution: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    pub approvals: Vec<Pubkey>,  // Admins that approved, proposer first
+    pub created_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl ConfigProposal {
+    pub const LEN: usize = 8 + 32 + 8 + 32 + ConfigChange::MAX_LEN
+        + 4 + ConfigGovernance::MAX_ADMINS * 32
+        + 8 + 8 + 1;
+}
+
+/// Administrative roles an institution grants through `RoleAssignment`
+/// accounts. The institution authority holds every role implicitly.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    SuperAdmin,  // Grants and revokes the other roles
+    Registrar,  // Terms, grade scale, grade overrides, transcripts, equivalencies
+    FinanceAdmin,  // Treasury and credit price
+    CourseManager,  // Catalog and offerings
+    Reviewer,  // Certificate batches and trees
+}
+
+/// One role held by one key at one institution:
+/// `["role", institution, holder, role as u8]`
+#[account]
+pub struct RoleAssignment {
+    pub institution: Pubkey,
+    pub holder: Pubkey,
+    pub role: Role,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 32 + 8 + 1;
+}
+
+/// Grade scale row: scores at or above `min_score` map to this letter grade
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GradeScaleEntry {
+    pub min_score: u8,
+    pub letter: String,
+    pub grade_points: u16,  // Grade points x100 (e.g. 400 = 4.00)
+}
+
+impl GradeScaleEntry {
+    pub const MAX_LETTER_LEN: usize = 2;
+    pub const LEN: usize = 1 + 4 + Self::MAX_LETTER_LEN + 2;
+}
+
+/// Student and grade pair for batch grading
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StudentGrade {
+    pub student: Pubkey,
+    pub grade: u8,
 }
 
 /// Course information
 #[account]
 pub struct Course {
+    pub institution: Pubkey,
     pub course_id: String,
     pub course_name: String,
     pub instructor: Pubkey,
-    pub required_credits: u64,
+    pub required_credits: u64,  // Price in credit tokens
+    pub credit_hours: u8,  // Academic weight used for GPA
+    pub max_attempts: u8,  // Attempts allowed per student across all offerings, including retakes
     pub is_active: bool,
     pub created_at: i64,
     pub bump: u8,
@@ -29,7 +149,69 @@ pub struct Course {
 impl Course {
     pub const MAX_ID_LEN: usize = 32;
     pub const MAX_NAME_LEN: usize = 100;
-    pub const LEN: usize = 8 + 4 + Self::MAX_ID_LEN + 4 + Self::MAX_NAME_LEN + 32 + 8 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_ID_LEN + 4 + Self::MAX_NAME_LEN + 32 + 8 + 1 + 1 + 1 + 8 + 1;
+}
+
+/// Academic term (e.g. Fall 2025)
+#[account]
+pub struct Term {
+    pub institution: Pubkey,
+    pub term_id: String,
+    pub name: String,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub registration_opens_at: i64,
+    pub registration_closes_at: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Term {
+    pub const MAX_ID_LEN: usize = 16;
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_ID_LEN + 4 + Self::MAX_NAME_LEN + 8 + 8 + 8 + 8 + 8 + 1;
+}
+
+/// A course running in a specific term, with its own instructor and roster
+#[account]
+pub struct CourseOffering {
+    pub institution: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub instructor: Pubkey,
+    pub capacity: u32,
+    pub enrollment_count: u32,
+    pub enrollment_opens_at: i64,
+    pub enrollment_closes_at: i64,
+    pub grading_deadline: i64,  // Ungraded enrollments can be expired after this
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CourseOffering {
+    pub const LEN: usize = 8 + 32 + 4 + Course::MAX_ID_LEN + 4 + Term::MAX_ID_LEN + 32 + 4 + 4 + 8 + 8 + 8 + 8 + 1;
+}
+
+/// Enrollment lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnrollmentStatus {
+    Enrolled,
+    Completed,
+    Incomplete,  // Not graded before the grading deadline
+}
+
+/// A previous, failed attempt at a course kept on the enrollment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EnrollmentAttempt {
+    pub attempt: u8,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub enrolled_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+impl EnrollmentAttempt {
+    pub const LEN: usize = 1 + 1 + 4 + GradeScaleEntry::MAX_LETTER_LEN + 8 + 9;
 }
 
 /// Student course enrollment
@@ -37,32 +219,260 @@ impl Course {
 pub struct CourseEnrollment {
     pub student: Pubkey,
     pub course_id: String,
+    pub offering: Pubkey,
     pub credits_paid: u64,
     pub enrollment_date: i64,
     pub completion_date: Option<i64>,
-    pub is_completed: bool,
+    pub status: EnrollmentStatus,
     pub grade: u8,
+    pub letter_grade: String,
+    pub grade_points: u16,  // Grade points x100 applied to the student's GPA
+    pub credit_hours: u8,  // Credit hours applied to the student's GPA
     pub certificate_mint: Option<Pubkey>,
+    pub metadata_hash: Option<[u8; 32]>,  // SHA-256 of the certificate metadata JSON
+    pub certificate_batch: Option<Pubkey>,  // CertificateBatch the certificate was claimed from
+    pub compressed_certificate: Option<CompressedCertificate>,  // Leaf in a CertificateTree, minted instead of a mint
+    pub transferred_from: Option<Pubkey>,  // Enrollment at another institution this completion was transferred from
+    pub attempts: Vec<EnrollmentAttempt>,  // Earlier attempts, oldest first; grows on retake
     pub bump: u8,
 }
 
 impl CourseEnrollment {
     pub const MAX_COURSE_ID_LEN: usize = 32;
-    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_COURSE_ID_LEN + 8 + 8 + 9 + 1 + 1 + 33 + 1;
+    // Size with an empty attempts history
+    pub const LEN: usize = 8 + 32 + 4 + Self::MAX_COURSE_ID_LEN + 32 + 8 + 8 + 9 + 1 + 1
+        + 4 + GradeScaleEntry::MAX_LETTER_LEN + 2 + 1
+        + 33 + 33 + 33 + 1 + CompressedCertificate::LEN + 33 + 4 + 1;
+
+    /// Account size with `attempts` entries in the history
+    pub fn space(attempts: usize) -> usize {
+        Self::LEN + attempts * EnrollmentAttempt::LEN
+    }
 }
 
-/// Student profile
+/// Attempts a student has made at a course across all of its offerings:
+/// `["course_attempts", student, course]`. Registrations and retakes both
+/// count, so a new term's offering does not reset `Course::max_attempts`.
+#[account]
+pub struct CourseAttempts {
+    pub student: Pubkey,
+    pub course: Pubkey,
+    pub attempts: u8,
+    pub bump: u8,
+}
+
+impl CourseAttempts {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1;
+}
+
+/// Points a certificate mint back at its enrollment, so verifiers resolve a
+/// mint with one lookup: `["certificate_record", certificate_mint]`
+#[account]
+pub struct CertificateRecord {
+    pub enrollment: Pubkey,
+    pub bump: u8,
+}
+
+impl CertificateRecord {
+    pub const LEN: usize = 8 + 32 + 1;
+}
+
+/// A course at another institution that an institution accepts in place of
+/// one of its own. Both authorities sign it; students then carry completions
+/// over with `transfer_completion`.
+#[account]
+pub struct CourseEquivalency {
+    pub institution: Pubkey,  // Receiving institution
+    pub course: Pubkey,  // Course credited at the receiving institution
+    pub course_id: String,
+    pub source_institution: Pubkey,
+    pub source_course: Pubkey,
+    pub source_course_id: String,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CourseEquivalency {
+    pub const LEN: usize = 8 + 32 + 32 + 4 + Course::MAX_ID_LEN + 32 + 32 + 4 + Course::MAX_ID_LEN + 8 + 1;
+}
+
+/// Student profile at one institution
 #[account]
 pub struct StudentProfile {
     pub student: Pubkey,
+    pub institution: Pubkey,
     pub total_credits_purchased: u64,
     pub total_credits_spent: u64,
     pub courses_completed: u16,
+    pub open_enrollments: u16,  // Enrollment accounts not yet closed
+    pub credit_hours_attempted: u16,  // Graded credit hours counted in the GPA
+    pub credit_hours_earned: u16,  // Credit hours from passed courses
+    pub quality_points: u64,  // Sum of grade points x100 weighted by credit hours
+    pub gpa: u16,  // Weighted GPA x100 (e.g. 350 = 3.50)
     pub graduation_nft: Option<Pubkey>,
+    pub display_name: Option<String>,
+    pub metadata_uri: Option<String>,  // Off-chain profile metadata (avatar, bio, ...)
     pub created_at: i64,
     pub bump: u8,
 }
 
 impl StudentProfile {
-    pub const LEN: usize = 8 + 32 + 8 + 8 + 2 + 33 + 8 + 1;
+    pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+    pub const MAX_METADATA_URI_LEN: usize = 200;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 2 + 2 + 2 + 2 + 8 + 2 + 33
+        + 1 + 4 + Self::MAX_DISPLAY_NAME_LEN
+        + 1 + 4 + Self::MAX_METADATA_URI_LEN
+        + 8 + 1;
+}
+
+/// Left behind by `close_profile` with the closed profile's academic record.
+/// `create_student_profile` restores the record from it and closes it, so a
+/// re-created profile does not start with a clean slate.
+#[account]
+pub struct ProfileTombstone {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub courses_completed: u16,
+    pub credit_hours_attempted: u16,
+    pub credit_hours_earned: u16,
+    pub quality_points: u64,
+    pub gpa: u16,
+    pub graduation_nft: Option<Pubkey>,
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+impl ProfileTombstone {
+    pub const LEN: usize = 8 + 32 + 32 + 2 + 2 + 2 + 8 + 2 + 33 + 8 + 1;
+}
+
+/// One transcript line. Transcript Merkle leaves hash its Borsh encoding,
+/// so it only holds what the on-chain enrollment can vouch for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TranscriptEntry {
+    pub enrollment: Pubkey,
+    pub course_id: String,
+    pub term_id: String,
+    pub status: EnrollmentStatus,
+    pub grade: u8,
+    pub letter_grade: String,
+    pub credit_hours: u8,
+    pub completion_date: Option<i64>,
+}
+
+/// What `close_enrollment` leaves of an enrollment: its transcript entry,
+/// GPA contribution and certificate, which verifiers and transcripts read
+/// once the enrollment is gone. `["enrollment_record", enrollment]`
+#[account]
+pub struct EnrollmentRecord {
+    pub student: Pubkey,
+    pub offering: Pubkey,
+    pub entry: TranscriptEntry,
+    pub enrollment_date: i64,
+    pub grade_points: u16,
+    pub certificate_mint: Option<Pubkey>,
+    pub metadata_hash: Option<[u8; 32]>,
+    pub certificate_batch: Option<Pubkey>,
+    pub compressed_certificate: Option<CompressedCertificate>,
+    pub closed_at: i64,
+    pub bump: u8,
+}
+
+impl EnrollmentRecord {
+    // Size with an empty course ID, term ID and letter grade
+    pub const LEN: usize = 8 + 32 + 32 + (32 + 4 + 4 + 1 + 1 + 4 + 1 + 9) + 8 + 2
+        + 33 + 33 + 33 + 1 + CompressedCertificate::LEN + 8 + 1;
+
+    /// Account size for an entry with these strings; sized exactly, so the
+    /// record is smaller than the enrollment it replaces
+    pub fn space(course_id: &str, term_id: &str, letter_grade: &str) -> usize {
+        Self::LEN + course_id.len() + term_id.len() + letter_grade.len()
+    }
+}
+
+/// Merkle root of an issued transcript; one account per institution, student and root
+#[account]
+pub struct TranscriptAnchor {
+    pub institution: Pubkey,
+    pub student: Pubkey,
+    pub issuer: Pubkey,  // Institution authority or Registrar that anchored and signed the transcript
+    pub merkle_root: [u8; 32],
+    pub entry_count: u16,
+    pub issued_at: i64,
+    pub bump: u8,
+}
+
+impl TranscriptAnchor {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 2 + 8 + 1;
+}
+
+/// One certificate of a `CertificateBatch`. Batch Merkle leaves hash its
+/// Borsh encoding, so it can be rebuilt from the enrollment when claiming.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CertificateLeaf {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub grade: u8,
+    pub completion_date: i64,
+}
+
+/// Merkle root over the certificates of one offering, posted in a single
+/// transaction instead of one mint per student
+#[account]
+pub struct CertificateBatch {
+    pub offering: Pubkey,
+    pub issuer: Pubkey,  // Offering instructor, Reviewer or institution authority
+    pub merkle_root: [u8; 32],
+    pub certificate_count: u16,
+    pub claimed_count: u16,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CertificateBatch {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 2 + 2 + 8 + 1;
+}
+
+/// Where a compressed certificate lives: its leaf in a `CertificateTree`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressedCertificate {
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u32,
+    pub leaf_hash: [u8; 32],
+}
+
+impl CompressedCertificate {
+    pub const LEN: usize = 32 + 4 + 32;
+}
+
+/// Fields hashed into a compressed certificate leaf. The tree and index make
+/// every leaf unique, like a mint address.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedCertificateLeaf {
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u32,
+    pub enrollment: Pubkey,
+    pub student: Pubkey,
+    pub course_id: String,
+    pub grade: u8,
+    pub completion_date: i64,
+    pub metadata_hash: [u8; 32],
+}
+
+/// An spl-account-compression concurrent Merkle tree the program appends
+/// certificates to. The PDA is the tree's authority.
+#[account]
+pub struct CertificateTree {
+    pub merkle_tree: Pubkey,
+    pub institution: Pubkey,  // Only this institution's enrollments can be appended
+    pub authority: Pubkey,  // Institution authority that created the tree
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub minted: u64,  // Leaves appended so far, which is the next leaf index
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl CertificateTree {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 4 + 4 + 8 + 8 + 1;
 }
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_keccak_hasher::hashv;
+use crate::constants::*;
+
+// Anchor discriminators of the spl-account-compression instructions used here:
+// sha256("global:<name>")[..8]
+const INIT_EMPTY_MERKLE_TREE: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+const APPEND: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+fn compression_instruction(merkle_tree: &Pubkey, authority: &Pubkey, data: Vec<u8>) -> Instruction {
+    Instruction {
+        program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*merkle_tree, false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+        ],
+        data,
+    }
+}
+
+/// Initialize an allocated, zeroed tree account with `authority` as its owner
+pub fn init_empty_merkle_tree<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    let mut data = INIT_EMPTY_MERKLE_TREE.to_vec();
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    let ix = compression_instruction(merkle_tree.key, authority.key, data);
+    invoke_signed(
+        &ix,
+        &[merkle_tree.clone(), authority.clone(), noop_program.clone()],
+        &[signer_seeds],
+    )?;
+    Ok(())
+}
+
+/// Append `leaf` as the next leaf of the tree, signed by its authority
+pub fn append_leaf<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    noop_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    leaf: [u8; 32],
+) -> Result<()> {
+    let mut data = APPEND.to_vec();
+    data.extend_from_slice(&leaf);
+    let ix = compression_instruction(merkle_tree.key, authority.key, data);
+    invoke_signed(
+        &ix,
+        &[merkle_tree.clone(), authority.clone(), noop_program.clone()],
+        &[signer_seeds],
+    )?;
+    Ok(())
+}
+
+/// Parent in a concurrent Merkle tree: Keccak-256 of left || right
+pub fn tree_parent(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[left, right]).to_bytes()
+}
+
+/// Root of an empty subtree `level` levels high
+pub fn empty_tree_node(level: u32) -> [u8; 32] {
+    (0..level).fold([0; 32], |node, _| tree_parent(&node, &node))
+}
+
+/// Root reached from `leaf` at `index` with `proof`; the index bit at each
+/// level says whether the node is the right child
+pub fn recompute_tree_root(leaf: &[u8; 32], index: u32, proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().enumerate().fold(*leaf, |node, (depth, sibling)| {
+        if index >> depth & 1 == 0 {
+            tree_parent(&node, sibling)
+        } else {
+            tree_parent(sibling, &node)
+        }
+    })
+}
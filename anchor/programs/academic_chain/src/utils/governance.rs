@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::constants::*;
+
+/// Validate a governance: 1-10 distinct admins, a threshold they can reach
+/// and a delay between `MIN_CONFIG_DELAY` and `MAX_CONFIG_DELAY`
+pub fn validate_governance(admins: &[Pubkey], threshold: u8, min_delay: i64) -> Result<()> {
+    require!(
+        !admins.is_empty() && admins.len() <= ConfigGovernance::MAX_ADMINS,
+        AcademicChainError::InvalidGovernance
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= admins.len(),
+        AcademicChainError::InvalidGovernance
+    );
+    require!(
+        (MIN_CONFIG_DELAY..=MAX_CONFIG_DELAY).contains(&min_delay),
+        AcademicChainError::InvalidGovernance
+    );
+
+    for (i, admin) in admins.iter().enumerate() {
+        require!(!admins[..i].contains(admin), AcademicChainError::InvalidGovernance);
+    }
+    Ok(())
+}
+
+/// Validate the governance `change` would leave behind
+pub fn validate_governance_change(governance: &ConfigGovernance, change: &ConfigChange) -> Result<()> {
+    validate_governance(
+        change.admins.as_deref().unwrap_or(&governance.admins),
+        change.threshold.unwrap_or(governance.threshold),
+        change.min_delay.unwrap_or(governance.min_delay),
+    )
+}
+
+/// Approvals from keys that are still admins; approvals of removed admins
+/// no longer count
+pub fn counted_approvals(governance: &ConfigGovernance, proposal: &ConfigProposal) -> usize {
+    proposal
+        .approvals
+        .iter()
+        .filter(|approver| governance.is_admin(approver))
+        .count()
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::{is_passing_grade, validate_grade};
+
+/// Default 4.0 grade scale every new institution starts with (see `create_institution`)
+pub fn default_grade_scale() -> Vec<GradeScaleEntry> {
+    [(90, "A", 400), (80, "B", 300), (70, "C", 200), (50, "D", 100), (0, "F", 0)]
+        .into_iter()
+        .map(|(min_score, letter, grade_points)| GradeScaleEntry {
+            min_score,
+            letter: letter.to_string(),
+            grade_points,
+        })
+        .collect()
+}
+
+/// Validate a grade scale: descending thresholds ending at 0, non-increasing points
+pub fn validate_grade_scale(scale: &[GradeScaleEntry]) -> Result<()> {
+    require!(
+        !scale.is_empty() && scale.len() <= Institution::MAX_GRADE_SCALE_ENTRIES,
+        AcademicChainError::InvalidGradeScale
+    );
+
+    for entry in scale {
+        require!(
+            !entry.letter.is_empty() && entry.letter.len() <= GradeScaleEntry::MAX_LETTER_LEN,
+            AcademicChainError::InvalidGradeScale
+        );
+    }
+
+    for pair in scale.windows(2) {
+        require!(
+            pair[0].min_score > pair[1].min_score && pair[0].grade_points >= pair[1].grade_points,
+            AcademicChainError::InvalidGradeScale
+        );
+    }
+
+    require!(
+        scale[scale.len() - 1].min_score == 0,
+        AcademicChainError::InvalidGradeScale
+    );
+
+    Ok(())
+}
+
+/// Find the grade scale row for a score
+pub fn lookup_grade(scale: &[GradeScaleEntry], grade: u8) -> Result<&GradeScaleEntry> {
+    scale
+        .iter()
+        .find(|entry| grade >= entry.min_score)
+        .ok_or_else(|| error!(AcademicChainError::InvalidGradeScale))
+}
+
+/// Complete an enrollment with a grade and update the student's profile
+pub fn complete_enrollment(
+    institution: &Institution,
+    course: &Course,
+    offering: &CourseOffering,
+    enrollment: &mut CourseEnrollment,
+    profile: &mut StudentProfile,
+    grade: u8,
+    completed_at: i64,
+) -> Result<()> {
+    validate_grade(grade)?;
+    require!(
+        enrollment.status != EnrollmentStatus::Completed,
+        AcademicChainError::CourseAlreadyCompleted
+    );
+    require!(
+        enrollment.status == EnrollmentStatus::Enrolled,
+        AcademicChainError::EnrollmentNotActive
+    );
+    require!(
+        completed_at <= offering.grading_deadline,
+        AcademicChainError::GradingDeadlinePassed
+    );
+
+    let entry = lookup_grade(&institution.grade_scale, grade)?;
+
+    enrollment.status = EnrollmentStatus::Completed;
+    enrollment.completion_date = Some(completed_at);
+    apply_grade(profile, enrollment, entry, grade, course.credit_hours)?;
+
+    profile.courses_completed = profile.courses_completed
+        .checked_add(1)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Record a grade on the enrollment and fold it into the student's GPA
+pub fn apply_grade(
+    profile: &mut StudentProfile,
+    enrollment: &mut CourseEnrollment,
+    entry: &GradeScaleEntry,
+    grade: u8,
+    credit_hours: u8,
+) -> Result<()> {
+    enrollment.grade = grade;
+    enrollment.letter_grade = entry.letter.clone();
+    enrollment.grade_points = entry.grade_points;
+    enrollment.credit_hours = credit_hours;
+
+    profile.credit_hours_attempted = profile.credit_hours_attempted
+        .checked_add(credit_hours as u16)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    profile.quality_points = profile.quality_points
+        .checked_add(entry.grade_points as u64 * credit_hours as u64)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    if is_passing_grade(grade) {
+        profile.credit_hours_earned = profile.credit_hours_earned
+            .checked_add(credit_hours as u16)
+            .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    }
+
+    refresh_gpa(profile);
+    Ok(())
+}
+
+/// Remove a previously applied grade from the student's GPA
+pub fn revert_grade(profile: &mut StudentProfile, enrollment: &CourseEnrollment) -> Result<()> {
+    let credit_hours = enrollment.credit_hours as u16;
+
+    profile.credit_hours_attempted = profile.credit_hours_attempted
+        .checked_sub(credit_hours)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    profile.quality_points = profile.quality_points
+        .checked_sub(enrollment.grade_points as u64 * credit_hours as u64)
+        .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    if is_passing_grade(enrollment.grade) {
+        profile.credit_hours_earned = profile.credit_hours_earned
+            .checked_sub(credit_hours)
+            .ok_or(AcademicChainError::ArithmeticOverflow)?;
+    }
+
+    refresh_gpa(profile);
+    Ok(())
+}
+
+/// Recompute the weighted GPA (x100, rounded to nearest)
+fn refresh_gpa(profile: &mut StudentProfile) {
+    let hours = profile.credit_hours_attempted as u64;
+    profile.gpa = (profile.quality_points + hours / 2)
+        .checked_div(hours)
+        .unwrap_or(0) as u16;
+}
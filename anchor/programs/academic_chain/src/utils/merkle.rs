@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::{CertificateLeaf, CompressedCertificateLeaf, TranscriptEntry};
+
+// Leaves and inner nodes are hashed with different prefixes so a node can
+// never be presented as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Merkle leaf of a transcript entry: SHA-256 of 0x00 || Borsh(entry)
+pub fn transcript_leaf(entry: &TranscriptEntry) -> [u8; 32] {
+    let mut data = Vec::new();
+    entry.serialize(&mut data).expect("writing to a Vec cannot fail");
+    hashv(&[LEAF_PREFIX, &data]).to_bytes()
+}
+
+/// Merkle leaf of a batch certificate: SHA-256 of 0x00 || Borsh(leaf)
+pub fn certificate_leaf(leaf: &CertificateLeaf) -> [u8; 32] {
+    let mut data = Vec::new();
+    leaf.serialize(&mut data).expect("writing to a Vec cannot fail");
+    hashv(&[LEAF_PREFIX, &data]).to_bytes()
+}
+
+/// Merkle leaf of a compressed certificate: SHA-256 of 0x00 || Borsh(leaf).
+/// The concurrent tree above it hashes nodes its own way (see `compression`).
+pub fn compressed_certificate_leaf(leaf: &CompressedCertificateLeaf) -> [u8; 32] {
+    let mut data = Vec::new();
+    leaf.serialize(&mut data).expect("writing to a Vec cannot fail");
+    hashv(&[LEAF_PREFIX, &data]).to_bytes()
+}
+
+/// Parent of two nodes. Children are sorted first, so proofs need no
+/// left/right flags.
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, low, high]).to_bytes()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => merkle_parent(a, b),
+            [a] => *a,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Root over `leaves` in order; an unpaired node moves up a level unchanged
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0; 32];
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Sibling hashes from the leaf at `index` up to the root. Levels where the
+/// node is unpaired contribute nothing.
+pub fn merkle_proof(leaves: &[[u8; 32]], index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut index = index;
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Whether `proof` leads from `leaf` to `root`
+pub fn verify_merkle_proof(leaf: &[u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let computed = proof.iter().fold(*leaf, |node, sibling| merkle_parent(&node, sibling));
+    computed == *root
+}
@@ -1,5 +1,15 @@
 pub mod validations;
 pub mod token_operations;
+pub mod grading;
+pub mod merkle;
+pub mod compression;
+pub mod roles;
+pub mod governance;
 
 pub use validations::*;
 pub use token_operations::*;
+pub use grading::*;
+pub use merkle::*;
+pub use compression::*;
+pub use roles::*;
+pub use governance::*;
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{Institution, Role, RoleAssignment};
+use crate::errors::*;
+
+/// Whether `signer` may act as `role` at `institution`. The institution
+/// authority always may; anyone else needs a matching `RoleAssignment`.
+pub fn has_role(
+    institution: &Account<Institution>,
+    signer: &Pubkey,
+    assignment: Option<&Account<RoleAssignment>>,
+    role: Role,
+) -> bool {
+    if *signer == institution.authority {
+        return true;
+    }
+
+    assignment.is_some_and(|assignment| {
+        assignment.institution == institution.key() && assignment.holder == *signer && assignment.role == role
+    })
+}
+
+/// Fail with `Unauthorized` unless `signer` may act as `role` (see `has_role`)
+pub fn require_role(
+    institution: &Account<Institution>,
+    signer: &Pubkey,
+    assignment: Option<&Account<RoleAssignment>>,
+    role: Role,
+) -> Result<()> {
+    require!(
+        has_role(institution, signer, assignment, role),
+        AcademicChainError::Unauthorized
+    );
+    Ok(())
+}
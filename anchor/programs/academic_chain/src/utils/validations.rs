@@ -14,7 +14,7 @@ pub fn validate_course_id(course_id: &str) -> Result<()> {
 /// Validate grade is within acceptable range
 pub fn validate_grade(grade: u8) -> Result<()> {
     require!(
-        grade >= MIN_GRADE && grade <= MAX_GRADE,
+        (MIN_GRADE..=MAX_GRADE).contains(&grade),
         AcademicChainError::InvalidGrade
     );
     Ok(())
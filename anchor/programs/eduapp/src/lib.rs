@@ -27,7 +27,7 @@ pub mod eduapp {
     }
 
     pub fn set(ctx: Context<Update>, value: u8) -> Result<()> {
-        ctx.accounts.eduapp.count = value.clone();
+        ctx.accounts.eduapp.count = value;
         Ok(())
     }
 }
@@ -0,0 +1,270 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  AccountMeta,
+  AddressLookupTableAccount,
+  AddressLookupTableProgram,
+  ComputeBudgetProgram,
+  Connection,
+  Keypair,
+  PublicKey,
+  TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import * as fs from "fs";
+import { AcademicChain } from "../target/types/academic_chain";
+
+// Must match MAX_GRADING_BATCH_SIZE in programs/academic_chain/src/constants.rs
+export const MAX_GRADING_BATCH_SIZE = 20;
+
+// Address lookup tables hold at most 256 addresses
+const MAX_LOOKUP_TABLE_ADDRESSES = 256;
+
+// Addresses per extend instruction that keep the transaction under the size limit
+const LOOKUP_TABLE_EXTEND_CHUNK = 30;
+
+// Compute budget: fixed overhead plus the cost of grading one student,
+// including the CourseCompleted event CPI
+const BASE_COMPUTE_UNITS = 30_000;
+const COMPUTE_UNITS_PER_GRADE = 32_000;
+
+export interface StudentGrade {
+  student: PublicKey;
+  grade: number;
+}
+
+export interface GradingBatch {
+  grades: StudentGrade[];
+  remainingAccounts: AccountMeta[];
+}
+
+export interface LookupTablePlan {
+  // Addresses to store in one lookup table
+  addresses: PublicKey[];
+  // Batches whose accounts are all resolvable through that table
+  batches: GradingBatch[];
+}
+
+/**
+ * Split a cohort into complete_course_batch instructions and group them
+ * so that each group's accounts fit into a single address lookup table.
+ * Mirrors plan_grading_batches in the Rust client's grading module.
+ */
+export function planGradingBatches(
+  programId: PublicKey,
+  institution: PublicKey,
+  courseId: string,
+  termId: string,
+  grades: StudentGrade[],
+  batchSize: number = MAX_GRADING_BATCH_SIZE
+): LookupTablePlan[] {
+  if (batchSize < 1 || batchSize > MAX_GRADING_BATCH_SIZE) {
+    throw new Error(`Batch size must be between 1 and ${MAX_GRADING_BATCH_SIZE}`);
+  }
+
+  const [coursePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("course"), institution.toBuffer(), Buffer.from(courseId)],
+    programId
+  );
+  const [offeringPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("offering"), institution.toBuffer(), Buffer.from(courseId), Buffer.from(termId)],
+    programId
+  );
+
+  // Institution, course and offering are shared by every batch in a table
+  const sharedAddresses = [institution, coursePda, offeringPda];
+  const batchesPerTable = Math.max(
+    1,
+    Math.floor((MAX_LOOKUP_TABLE_ADDRESSES - sharedAddresses.length) / (batchSize * 2))
+  );
+
+  const batches: GradingBatch[] = [];
+  for (let i = 0; i < grades.length; i += batchSize) {
+    const chunk = grades.slice(i, i + batchSize);
+    const remainingAccounts = chunk.flatMap(({ student }) => {
+      const [enrollmentPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("enrollment"), student.toBuffer(), offeringPda.toBuffer()],
+        programId
+      );
+      const [studentProfilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("student_profile"), institution.toBuffer(), student.toBuffer()],
+        programId
+      );
+      return [
+        { pubkey: enrollmentPda, isSigner: false, isWritable: true },
+        { pubkey: studentProfilePda, isSigner: false, isWritable: true },
+      ];
+    });
+    batches.push({ grades: chunk, remainingAccounts });
+  }
+
+  const plans: LookupTablePlan[] = [];
+  for (let i = 0; i < batches.length; i += batchesPerTable) {
+    const tableBatches = batches.slice(i, i + batchesPerTable);
+    plans.push({
+      addresses: [
+        ...sharedAddresses,
+        ...tableBatches.flatMap((batch) => batch.remainingAccounts.map((meta) => meta.pubkey)),
+      ],
+      batches: tableBatches,
+    });
+  }
+
+  return plans;
+}
+
+async function sendV0(
+  connection: Connection,
+  payer: Keypair,
+  instructions: TransactionInstruction[],
+  lookupTables: AddressLookupTableAccount[] = []
+): Promise<string> {
+  const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash();
+  const message = new TransactionMessage({
+    payerKey: payer.publicKey,
+    recentBlockhash: blockhash,
+    instructions,
+  }).compileToV0Message(lookupTables);
+
+  const transaction = new VersionedTransaction(message);
+  transaction.sign([payer]);
+
+  const signature = await connection.sendTransaction(transaction);
+  await connection.confirmTransaction(
+    { signature, blockhash, lastValidBlockHeight },
+    "confirmed"
+  );
+  return signature;
+}
+
+async function createLookupTable(
+  connection: Connection,
+  payer: Keypair,
+  addresses: PublicKey[]
+): Promise<AddressLookupTableAccount> {
+  const recentSlot = await connection.getSlot("finalized");
+  const [createIx, lookupTableAddress] = AddressLookupTableProgram.createLookupTable({
+    authority: payer.publicKey,
+    payer: payer.publicKey,
+    recentSlot,
+  });
+  await sendV0(connection, payer, [createIx]);
+
+  for (let i = 0; i < addresses.length; i += LOOKUP_TABLE_EXTEND_CHUNK) {
+    const extendIx = AddressLookupTableProgram.extendLookupTable({
+      lookupTable: lookupTableAddress,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      addresses: addresses.slice(i, i + LOOKUP_TABLE_EXTEND_CHUNK),
+    });
+    await sendV0(connection, payer, [extendIx]);
+  }
+
+  // New addresses become usable one slot after they are added
+  const extendedAt = await connection.getSlot("confirmed");
+  while ((await connection.getSlot("confirmed")) <= extendedAt) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+
+  const { value: lookupTable } = await connection.getAddressLookupTable(lookupTableAddress);
+  if (!lookupTable) {
+    throw new Error(`Lookup table ${lookupTableAddress.toString()} not found`);
+  }
+  return lookupTable;
+}
+
+/**
+ * Grade a whole cohort with complete_course_batch.
+ * Lookup tables are deactivated afterwards; their rent can be reclaimed
+ * with closeLookupTable once deactivation has completed.
+ */
+export async function completeCourseBatch(
+  program: Program<AcademicChain>,
+  institution: PublicKey,
+  instructor: Keypair,
+  courseId: string,
+  termId: string,
+  grades: StudentGrade[],
+  batchSize: number = MAX_GRADING_BATCH_SIZE
+): Promise<{ signatures: string[]; lookupTables: PublicKey[] }> {
+  const connection = program.provider.connection;
+  const plans = planGradingBatches(program.programId, institution, courseId, termId, grades, batchSize);
+
+  const signatures: string[] = [];
+  const lookupTables: PublicKey[] = [];
+
+  for (const plan of plans) {
+    const lookupTable = await createLookupTable(connection, instructor, plan.addresses);
+    lookupTables.push(lookupTable.key);
+
+    for (const batch of plan.batches) {
+      const ix = await program.methods
+        .completeCourseBatch(courseId, termId, batch.grades)
+        .accounts({
+          instructor: instructor.publicKey,
+          institution,
+        })
+        .remainingAccounts(batch.remainingAccounts)
+        .instruction();
+
+      const computeIx = ComputeBudgetProgram.setComputeUnitLimit({
+        units: BASE_COMPUTE_UNITS + COMPUTE_UNITS_PER_GRADE * batch.grades.length,
+      });
+
+      const signature = await sendV0(connection, instructor, [computeIx, ix], [lookupTable]);
+      signatures.push(signature);
+      console.log(`✅ Graded ${batch.grades.length} students: ${signature}`);
+    }
+
+    await sendV0(connection, instructor, [
+      AddressLookupTableProgram.deactivateLookupTable({
+        lookupTable: lookupTable.key,
+        authority: instructor.publicKey,
+      }),
+    ]);
+  }
+
+  return { signatures, lookupTables };
+}
+
+// Run if called directly: npx tsx scripts/complete_course_batch.ts <institution_id> <course_id> <term_id> <grades.csv>
+// CSV rows: student_pubkey,grade
+if (require.main === module) {
+  const [institutionId, courseId, termId, csvPath] = process.argv.slice(2);
+  if (!institutionId || !courseId || !termId || !csvPath) {
+    console.error("Usage: complete_course_batch.ts <institution_id> <course_id> <term_id> <grades.csv>");
+    process.exit(1);
+  }
+
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.AcademicChain as Program<AcademicChain>;
+  const instructor = (provider.wallet as anchor.Wallet).payer;
+
+  const grades = fs
+    .readFileSync(csvPath, "utf8")
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0)
+    .map((line) => {
+      const [student, grade] = line.split(",");
+      return { student: new PublicKey(student.trim()), grade: Number(grade) };
+    });
+
+  const [institution] = PublicKey.findProgramAddressSync(
+    [Buffer.from("institution"), Buffer.from(institutionId)],
+    program.programId
+  );
+
+  completeCourseBatch(program, institution, instructor, courseId, termId, grades)
+    .then(({ signatures }) => {
+      console.log(`✅ Submitted ${signatures.length} batches for ${grades.length} students`);
+      process.exit(0);
+    })
+    .catch((error) => {
+      console.error(error);
+      process.exit(1);
+    });
+}
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Dump the state compression programs that `anchor test` loads at genesis
+# (see `[[test.genesis]]` in Anchor.toml) and the LiteSVM compressed
+# certificate test loads from tests/fixtures. Programs already dumped are
+# kept; pass another cluster with CLUSTER=d|t|<url> (default: mainnet).
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+mkdir -p tests/fixtures
+
+dump() {
+  local address=$1 file=tests/fixtures/$2.so
+  if [[ -s $file ]]; then
+    echo "✅ $file"
+    return
+  fi
+  solana program dump -u "${CLUSTER:-m}" "$address" "$file"
+}
+
+dump cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK spl_account_compression
+dump noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV spl_noop
@@ -1,6 +1,6 @@
 /**
  * Initialize Academic Chain Program - Simple Version
- * Run: cd anchor && npx tsx scripts/init-simple.ts
+ * Run: cd anchor && npx tsx scripts/init-simple.ts [institution_id] [name]
  */
 
 import * as anchor from "@coral-xyz/anchor"
@@ -59,17 +59,19 @@ async function main() {
   const program = anchor.workspace.AcademicChain as Program<AcademicChain>
   console.log('Program ID:', program.programId.toBase58())
 
-  // Find config PDA
-  const [configPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("config")],
+  const [institutionId = 'EXU', name = 'Example University'] = process.argv.slice(2)
+
+  // Find institution PDA
+  const [institutionPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("institution"), Buffer.from(institutionId)],
     program.programId
   )
-  console.log('Config PDA:', configPda.toBase58())
+  console.log('Institution PDA:', institutionPda.toBase58())
   console.log('')
 
   // Check if already initialized
   try {
-    const config = await program.account.programConfig.fetch(configPda)
+    const config = await program.account.institution.fetch(institutionPda)
     console.log('✅ Already initialized!')
     console.log('Credit Mint:', config.creditMint.toBase58())
     return
@@ -77,12 +79,12 @@ async function main() {
     console.log('Proceeding with initialization...\n')
   }
 
-  // Create credit mint (authority will be config PDA)
+  // Create credit mint (authority will be institution PDA)
   console.log('Creating credit mint...')
   const creditMint = await createMint(
     connection,
     authority,
-    configPda, // mint authority
+    institutionPda, // mint authority
     null, // no freeze authority
     0, // 0 decimals
     undefined,
@@ -92,13 +94,13 @@ async function main() {
   console.log('Credit Mint:', creditMint.toBase58())
   console.log('')
 
-  // Initialize program
-  console.log('Initializing program...')
+  // Create institution
+  console.log(`Creating institution ${institutionId}...`)
   const tx = await program.methods
-    .initialize()
+    .createInstitution(institutionId, name)
     .accounts({
       authority: authority.publicKey,
-      config: configPda,
+      institution: institutionPda,
       treasury: treasury.publicKey,
       creditMint: creditMint,
       systemProgram: SystemProgram.programId,
@@ -110,8 +112,8 @@ async function main() {
   console.log('Transaction:', `https://explorer.solana.com/tx/${tx}?cluster=devnet`)
 
   // Verify
-  const config = await program.account.programConfig.fetch(configPda)
-  console.log('\nConfiguration:')
+  const config = await program.account.institution.fetch(institutionPda)
+  console.log('\nInstitution:', config.name)
   console.log('  Credit Price:', (config.creditPrice.toNumber() / LAMPORTS_PER_SOL).toFixed(4), 'SOL')
   console.log('  Treasury:', config.treasury.toBase58())
   console.log('  Credit Mint:', config.creditMint.toBase58())
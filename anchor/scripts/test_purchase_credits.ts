@@ -16,7 +16,7 @@ import {
 } from 'gill'
 import {
   purchaseCredits,
-  getProgramConfiguration,
+  getInstitutionConfiguration,
   getStudentProfile,
   lamportsToSol,
   getExplorerUrl,
@@ -33,9 +33,9 @@ async function main() {
   const rpcSubscriptions = createSolanaRpcSubscriptions(DEVNET_RPC_URL.replace('https', 'wss'))
 
   try {
-    // Step 1: Get program configuration
-    console.log('\n📋 Step 1: Fetching program configuration...')
-    const config = await getProgramConfiguration(DEVNET_RPC_URL)
+    // Step 1: Get institution configuration
+    console.log('\n📋 Step 1: Fetching institution configuration...')
+    const config = await getInstitutionConfiguration(DEVNET_RPC_URL)
     console.log('✓ Config loaded')
     console.log(`  Credit Price: ${lamportsToSol(config.data.creditPrice)} SOL`)
     console.log(`  Treasury: ${config.data.treasury}`)
@@ -59,20 +59,26 @@ export function getCourseDiscriminatorBytes() {
 
 export type Course = {
   discriminator: ReadonlyUint8Array;
+  institution: Address;
   courseId: string;
   courseName: string;
   instructor: Address;
   requiredCredits: bigint;
+  creditHours: number;
+  maxAttempts: number;
   isActive: boolean;
   createdAt: bigint;
   bump: number;
 };
 
 export type CourseArgs = {
+  institution: Address;
   courseId: string;
   courseName: string;
   instructor: Address;
   requiredCredits: number | bigint;
+  creditHours: number;
+  maxAttempts: number;
   isActive: boolean;
   createdAt: number | bigint;
   bump: number;
@@ -82,10 +88,13 @@ export function getCourseEncoder(): Encoder<CourseArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['institution', getAddressEncoder()],
       ['courseId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['courseName', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['instructor', getAddressEncoder()],
       ['requiredCredits', getU64Encoder()],
+      ['creditHours', getU8Encoder()],
+      ['maxAttempts', getU8Encoder()],
       ['isActive', getBooleanEncoder()],
       ['createdAt', getI64Encoder()],
       ['bump', getU8Encoder()],
@@ -97,10 +106,13 @@ export function getCourseEncoder(): Encoder<CourseArgs> {
 export function getCourseDecoder(): Decoder<Course> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['institution', getAddressDecoder()],
     ['courseId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['courseName', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['instructor', getAddressDecoder()],
     ['requiredCredits', getU64Decoder()],
+    ['creditHours', getU8Decoder()],
+    ['maxAttempts', getU8Decoder()],
     ['isActive', getBooleanDecoder()],
     ['createdAt', getI64Decoder()],
     ['bump', getU8Decoder()],
@@ -19,8 +19,8 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getBooleanDecoder,
-  getBooleanEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
@@ -29,6 +29,8 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -52,6 +54,19 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getCompressedCertificateDecoder,
+  getCompressedCertificateEncoder,
+  getEnrollmentAttemptDecoder,
+  getEnrollmentAttemptEncoder,
+  getEnrollmentStatusDecoder,
+  getEnrollmentStatusEncoder,
+  type CompressedCertificate,
+  type EnrollmentAttempt,
+  type EnrollmentAttemptArgs,
+  type EnrollmentStatus,
+  type EnrollmentStatusArgs,
+} from '../types';
 
 export const COURSE_ENROLLMENT_DISCRIMINATOR = new Uint8Array([
   119, 94, 144, 89, 26, 179, 54, 137,
@@ -67,24 +82,42 @@ export type CourseEnrollment = {
   discriminator: ReadonlyUint8Array;
   student: Address;
   courseId: string;
+  offering: Address;
   creditsPaid: bigint;
   enrollmentDate: bigint;
   completionDate: Option<bigint>;
-  isCompleted: boolean;
+  status: EnrollmentStatus;
   grade: number;
+  letterGrade: string;
+  gradePoints: number;
+  creditHours: number;
   certificateMint: Option<Address>;
+  metadataHash: Option<ReadonlyUint8Array>;
+  certificateBatch: Option<Address>;
+  compressedCertificate: Option<CompressedCertificate>;
+  transferredFrom: Option<Address>;
+  attempts: Array<EnrollmentAttempt>;
   bump: number;
 };
 
 export type CourseEnrollmentArgs = {
   student: Address;
   courseId: string;
+  offering: Address;
   creditsPaid: number | bigint;
   enrollmentDate: number | bigint;
   completionDate: OptionOrNullable<number | bigint>;
-  isCompleted: boolean;
+  status: EnrollmentStatusArgs;
   grade: number;
+  letterGrade: string;
+  gradePoints: number;
+  creditHours: number;
   certificateMint: OptionOrNullable<Address>;
+  metadataHash: OptionOrNullable<ReadonlyUint8Array>;
+  certificateBatch: OptionOrNullable<Address>;
+  compressedCertificate: OptionOrNullable<CompressedCertificate>;
+  transferredFrom: OptionOrNullable<Address>;
+  attempts: Array<EnrollmentAttemptArgs>;
   bump: number;
 };
 
@@ -94,12 +127,24 @@ export function getCourseEnrollmentEncoder(): Encoder<CourseEnrollmentArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['student', getAddressEncoder()],
       ['courseId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['offering', getAddressEncoder()],
       ['creditsPaid', getU64Encoder()],
       ['enrollmentDate', getI64Encoder()],
       ['completionDate', getOptionEncoder(getI64Encoder())],
-      ['isCompleted', getBooleanEncoder()],
+      ['status', getEnrollmentStatusEncoder()],
       ['grade', getU8Encoder()],
+      ['letterGrade', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['gradePoints', getU16Encoder()],
+      ['creditHours', getU8Encoder()],
       ['certificateMint', getOptionEncoder(getAddressEncoder())],
+      ['metadataHash', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+      ['certificateBatch', getOptionEncoder(getAddressEncoder())],
+      [
+        'compressedCertificate',
+        getOptionEncoder(getCompressedCertificateEncoder()),
+      ],
+      ['transferredFrom', getOptionEncoder(getAddressEncoder())],
+      ['attempts', getArrayEncoder(getEnrollmentAttemptEncoder())],
       ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: COURSE_ENROLLMENT_DISCRIMINATOR })
@@ -111,12 +156,24 @@ export function getCourseEnrollmentDecoder(): Decoder<CourseEnrollment> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['student', getAddressDecoder()],
     ['courseId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['offering', getAddressDecoder()],
     ['creditsPaid', getU64Decoder()],
     ['enrollmentDate', getI64Decoder()],
     ['completionDate', getOptionDecoder(getI64Decoder())],
-    ['isCompleted', getBooleanDecoder()],
+    ['status', getEnrollmentStatusDecoder()],
     ['grade', getU8Decoder()],
+    ['letterGrade', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['gradePoints', getU16Decoder()],
+    ['creditHours', getU8Decoder()],
     ['certificateMint', getOptionDecoder(getAddressDecoder())],
+    ['metadataHash', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    ['certificateBatch', getOptionDecoder(getAddressDecoder())],
+    [
+      'compressedCertificate',
+      getOptionDecoder(getCompressedCertificateDecoder()),
+    ],
+    ['transferredFrom', getOptionDecoder(getAddressDecoder())],
+    ['attempts', getArrayDecoder(getEnrollmentAttemptDecoder())],
     ['bump', getU8Decoder()],
   ]);
 }
@@ -139,9 +196,7 @@ export function decodeCourseEnrollment<TAddress extends string = string>(
 ): MaybeAccount<CourseEnrollment, TAddress>;
 export function decodeCourseEnrollment<TAddress extends string = string>(
   encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
-):
-  | Account<CourseEnrollment, TAddress>
-  | MaybeAccount<CourseEnrollment, TAddress> {
+): Account<CourseEnrollment, TAddress> | MaybeAccount<CourseEnrollment, TAddress> {
   return decodeAccount(
     encodedAccount as MaybeEncodedAccount<TAddress>,
     getCourseEnrollmentDecoder()
@@ -158,9 +213,7 @@ export async function fetchCourseEnrollment<TAddress extends string = string>(
   return maybeAccount;
 }
 
-export async function fetchMaybeCourseEnrollment<
-  TAddress extends string = string,
->(
+export async function fetchMaybeCourseEnrollment<TAddress extends string = string>(
   rpc: Parameters<typeof fetchEncodedAccount>[0],
   address: Address<TAddress>,
   config?: FetchAccountConfig
@@ -174,11 +227,7 @@ export async function fetchAllCourseEnrollment(
   addresses: Array<Address>,
   config?: FetchAccountsConfig
 ): Promise<Account<CourseEnrollment>[]> {
-  const maybeAccounts = await fetchAllMaybeCourseEnrollment(
-    rpc,
-    addresses,
-    config
-  );
+  const maybeAccounts = await fetchAllMaybeCourseEnrollment(rpc, addresses, config);
   assertAccountsExist(maybeAccounts);
   return maybeAccounts;
 }
@@ -189,7 +238,5 @@ export async function fetchAllMaybeCourseEnrollment(
   config?: FetchAccountsConfig
 ): Promise<MaybeAccount<CourseEnrollment>[]> {
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
-  return maybeAccounts.map((maybeAccount) =>
-    decodeCourseEnrollment(maybeAccount)
-  );
+  return maybeAccounts.map((maybeAccount) => decodeCourseEnrollment(maybeAccount));
 }
@@ -6,7 +6,20 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './certificateBatch';
+export * from './certificateRecord';
+export * from './certificateTree';
+export * from './configGovernance';
+export * from './configProposal';
 export * from './course';
+export * from './courseAttempts';
 export * from './courseEnrollment';
-export * from './programConfig';
+export * from './courseEquivalency';
+export * from './courseOffering';
+export * from './enrollmentRecord';
+export * from './institution';
+export * from './profileTombstone';
+export * from './roleAssignment';
 export * from './studentProfile';
+export * from './term';
+export * from './transcriptAnchor';
@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   assertAccountExists,
   assertAccountsExist,
   combineCodec,
@@ -27,10 +29,14 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -60,20 +66,36 @@ export function getStudentProfileDiscriminatorBytes() {
 export type StudentProfile = {
   discriminator: ReadonlyUint8Array;
   student: Address;
+  institution: Address;
   totalCreditsPurchased: bigint;
   totalCreditsSpent: bigint;
   coursesCompleted: number;
+  openEnrollments: number;
+  creditHoursAttempted: number;
+  creditHoursEarned: number;
+  qualityPoints: bigint;
+  gpa: number;
   graduationNft: Option<Address>;
+  displayName: Option<string>;
+  metadataUri: Option<string>;
   createdAt: bigint;
   bump: number;
 };
 
 export type StudentProfileArgs = {
   student: Address;
+  institution: Address;
   totalCreditsPurchased: number | bigint;
   totalCreditsSpent: number | bigint;
   coursesCompleted: number;
+  openEnrollments: number;
+  creditHoursAttempted: number;
+  creditHoursEarned: number;
+  qualityPoints: number | bigint;
+  gpa: number;
   graduationNft: OptionOrNullable<Address>;
+  displayName: OptionOrNullable<string>;
+  metadataUri: OptionOrNullable<string>;
   createdAt: number | bigint;
   bump: number;
 };
@@ -83,10 +105,28 @@ export function getStudentProfileEncoder(): Encoder<StudentProfileArgs> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['student', getAddressEncoder()],
+      ['institution', getAddressEncoder()],
       ['totalCreditsPurchased', getU64Encoder()],
       ['totalCreditsSpent', getU64Encoder()],
       ['coursesCompleted', getU16Encoder()],
+      ['openEnrollments', getU16Encoder()],
+      ['creditHoursAttempted', getU16Encoder()],
+      ['creditHoursEarned', getU16Encoder()],
+      ['qualityPoints', getU64Encoder()],
+      ['gpa', getU16Encoder()],
       ['graduationNft', getOptionEncoder(getAddressEncoder())],
+      [
+        'displayName',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
+      [
+        'metadataUri',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
       ['createdAt', getI64Encoder()],
       ['bump', getU8Encoder()],
     ]),
@@ -98,10 +138,24 @@ export function getStudentProfileDecoder(): Decoder<StudentProfile> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['student', getAddressDecoder()],
+    ['institution', getAddressDecoder()],
     ['totalCreditsPurchased', getU64Decoder()],
     ['totalCreditsSpent', getU64Decoder()],
     ['coursesCompleted', getU16Decoder()],
+    ['openEnrollments', getU16Decoder()],
+    ['creditHoursAttempted', getU16Decoder()],
+    ['creditHoursEarned', getU16Decoder()],
+    ['qualityPoints', getU64Decoder()],
+    ['gpa', getU16Decoder()],
     ['graduationNft', getOptionDecoder(getAddressDecoder())],
+    [
+      'displayName',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
+    [
+      'metadataUri',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
     ['createdAt', getI64Decoder()],
     ['bump', getU8Decoder()],
   ]);
@@ -139,9 +193,7 @@ export async function fetchStudentProfile<TAddress extends string = string>(
   return maybeAccount;
 }
 
-export async function fetchMaybeStudentProfile<
-  TAddress extends string = string,
->(
+export async function fetchMaybeStudentProfile<TAddress extends string = string>(
   rpc: Parameters<typeof fetchEncodedAccount>[0],
   address: Address<TAddress>,
   config?: FetchAccountConfig
@@ -155,11 +207,7 @@ export async function fetchAllStudentProfile(
   addresses: Array<Address>,
   config?: FetchAccountsConfig
 ): Promise<Account<StudentProfile>[]> {
-  const maybeAccounts = await fetchAllMaybeStudentProfile(
-    rpc,
-    addresses,
-    config
-  );
+  const maybeAccounts = await fetchAllMaybeStudentProfile(rpc, addresses, config);
   assertAccountsExist(maybeAccounts);
   return maybeAccounts;
 }
@@ -170,7 +218,5 @@ export async function fetchAllMaybeStudentProfile(
   config?: FetchAccountsConfig
 ): Promise<MaybeAccount<StudentProfile>[]> {
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
-  return maybeAccounts.map((maybeAccount) =>
-    decodeStudentProfile(maybeAccount)
-  );
+  return maybeAccounts.map((maybeAccount) => decodeStudentProfile(maybeAccount));
 }
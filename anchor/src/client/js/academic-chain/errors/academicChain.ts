@@ -36,41 +36,209 @@ export const ACADEMIC_CHAIN_ERROR__INVALID_COURSE_ID = 0x1778; // 6008
 export const ACADEMIC_CHAIN_ERROR__INVALID_COURSE_NAME = 0x1779; // 6009
 /** InvalidCredits: Invalid credits amount */
 export const ACADEMIC_CHAIN_ERROR__INVALID_CREDITS = 0x177a; // 6010
-/** Unauthorized: Unauthorized: Only program authority can perform this action */
+/** Unauthorized: Unauthorized: Signer is not the institution authority and lacks the required role */
 export const ACADEMIC_CHAIN_ERROR__UNAUTHORIZED = 0x177b; // 6011
 /** ArithmeticOverflow: Arithmetic overflow */
 export const ACADEMIC_CHAIN_ERROR__ARITHMETIC_OVERFLOW = 0x177c; // 6012
+/** InvalidCreditHours: Invalid credit hours */
+export const ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_HOURS = 0x177d; // 6013
+/** InvalidGradeScale: Invalid grade scale */
+export const ACADEMIC_CHAIN_ERROR__INVALID_GRADE_SCALE = 0x177e; // 6014
+/** CourseAlreadyCompleted: Course already completed */
+export const ACADEMIC_CHAIN_ERROR__COURSE_ALREADY_COMPLETED = 0x177f; // 6015
+/** InvalidBatchSize: Invalid batch size */
+export const ACADEMIC_CHAIN_ERROR__INVALID_BATCH_SIZE = 0x1780; // 6016
+/** InvalidBatchAccounts: Batch accounts do not match the student list */
+export const ACADEMIC_CHAIN_ERROR__INVALID_BATCH_ACCOUNTS = 0x1781; // 6017
+/** InvalidTermId: Invalid term ID format */
+export const ACADEMIC_CHAIN_ERROR__INVALID_TERM_ID = 0x1782; // 6018
+/** InvalidTermName: Invalid term name */
+export const ACADEMIC_CHAIN_ERROR__INVALID_TERM_NAME = 0x1783; // 6019
+/** InvalidTermDates: Invalid term dates */
+export const ACADEMIC_CHAIN_ERROR__INVALID_TERM_DATES = 0x1784; // 6020
+/** InvalidCapacity: Invalid offering capacity */
+export const ACADEMIC_CHAIN_ERROR__INVALID_CAPACITY = 0x1785; // 6021
+/** OfferingFull: Course offering is full */
+export const ACADEMIC_CHAIN_ERROR__OFFERING_FULL = 0x1786; // 6022
+/** InvalidDeadlines: Invalid enrollment or grading deadlines */
+export const ACADEMIC_CHAIN_ERROR__INVALID_DEADLINES = 0x1787; // 6023
+/** EnrollmentNotOpen: Enrollment has not opened yet */
+export const ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_OPEN = 0x1788; // 6024
+/** EnrollmentClosed: Enrollment is closed */
+export const ACADEMIC_CHAIN_ERROR__ENROLLMENT_CLOSED = 0x1789; // 6025
+/** GradingDeadlinePassed: Grading deadline has passed */
+export const ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_PASSED = 0x178a; // 6026
+/** GradingDeadlineNotReached: Grading deadline has not passed yet */
+export const ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_NOT_REACHED = 0x178b; // 6027
+/** EnrollmentNotActive: Enrollment is not awaiting a grade */
+export const ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_ACTIVE = 0x178c; // 6028
+/** InvalidMaxAttempts: Invalid maximum attempts */
+export const ACADEMIC_CHAIN_ERROR__INVALID_MAX_ATTEMPTS = 0x178d; // 6029
+/** RetakeNotAllowed: Only a failed course can be retaken */
+export const ACADEMIC_CHAIN_ERROR__RETAKE_NOT_ALLOWED = 0x178e; // 6030
+/** AttemptLimitReached: Maximum attempts for this course reached */
+export const ACADEMIC_CHAIN_ERROR__ATTEMPT_LIMIT_REACHED = 0x178f; // 6031
+/** InvalidDisplayName: Invalid display name */
+export const ACADEMIC_CHAIN_ERROR__INVALID_DISPLAY_NAME = 0x1790; // 6032
+/** InvalidMetadataUri: Invalid metadata URI */
+export const ACADEMIC_CHAIN_ERROR__INVALID_METADATA_URI = 0x1791; // 6033
+/** EnrollmentNotFinished: Enrollment is not finished */
+export const ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_FINISHED = 0x1792; // 6034
+/** OpenEnrollmentsRemain: Student still has open enrollments */
+export const ACADEMIC_CHAIN_ERROR__OPEN_ENROLLMENTS_REMAIN = 0x1793; // 6035
+/** InvalidCreditPrice: Invalid credit price */
+export const ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_PRICE = 0x1794; // 6036
+/** InvalidMetadataHash: Invalid metadata hash */
+export const ACADEMIC_CHAIN_ERROR__INVALID_METADATA_HASH = 0x1795; // 6037
+/** InvalidTranscript: Invalid transcript Merkle root or entry count */
+export const ACADEMIC_CHAIN_ERROR__INVALID_TRANSCRIPT = 0x1796; // 6038
+/** InvalidCertificateBatch: Invalid certificate batch root or count */
+export const ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_BATCH = 0x1797; // 6039
+/** InvalidMerkleProof: Merkle proof does not match the certificate batch */
+export const ACADEMIC_CHAIN_ERROR__INVALID_MERKLE_PROOF = 0x1798; // 6040
+/** InvalidCertificateTree: Invalid certificate tree depth or buffer size */
+export const ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_TREE = 0x1799; // 6041
+/** CertificateTreeFull: Certificate tree is full */
+export const ACADEMIC_CHAIN_ERROR__CERTIFICATE_TREE_FULL = 0x179a; // 6042
+/** InvalidInstitutionId: Invalid institution ID */
+export const ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_ID = 0x179b; // 6043
+/** InvalidInstitutionName: Invalid institution name */
+export const ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_NAME = 0x179c; // 6044
+/** InstitutionMismatch: Account belongs to a different institution */
+export const ACADEMIC_CHAIN_ERROR__INSTITUTION_MISMATCH = 0x179d; // 6045
+/** SameInstitution: Course equivalency must link two different institutions */
+export const ACADEMIC_CHAIN_ERROR__SAME_INSTITUTION = 0x179e; // 6046
+/** TransferNotPassed: Only a passed course can be transferred */
+export const ACADEMIC_CHAIN_ERROR__TRANSFER_NOT_PASSED = 0x179f; // 6047
+/** InvalidGovernance: Invalid governance admins, threshold or delay */
+export const ACADEMIC_CHAIN_ERROR__INVALID_GOVERNANCE = 0x17a0; // 6048
+/** NotGovernanceAdmin: Signer is not a governance admin */
+export const ACADEMIC_CHAIN_ERROR__NOT_GOVERNANCE_ADMIN = 0x17a1; // 6049
+/** EmptyConfigChange: Config change changes nothing */
+export const ACADEMIC_CHAIN_ERROR__EMPTY_CONFIG_CHANGE = 0x17a2; // 6050
+/** AlreadyApproved: Admin already approved this proposal */
+export const ACADEMIC_CHAIN_ERROR__ALREADY_APPROVED = 0x17a3; // 6051
+/** ProposalNotApproved: Proposal does not have enough approvals */
+export const ACADEMIC_CHAIN_ERROR__PROPOSAL_NOT_APPROVED = 0x17a4; // 6052
+/** ProposalTimelocked: Proposal delay has not elapsed */
+export const ACADEMIC_CHAIN_ERROR__PROPOSAL_TIMELOCKED = 0x17a5; // 6053
+/** ConfigChangeGoverned: Authority, treasury and credit mint changes need a config proposal */
+export const ACADEMIC_CHAIN_ERROR__CONFIG_CHANGE_GOVERNED = 0x17a6; // 6054
 
 export type AcademicChainError =
+  | typeof ACADEMIC_CHAIN_ERROR__ALREADY_APPROVED
   | typeof ACADEMIC_CHAIN_ERROR__ALREADY_ENROLLED
   | typeof ACADEMIC_CHAIN_ERROR__ARITHMETIC_OVERFLOW
+  | typeof ACADEMIC_CHAIN_ERROR__ATTEMPT_LIMIT_REACHED
   | typeof ACADEMIC_CHAIN_ERROR__CERTIFICATE_ALREADY_MINTED
+  | typeof ACADEMIC_CHAIN_ERROR__CERTIFICATE_TREE_FULL
+  | typeof ACADEMIC_CHAIN_ERROR__CONFIG_CHANGE_GOVERNED
+  | typeof ACADEMIC_CHAIN_ERROR__COURSE_ALREADY_COMPLETED
   | typeof ACADEMIC_CHAIN_ERROR__COURSE_NOT_ACTIVE
   | typeof ACADEMIC_CHAIN_ERROR__COURSE_NOT_COMPLETED
+  | typeof ACADEMIC_CHAIN_ERROR__EMPTY_CONFIG_CHANGE
+  | typeof ACADEMIC_CHAIN_ERROR__ENROLLMENT_CLOSED
+  | typeof ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_ACTIVE
+  | typeof ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_FINISHED
+  | typeof ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_OPEN
+  | typeof ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_NOT_REACHED
+  | typeof ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_PASSED
+  | typeof ACADEMIC_CHAIN_ERROR__INSTITUTION_MISMATCH
   | typeof ACADEMIC_CHAIN_ERROR__INSUFFICIENT_CREDITS
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_BATCH_ACCOUNTS
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_BATCH_SIZE
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_CAPACITY
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_BATCH
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_TREE
   | typeof ACADEMIC_CHAIN_ERROR__INVALID_COURSE_ID
   | typeof ACADEMIC_CHAIN_ERROR__INVALID_COURSE_NAME
   | typeof ACADEMIC_CHAIN_ERROR__INVALID_CREDITS
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_HOURS
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_PRICE
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_DEADLINES
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_DISPLAY_NAME
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_GOVERNANCE
   | typeof ACADEMIC_CHAIN_ERROR__INVALID_GRADE
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_GRADE_SCALE
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_ID
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_NAME
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_MAX_ATTEMPTS
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_MERKLE_PROOF
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_METADATA_HASH
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_METADATA_URI
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_TERM_DATES
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_TERM_ID
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_TERM_NAME
+  | typeof ACADEMIC_CHAIN_ERROR__INVALID_TRANSCRIPT
+  | typeof ACADEMIC_CHAIN_ERROR__NOT_GOVERNANCE_ADMIN
+  | typeof ACADEMIC_CHAIN_ERROR__OFFERING_FULL
+  | typeof ACADEMIC_CHAIN_ERROR__OPEN_ENROLLMENTS_REMAIN
+  | typeof ACADEMIC_CHAIN_ERROR__PROPOSAL_NOT_APPROVED
+  | typeof ACADEMIC_CHAIN_ERROR__PROPOSAL_TIMELOCKED
   | typeof ACADEMIC_CHAIN_ERROR__REQUIREMENTS_NOT_MET
+  | typeof ACADEMIC_CHAIN_ERROR__RETAKE_NOT_ALLOWED
+  | typeof ACADEMIC_CHAIN_ERROR__SAME_INSTITUTION
+  | typeof ACADEMIC_CHAIN_ERROR__TRANSFER_NOT_PASSED
   | typeof ACADEMIC_CHAIN_ERROR__UNAUTHORIZED
   | typeof ACADEMIC_CHAIN_ERROR__UNAUTHORIZED_INSTRUCTOR;
 
 let academicChainErrorMessages: Record<AcademicChainError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   academicChainErrorMessages = {
+    [ACADEMIC_CHAIN_ERROR__ALREADY_APPROVED]: `Admin already approved this proposal`,
     [ACADEMIC_CHAIN_ERROR__ALREADY_ENROLLED]: `Student already enrolled in this course`,
     [ACADEMIC_CHAIN_ERROR__ARITHMETIC_OVERFLOW]: `Arithmetic overflow`,
+    [ACADEMIC_CHAIN_ERROR__ATTEMPT_LIMIT_REACHED]: `Maximum attempts for this course reached`,
     [ACADEMIC_CHAIN_ERROR__CERTIFICATE_ALREADY_MINTED]: `Certificate already minted for this course`,
+    [ACADEMIC_CHAIN_ERROR__CERTIFICATE_TREE_FULL]: `Certificate tree is full`,
+    [ACADEMIC_CHAIN_ERROR__CONFIG_CHANGE_GOVERNED]: `Authority, treasury and credit mint changes need a config proposal`,
+    [ACADEMIC_CHAIN_ERROR__COURSE_ALREADY_COMPLETED]: `Course already completed`,
     [ACADEMIC_CHAIN_ERROR__COURSE_NOT_ACTIVE]: `Course is not active`,
     [ACADEMIC_CHAIN_ERROR__COURSE_NOT_COMPLETED]: `Course not completed yet`,
+    [ACADEMIC_CHAIN_ERROR__EMPTY_CONFIG_CHANGE]: `Config change changes nothing`,
+    [ACADEMIC_CHAIN_ERROR__ENROLLMENT_CLOSED]: `Enrollment is closed`,
+    [ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_ACTIVE]: `Enrollment is not awaiting a grade`,
+    [ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_FINISHED]: `Enrollment is not finished`,
+    [ACADEMIC_CHAIN_ERROR__ENROLLMENT_NOT_OPEN]: `Enrollment has not opened yet`,
+    [ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_NOT_REACHED]: `Grading deadline has not passed yet`,
+    [ACADEMIC_CHAIN_ERROR__GRADING_DEADLINE_PASSED]: `Grading deadline has passed`,
+    [ACADEMIC_CHAIN_ERROR__INSTITUTION_MISMATCH]: `Account belongs to a different institution`,
     [ACADEMIC_CHAIN_ERROR__INSUFFICIENT_CREDITS]: `Insufficient credits to register for this course`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_BATCH_ACCOUNTS]: `Batch accounts do not match the student list`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_BATCH_SIZE]: `Invalid batch size`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_CAPACITY]: `Invalid offering capacity`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_BATCH]: `Invalid certificate batch root or count`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_CERTIFICATE_TREE]: `Invalid certificate tree depth or buffer size`,
     [ACADEMIC_CHAIN_ERROR__INVALID_COURSE_ID]: `Invalid course ID format`,
     [ACADEMIC_CHAIN_ERROR__INVALID_COURSE_NAME]: `Invalid course name`,
     [ACADEMIC_CHAIN_ERROR__INVALID_CREDITS]: `Invalid credits amount`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_HOURS]: `Invalid credit hours`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_CREDIT_PRICE]: `Invalid credit price`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_DEADLINES]: `Invalid enrollment or grading deadlines`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_DISPLAY_NAME]: `Invalid display name`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_GOVERNANCE]: `Invalid governance admins, threshold or delay`,
     [ACADEMIC_CHAIN_ERROR__INVALID_GRADE]: `Invalid grade value (must be 0-100)`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_GRADE_SCALE]: `Invalid grade scale`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_ID]: `Invalid institution ID`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_INSTITUTION_NAME]: `Invalid institution name`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_MAX_ATTEMPTS]: `Invalid maximum attempts`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_MERKLE_PROOF]: `Merkle proof does not match the certificate batch`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_METADATA_HASH]: `Invalid metadata hash`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_METADATA_URI]: `Invalid metadata URI`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_TERM_DATES]: `Invalid term dates`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_TERM_ID]: `Invalid term ID format`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_TERM_NAME]: `Invalid term name`,
+    [ACADEMIC_CHAIN_ERROR__INVALID_TRANSCRIPT]: `Invalid transcript Merkle root or entry count`,
+    [ACADEMIC_CHAIN_ERROR__NOT_GOVERNANCE_ADMIN]: `Signer is not a governance admin`,
+    [ACADEMIC_CHAIN_ERROR__OFFERING_FULL]: `Course offering is full`,
+    [ACADEMIC_CHAIN_ERROR__OPEN_ENROLLMENTS_REMAIN]: `Student still has open enrollments`,
+    [ACADEMIC_CHAIN_ERROR__PROPOSAL_NOT_APPROVED]: `Proposal does not have enough approvals`,
+    [ACADEMIC_CHAIN_ERROR__PROPOSAL_TIMELOCKED]: `Proposal delay has not elapsed`,
     [ACADEMIC_CHAIN_ERROR__REQUIREMENTS_NOT_MET]: `Not all required courses completed`,
-    [ACADEMIC_CHAIN_ERROR__UNAUTHORIZED]: `Unauthorized: Only program authority can perform this action`,
+    [ACADEMIC_CHAIN_ERROR__RETAKE_NOT_ALLOWED]: `Only a failed course can be retaken`,
+    [ACADEMIC_CHAIN_ERROR__SAME_INSTITUTION]: `Course equivalency must link two different institutions`,
+    [ACADEMIC_CHAIN_ERROR__TRANSFER_NOT_PASSED]: `Only a passed course can be transferred`,
+    [ACADEMIC_CHAIN_ERROR__UNAUTHORIZED]: `Unauthorized: Signer is not the institution authority and lacks the required role`,
     [ACADEMIC_CHAIN_ERROR__UNAUTHORIZED_INSTRUCTOR]: `Unauthorized: Only instructor can perform this action`,
   };
 }
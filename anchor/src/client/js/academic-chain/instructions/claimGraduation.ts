@@ -60,11 +60,14 @@ export function getClaimGraduationDiscriminatorBytes() {
 export type ClaimGraduationInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountStudent extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountStudentProfile extends string | AccountMeta<string> = string,
   TAccountGraduationMint extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,6 +77,9 @@ export type ClaimGraduationInstruction<
         ? WritableSignerAccount<TAccountStudent> &
             AccountSignerMeta<TAccountStudent>
         : TAccountStudent,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountStudentProfile extends string
         ? WritableAccount<TAccountStudentProfile>
         : TAccountStudentProfile,
@@ -83,6 +89,12 @@ export type ClaimGraduationInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -133,38 +145,53 @@ export function getClaimGraduationInstructionDataCodec(): Codec<
 
 export type ClaimGraduationAsyncInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountStudentProfile extends string = string,
   TAccountGraduationMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   studentProfile?: Address<TAccountStudentProfile>;
   graduationMint?: Address<TAccountGraduationMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   requiredCourses: ClaimGraduationInstructionDataArgs['requiredCourses'];
 };
 
 export async function getClaimGraduationInstructionAsync<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountStudentProfile extends string,
   TAccountGraduationMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: ClaimGraduationAsyncInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountStudentProfile,
     TAccountGraduationMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   ClaimGraduationInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountStudentProfile,
     TAccountGraduationMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -174,9 +201,12 @@ export async function getClaimGraduationInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
     graduationMint: { value: input.graduationMint ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -197,6 +227,7 @@ export async function getClaimGraduationInstructionAsync<
             101,
           ])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
       ],
     });
@@ -211,6 +242,7 @@ export async function getClaimGraduationInstructionAsync<
             116,
           ])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
       ],
     });
@@ -219,14 +251,30 @@ export async function getClaimGraduationInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.studentProfile),
       getAccountMeta(accounts.graduationMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimGraduationInstructionDataEncoder().encode(
       args as ClaimGraduationInstructionDataArgs
@@ -235,45 +283,63 @@ export async function getClaimGraduationInstructionAsync<
   } as ClaimGraduationInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountStudentProfile,
     TAccountGraduationMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type ClaimGraduationInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountStudentProfile extends string = string,
   TAccountGraduationMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   studentProfile: Address<TAccountStudentProfile>;
   graduationMint: Address<TAccountGraduationMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   requiredCourses: ClaimGraduationInstructionDataArgs['requiredCourses'];
 };
 
 export function getClaimGraduationInstruction<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountStudentProfile extends string,
   TAccountGraduationMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: ClaimGraduationInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountStudentProfile,
     TAccountGraduationMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClaimGraduationInstruction<
   TProgramAddress,
   TAccountStudent,
+  TAccountInstitution,
   TAccountStudentProfile,
   TAccountGraduationMint,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -282,9 +348,12 @@ export function getClaimGraduationInstruction<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
     graduationMint: { value: input.graduationMint ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -304,9 +373,12 @@ export function getClaimGraduationInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.studentProfile),
       getAccountMeta(accounts.graduationMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getClaimGraduationInstructionDataEncoder().encode(
       args as ClaimGraduationInstructionDataArgs
@@ -315,9 +387,12 @@ export function getClaimGraduationInstruction<
   } as ClaimGraduationInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountStudentProfile,
     TAccountGraduationMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -328,9 +403,12 @@ export type ParsedClaimGraduationInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     student: TAccountMetas[0];
-    studentProfile: TAccountMetas[1];
-    graduationMint: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
+    institution: TAccountMetas[1];
+    studentProfile: TAccountMetas[2];
+    graduationMint: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: ClaimGraduationInstructionData;
 };
@@ -343,7 +421,7 @@ export function parseClaimGraduationInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimGraduationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -357,9 +435,12 @@ export function parseClaimGraduationInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       student: getNextAccount(),
+      institution: getNextAccount(),
       studentProfile: getNextAccount(),
       graduationMint: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getClaimGraduationInstructionDataDecoder().decode(instruction.data),
   };
@@ -61,10 +61,14 @@ export function getCompleteCourseDiscriminatorBytes() {
 export type CompleteCourseInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountInstructor extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountCourse extends string | AccountMeta<string> = string,
+  TAccountOffering extends string | AccountMeta<string> = string,
   TAccountStudent extends string | AccountMeta<string> = string,
   TAccountEnrollment extends string | AccountMeta<string> = string,
   TAccountStudentProfile extends string | AccountMeta<string> = string,
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,9 +78,15 @@ export type CompleteCourseInstruction<
         ? WritableSignerAccount<TAccountInstructor> &
             AccountSignerMeta<TAccountInstructor>
         : TAccountInstructor,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountCourse extends string
         ? ReadonlyAccount<TAccountCourse>
         : TAccountCourse,
+      TAccountOffering extends string
+        ? ReadonlyAccount<TAccountOffering>
+        : TAccountOffering,
       TAccountStudent extends string
         ? ReadonlyAccount<TAccountStudent>
         : TAccountStudent,
@@ -86,6 +96,12 @@ export type CompleteCourseInstruction<
       TAccountStudentProfile extends string
         ? WritableAccount<TAccountStudentProfile>
         : TAccountStudentProfile,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -93,11 +109,13 @@ export type CompleteCourseInstruction<
 export type CompleteCourseInstructionData = {
   discriminator: ReadonlyUint8Array;
   courseId: string;
+  termId: string;
   grade: number;
 };
 
 export type CompleteCourseInstructionDataArgs = {
   courseId: string;
+  termId: string;
   grade: number;
 };
 
@@ -106,6 +124,7 @@ export function getCompleteCourseInstructionDataEncoder(): Encoder<CompleteCours
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['courseId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['termId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['grade', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: COMPLETE_COURSE_DISCRIMINATOR })
@@ -116,6 +135,7 @@ export function getCompleteCourseInstructionDataDecoder(): Decoder<CompleteCours
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['courseId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['termId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['grade', getU8Decoder()],
   ]);
 }
@@ -132,44 +152,65 @@ export function getCompleteCourseInstructionDataCodec(): Codec<
 
 export type CompleteCourseAsyncInput<
   TAccountInstructor extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountStudent extends string = string,
   TAccountEnrollment extends string = string,
   TAccountStudentProfile extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   instructor: TransactionSigner<TAccountInstructor>;
+  institution: Address<TAccountInstitution>;
   course?: Address<TAccountCourse>;
+  offering?: Address<TAccountOffering>;
   student: Address<TAccountStudent>;
   enrollment?: Address<TAccountEnrollment>;
   studentProfile?: Address<TAccountStudentProfile>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: CompleteCourseInstructionDataArgs['courseId'];
+  termId: CompleteCourseInstructionDataArgs['termId'];
   grade: CompleteCourseInstructionDataArgs['grade'];
 };
 
 export async function getCompleteCourseInstructionAsync<
   TAccountInstructor extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountStudent extends string,
   TAccountEnrollment extends string,
   TAccountStudentProfile extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: CompleteCourseAsyncInput<
     TAccountInstructor,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountStudent,
     TAccountEnrollment,
-    TAccountStudentProfile
+    TAccountStudentProfile,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   CompleteCourseInstruction<
     TProgramAddress,
     TAccountInstructor,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountStudent,
     TAccountEnrollment,
-    TAccountStudentProfile
+    TAccountStudentProfile,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -179,10 +220,14 @@ export async function getCompleteCourseInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     instructor: { value: input.instructor ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: false },
     student: { value: input.student ?? null, isWritable: false },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -198,9 +243,21 @@ export async function getCompleteCourseInstructionAsync<
       programAddress,
       seeds: [
         getBytesEncoder().encode(new Uint8Array([99, 111, 117, 114, 115, 101])),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+      ],
+    });
+  }
+  if (!accounts.offering.value) {
+    accounts.offering.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([111, 102, 102, 101, 114, 105, 110, 103])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+        getUtf8Encoder().encode(expectSome(args.termId)),
       ],
     });
   }
@@ -212,9 +269,7 @@ export async function getCompleteCourseInstructionAsync<
           new Uint8Array([101, 110, 114, 111, 108, 108, 109, 101, 110, 116])
         ),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.offering.value)),
       ],
     });
   }
@@ -228,19 +283,37 @@ export async function getCompleteCourseInstructionAsync<
             101,
           ])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
       ],
     });
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.instructor),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.student),
       getAccountMeta(accounts.enrollment),
       getAccountMeta(accounts.studentProfile),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCompleteCourseInstructionDataEncoder().encode(
       args as CompleteCourseInstructionDataArgs
@@ -249,52 +322,77 @@ export async function getCompleteCourseInstructionAsync<
   } as CompleteCourseInstruction<
     TProgramAddress,
     TAccountInstructor,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountStudent,
     TAccountEnrollment,
-    TAccountStudentProfile
+    TAccountStudentProfile,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type CompleteCourseInput<
   TAccountInstructor extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountStudent extends string = string,
   TAccountEnrollment extends string = string,
   TAccountStudentProfile extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   instructor: TransactionSigner<TAccountInstructor>;
+  institution: Address<TAccountInstitution>;
   course: Address<TAccountCourse>;
+  offering: Address<TAccountOffering>;
   student: Address<TAccountStudent>;
   enrollment: Address<TAccountEnrollment>;
   studentProfile: Address<TAccountStudentProfile>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: CompleteCourseInstructionDataArgs['courseId'];
+  termId: CompleteCourseInstructionDataArgs['termId'];
   grade: CompleteCourseInstructionDataArgs['grade'];
 };
 
 export function getCompleteCourseInstruction<
   TAccountInstructor extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountStudent extends string,
   TAccountEnrollment extends string,
   TAccountStudentProfile extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: CompleteCourseInput<
     TAccountInstructor,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountStudent,
     TAccountEnrollment,
-    TAccountStudentProfile
+    TAccountStudentProfile,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CompleteCourseInstruction<
   TProgramAddress,
   TAccountInstructor,
+  TAccountInstitution,
   TAccountCourse,
+  TAccountOffering,
   TAccountStudent,
   TAccountEnrollment,
-  TAccountStudentProfile
+  TAccountStudentProfile,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -303,10 +401,14 @@ export function getCompleteCourseInstruction<
   // Original accounts.
   const originalAccounts = {
     instructor: { value: input.instructor ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: false },
     student: { value: input.student ?? null, isWritable: false },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -320,10 +422,14 @@ export function getCompleteCourseInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.instructor),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.student),
       getAccountMeta(accounts.enrollment),
       getAccountMeta(accounts.studentProfile),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCompleteCourseInstructionDataEncoder().encode(
       args as CompleteCourseInstructionDataArgs
@@ -332,10 +438,14 @@ export function getCompleteCourseInstruction<
   } as CompleteCourseInstruction<
     TProgramAddress,
     TAccountInstructor,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountStudent,
     TAccountEnrollment,
-    TAccountStudentProfile
+    TAccountStudentProfile,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -346,10 +456,14 @@ export type ParsedCompleteCourseInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     instructor: TAccountMetas[0];
-    course: TAccountMetas[1];
-    student: TAccountMetas[2];
-    enrollment: TAccountMetas[3];
-    studentProfile: TAccountMetas[4];
+    institution: TAccountMetas[1];
+    course: TAccountMetas[2];
+    offering: TAccountMetas[3];
+    student: TAccountMetas[4];
+    enrollment: TAccountMetas[5];
+    studentProfile: TAccountMetas[6];
+    eventAuthority: TAccountMetas[7];
+    program: TAccountMetas[8];
   };
   data: CompleteCourseInstructionData;
 };
@@ -362,7 +476,7 @@ export function parseCompleteCourseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCompleteCourseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -376,10 +490,14 @@ export function parseCompleteCourseInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       instructor: getNextAccount(),
+      institution: getNextAccount(),
       course: getNextAccount(),
+      offering: getNextAccount(),
       student: getNextAccount(),
       enrollment: getNextAccount(),
       studentProfile: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCompleteCourseInstructionDataDecoder().decode(instruction.data),
   };
@@ -23,6 +23,8 @@ import {
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   transformEncoder,
@@ -43,6 +45,7 @@ import {
 } from 'gill';
 import { ACADEMIC_CHAIN_PROGRAM_ADDRESS } from '../programs';
 import {
+  expectAddress,
   expectSome,
   getAccountMetaFactory,
   type ResolvedAccount,
@@ -61,10 +64,14 @@ export function getCreateCourseDiscriminatorBytes() {
 export type CreateCourseInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
+  TAccountAuthorityRole extends string | AccountMeta<string> = string,
   TAccountCourse extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,12 +81,24 @@ export type CreateCourseInstruction<
         ? WritableSignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
+      TAccountAuthorityRole extends string
+        ? ReadonlyAccount<TAccountAuthorityRole>
+        : TAccountAuthorityRole,
       TAccountCourse extends string
         ? WritableAccount<TAccountCourse>
         : TAccountCourse,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -90,6 +109,8 @@ export type CreateCourseInstructionData = {
   courseName: string;
   instructor: Address;
   requiredCredits: bigint;
+  creditHours: number;
+  maxAttempts: number;
 };
 
 export type CreateCourseInstructionDataArgs = {
@@ -97,6 +118,8 @@ export type CreateCourseInstructionDataArgs = {
   courseName: string;
   instructor: Address;
   requiredCredits: number | bigint;
+  creditHours: number;
+  maxAttempts: number;
 };
 
 export function getCreateCourseInstructionDataEncoder(): Encoder<CreateCourseInstructionDataArgs> {
@@ -107,6 +130,8 @@ export function getCreateCourseInstructionDataEncoder(): Encoder<CreateCourseIns
       ['courseName', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['instructor', getAddressEncoder()],
       ['requiredCredits', getU64Encoder()],
+      ['creditHours', getU8Encoder()],
+      ['maxAttempts', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_COURSE_DISCRIMINATOR })
   );
@@ -119,6 +144,8 @@ export function getCreateCourseInstructionDataDecoder(): Decoder<CreateCourseIns
     ['courseName', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['instructor', getAddressDecoder()],
     ['requiredCredits', getU64Decoder()],
+    ['creditHours', getU8Decoder()],
+    ['maxAttempts', getU8Decoder()],
   ]);
 }
 
@@ -134,36 +161,58 @@ export function getCreateCourseInstructionDataCodec(): Codec<
 
 export type CreateCourseAsyncInput<
   TAccountAuthority extends string = string,
+  TAccountInstitution extends string = string,
+  TAccountAuthorityRole extends string = string,
   TAccountCourse extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
+  institution: Address<TAccountInstitution>;
+  authorityRole?: Address<TAccountAuthorityRole>;
   course?: Address<TAccountCourse>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: CreateCourseInstructionDataArgs['courseId'];
   courseName: CreateCourseInstructionDataArgs['courseName'];
   instructor: CreateCourseInstructionDataArgs['instructor'];
   requiredCredits: CreateCourseInstructionDataArgs['requiredCredits'];
+  creditHours: CreateCourseInstructionDataArgs['creditHours'];
+  maxAttempts: CreateCourseInstructionDataArgs['maxAttempts'];
 };
 
 export async function getCreateCourseInstructionAsync<
   TAccountAuthority extends string,
+  TAccountInstitution extends string,
+  TAccountAuthorityRole extends string,
   TAccountCourse extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: CreateCourseAsyncInput<
     TAccountAuthority,
+    TAccountInstitution,
+    TAccountAuthorityRole,
     TAccountCourse,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   CreateCourseInstruction<
     TProgramAddress,
     TAccountAuthority,
+    TAccountInstitution,
+    TAccountAuthorityRole,
     TAccountCourse,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -173,8 +222,12 @@ export async function getCreateCourseInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
+    authorityRole: { value: input.authorityRole ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -190,9 +243,8 @@ export async function getCreateCourseInstructionAsync<
       programAddress,
       seeds: [
         getBytesEncoder().encode(new Uint8Array([99, 111, 117, 114, 115, 101])),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
       ],
     });
   }
@@ -200,13 +252,30 @@ export async function getCreateCourseInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.institution),
+      getAccountMeta(accounts.authorityRole),
       getAccountMeta(accounts.course),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCreateCourseInstructionDataEncoder().encode(
       args as CreateCourseInstructionDataArgs
@@ -215,42 +284,68 @@ export async function getCreateCourseInstructionAsync<
   } as CreateCourseInstruction<
     TProgramAddress,
     TAccountAuthority,
+    TAccountInstitution,
+    TAccountAuthorityRole,
     TAccountCourse,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type CreateCourseInput<
   TAccountAuthority extends string = string,
+  TAccountInstitution extends string = string,
+  TAccountAuthorityRole extends string = string,
   TAccountCourse extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
+  institution: Address<TAccountInstitution>;
+  authorityRole?: Address<TAccountAuthorityRole>;
   course: Address<TAccountCourse>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: CreateCourseInstructionDataArgs['courseId'];
   courseName: CreateCourseInstructionDataArgs['courseName'];
   instructor: CreateCourseInstructionDataArgs['instructor'];
   requiredCredits: CreateCourseInstructionDataArgs['requiredCredits'];
+  creditHours: CreateCourseInstructionDataArgs['creditHours'];
+  maxAttempts: CreateCourseInstructionDataArgs['maxAttempts'];
 };
 
 export function getCreateCourseInstruction<
   TAccountAuthority extends string,
+  TAccountInstitution extends string,
+  TAccountAuthorityRole extends string,
   TAccountCourse extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: CreateCourseInput<
     TAccountAuthority,
+    TAccountInstitution,
+    TAccountAuthorityRole,
     TAccountCourse,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateCourseInstruction<
   TProgramAddress,
   TAccountAuthority,
+  TAccountInstitution,
+  TAccountAuthorityRole,
   TAccountCourse,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -259,8 +354,12 @@ export function getCreateCourseInstruction<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
+    authorityRole: { value: input.authorityRole ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -280,8 +379,12 @@ export function getCreateCourseInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.institution),
+      getAccountMeta(accounts.authorityRole),
       getAccountMeta(accounts.course),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getCreateCourseInstructionDataEncoder().encode(
       args as CreateCourseInstructionDataArgs
@@ -290,8 +393,12 @@ export function getCreateCourseInstruction<
   } as CreateCourseInstruction<
     TProgramAddress,
     TAccountAuthority,
+    TAccountInstitution,
+    TAccountAuthorityRole,
     TAccountCourse,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -302,8 +409,12 @@ export type ParsedCreateCourseInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     authority: TAccountMetas[0];
-    course: TAccountMetas[1];
-    systemProgram: TAccountMetas[2];
+    institution: TAccountMetas[1];
+    authorityRole?: TAccountMetas[2] | undefined;
+    course: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CreateCourseInstructionData;
 };
@@ -316,7 +427,7 @@ export function parseCreateCourseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCreateCourseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -326,12 +437,22 @@ export function parseCreateCourseInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ACADEMIC_CHAIN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       authority: getNextAccount(),
+      institution: getNextAccount(),
+      authorityRole: getNextOptionalAccount(),
       course: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getCreateCourseInstructionDataDecoder().decode(instruction.data),
   };
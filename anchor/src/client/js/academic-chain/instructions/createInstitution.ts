@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
@@ -15,13 +17,17 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -32,25 +38,33 @@ import {
   type WritableSignerAccount,
 } from 'gill';
 import { ACADEMIC_CHAIN_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectSome,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
-export const INITIALIZE_DISCRIMINATOR = new Uint8Array([
-  175, 175, 109, 31, 13, 152, 155, 237,
+export const CREATE_INSTITUTION_DISCRIMINATOR = new Uint8Array([
+  72, 152, 229, 14, 153, 141, 73, 139,
 ]);
 
-export function getInitializeDiscriminatorBytes() {
-  return fixEncoderSize(getBytesEncoder(), 8).encode(INITIALIZE_DISCRIMINATOR);
+export function getCreateInstitutionDiscriminatorBytes() {
+  return fixEncoderSize(getBytesEncoder(), 8).encode(
+    CREATE_INSTITUTION_DISCRIMINATOR
+  );
 }
 
-export type InitializeInstruction<
+export type CreateInstitutionInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountAuthority extends string | AccountMeta<string> = string,
-  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountTreasury extends string | AccountMeta<string> = string,
   TAccountCreditMint extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,9 +74,9 @@ export type InitializeInstruction<
         ? WritableSignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
-      TAccountConfig extends string
-        ? WritableAccount<TAccountConfig>
-        : TAccountConfig,
+      TAccountInstitution extends string
+        ? WritableAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountTreasury extends string
         ? ReadonlyAccount<TAccountTreasury>
         : TAccountTreasury,
@@ -72,75 +86,109 @@ export type InitializeInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type InitializeInstructionData = { discriminator: ReadonlyUint8Array };
+export type CreateInstitutionInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  institutionId: string;
+  name: string;
+};
 
-export type InitializeInstructionDataArgs = {};
+export type CreateInstitutionInstructionDataArgs = {
+  institutionId: string;
+  name: string;
+};
 
-export function getInitializeInstructionDataEncoder(): FixedSizeEncoder<InitializeInstructionDataArgs> {
+export function getCreateInstitutionInstructionDataEncoder(): Encoder<CreateInstitutionInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
-    (value) => ({ ...value, discriminator: INITIALIZE_DISCRIMINATOR })
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      [
+        'institutionId',
+        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()),
+      ],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+    ]),
+    (value) => ({ ...value, discriminator: CREATE_INSTITUTION_DISCRIMINATOR })
   );
 }
 
-export function getInitializeInstructionDataDecoder(): FixedSizeDecoder<InitializeInstructionData> {
+export function getCreateInstitutionInstructionDataDecoder(): Decoder<CreateInstitutionInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['institutionId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
   ]);
 }
 
-export function getInitializeInstructionDataCodec(): FixedSizeCodec<
-  InitializeInstructionDataArgs,
-  InitializeInstructionData
+export function getCreateInstitutionInstructionDataCodec(): Codec<
+  CreateInstitutionInstructionDataArgs,
+  CreateInstitutionInstructionData
 > {
   return combineCodec(
-    getInitializeInstructionDataEncoder(),
-    getInitializeInstructionDataDecoder()
+    getCreateInstitutionInstructionDataEncoder(),
+    getCreateInstitutionInstructionDataDecoder()
   );
 }
 
-export type InitializeAsyncInput<
+export type CreateInstitutionAsyncInput<
   TAccountAuthority extends string = string,
-  TAccountConfig extends string = string,
+  TAccountInstitution extends string = string,
   TAccountTreasury extends string = string,
   TAccountCreditMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
-  config?: Address<TAccountConfig>;
+  institution?: Address<TAccountInstitution>;
   treasury: Address<TAccountTreasury>;
   creditMint: Address<TAccountCreditMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  institutionId: CreateInstitutionInstructionDataArgs['institutionId'];
+  name: CreateInstitutionInstructionDataArgs['name'];
 };
 
-export async function getInitializeInstructionAsync<
+export async function getCreateInstitutionInstructionAsync<
   TAccountAuthority extends string,
-  TAccountConfig extends string,
+  TAccountInstitution extends string,
   TAccountTreasury extends string,
   TAccountCreditMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
-  input: InitializeAsyncInput<
+  input: CreateInstitutionAsyncInput<
     TAccountAuthority,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
-  InitializeInstruction<
+  CreateInstitutionInstruction<
     TProgramAddress,
     TAccountAuthority,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -150,22 +198,32 @@ export async function getInitializeInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: true },
     treasury: { value: input.treasury ?? null, isWritable: false },
     creditMint: { value: input.creditMint ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
+  if (!accounts.institution.value) {
+    accounts.institution.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            105, 110, 115, 116, 105, 116, 117, 116, 105, 111, 110,
+          ])
+        ),
+        getUtf8Encoder().encode(expectSome(args.institutionId)),
       ],
     });
   }
@@ -173,65 +231,96 @@ export async function getInitializeInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
-      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getInitializeInstructionDataEncoder().encode({}),
+    data: getCreateInstitutionInstructionDataEncoder().encode(
+      args as CreateInstitutionInstructionDataArgs
+    ),
     programAddress,
-  } as InitializeInstruction<
+  } as CreateInstitutionInstruction<
     TProgramAddress,
     TAccountAuthority,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
-export type InitializeInput<
+export type CreateInstitutionInput<
   TAccountAuthority extends string = string,
-  TAccountConfig extends string = string,
+  TAccountInstitution extends string = string,
   TAccountTreasury extends string = string,
   TAccountCreditMint extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
-  config: Address<TAccountConfig>;
+  institution: Address<TAccountInstitution>;
   treasury: Address<TAccountTreasury>;
   creditMint: Address<TAccountCreditMint>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
+  institutionId: CreateInstitutionInstructionDataArgs['institutionId'];
+  name: CreateInstitutionInstructionDataArgs['name'];
 };
 
-export function getInitializeInstruction<
+export function getCreateInstitutionInstruction<
   TAccountAuthority extends string,
-  TAccountConfig extends string,
+  TAccountInstitution extends string,
   TAccountTreasury extends string,
   TAccountCreditMint extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
-  input: InitializeInput<
+  input: CreateInstitutionInput<
     TAccountAuthority,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
-): InitializeInstruction<
+): CreateInstitutionInstruction<
   TProgramAddress,
   TAccountAuthority,
-  TAccountConfig,
+  TAccountInstitution,
   TAccountTreasury,
   TAccountCreditMint,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -240,16 +329,21 @@ export function getInitializeInstruction<
   // Original accounts.
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: true },
     treasury: { value: input.treasury ?? null, isWritable: false },
     creditMint: { value: input.creditMint ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -260,47 +354,55 @@ export function getInitializeInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.authority),
-      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
-    data: getInitializeInstructionDataEncoder().encode({}),
+    data: getCreateInstitutionInstructionDataEncoder().encode(
+      args as CreateInstitutionInstructionDataArgs
+    ),
     programAddress,
-  } as InitializeInstruction<
+  } as CreateInstitutionInstruction<
     TProgramAddress,
     TAccountAuthority,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
-export type ParsedInitializeInstruction<
+export type ParsedCreateInstitutionInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
   programAddress: Address<TProgram>;
   accounts: {
     authority: TAccountMetas[0];
-    config: TAccountMetas[1];
+    institution: TAccountMetas[1];
     treasury: TAccountMetas[2];
     creditMint: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
-  data: InitializeInstructionData;
+  data: CreateInstitutionInstructionData;
 };
 
-export function parseInitializeInstruction<
+export function parseCreateInstitutionInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+): ParsedCreateInstitutionInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -314,11 +416,13 @@ export function parseInitializeInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       authority: getNextAccount(),
-      config: getNextAccount(),
+      institution: getNextAccount(),
       treasury: getNextAccount(),
       creditMint: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
-    data: getInitializeInstructionDataDecoder().decode(instruction.data),
+    data: getCreateInstitutionInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -6,10 +6,38 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './amendGrade';
+export * from './anchorTranscript';
+export * from './approveConfigChange';
+export * from './cancelConfigChange';
+export * from './claimBatchCertificate';
 export * from './claimGraduation';
+export * from './closeEnrollment';
+export * from './closeProfile';
 export * from './completeCourse';
+export * from './completeCourseBatch';
+export * from './createCertificateBatch';
+export * from './createCertificateTree';
+export * from './createConfigGovernance';
 export * from './createCourse';
-export * from './initialize';
+export * from './createCourseEquivalency';
+export * from './createInstitution';
+export * from './createOffering';
+export * from './createStudentProfile';
+export * from './createTerm';
+export * from './executeConfigChange';
+export * from './expireEnrollment';
+export * from './grantRole';
 export * from './mintCertificate';
+export * from './mintCompressedCertificate';
+export * from './proposeConfigChange';
 export * from './purchaseCredits';
 export * from './registerCourse';
+export * from './retakeCourse';
+export * from './revokeRole';
+export * from './setGradeScale';
+export * from './transferCompletion';
+export * from './updateConfig';
+export * from './updateCourse';
+export * from './verifyBatchCertificate';
+export * from './verifyTranscriptEntry';
@@ -59,12 +59,17 @@ export function getMintCertificateDiscriminatorBytes() {
 export type MintCertificateInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountStudent extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountCourse extends string | AccountMeta<string> = string,
+  TAccountOffering extends string | AccountMeta<string> = string,
   TAccountEnrollment extends string | AccountMeta<string> = string,
   TAccountCertificateMint extends string | AccountMeta<string> = string,
+  TAccountCertificateRecord extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,18 +79,33 @@ export type MintCertificateInstruction<
         ? WritableSignerAccount<TAccountStudent> &
             AccountSignerMeta<TAccountStudent>
         : TAccountStudent,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountCourse extends string
         ? ReadonlyAccount<TAccountCourse>
         : TAccountCourse,
+      TAccountOffering extends string
+        ? ReadonlyAccount<TAccountOffering>
+        : TAccountOffering,
       TAccountEnrollment extends string
         ? WritableAccount<TAccountEnrollment>
         : TAccountEnrollment,
       TAccountCertificateMint extends string
         ? WritableAccount<TAccountCertificateMint>
         : TAccountCertificateMint,
+      TAccountCertificateRecord extends string
+        ? WritableAccount<TAccountCertificateRecord>
+        : TAccountCertificateRecord,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -93,12 +113,16 @@ export type MintCertificateInstruction<
 export type MintCertificateInstructionData = {
   discriminator: ReadonlyUint8Array;
   courseId: string;
+  termId: string;
   metadataUri: string;
+  metadataHash: ReadonlyUint8Array;
 };
 
 export type MintCertificateInstructionDataArgs = {
   courseId: string;
+  termId: string;
   metadataUri: string;
+  metadataHash: ReadonlyUint8Array;
 };
 
 export function getMintCertificateInstructionDataEncoder(): Encoder<MintCertificateInstructionDataArgs> {
@@ -106,7 +130,9 @@ export function getMintCertificateInstructionDataEncoder(): Encoder<MintCertific
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['courseId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['termId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['metadataUri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['metadataHash', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({ ...value, discriminator: MINT_CERTIFICATE_DISCRIMINATOR })
   );
@@ -116,7 +142,9 @@ export function getMintCertificateInstructionDataDecoder(): Decoder<MintCertific
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['courseId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['termId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['metadataUri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['metadataHash', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -132,44 +160,71 @@ export function getMintCertificateInstructionDataCodec(): Codec<
 
 export type MintCertificateAsyncInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountEnrollment extends string = string,
   TAccountCertificateMint extends string = string,
+  TAccountCertificateRecord extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   course?: Address<TAccountCourse>;
+  offering?: Address<TAccountOffering>;
   enrollment?: Address<TAccountEnrollment>;
   certificateMint?: Address<TAccountCertificateMint>;
+  certificateRecord?: Address<TAccountCertificateRecord>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: MintCertificateInstructionDataArgs['courseId'];
+  termId: MintCertificateInstructionDataArgs['termId'];
   metadataUri: MintCertificateInstructionDataArgs['metadataUri'];
+  metadataHash: MintCertificateInstructionDataArgs['metadataHash'];
 };
 
 export async function getMintCertificateInstructionAsync<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountEnrollment extends string,
   TAccountCertificateMint extends string,
+  TAccountCertificateRecord extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: MintCertificateAsyncInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
     TAccountCertificateMint,
-    TAccountSystemProgram
+    TAccountCertificateRecord,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   MintCertificateInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
     TAccountCertificateMint,
-    TAccountSystemProgram
+    TAccountCertificateRecord,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -179,10 +234,18 @@ export async function getMintCertificateInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: false },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
     certificateMint: { value: input.certificateMint ?? null, isWritable: true },
+    certificateRecord: {
+      value: input.certificateRecord ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -198,9 +261,21 @@ export async function getMintCertificateInstructionAsync<
       programAddress,
       seeds: [
         getBytesEncoder().encode(new Uint8Array([99, 111, 117, 114, 115, 101])),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+      ],
+    });
+  }
+  if (!accounts.offering.value) {
+    accounts.offering.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([111, 102, 102, 101, 114, 105, 110, 103])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+        getUtf8Encoder().encode(expectSome(args.termId)),
       ],
     });
   }
@@ -212,9 +287,7 @@ export async function getMintCertificateInstructionAsync<
           new Uint8Array([101, 110, 114, 111, 108, 108, 109, 101, 110, 116])
         ),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.offering.value)),
       ],
     });
   }
@@ -228,9 +301,22 @@ export async function getMintCertificateInstructionAsync<
             110, 116,
           ])
         ),
-        getAddressEncoder().encode(expectAddress(accounts.student.value)),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
+        getAddressEncoder().encode(expectAddress(accounts.enrollment.value)),
+      ],
+    });
+  }
+  if (!accounts.certificateRecord.value) {
+    accounts.certificateRecord.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            99, 101, 114, 116, 105, 102, 105, 99, 97, 116, 101, 95, 114, 101,
+            99, 111, 114, 100,
+          ])
+        ),
+        getAddressEncoder().encode(
+          expectAddress(accounts.certificateMint.value)
         ),
       ],
     });
@@ -239,15 +325,33 @@ export async function getMintCertificateInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.enrollment),
       getAccountMeta(accounts.certificateMint),
+      getAccountMeta(accounts.certificateRecord),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getMintCertificateInstructionDataEncoder().encode(
       args as MintCertificateInstructionDataArgs
@@ -256,52 +360,84 @@ export async function getMintCertificateInstructionAsync<
   } as MintCertificateInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
     TAccountCertificateMint,
-    TAccountSystemProgram
+    TAccountCertificateRecord,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type MintCertificateInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountEnrollment extends string = string,
   TAccountCertificateMint extends string = string,
+  TAccountCertificateRecord extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   course: Address<TAccountCourse>;
+  offering: Address<TAccountOffering>;
   enrollment: Address<TAccountEnrollment>;
   certificateMint: Address<TAccountCertificateMint>;
+  certificateRecord: Address<TAccountCertificateRecord>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: MintCertificateInstructionDataArgs['courseId'];
+  termId: MintCertificateInstructionDataArgs['termId'];
   metadataUri: MintCertificateInstructionDataArgs['metadataUri'];
+  metadataHash: MintCertificateInstructionDataArgs['metadataHash'];
 };
 
 export function getMintCertificateInstruction<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountEnrollment extends string,
   TAccountCertificateMint extends string,
+  TAccountCertificateRecord extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: MintCertificateInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
     TAccountCertificateMint,
-    TAccountSystemProgram
+    TAccountCertificateRecord,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): MintCertificateInstruction<
   TProgramAddress,
   TAccountStudent,
+  TAccountInstitution,
   TAccountCourse,
+  TAccountOffering,
   TAccountEnrollment,
   TAccountCertificateMint,
-  TAccountSystemProgram
+  TAccountCertificateRecord,
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -310,10 +446,18 @@ export function getMintCertificateInstruction<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: false },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
     certificateMint: { value: input.certificateMint ?? null, isWritable: true },
+    certificateRecord: {
+      value: input.certificateRecord ?? null,
+      isWritable: true,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -333,10 +477,15 @@ export function getMintCertificateInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.enrollment),
       getAccountMeta(accounts.certificateMint),
+      getAccountMeta(accounts.certificateRecord),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getMintCertificateInstructionDataEncoder().encode(
       args as MintCertificateInstructionDataArgs
@@ -345,10 +494,15 @@ export function getMintCertificateInstruction<
   } as MintCertificateInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
     TAccountCertificateMint,
-    TAccountSystemProgram
+    TAccountCertificateRecord,
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -359,10 +513,15 @@ export type ParsedMintCertificateInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     student: TAccountMetas[0];
-    course: TAccountMetas[1];
-    enrollment: TAccountMetas[2];
-    certificateMint: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
+    institution: TAccountMetas[1];
+    course: TAccountMetas[2];
+    offering: TAccountMetas[3];
+    enrollment: TAccountMetas[4];
+    certificateMint: TAccountMetas[5];
+    certificateRecord: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: MintCertificateInstructionData;
 };
@@ -375,7 +534,7 @@ export function parseMintCertificateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedMintCertificateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -389,10 +548,15 @@ export function parseMintCertificateInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       student: getNextAccount(),
+      institution: getNextAccount(),
       course: getNextAccount(),
+      offering: getNextAccount(),
       enrollment: getNextAccount(),
       certificateMint: getNextAccount(),
+      certificateRecord: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getMintCertificateInstructionDataDecoder().decode(instruction.data),
   };
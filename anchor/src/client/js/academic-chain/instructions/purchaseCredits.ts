@@ -54,7 +54,7 @@ export function getPurchaseCreditsDiscriminatorBytes() {
 export type PurchaseCreditsInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountStudent extends string | AccountMeta<string> = string,
-  TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountTreasury extends string | AccountMeta<string> = string,
   TAccountCreditMint extends string | AccountMeta<string> = string,
   TAccountStudentCreditAccount extends string | AccountMeta<string> = string,
@@ -65,6 +65,8 @@ export type PurchaseCreditsInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,9 +76,9 @@ export type PurchaseCreditsInstruction<
         ? WritableSignerAccount<TAccountStudent> &
             AccountSignerMeta<TAccountStudent>
         : TAccountStudent,
-      TAccountConfig extends string
-        ? ReadonlyAccount<TAccountConfig>
-        : TAccountConfig,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountTreasury extends string
         ? WritableAccount<TAccountTreasury>
         : TAccountTreasury,
@@ -95,6 +97,12 @@ export type PurchaseCreditsInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -104,7 +112,7 @@ export type PurchaseCreditsInstructionData = {
   amount: bigint;
 };
 
-export type PurchaseCreditsInstructionDataArgs = { amount: number | bigint };
+export type PurchaseCreditsInstructionDataArgs = { amount: number | bigint; };
 
 export function getPurchaseCreditsInstructionDataEncoder(): FixedSizeEncoder<PurchaseCreditsInstructionDataArgs> {
   return transformEncoder(
@@ -135,58 +143,68 @@ export function getPurchaseCreditsInstructionDataCodec(): FixedSizeCodec<
 
 export type PurchaseCreditsAsyncInput<
   TAccountStudent extends string = string,
-  TAccountConfig extends string = string,
+  TAccountInstitution extends string = string,
   TAccountTreasury extends string = string,
   TAccountCreditMint extends string = string,
   TAccountStudentCreditAccount extends string = string,
   TAccountStudentProfile extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
-  config?: Address<TAccountConfig>;
+  institution: Address<TAccountInstitution>;
   treasury: Address<TAccountTreasury>;
   creditMint: Address<TAccountCreditMint>;
   studentCreditAccount?: Address<TAccountStudentCreditAccount>;
   studentProfile?: Address<TAccountStudentProfile>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amount: PurchaseCreditsInstructionDataArgs['amount'];
 };
 
 export async function getPurchaseCreditsInstructionAsync<
   TAccountStudent extends string,
-  TAccountConfig extends string,
+  TAccountInstitution extends string,
   TAccountTreasury extends string,
   TAccountCreditMint extends string,
   TAccountStudentCreditAccount extends string,
   TAccountStudentProfile extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: PurchaseCreditsAsyncInput<
     TAccountStudent,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountStudentProfile,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   PurchaseCreditsInstruction<
     TProgramAddress,
     TAccountStudent,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountStudentProfile,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -196,7 +214,7 @@ export async function getPurchaseCreditsInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
+    institution: { value: input.institution ?? null, isWritable: false },
     treasury: { value: input.treasury ?? null, isWritable: true },
     creditMint: { value: input.creditMint ?? null, isWritable: true },
     studentCreditAccount: {
@@ -206,6 +224,8 @@ export async function getPurchaseCreditsInstructionAsync<
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -216,14 +236,6 @@ export async function getPurchaseCreditsInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
-      ],
-    });
-  }
   if (!accounts.studentCreditAccount.value) {
     accounts.studentCreditAccount.value = await getProgramDerivedAddress({
       programAddress:
@@ -251,6 +263,7 @@ export async function getPurchaseCreditsInstructionAsync<
             101,
           ])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
       ],
     });
@@ -263,18 +276,33 @@ export async function getPurchaseCreditsInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
-      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.studentCreditAccount),
       getAccountMeta(accounts.studentProfile),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getPurchaseCreditsInstructionDataEncoder().encode(
       args as PurchaseCreditsInstructionDataArgs
@@ -283,69 +311,81 @@ export async function getPurchaseCreditsInstructionAsync<
   } as PurchaseCreditsInstruction<
     TProgramAddress,
     TAccountStudent,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountStudentProfile,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type PurchaseCreditsInput<
   TAccountStudent extends string = string,
-  TAccountConfig extends string = string,
+  TAccountInstitution extends string = string,
   TAccountTreasury extends string = string,
   TAccountCreditMint extends string = string,
   TAccountStudentCreditAccount extends string = string,
   TAccountStudentProfile extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
-  config: Address<TAccountConfig>;
+  institution: Address<TAccountInstitution>;
   treasury: Address<TAccountTreasury>;
   creditMint: Address<TAccountCreditMint>;
   studentCreditAccount: Address<TAccountStudentCreditAccount>;
   studentProfile: Address<TAccountStudentProfile>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   amount: PurchaseCreditsInstructionDataArgs['amount'];
 };
 
 export function getPurchaseCreditsInstruction<
   TAccountStudent extends string,
-  TAccountConfig extends string,
+  TAccountInstitution extends string,
   TAccountTreasury extends string,
   TAccountCreditMint extends string,
   TAccountStudentCreditAccount extends string,
   TAccountStudentProfile extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: PurchaseCreditsInput<
     TAccountStudent,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountStudentProfile,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): PurchaseCreditsInstruction<
   TProgramAddress,
   TAccountStudent,
-  TAccountConfig,
+  TAccountInstitution,
   TAccountTreasury,
   TAccountCreditMint,
   TAccountStudentCreditAccount,
   TAccountStudentProfile,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -354,7 +394,7 @@ export function getPurchaseCreditsInstruction<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
+    institution: { value: input.institution ?? null, isWritable: false },
     treasury: { value: input.treasury ?? null, isWritable: true },
     creditMint: { value: input.creditMint ?? null, isWritable: true },
     studentCreditAccount: {
@@ -364,6 +404,8 @@ export function getPurchaseCreditsInstruction<
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -387,13 +429,15 @@ export function getPurchaseCreditsInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
-      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.treasury),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.studentCreditAccount),
       getAccountMeta(accounts.studentProfile),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getPurchaseCreditsInstructionDataEncoder().encode(
       args as PurchaseCreditsInstructionDataArgs
@@ -402,13 +446,15 @@ export function getPurchaseCreditsInstruction<
   } as PurchaseCreditsInstruction<
     TProgramAddress,
     TAccountStudent,
-    TAccountConfig,
+    TAccountInstitution,
     TAccountTreasury,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountStudentProfile,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -419,13 +465,15 @@ export type ParsedPurchaseCreditsInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     student: TAccountMetas[0];
-    config: TAccountMetas[1];
+    institution: TAccountMetas[1];
     treasury: TAccountMetas[2];
     creditMint: TAccountMetas[3];
     studentCreditAccount: TAccountMetas[4];
     studentProfile: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
     systemProgram: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: PurchaseCreditsInstructionData;
 };
@@ -438,7 +486,7 @@ export function parsePurchaseCreditsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedPurchaseCreditsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -452,13 +500,15 @@ export function parsePurchaseCreditsInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       student: getNextAccount(),
-      config: getNextAccount(),
+      institution: getNextAccount(),
       treasury: getNextAccount(),
       creditMint: getNextAccount(),
       studentCreditAccount: getNextAccount(),
       studentProfile: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getPurchaseCreditsInstructionDataDecoder().decode(instruction.data),
   };
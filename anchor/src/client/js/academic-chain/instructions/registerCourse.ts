@@ -59,10 +59,12 @@ export function getRegisterCourseDiscriminatorBytes() {
 export type RegisterCourseInstruction<
   TProgram extends string = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
   TAccountStudent extends string | AccountMeta<string> = string,
+  TAccountInstitution extends string | AccountMeta<string> = string,
   TAccountCourse extends string | AccountMeta<string> = string,
+  TAccountOffering extends string | AccountMeta<string> = string,
   TAccountEnrollment extends string | AccountMeta<string> = string,
+  TAccountCourseAttempts extends string | AccountMeta<string> = string,
   TAccountStudentProfile extends string | AccountMeta<string> = string,
-  TAccountConfig extends string | AccountMeta<string> = string,
   TAccountCreditMint extends string | AccountMeta<string> = string,
   TAccountStudentCreditAccount extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
@@ -71,6 +73,8 @@ export type RegisterCourseInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | AccountMeta<string> = string,
+  TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -80,18 +84,24 @@ export type RegisterCourseInstruction<
         ? WritableSignerAccount<TAccountStudent> &
             AccountSignerMeta<TAccountStudent>
         : TAccountStudent,
+      TAccountInstitution extends string
+        ? ReadonlyAccount<TAccountInstitution>
+        : TAccountInstitution,
       TAccountCourse extends string
         ? ReadonlyAccount<TAccountCourse>
         : TAccountCourse,
+      TAccountOffering extends string
+        ? WritableAccount<TAccountOffering>
+        : TAccountOffering,
       TAccountEnrollment extends string
         ? WritableAccount<TAccountEnrollment>
         : TAccountEnrollment,
+      TAccountCourseAttempts extends string
+        ? WritableAccount<TAccountCourseAttempts>
+        : TAccountCourseAttempts,
       TAccountStudentProfile extends string
         ? WritableAccount<TAccountStudentProfile>
         : TAccountStudentProfile,
-      TAccountConfig extends string
-        ? ReadonlyAccount<TAccountConfig>
-        : TAccountConfig,
       TAccountCreditMint extends string
         ? WritableAccount<TAccountCreditMint>
         : TAccountCreditMint,
@@ -104,6 +114,12 @@ export type RegisterCourseInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -111,15 +127,20 @@ export type RegisterCourseInstruction<
 export type RegisterCourseInstructionData = {
   discriminator: ReadonlyUint8Array;
   courseId: string;
+  termId: string;
 };
 
-export type RegisterCourseInstructionDataArgs = { courseId: string };
+export type RegisterCourseInstructionDataArgs = {
+  courseId: string;
+  termId: string;
+};
 
 export function getRegisterCourseInstructionDataEncoder(): Encoder<RegisterCourseInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['courseId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['termId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
     ]),
     (value) => ({ ...value, discriminator: REGISTER_COURSE_DISCRIMINATOR })
   );
@@ -129,6 +150,7 @@ export function getRegisterCourseInstructionDataDecoder(): Decoder<RegisterCours
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['courseId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['termId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
   ]);
 }
 
@@ -144,63 +166,84 @@ export function getRegisterCourseInstructionDataCodec(): Codec<
 
 export type RegisterCourseAsyncInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountEnrollment extends string = string,
+  TAccountCourseAttempts extends string = string,
   TAccountStudentProfile extends string = string,
-  TAccountConfig extends string = string,
   TAccountCreditMint extends string = string,
   TAccountStudentCreditAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   course?: Address<TAccountCourse>;
+  offering?: Address<TAccountOffering>;
   enrollment?: Address<TAccountEnrollment>;
+  courseAttempts?: Address<TAccountCourseAttempts>;
   studentProfile?: Address<TAccountStudentProfile>;
-  config?: Address<TAccountConfig>;
   creditMint: Address<TAccountCreditMint>;
   studentCreditAccount?: Address<TAccountStudentCreditAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: RegisterCourseInstructionDataArgs['courseId'];
+  termId: RegisterCourseInstructionDataArgs['termId'];
 };
 
 export async function getRegisterCourseInstructionAsync<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountEnrollment extends string,
+  TAccountCourseAttempts extends string,
   TAccountStudentProfile extends string,
-  TAccountConfig extends string,
   TAccountCreditMint extends string,
   TAccountStudentCreditAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: RegisterCourseAsyncInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
+    TAccountCourseAttempts,
     TAccountStudentProfile,
-    TAccountConfig,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): Promise<
   RegisterCourseInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
+    TAccountCourseAttempts,
     TAccountStudentProfile,
-    TAccountConfig,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >
 > {
   // Program address.
@@ -210,10 +253,12 @@ export async function getRegisterCourseInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: true },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
+    courseAttempts: { value: input.courseAttempts ?? null, isWritable: true },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
     creditMint: { value: input.creditMint ?? null, isWritable: true },
     studentCreditAccount: {
       value: input.studentCreditAccount ?? null,
@@ -221,6 +266,8 @@ export async function getRegisterCourseInstructionAsync<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -236,9 +283,21 @@ export async function getRegisterCourseInstructionAsync<
       programAddress,
       seeds: [
         getBytesEncoder().encode(new Uint8Array([99, 111, 117, 114, 115, 101])),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+      ],
+    });
+  }
+  if (!accounts.offering.value) {
+    accounts.offering.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([111, 102, 102, 101, 114, 105, 110, 103])
         ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getUtf8Encoder().encode(expectSome(args.courseId)),
+        getUtf8Encoder().encode(expectSome(args.termId)),
       ],
     });
   }
@@ -250,31 +309,37 @@ export async function getRegisterCourseInstructionAsync<
           new Uint8Array([101, 110, 114, 111, 108, 108, 109, 101, 110, 116])
         ),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
-        addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder()).encode(
-          expectSome(args.courseId)
-        ),
+        getAddressEncoder().encode(expectAddress(accounts.offering.value)),
       ],
     });
   }
-  if (!accounts.studentProfile.value) {
-    accounts.studentProfile.value = await getProgramDerivedAddress({
+  if (!accounts.courseAttempts.value) {
+    accounts.courseAttempts.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
         getBytesEncoder().encode(
           new Uint8Array([
-            115, 116, 117, 100, 101, 110, 116, 95, 112, 114, 111, 102, 105, 108,
-            101,
+            99, 111, 117, 114, 115, 101, 95, 97, 116, 116, 101, 109, 112, 116,
+            115,
           ])
         ),
         getAddressEncoder().encode(expectAddress(accounts.student.value)),
+        getAddressEncoder().encode(expectAddress(accounts.course.value)),
       ],
     });
   }
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
+  if (!accounts.studentProfile.value) {
+    accounts.studentProfile.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            115, 116, 117, 100, 101, 110, 116, 95, 112, 114, 111, 102, 105, 108,
+            101,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.institution.value)),
+        getAddressEncoder().encode(expectAddress(accounts.student.value)),
       ],
     });
   }
@@ -303,19 +368,36 @@ export async function getRegisterCourseInstructionAsync<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114,
+            105, 116, 121,
+          ])
+        ),
+      ],
+    });
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.enrollment),
+      getAccountMeta(accounts.courseAttempts),
       getAccountMeta(accounts.studentProfile),
-      getAccountMeta(accounts.config),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.studentCreditAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getRegisterCourseInstructionDataEncoder().encode(
       args as RegisterCourseInstructionDataArgs
@@ -324,75 +406,100 @@ export async function getRegisterCourseInstructionAsync<
   } as RegisterCourseInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
+    TAccountCourseAttempts,
     TAccountStudentProfile,
-    TAccountConfig,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
 export type RegisterCourseInput<
   TAccountStudent extends string = string,
+  TAccountInstitution extends string = string,
   TAccountCourse extends string = string,
+  TAccountOffering extends string = string,
   TAccountEnrollment extends string = string,
+  TAccountCourseAttempts extends string = string,
   TAccountStudentProfile extends string = string,
-  TAccountConfig extends string = string,
   TAccountCreditMint extends string = string,
   TAccountStudentCreditAccount extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   student: TransactionSigner<TAccountStudent>;
+  institution: Address<TAccountInstitution>;
   course: Address<TAccountCourse>;
+  offering: Address<TAccountOffering>;
   enrollment: Address<TAccountEnrollment>;
+  courseAttempts: Address<TAccountCourseAttempts>;
   studentProfile: Address<TAccountStudentProfile>;
-  config: Address<TAccountConfig>;
   creditMint: Address<TAccountCreditMint>;
   studentCreditAccount: Address<TAccountStudentCreditAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority: Address<TAccountEventAuthority>;
+  program: Address<TAccountProgram>;
   courseId: RegisterCourseInstructionDataArgs['courseId'];
+  termId: RegisterCourseInstructionDataArgs['termId'];
 };
 
 export function getRegisterCourseInstruction<
   TAccountStudent extends string,
+  TAccountInstitution extends string,
   TAccountCourse extends string,
+  TAccountOffering extends string,
   TAccountEnrollment extends string,
+  TAccountCourseAttempts extends string,
   TAccountStudentProfile extends string,
-  TAccountConfig extends string,
   TAccountCreditMint extends string,
   TAccountStudentCreditAccount extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 >(
   input: RegisterCourseInput<
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
+    TAccountCourseAttempts,
     TAccountStudentProfile,
-    TAccountConfig,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): RegisterCourseInstruction<
   TProgramAddress,
   TAccountStudent,
+  TAccountInstitution,
   TAccountCourse,
+  TAccountOffering,
   TAccountEnrollment,
+  TAccountCourseAttempts,
   TAccountStudentProfile,
-  TAccountConfig,
   TAccountCreditMint,
   TAccountStudentCreditAccount,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -401,10 +508,12 @@ export function getRegisterCourseInstruction<
   // Original accounts.
   const originalAccounts = {
     student: { value: input.student ?? null, isWritable: true },
+    institution: { value: input.institution ?? null, isWritable: false },
     course: { value: input.course ?? null, isWritable: false },
+    offering: { value: input.offering ?? null, isWritable: true },
     enrollment: { value: input.enrollment ?? null, isWritable: true },
+    courseAttempts: { value: input.courseAttempts ?? null, isWritable: true },
     studentProfile: { value: input.studentProfile ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
     creditMint: { value: input.creditMint ?? null, isWritable: true },
     studentCreditAccount: {
       value: input.studentCreditAccount ?? null,
@@ -412,6 +521,8 @@ export function getRegisterCourseInstruction<
     },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -435,14 +546,18 @@ export function getRegisterCourseInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.student),
+      getAccountMeta(accounts.institution),
       getAccountMeta(accounts.course),
+      getAccountMeta(accounts.offering),
       getAccountMeta(accounts.enrollment),
+      getAccountMeta(accounts.courseAttempts),
       getAccountMeta(accounts.studentProfile),
-      getAccountMeta(accounts.config),
       getAccountMeta(accounts.creditMint),
       getAccountMeta(accounts.studentCreditAccount),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     data: getRegisterCourseInstructionDataEncoder().encode(
       args as RegisterCourseInstructionDataArgs
@@ -451,14 +566,18 @@ export function getRegisterCourseInstruction<
   } as RegisterCourseInstruction<
     TProgramAddress,
     TAccountStudent,
+    TAccountInstitution,
     TAccountCourse,
+    TAccountOffering,
     TAccountEnrollment,
+    TAccountCourseAttempts,
     TAccountStudentProfile,
-    TAccountConfig,
     TAccountCreditMint,
     TAccountStudentCreditAccount,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >);
 }
 
@@ -469,14 +588,18 @@ export type ParsedRegisterCourseInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     student: TAccountMetas[0];
-    course: TAccountMetas[1];
-    enrollment: TAccountMetas[2];
-    studentProfile: TAccountMetas[3];
-    config: TAccountMetas[4];
-    creditMint: TAccountMetas[5];
-    studentCreditAccount: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    systemProgram: TAccountMetas[8];
+    institution: TAccountMetas[1];
+    course: TAccountMetas[2];
+    offering: TAccountMetas[3];
+    enrollment: TAccountMetas[4];
+    courseAttempts: TAccountMetas[5];
+    studentProfile: TAccountMetas[6];
+    creditMint: TAccountMetas[7];
+    studentCreditAccount: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
+    eventAuthority: TAccountMetas[11];
+    program: TAccountMetas[12];
   };
   data: RegisterCourseInstructionData;
 };
@@ -489,7 +612,7 @@ export function parseRegisterCourseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRegisterCourseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -503,14 +626,18 @@ export function parseRegisterCourseInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       student: getNextAccount(),
+      institution: getNextAccount(),
       course: getNextAccount(),
+      offering: getNextAccount(),
       enrollment: getNextAccount(),
+      courseAttempts: getNextAccount(),
       studentProfile: getNextAccount(),
-      config: getNextAccount(),
       creditMint: getNextAccount(),
       studentCreditAccount: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getRegisterCourseInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,29 +14,125 @@ import {
   type ReadonlyUint8Array,
 } from 'gill';
 import {
+  type ParsedAmendGradeInstruction,
+  type ParsedAnchorTranscriptInstruction,
+  type ParsedApproveConfigChangeInstruction,
+  type ParsedCancelConfigChangeInstruction,
+  type ParsedClaimBatchCertificateInstruction,
   type ParsedClaimGraduationInstruction,
+  type ParsedCloseEnrollmentInstruction,
+  type ParsedCloseProfileInstruction,
+  type ParsedCompleteCourseBatchInstruction,
   type ParsedCompleteCourseInstruction,
+  type ParsedCreateCertificateBatchInstruction,
+  type ParsedCreateCertificateTreeInstruction,
+  type ParsedCreateConfigGovernanceInstruction,
+  type ParsedCreateCourseEquivalencyInstruction,
   type ParsedCreateCourseInstruction,
-  type ParsedInitializeInstruction,
+  type ParsedCreateInstitutionInstruction,
+  type ParsedCreateOfferingInstruction,
+  type ParsedCreateStudentProfileInstruction,
+  type ParsedCreateTermInstruction,
+  type ParsedExecuteConfigChangeInstruction,
+  type ParsedExpireEnrollmentInstruction,
+  type ParsedGrantRoleInstruction,
   type ParsedMintCertificateInstruction,
+  type ParsedMintCompressedCertificateInstruction,
+  type ParsedProposeConfigChangeInstruction,
   type ParsedPurchaseCreditsInstruction,
   type ParsedRegisterCourseInstruction,
+  type ParsedRetakeCourseInstruction,
+  type ParsedRevokeRoleInstruction,
+  type ParsedSetGradeScaleInstruction,
+  type ParsedTransferCompletionInstruction,
+  type ParsedUpdateConfigInstruction,
+  type ParsedUpdateCourseInstruction,
+  type ParsedVerifyBatchCertificateInstruction,
+  type ParsedVerifyTranscriptEntryInstruction,
 } from '../instructions';
 
 export const ACADEMIC_CHAIN_PROGRAM_ADDRESS =
   '9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F' as Address<'9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F'>;
 
 export enum AcademicChainAccount {
+  CertificateBatch,
+  CertificateRecord,
+  CertificateTree,
+  ConfigGovernance,
+  ConfigProposal,
   Course,
+  CourseAttempts,
   CourseEnrollment,
-  ProgramConfig,
+  CourseEquivalency,
+  CourseOffering,
+  EnrollmentRecord,
+  Institution,
+  ProfileTombstone,
+  RoleAssignment,
   StudentProfile,
+  Term,
+  TranscriptAnchor,
 }
 
 export function identifyAcademicChainAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AcademicChainAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([123, 205, 61, 118, 142, 124, 37, 98])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CertificateBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([250, 87, 4, 127, 86, 3, 52, 240])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CertificateRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 117, 129, 82, 53, 73, 194, 62])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CertificateTree;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 56, 212, 45, 89, 194, 26, 86])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.ConfigGovernance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([159, 239, 163, 63, 154, 191, 78, 98])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.ConfigProposal;
+  }
   if (
     containsBytes(
       data,
@@ -48,6 +144,17 @@ export function identifyAcademicChainAccount(
   ) {
     return AcademicChainAccount.Course;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 52, 26, 142, 105, 105, 14, 67])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CourseAttempts;
+  }
   if (
     containsBytes(
       data,
@@ -63,12 +170,67 @@ export function identifyAcademicChainAccount(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([196, 210, 90, 231, 144, 149, 140, 63])
+        new Uint8Array([144, 100, 108, 56, 251, 110, 178, 77])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CourseEquivalency;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 182, 64, 34, 158, 222, 149, 198])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.CourseOffering;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 227, 83, 189, 219, 181, 30, 167])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.EnrollmentRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 67, 44, 135, 26, 236, 199, 188])
       ),
       0
     )
   ) {
-    return AcademicChainAccount.ProgramConfig;
+    return AcademicChainAccount.Institution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([154, 229, 105, 206, 205, 125, 88, 112])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.ProfileTombstone;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([205, 130, 191, 231, 211, 225, 155, 246])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.RoleAssignment;
   }
   if (
     containsBytes(
@@ -81,25 +243,130 @@ export function identifyAcademicChainAccount(
   ) {
     return AcademicChainAccount.StudentProfile;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([223, 23, 94, 27, 187, 5, 151, 61])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.Term;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([61, 70, 176, 163, 70, 75, 118, 140])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainAccount.TranscriptAnchor;
+  }
   throw new Error(
     'The provided account could not be identified as a academicChain account.'
   );
 }
 
 export enum AcademicChainInstruction {
+  AmendGrade,
+  AnchorTranscript,
+  ApproveConfigChange,
+  CancelConfigChange,
+  ClaimBatchCertificate,
   ClaimGraduation,
+  CloseEnrollment,
+  CloseProfile,
   CompleteCourse,
+  CompleteCourseBatch,
+  CreateCertificateBatch,
+  CreateCertificateTree,
+  CreateConfigGovernance,
   CreateCourse,
-  Initialize,
+  CreateCourseEquivalency,
+  CreateInstitution,
+  CreateOffering,
+  CreateStudentProfile,
+  CreateTerm,
+  ExecuteConfigChange,
+  ExpireEnrollment,
+  GrantRole,
   MintCertificate,
+  MintCompressedCertificate,
+  ProposeConfigChange,
   PurchaseCredits,
   RegisterCourse,
+  RetakeCourse,
+  RevokeRole,
+  SetGradeScale,
+  TransferCompletion,
+  UpdateConfig,
+  UpdateCourse,
+  VerifyBatchCertificate,
+  VerifyTranscriptEntry,
 }
 
 export function identifyAcademicChainInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): AcademicChainInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([140, 18, 158, 104, 244, 188, 93, 246])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.AmendGrade;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([5, 33, 114, 249, 205, 199, 221, 62])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.AnchorTranscript;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 238, 197, 168, 142, 167, 250, 213])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.ApproveConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 114, 136, 167, 183, 86, 61, 158])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CancelConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 241, 148, 91, 183, 132, 60, 172])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.ClaimBatchCertificate;
+  }
   if (
     containsBytes(
       data,
@@ -111,6 +378,28 @@ export function identifyAcademicChainInstruction(
   ) {
     return AcademicChainInstruction.ClaimGraduation;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([236, 137, 133, 253, 91, 138, 217, 91])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CloseEnrollment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([167, 36, 181, 8, 136, 158, 46, 207])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CloseProfile;
+  }
   if (
     containsBytes(
       data,
@@ -122,6 +411,50 @@ export function identifyAcademicChainInstruction(
   ) {
     return AcademicChainInstruction.CompleteCourse;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([214, 36, 156, 209, 226, 226, 249, 4])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CompleteCourseBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([169, 70, 25, 131, 67, 141, 168, 198])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateCertificateBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 210, 2, 67, 74, 194, 77, 148])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateCertificateTree;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([125, 83, 143, 210, 143, 194, 155, 225])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateConfigGovernance;
+  }
   if (
     containsBytes(
       data,
@@ -137,12 +470,89 @@ export function identifyAcademicChainInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([175, 175, 109, 31, 13, 152, 155, 237])
+        new Uint8Array([120, 179, 201, 186, 125, 201, 253, 99])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateCourseEquivalency;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 152, 229, 14, 153, 141, 73, 139])
       ),
       0
     )
   ) {
-    return AcademicChainInstruction.Initialize;
+    return AcademicChainInstruction.CreateInstitution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([48, 254, 213, 82, 85, 141, 15, 220])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateOffering;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 78, 23, 229, 123, 10, 212, 46])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateStudentProfile;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 74, 24, 36, 219, 103, 49, 5])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.CreateTerm;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([100, 150, 198, 236, 103, 241, 118, 71])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.ExecuteConfigChange;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([184, 183, 147, 25, 239, 158, 67, 120])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.ExpireEnrollment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([218, 234, 128, 15, 82, 33, 236, 253])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.GrantRole;
   }
   if (
     containsBytes(
@@ -155,6 +565,28 @@ export function identifyAcademicChainInstruction(
   ) {
     return AcademicChainInstruction.MintCertificate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([33, 169, 177, 192, 10, 48, 172, 222])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.MintCompressedCertificate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([165, 15, 231, 227, 223, 229, 247, 119])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.ProposeConfigChange;
+  }
   if (
     containsBytes(
       data,
@@ -177,6 +609,94 @@ export function identifyAcademicChainInstruction(
   ) {
     return AcademicChainInstruction.RegisterCourse;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([227, 199, 190, 186, 161, 212, 52, 18])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.RetakeCourse;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([179, 232, 2, 180, 48, 227, 82, 7])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.RevokeRole;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([15, 147, 117, 17, 80, 241, 151, 118])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.SetGradeScale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([91, 142, 33, 83, 232, 224, 237, 153])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.TransferCompletion;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 158, 252, 191, 10, 83, 219, 99])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.UpdateConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([81, 217, 18, 192, 129, 233, 129, 231])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.UpdateCourse;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 214, 151, 21, 69, 75, 69, 147])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.VerifyBatchCertificate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([186, 105, 114, 148, 217, 227, 246, 57])
+      ),
+      0
+    )
+  ) {
+    return AcademicChainInstruction.VerifyTranscriptEntry;
+  }
   throw new Error(
     'The provided instruction could not be identified as a academicChain instruction.'
   );
@@ -185,24 +705,108 @@ export function identifyAcademicChainInstruction(
 export type ParsedAcademicChainInstruction<
   TProgram extends string = '9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F',
 > =
+  | ({
+      instructionType: AcademicChainInstruction.AmendGrade;
+    } & ParsedAmendGradeInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.AnchorTranscript;
+    } & ParsedAnchorTranscriptInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.ApproveConfigChange;
+    } & ParsedApproveConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CancelConfigChange;
+    } & ParsedCancelConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.ClaimBatchCertificate;
+    } & ParsedClaimBatchCertificateInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.ClaimGraduation;
     } & ParsedClaimGraduationInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CloseEnrollment;
+    } & ParsedCloseEnrollmentInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CloseProfile;
+    } & ParsedCloseProfileInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.CompleteCourse;
     } & ParsedCompleteCourseInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CompleteCourseBatch;
+    } & ParsedCompleteCourseBatchInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateCertificateBatch;
+    } & ParsedCreateCertificateBatchInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateCertificateTree;
+    } & ParsedCreateCertificateTreeInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateConfigGovernance;
+    } & ParsedCreateConfigGovernanceInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.CreateCourse;
     } & ParsedCreateCourseInstruction<TProgram>)
   | ({
-      instructionType: AcademicChainInstruction.Initialize;
-    } & ParsedInitializeInstruction<TProgram>)
+      instructionType: AcademicChainInstruction.CreateCourseEquivalency;
+    } & ParsedCreateCourseEquivalencyInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateInstitution;
+    } & ParsedCreateInstitutionInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateOffering;
+    } & ParsedCreateOfferingInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateStudentProfile;
+    } & ParsedCreateStudentProfileInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.CreateTerm;
+    } & ParsedCreateTermInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.ExecuteConfigChange;
+    } & ParsedExecuteConfigChangeInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.ExpireEnrollment;
+    } & ParsedExpireEnrollmentInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.GrantRole;
+    } & ParsedGrantRoleInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.MintCertificate;
     } & ParsedMintCertificateInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.MintCompressedCertificate;
+    } & ParsedMintCompressedCertificateInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.ProposeConfigChange;
+    } & ParsedProposeConfigChangeInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.PurchaseCredits;
     } & ParsedPurchaseCreditsInstruction<TProgram>)
   | ({
       instructionType: AcademicChainInstruction.RegisterCourse;
-    } & ParsedRegisterCourseInstruction<TProgram>);
+    } & ParsedRegisterCourseInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.RetakeCourse;
+    } & ParsedRetakeCourseInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.RevokeRole;
+    } & ParsedRevokeRoleInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.SetGradeScale;
+    } & ParsedSetGradeScaleInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.TransferCompletion;
+    } & ParsedTransferCompletionInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.UpdateConfig;
+    } & ParsedUpdateConfigInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.UpdateCourse;
+    } & ParsedUpdateCourseInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.VerifyBatchCertificate;
+    } & ParsedVerifyBatchCertificateInstruction<TProgram>)
+  | ({
+      instructionType: AcademicChainInstruction.VerifyTranscriptEntry;
+    } & ParsedVerifyTranscriptEntryInstruction<TProgram>);
@@ -27,10 +27,10 @@ describe("AcademicChain", () => {
   describe("Program Initialization", () => {
     it("Initializes the program configuration", async () => {
       const tx = await env.program.methods
-        .initialize()
+        .createInstitution(env.institutionId, "Test University")
         .accounts({
           authority: env.authority.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -40,7 +40,7 @@ describe("AcademicChain", () => {
       console.log("Initialize transaction signature:", tx);
       
       // Verify config account
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       expect(config.authority.toString()).to.equal(env.authority.publicKey.toString());
       expect(config.treasury.toString()).to.equal(env.treasury.publicKey.toString());
@@ -52,10 +52,10 @@ describe("AcademicChain", () => {
     it("Fails to initialize twice", async () => {
       try {
         await env.program.methods
-          .initialize()
+          .createInstitution(env.institutionId, "Test University")
           .accounts({
             authority: env.authority.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             treasury: env.treasury.publicKey,
             systemProgram: SystemProgram.programId,
           })
@@ -71,7 +71,7 @@ describe("AcademicChain", () => {
 
   describe("Credit Mint", () => {
     it("Creates credit token mint", async () => {
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       expect(config.creditMint).to.not.be.null;
       
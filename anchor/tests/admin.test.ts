@@ -0,0 +1,142 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import { createCourse } from "./utils/helpers";
+import { COURSE_FIXTURES } from "./utils/fixtures";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+
+describe("Program Administration", () => {
+  let env: TestEnvironment;
+  let coursePda: anchor.web3.PublicKey;
+  const course = COURSE_FIXTURES.SOL101;
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    coursePda = await createCourse(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      course.id,
+      course.name,
+      env.instructor.publicKey,
+      course.requiredCredits,
+      course.creditHours
+    );
+  });
+
+  describe("Update Config", () => {
+    it("Authority updates the credit price and treasury", async () => {
+      const newTreasury = Keypair.generate().publicKey;
+
+      await env.program.methods
+        .updateConfig(null, newTreasury, new anchor.BN(2_000_000))
+        .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+        .signers([env.authority])
+        .rpc();
+
+      const config = await env.program.account.institution.fetch(env.institutionPda);
+      expect(config.creditPrice.toNumber()).to.equal(2_000_000);
+      expect(config.treasury.toString()).to.equal(newTreasury.toString());
+      expect(config.authority.toString()).to.equal(env.authority.publicKey.toString());
+
+      console.log("✅ Config updated");
+    });
+
+    it("Fails to set a zero credit price", async () => {
+      try {
+        await env.program.methods
+          .updateConfig(null, null, new anchor.BN(0))
+          .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+          .signers([env.authority])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid credit price");
+      }
+    });
+
+    it("Fails when the signer is not the authority", async () => {
+      try {
+        await env.program.methods
+          .updateConfig(env.student1.publicKey, null, null)
+          .accounts({ authority: env.student1.publicKey, institution: env.institutionPda })
+          .signers([env.student1])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Update Course", () => {
+    it("Authority updates course fields and leaves the rest unchanged", async () => {
+      await env.program.methods
+        .updateCourse(course.id, "Solana Fundamentals II", null, new anchor.BN(7), null, 2, null)
+        .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+        .signers([env.authority])
+        .rpc();
+
+      const updated = await env.program.account.course.fetch(coursePda);
+      expect(updated.courseName).to.equal("Solana Fundamentals II");
+      expect(updated.requiredCredits.toNumber()).to.equal(7);
+      expect(updated.maxAttempts).to.equal(2);
+      expect(updated.creditHours).to.equal(course.creditHours);
+      expect(updated.instructor.toString()).to.equal(env.instructor.publicKey.toString());
+      expect(updated.isActive).to.be.true;
+
+      console.log("✅ Course updated");
+    });
+
+    it("Authority deactivates a course", async () => {
+      await env.program.methods
+        .updateCourse(course.id, null, null, null, null, null, false)
+        .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+        .signers([env.authority])
+        .rpc();
+
+      const updated = await env.program.account.course.fetch(coursePda);
+      expect(updated.isActive).to.be.false;
+    });
+
+    it("Fails with invalid credit hours", async () => {
+      try {
+        await env.program.methods
+          .updateCourse(course.id, null, null, null, 0, null, null)
+          .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+          .signers([env.authority])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid credit hours");
+      }
+    });
+
+    it("Fails when the signer is not the authority", async () => {
+      try {
+        await env.program.methods
+          .updateCourse(course.id, null, env.student1.publicKey, null, null, null, null)
+          .accounts({ authority: env.student1.publicKey, institution: env.institutionPda })
+          .signers([env.student1])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+  });
+});
@@ -0,0 +1,233 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  findPda,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  completeCourseBatch,
+  planGradingBatches,
+  MAX_GRADING_BATCH_SIZE,
+} from "../scripts/complete_course_batch";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+
+describe("Batch Grading", () => {
+  let env: TestEnvironment;
+  let coursePda: anchor.web3.PublicKey;
+  const course = COURSE_FIXTURES.SOL101;
+  const term = TERM_FIXTURES.FALL;
+  const students = Array.from({ length: 5 }, () => Keypair.generate());
+
+  const enrollmentPda = (student: anchor.web3.PublicKey) =>
+    getEnrollmentPda(
+      env.program.programId,
+      student,
+      getOfferingPda(env.program.programId, env.institutionPda, course.id, term.id)
+    );
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    coursePda = await createCourse(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      course.id,
+      course.name,
+      env.instructor.publicKey,
+      course.requiredCredits,
+      course.creditHours
+    );
+    await createTerm(env.program, env.institutionPda, env.authority, term);
+    await createOffering(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      course.id,
+      term.id,
+      env.instructor.publicKey,
+      OFFERING_CAPACITY
+    );
+
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+
+    for (const student of students) {
+      await env.airdrop(student.publicKey, 1);
+
+      const studentCreditAccount = getAssociatedTokenAddressSync(
+        config.creditMint,
+        student.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const [studentProfilePda] = await findPda(
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), student.publicKey.toBuffer()],
+        env.program.programId
+      );
+
+      await createStudentProfile(env.program, env.institutionPda, student);
+
+      await env.program.methods
+        .purchaseCredits(new anchor.BN(course.requiredCredits))
+        .accounts({
+          student: student.publicKey,
+          institution: env.institutionPda,
+          treasury: env.treasury.publicKey,
+          creditMint: config.creditMint,
+          studentCreditAccount: studentCreditAccount,
+          studentProfile: studentProfilePda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([student])
+        .rpc();
+
+      await env.program.methods
+        .registerCourse(course.id, term.id)
+        .accounts({
+          student: student.publicKey,
+          institution: env.institutionPda,
+          course: coursePda,
+          enrollment: enrollmentPda(student.publicKey),
+          studentCreditAccount: studentCreditAccount,
+          creditMint: config.creditMint,
+          studentProfile: studentProfilePda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([student])
+        .rpc();
+    }
+  });
+
+  describe("Batch Planning", () => {
+    it("Splits a cohort into batches that fit lookup tables", () => {
+      const cohort = Array.from({ length: 200 }, () => ({
+        student: Keypair.generate().publicKey,
+        grade: 80,
+      }));
+
+      const plans = planGradingBatches(env.program.programId, env.institutionPda, course.id, term.id, cohort);
+      const batches = plans.flatMap((plan) => plan.batches);
+
+      expect(batches.length).to.equal(Math.ceil(200 / MAX_GRADING_BATCH_SIZE));
+      expect(batches.reduce((sum, batch) => sum + batch.grades.length, 0)).to.equal(200);
+      for (const plan of plans) {
+        expect(plan.addresses.length).to.be.at.most(256);
+      }
+      for (const batch of batches) {
+        expect(batch.remainingAccounts.length).to.equal(batch.grades.length * 2);
+      }
+    });
+  });
+
+  describe("Successful Batch Grading", () => {
+    it("Instructor grades the whole cohort", async () => {
+      const grades = students.map((student, i) => ({
+        student: student.publicKey,
+        grade: 60 + i * 10,
+      }));
+
+      const { signatures } = await completeCourseBatch(
+        env.program,
+        env.institutionPda,
+        env.instructor,
+        course.id,
+        term.id,
+        grades,
+        2
+      );
+      expect(signatures.length).to.equal(3);
+
+      for (const { student, grade } of grades) {
+        const enrollment = await env.program.account.courseEnrollment.fetch(
+          enrollmentPda(student)
+        );
+        expect(enrollment.status).to.deep.equal({ completed: {} });
+        expect(enrollment.grade).to.equal(grade);
+      }
+
+      console.log("✅ Cohort graded in", signatures.length, "transactions");
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Fails when remaining accounts do not match the students", async () => {
+      const [plan] = planGradingBatches(
+        env.program.programId,
+        env.institutionPda,
+        course.id,
+        term.id,
+        [{ student: students[0].publicKey, grade: 90 }]
+      );
+
+      try {
+        await env.program.methods
+          .completeCourseBatch(course.id, term.id, [{ student: students[1].publicKey, grade: 90 }])
+          .accounts({
+            instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
+          })
+          .remainingAccounts(plan.batches[0].remainingAccounts)
+          .signers([env.instructor])
+          .rpc();
+
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Batch accounts do not match");
+      }
+    });
+
+    it("Fails to grade an already completed enrollment", async () => {
+      const [plan] = planGradingBatches(
+        env.program.programId,
+        env.institutionPda,
+        course.id,
+        term.id,
+        [{ student: students[0].publicKey, grade: 90 }]
+      );
+
+      try {
+        await env.program.methods
+          .completeCourseBatch(course.id, term.id, plan.batches[0].grades)
+          .accounts({
+            instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
+          })
+          .remainingAccounts(plan.batches[0].remainingAccounts)
+          .signers([env.instructor])
+          .rpc();
+
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Course already completed");
+      }
+    });
+  });
+});
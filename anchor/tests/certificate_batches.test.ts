@@ -0,0 +1,247 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  createCourse,
+  createOffering,
+  createStudentProfile,
+  createTerm,
+  getCertificateBatchPda,
+  getCpiEvents,
+  getEnrollmentPda,
+  getOfferingPda,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+const COURSE = COURSE_FIXTURES.SOL101;
+const TERM = TERM_FIXTURES.FALL;
+
+describe("Certificate Batches", () => {
+  let env: TestEnvironment;
+  let coursePda: PublicKey;
+  let offeringPda: PublicKey;
+
+  const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+  // Leaves are sha256(0x00 || borsh(leaf)); parents hash the sorted pair after 0x01
+  const leafOf = (enrollment: any) =>
+    sha256(
+      Buffer.from([0]),
+      env.program.coder.types.encode("certificateLeaf", {
+        student: enrollment.student,
+        courseId: enrollment.courseId,
+        grade: enrollment.grade,
+        completionDate: enrollment.completionDate,
+      })
+    );
+  const parent = (a: Buffer, b: Buffer) => sha256(Buffer.from([1]), ...(Buffer.compare(a, b) <= 0 ? [a, b] : [b, a]));
+
+  let leaves: Buffer[];
+  let root: number[];
+
+  const batchPda = (merkleRoot: number[]) => getCertificateBatchPda(env.program.programId, offeringPda, merkleRoot);
+  const enrollmentPda = (student: Keypair) => getEnrollmentPda(env.program.programId, student.publicKey, offeringPda);
+
+  const createBatch = (merkleRoot: number[], count: number, signer = env.instructor) =>
+    env.program.methods
+      .createCertificateBatch(COURSE.id, TERM.id, merkleRoot, count)
+      .accountsPartial({
+        issuer: signer.publicKey,
+        institution: env.institutionPda,
+        offering: offeringPda,
+        certificateBatch: batchPda(merkleRoot),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+
+  const claim = (student: Keypair, proof: Buffer[]) =>
+    env.program.methods
+      .claimBatchCertificate(COURSE.id, TERM.id, proof.map((node) => Array.from(node)))
+      .accountsPartial({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        offering: offeringPda,
+        enrollment: enrollmentPda(student),
+        certificateBatch: batchPda(root),
+      })
+      .signers([student])
+      .rpc();
+
+  const enrollAndGrade = async (student: Keypair, grade: number) => {
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const studentCreditAccount = getAssociatedTokenAddressSync(
+      config.creditMint,
+      student.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const studentProfilePda = await createStudentProfile(env.program, env.institutionPda, student);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(COURSE.requiredCredits))
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: studentCreditAccount,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .registerCourse(COURSE.id, TERM.id)
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        course: coursePda,
+        enrollment: enrollmentPda(student),
+        studentCreditAccount: studentCreditAccount,
+        creditMint: config.creditMint,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .completeCourse(COURSE.id, TERM.id, grade)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        course: coursePda,
+        enrollment: enrollmentPda(student),
+      })
+      .signers([env.instructor])
+      .rpc();
+  };
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    coursePda = await createCourse(env.program, env.institutionPda, env.authority, COURSE.id, COURSE.name, env.instructor.publicKey, COURSE.requiredCredits);
+    await createTerm(env.program, env.institutionPda, env.authority, TERM);
+    await createOffering(env.program, env.institutionPda, env.authority, COURSE.id, TERM.id, env.instructor.publicKey, OFFERING_CAPACITY);
+    offeringPda = getOfferingPda(env.program.programId, env.institutionPda, COURSE.id, TERM.id);
+
+    await enrollAndGrade(env.student1, 91);
+    await enrollAndGrade(env.student2, 78);
+
+    const enrollments = await Promise.all(
+      [env.student1, env.student2].map((student) => env.program.account.courseEnrollment.fetch(enrollmentPda(student)))
+    );
+    leaves = enrollments.map(leafOf);
+    root = Array.from(parent(leaves[0], leaves[1]));
+  });
+
+  describe("Error Cases", () => {
+    it("Fails when the signer is neither the instructor nor the authority", async () => {
+      try {
+        await createBatch(root, 2, env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Only the course instructor");
+      }
+    });
+
+    it("Fails with an empty batch", async () => {
+      try {
+        await createBatch(root, 0);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid certificate batch root or count");
+      }
+    });
+  });
+
+  describe("Issuance", () => {
+    it("Stores the root and emits CertificateBatchCreated", async () => {
+      const signature = await createBatch(root, 2);
+
+      const batch = await env.program.account.certificateBatch.fetch(batchPda(root));
+      expect(batch.offering.toString()).to.equal(offeringPda.toString());
+      expect(batch.issuer.toString()).to.equal(env.instructor.publicKey.toString());
+      expect(batch.merkleRoot).to.deep.equal(root);
+      expect(batch.certificateCount).to.equal(2);
+      expect(batch.claimedCount).to.equal(0);
+
+      const events = await getCpiEvents(env.program, signature);
+      expect(events.map((event) => event.name)).to.include("certificateBatchCreated");
+    });
+
+    it("Verifies each certificate read-only", async () => {
+      const enrollment = await env.program.account.courseEnrollment.fetch(enrollmentPda(env.student1));
+      const leaf = {
+        student: enrollment.student,
+        courseId: enrollment.courseId,
+        grade: enrollment.grade,
+        completionDate: enrollment.completionDate,
+      };
+      const verify = (value: typeof leaf) =>
+        env.program.methods
+          .verifyBatchCertificate(value, [Array.from(leaves[1])])
+          .accounts({ certificateBatch: batchPda(root) })
+          .view();
+
+      expect(await verify(leaf)).to.equal(true);
+      expect(await verify({ ...leaf, grade: 99 })).to.equal(false);
+    });
+  });
+
+  describe("Claims", () => {
+    it("Rejects a proof for another student's leaf", async () => {
+      try {
+        await claim(env.student1, [leaves[0]]);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Merkle proof does not match the certificate batch");
+      }
+    });
+
+    it("Links the enrollment to the batch and emits BatchCertificateClaimed", async () => {
+      const signature = await claim(env.student1, [leaves[1]]);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(enrollmentPda(env.student1));
+      expect(enrollment.certificateBatch?.toString()).to.equal(batchPda(root).toString());
+      const batch = await env.program.account.certificateBatch.fetch(batchPda(root));
+      expect(batch.claimedCount).to.equal(1);
+
+      const events = await getCpiEvents(env.program, signature);
+      expect(events.map((event) => event.name)).to.include("batchCertificateClaimed");
+    });
+
+    it("Cannot claim twice", async () => {
+      try {
+        await claim(env.student1, [leaves[1]]);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Certificate already minted");
+      }
+    });
+  });
+});
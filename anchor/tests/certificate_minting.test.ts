@@ -2,8 +2,24 @@ import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { beforeAll, describe, it } from "vitest";
 import { createTestEnvironment, TestEnvironment } from "./setup";
-import { findPda, getTokenBalance, createCourse } from "./utils/helpers";
-import { COURSE_FIXTURES, METADATA_URI } from "./utils/fixtures";
+import {
+  findPda,
+  getTokenBalance,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  getCertificateMintPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import {
+  COURSE_FIXTURES,
+  TERM_FIXTURES,
+  OFFERING_CAPACITY,
+  METADATA_URI,
+  METADATA_HASH,
+} from "./utils/fixtures";
 import { 
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -21,10 +37,10 @@ describe("Certificate Minting", () => {
     
     // Initialize program
     await env.program.methods
-      .initialize()
+      .createInstitution(env.institutionId, "Test University")
       .accounts({
         authority: env.authority.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -34,15 +50,26 @@ describe("Certificate Minting", () => {
     // Create course
     sol101CoursePda = await createCourse(
       env.program,
+      env.institutionPda,
       env.authority,
       COURSE_FIXTURES.SOL101.id,
       COURSE_FIXTURES.SOL101.name,
       env.instructor.publicKey,
       COURSE_FIXTURES.SOL101.requiredCredits
     );
+    await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.FALL);
+    await createOffering(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      COURSE_FIXTURES.SOL101.id,
+      TERM_FIXTURES.FALL.id,
+      env.instructor.publicKey,
+      OFFERING_CAPACITY
+    );
     
     // Student purchases credits and registers
-    const config = await env.program.account.programConfig.fetch(env.configPda);
+    const config = await env.program.account.institution.fetch(env.institutionPda);
     const studentCreditAccount = getAssociatedTokenAddressSync(
       config.creditMint,
       env.student1.publicKey,
@@ -51,15 +78,17 @@ describe("Certificate Minting", () => {
     );
     
     const [studentProfilePda] = await findPda(
-      [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
       env.program.programId
     );
     
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
     await env.program.methods
       .purchaseCredits(new anchor.BN(30))
       .accounts({
         student: env.student1.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         creditMint: config.creditMint,
         studentCreditAccount: studentCreditAccount,
@@ -71,20 +100,17 @@ describe("Certificate Minting", () => {
       .signers([env.student1])
       .rpc();
     
-    [enrollmentPda] = await findPda(
-      [
-        Buffer.from("enrollment"),
-        env.student1.publicKey.toBuffer(),
-        Buffer.from(COURSE_FIXTURES.SOL101.id)
-      ],
-      env.program.programId
+    enrollmentPda = getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
     );
     
     await env.program.methods
-      .registerCourse(COURSE_FIXTURES.SOL101.id)
+      .registerCourse(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
       .accounts({
         student: env.student1.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         course: sol101CoursePda,
         enrollment: enrollmentPda,
         studentCreditAccount: studentCreditAccount,
@@ -98,9 +124,10 @@ describe("Certificate Minting", () => {
     
     // Complete course
     await env.program.methods
-      .completeCourse(COURSE_FIXTURES.SOL101.id, 85)
+      .completeCourse(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id, 85)
       .accounts({
         instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
         course: sol101CoursePda,
         enrollment: enrollmentPda,
       })
@@ -110,14 +137,7 @@ describe("Certificate Minting", () => {
 
   describe("Successful Certificate Minting", () => {
     it("Instructor mints certificate NFT for completed course", async () => {
-      const [certificateMintPda] = await findPda(
-        [
-          Buffer.from("certificate_mint"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL101.id)
-        ],
-        env.program.programId
-      );
+      const certificateMintPda = getCertificateMintPda(env.program.programId, enrollmentPda);
       
       const studentCertificateAccount = getAssociatedTokenAddressSync(
         certificateMintPda,
@@ -127,14 +147,15 @@ describe("Certificate Minting", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       const tx = await env.program.methods
-        .mintCertificate(COURSE_FIXTURES.SOL101.id, METADATA_URI)
+        .mintCertificate(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id, METADATA_URI, METADATA_HASH)
         .accounts({
           instructor: env.instructor.publicKey,
+          institution: env.institutionPda,
           student: env.student1.publicKey,
           course: sol101CoursePda,
           enrollment: enrollmentPda,
@@ -157,6 +178,15 @@ describe("Certificate Minting", () => {
       // Verify enrollment updated with certificate mint
       const enrollment = await env.program.account.courseEnrollment.fetch(enrollmentPda);
       expect(enrollment.certificateMint?.toString()).to.equal(certificateMintPda.toString());
+      expect(enrollment.metadataHash).to.deep.equal(METADATA_HASH);
+
+      // Verify the mint resolves back to its enrollment
+      const [certificateRecordPda] = await findPda(
+        [Buffer.from("certificate_record"), certificateMintPda.toBuffer()],
+        env.program.programId
+      );
+      const record = await env.program.account.certificateRecord.fetch(certificateRecordPda);
+      expect(record.enrollment.toString()).to.equal(enrollmentPda.toString());
       
       // Verify student profile updated
       const studentProfile = await env.program.account.studentProfile.fetch(studentProfilePda);
@@ -172,14 +202,24 @@ describe("Certificate Minting", () => {
       // Create new course and enroll (but don't complete)
       const sol102CoursePda = await createCourse(
         env.program,
+        env.institutionPda,
         env.authority,
         COURSE_FIXTURES.SOL102.id,
         COURSE_FIXTURES.SOL102.name,
         env.instructor.publicKey,
         COURSE_FIXTURES.SOL102.requiredCredits
       );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.SOL102.id,
+        TERM_FIXTURES.FALL.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
       
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         env.student1.publicKey,
@@ -187,25 +227,22 @@ describe("Certificate Minting", () => {
         TOKEN_2022_PROGRAM_ID
       );
       
-      const [enrollment2Pda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL102.id)
-        ],
-        env.program.programId
+      const enrollment2Pda = getEnrollmentPda(
+        env.program.programId,
+        env.student1.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, COURSE_FIXTURES.SOL102.id, TERM_FIXTURES.FALL.id)
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       await env.program.methods
-        .registerCourse(COURSE_FIXTURES.SOL102.id)
+        .registerCourse(COURSE_FIXTURES.SOL102.id, TERM_FIXTURES.FALL.id)
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           course: sol102CoursePda,
           enrollment: enrollment2Pda,
           studentCreditAccount: studentCreditAccount,
@@ -217,14 +254,7 @@ describe("Certificate Minting", () => {
         .signers([env.student1])
         .rpc();
       
-      const [certificate2MintPda] = await findPda(
-        [
-          Buffer.from("certificate_mint"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL102.id)
-        ],
-        env.program.programId
-      );
+      const certificate2MintPda = getCertificateMintPda(env.program.programId, enrollment2Pda);
       
       const studentCertificate2Account = getAssociatedTokenAddressSync(
         certificate2MintPda,
@@ -235,9 +265,10 @@ describe("Certificate Minting", () => {
       
       try {
         await env.program.methods
-          .mintCertificate(COURSE_FIXTURES.SOL102.id, METADATA_URI)
+          .mintCertificate(COURSE_FIXTURES.SOL102.id, TERM_FIXTURES.FALL.id, METADATA_URI, METADATA_HASH)
           .accounts({
             instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
             student: env.student1.publicKey,
             course: sol102CoursePda,
             enrollment: enrollment2Pda,
@@ -258,14 +289,7 @@ describe("Certificate Minting", () => {
     });
     
     it("Fails when certificate already minted", async () => {
-      const [certificateMintPda] = await findPda(
-        [
-          Buffer.from("certificate_mint"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL101.id)
-        ],
-        env.program.programId
-      );
+      const certificateMintPda = getCertificateMintPda(env.program.programId, enrollmentPda);
       
       const studentCertificateAccount = getAssociatedTokenAddressSync(
         certificateMintPda,
@@ -275,15 +299,16 @@ describe("Certificate Minting", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       try {
         await env.program.methods
-          .mintCertificate(COURSE_FIXTURES.SOL101.id, METADATA_URI)
+          .mintCertificate(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id, METADATA_URI, METADATA_HASH)
           .accounts({
             instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
             student: env.student1.publicKey,
             course: sol101CoursePda,
             enrollment: enrollmentPda,
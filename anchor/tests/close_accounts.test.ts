@@ -0,0 +1,278 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  createCourse,
+  createTerm,
+  createOffering,
+  createStudentProfile,
+  getEnrollmentPda,
+  getOfferingPda,
+  sleep,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Closing Accounts", () => {
+  let env: TestEnvironment;
+  let studentProfilePda: anchor.web3.PublicKey;
+  const graded = COURSE_FIXTURES.SOL101;
+  const ungraded = COURSE_FIXTURES.WEB301;
+  const term = TERM_FIXTURES.FALL;
+
+  const enrollmentPda = (courseId: string) =>
+    getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, courseId, term.id)
+    );
+
+  const closeEnrollment = (courseId: string) =>
+    env.program.methods
+      .closeEnrollment(courseId, term.id)
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+      })
+      .signers([env.student1])
+      .rpc();
+
+  const closeProfile = (student = env.student1) =>
+    env.program.methods
+      .closeProfile()
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+      })
+      .signers([student])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    await createTerm(env.program, env.institutionPda, env.authority, term);
+    studentProfilePda = await createStudentProfile(env.program, env.institutionPda, env.student1);
+
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(20))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: getAssociatedTokenAddressSync(
+          config.creditMint,
+          env.student1.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.student1])
+      .rpc();
+
+    // Short grading deadlines so the enrollments can be closed during the test
+    const now = Math.floor(Date.now() / 1000);
+    for (const course of [graded, ungraded]) {
+      await createCourse(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        course.name,
+        env.instructor.publicKey,
+        course.requiredCredits,
+        course.creditHours
+      );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY,
+        {
+          enrollmentOpensAt: now - 60,
+          enrollmentClosesAt: now + 5,
+          gradingDeadline: now + 8,
+        }
+      );
+
+      await env.program.methods
+        .registerCourse(course.id, term.id)
+        .accounts({
+          student: env.student1.publicKey,
+          institution: env.institutionPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([env.student1])
+        .rpc();
+    }
+
+    await env.program.methods
+      .completeCourse(graded.id, term.id, 90)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        student: env.student1.publicKey,
+      })
+      .signers([env.instructor])
+      .rpc();
+
+    await env.program.methods
+      .mintCertificate(graded.id, term.id, "https://metadata-uri.com/cert.json", Array(32).fill(1))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+      })
+      .signers([env.student1])
+      .rpc();
+  });
+
+  describe("Error Cases", () => {
+    it("Fails to close a profile with open enrollments", async () => {
+      try {
+        await closeProfile();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Student still has open enrollments");
+      }
+    });
+
+    it("Fails to close an enrollment before the grading deadline", async () => {
+      try {
+        await closeEnrollment(graded.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Grading deadline has not passed yet");
+      }
+    });
+
+    it("Fails to close an enrollment that is still awaiting a grade", async () => {
+      await sleep(10000);
+
+      try {
+        await closeEnrollment(ungraded.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Enrollment is not finished");
+      }
+    });
+  });
+
+  describe("Reclaiming Rent", () => {
+    it("Certified enrollment is closed and the rest of its rent returned", async () => {
+      const enrollmentRent = await env.getBalance(enrollmentPda(graded.id));
+      const balanceBefore = await env.getBalance(env.student1.publicKey);
+
+      await closeEnrollment(graded.id);
+
+      const balanceAfter = await env.getBalance(env.student1.publicKey);
+      expect(balanceAfter).to.be.greaterThan(balanceBefore);
+      expect(balanceAfter - balanceBefore).to.be.at.most(enrollmentRent);
+
+      const enrollment = await env.program.account.courseEnrollment.fetchNullable(
+        enrollmentPda(graded.id)
+      );
+      expect(enrollment).to.be.null;
+
+      // The grade stays in the profile's GPA
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.openEnrollments).to.equal(1);
+      expect(profile.coursesCompleted).to.equal(1);
+
+      console.log("✅ Enrollment closed, reclaimed", balanceAfter - balanceBefore, "lamports");
+    });
+
+    it("Expired enrollment is closed into an enrollment record", async () => {
+      await env.program.methods
+        .expireEnrollment(ungraded.id, term.id)
+        .accounts({ student: env.student1.publicKey, institution: env.institutionPda })
+        .rpc();
+
+      await closeEnrollment(ungraded.id);
+
+      const [recordPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("enrollment_record"), enrollmentPda(ungraded.id).toBuffer()],
+        env.program.programId
+      );
+      const record = await env.program.account.enrollmentRecord.fetch(recordPda);
+      expect(record.student.toString()).to.equal(env.student1.publicKey.toString());
+      expect(record.entry.courseId).to.equal(ungraded.id);
+      expect(record.entry.termId).to.equal(term.id);
+      expect(record.entry.status).to.deep.equal({ incomplete: {} });
+      expect(record.certificateMint).to.be.null;
+
+      // Failed and expired enrollments no longer keep the profile open
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.openEnrollments).to.equal(0);
+    });
+
+    it("Certified enrollment keeps its certificate in the record", async () => {
+      const [recordPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("enrollment_record"), enrollmentPda(graded.id).toBuffer()],
+        env.program.programId
+      );
+      const record = await env.program.account.enrollmentRecord.fetch(recordPda);
+      expect(record.entry.grade).to.equal(90);
+      expect(record.certificateMint).to.not.be.null;
+      expect(record.metadataHash).to.deep.equal(Array(32).fill(1));
+    });
+  });
+
+  describe("Profile Tombstone", () => {
+    it("Closed profile leaves a tombstone that a re-created profile restores", async () => {
+      await closeProfile();
+
+      const profile = await env.program.account.studentProfile.fetchNullable(studentProfilePda);
+      expect(profile).to.be.null;
+
+      const [tombstonePda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("profile_tombstone"),
+          env.institutionPda.toBuffer(),
+          env.student1.publicKey.toBuffer(),
+        ],
+        env.program.programId
+      );
+      const tombstone = await env.program.account.profileTombstone.fetch(tombstonePda);
+      expect(tombstone.student.toString()).to.equal(env.student1.publicKey.toString());
+      expect(tombstone.coursesCompleted).to.equal(1);
+
+      // Re-onboarding is allowed, but the GPA and completions carry over
+      await createStudentProfile(env.program, env.institutionPda, env.student1);
+      const restored = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(restored.coursesCompleted).to.equal(1);
+      expect(restored.gpa).to.equal(tombstone.gpa);
+      expect(restored.openEnrollments).to.equal(0);
+
+      const closedTombstone = await env.program.account.profileTombstone.fetchNullable(tombstonePda);
+      expect(closedTombstone).to.be.null;
+
+      console.log("✅ Student profile closed into a tombstone and restored");
+    });
+  });
+});
@@ -0,0 +1,224 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  createCourse,
+  createOffering,
+  createStudentProfile,
+  createTerm,
+  getCertificateTreePda,
+  getCpiEvents,
+  getEnrollmentPda,
+  getOfferingPda,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY, METADATA_URI, METADATA_HASH } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+// Loaded from tests/fixtures through [[test.genesis]] in Anchor.toml
+const ACCOUNT_COMPRESSION_PROGRAM_ID = new PublicKey("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+const NOOP_PROGRAM_ID = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+const COURSE = COURSE_FIXTURES.SOL101;
+const TERM = TERM_FIXTURES.FALL;
+const MAX_DEPTH = 3;
+const MAX_BUFFER_SIZE = 8;
+
+// Header, sequence/index/size counters, change logs and rightmost proof
+const treeAccountSize = (depth: number, buffer: number) =>
+  56 + 24 + buffer * (32 + depth * 32 + 8) + (depth * 32 + 32 + 8);
+
+describe("Compressed Certificates", () => {
+  let env: TestEnvironment;
+  let coursePda: PublicKey;
+  let offeringPda: PublicKey;
+  const merkleTree = Keypair.generate();
+
+  const treePda = () => getCertificateTreePda(env.program.programId, merkleTree.publicKey);
+  const enrollmentPda = (student: Keypair) => getEnrollmentPda(env.program.programId, student.publicKey, offeringPda);
+
+  const createTree = async (tree: Keypair, depth: number, buffer: number) => {
+    const space = treeAccountSize(depth, buffer);
+    const allocate = SystemProgram.createAccount({
+      fromPubkey: env.authority.publicKey,
+      newAccountPubkey: tree.publicKey,
+      lamports: await env.connection.getMinimumBalanceForRentExemption(space),
+      space,
+      programId: ACCOUNT_COMPRESSION_PROGRAM_ID,
+    });
+    return env.program.methods
+      .createCertificateTree(depth, buffer)
+      .accountsPartial({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        merkleTree: tree.publicKey,
+        certificateTree: getCertificateTreePda(env.program.programId, tree.publicKey),
+        compressionProgram: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        noopProgram: NOOP_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .preInstructions([allocate])
+      .signers([env.authority, tree])
+      .rpc();
+  };
+
+  const mint = (student: Keypair) =>
+    env.program.methods
+      .mintCompressedCertificate(COURSE.id, TERM.id, METADATA_URI, METADATA_HASH)
+      .accountsPartial({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        offering: offeringPda,
+        enrollment: enrollmentPda(student),
+        certificateTree: treePda(),
+        merkleTree: merkleTree.publicKey,
+        compressionProgram: ACCOUNT_COMPRESSION_PROGRAM_ID,
+        noopProgram: NOOP_PROGRAM_ID,
+      })
+      .signers([student])
+      .rpc();
+
+  const enrollAndGrade = async (student: Keypair, grade: number) => {
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const studentCreditAccount = getAssociatedTokenAddressSync(
+      config.creditMint,
+      student.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const studentProfilePda = await createStudentProfile(env.program, env.institutionPda, student);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(COURSE.requiredCredits))
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: studentCreditAccount,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .registerCourse(COURSE.id, TERM.id)
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        course: coursePda,
+        enrollment: enrollmentPda(student),
+        studentCreditAccount: studentCreditAccount,
+        creditMint: config.creditMint,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .completeCourse(COURSE.id, TERM.id, grade)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        course: coursePda,
+        enrollment: enrollmentPda(student),
+      })
+      .signers([env.instructor])
+      .rpc();
+  };
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    coursePda = await createCourse(env.program, env.institutionPda, env.authority, COURSE.id, COURSE.name, env.instructor.publicKey, COURSE.requiredCredits);
+    await createTerm(env.program, env.institutionPda, env.authority, TERM);
+    await createOffering(env.program, env.institutionPda, env.authority, COURSE.id, TERM.id, env.instructor.publicKey, OFFERING_CAPACITY);
+    offeringPda = getOfferingPda(env.program.programId, env.institutionPda, COURSE.id, TERM.id);
+
+    await enrollAndGrade(env.student1, 91);
+    await enrollAndGrade(env.student2, 78);
+  });
+
+  describe("Trees", () => {
+    it("Fails with a buffer size that is not a power of two", async () => {
+      try {
+        await createTree(Keypair.generate(), MAX_DEPTH, 6);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid certificate tree depth or buffer size");
+      }
+    });
+
+    it("Initializes the tree with the PDA as its authority", async () => {
+      const signature = await createTree(merkleTree, MAX_DEPTH, MAX_BUFFER_SIZE);
+
+      const tree = await env.program.account.certificateTree.fetch(treePda());
+      expect(tree.merkleTree.toString()).to.equal(merkleTree.publicKey.toString());
+      expect(tree.authority.toString()).to.equal(env.authority.publicKey.toString());
+      expect(tree.maxDepth).to.equal(MAX_DEPTH);
+      expect(tree.minted.toNumber()).to.equal(0);
+
+      // The compression program records the tree authority at bytes 10..42 of the header
+      const account = await env.connection.getAccountInfo(merkleTree.publicKey);
+      expect(account!.owner.toString()).to.equal(ACCOUNT_COMPRESSION_PROGRAM_ID.toString());
+      expect(new PublicKey(account!.data.subarray(10, 42)).toString()).to.equal(treePda().toString());
+
+      const events = await getCpiEvents(env.program, signature);
+      expect(events.map((event) => event.name)).to.include("certificateTreeCreated");
+    });
+  });
+
+  describe("Minting", () => {
+    it("Appends leaves in order and records them on the enrollment", async () => {
+      const signature = await mint(env.student1);
+      await mint(env.student2);
+
+      const first = await env.program.account.courseEnrollment.fetch(enrollmentPda(env.student1));
+      const second = await env.program.account.courseEnrollment.fetch(enrollmentPda(env.student2));
+      expect(first.certificateMint).to.equal(null);
+      expect(first.compressedCertificate!.merkleTree.toString()).to.equal(merkleTree.publicKey.toString());
+      expect(first.compressedCertificate!.leafIndex).to.equal(0);
+      expect(second.compressedCertificate!.leafIndex).to.equal(1);
+      expect(first.compressedCertificate!.leafHash).to.not.deep.equal(second.compressedCertificate!.leafHash);
+      expect(first.metadataHash).to.deep.equal(METADATA_HASH);
+
+      const tree = await env.program.account.certificateTree.fetch(treePda());
+      expect(tree.minted.toNumber()).to.equal(2);
+
+      const events = await getCpiEvents(env.program, signature);
+      const minted = events.find((event) => event.name === "compressedCertificateMinted");
+      expect(minted?.data.leafIndex).to.equal(0);
+    });
+
+    it("Cannot mint twice", async () => {
+      try {
+        await mint(env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Certificate already minted");
+      }
+    });
+  });
+});
@@ -0,0 +1,190 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  findPda,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+
+describe("Terms and Course Offerings", () => {
+  let env: TestEnvironment;
+  const course = COURSE_FIXTURES.SOL101;
+
+  const purchaseCredits = async (student: Keypair, amount: number) => {
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const [studentProfilePda] = await findPda(
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), student.publicKey.toBuffer()],
+      env.program.programId
+    );
+
+    await createStudentProfile(env.program, env.institutionPda, student);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(amount))
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: getAssociatedTokenAddressSync(
+          config.creditMint,
+          student.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+  };
+
+  const registerCourse = (student: Keypair, termId: string) =>
+    env.program.methods
+      .registerCourse(course.id, termId)
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([student])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    await createCourse(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      course.id,
+      course.name,
+      env.instructor.publicKey,
+      course.requiredCredits,
+      course.creditHours
+    );
+
+    await purchaseCredits(env.student1, course.requiredCredits * 2);
+    await purchaseCredits(env.student2, course.requiredCredits);
+  });
+
+  describe("Terms", () => {
+    it("Authority creates fall and spring terms", async () => {
+      const fallPda = await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.FALL);
+      await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.SPRING);
+
+      const term = await env.program.account.term.fetch(fallPda);
+      expect(term.termId).to.equal(TERM_FIXTURES.FALL.id);
+      expect(term.name).to.equal(TERM_FIXTURES.FALL.name);
+      expect(term.registrationClosesAt.toNumber()).to.equal(
+        TERM_FIXTURES.FALL.registrationClosesAt
+      );
+    });
+
+    it("Fails when registration closes after the term ends", async () => {
+      try {
+        await createTerm(env.program, env.institutionPda, env.authority, {
+          ...TERM_FIXTURES.FALL,
+          id: "BADTERM",
+          registrationClosesAt: TERM_FIXTURES.FALL.endsAt + 1,
+        });
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid term dates");
+      }
+    });
+  });
+
+  describe("Offerings", () => {
+    it("Same course is offered in two terms with different instructors", async () => {
+      const springInstructor = Keypair.generate().publicKey;
+
+      const fall = await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        TERM_FIXTURES.FALL.id,
+        env.instructor.publicKey,
+        1
+      );
+      const spring = await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        TERM_FIXTURES.SPRING.id,
+        springInstructor,
+        30
+      );
+
+      const fallOffering = await env.program.account.courseOffering.fetch(fall);
+      const springOffering = await env.program.account.courseOffering.fetch(spring);
+      expect(fallOffering.instructor.toString()).to.equal(env.instructor.publicKey.toString());
+      expect(springOffering.instructor.toString()).to.equal(springInstructor.toString());
+      expect(springOffering.capacity).to.equal(30);
+    });
+
+    it("Registration is counted against the offering", async () => {
+      await registerCourse(env.student1, TERM_FIXTURES.FALL.id);
+
+      const offeringPda = getOfferingPda(env.program.programId, env.institutionPda, course.id, TERM_FIXTURES.FALL.id);
+      const offering = await env.program.account.courseOffering.fetch(offeringPda);
+      expect(offering.enrollmentCount).to.equal(1);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        getEnrollmentPda(env.program.programId, env.student1.publicKey, offeringPda)
+      );
+      expect(enrollment.offering.toString()).to.equal(offeringPda.toString());
+    });
+
+    it("Fails when the offering is full", async () => {
+      try {
+        await registerCourse(env.student2, TERM_FIXTURES.FALL.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Course offering is full");
+      }
+    });
+
+    it("Student takes the same course again in a later term", async () => {
+      await registerCourse(env.student1, TERM_FIXTURES.SPRING.id);
+
+      const springPda = getOfferingPda(env.program.programId, env.institutionPda, course.id, TERM_FIXTURES.SPRING.id);
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        getEnrollmentPda(env.program.programId, env.student1.publicKey, springPda)
+      );
+      expect(enrollment.courseId).to.equal(course.id);
+      expect(enrollment.offering.toString()).to.equal(springPda.toString());
+
+      console.log("✅ Student enrolled in a second offering of", course.id);
+    });
+  });
+});
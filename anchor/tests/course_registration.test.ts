@@ -2,8 +2,17 @@ import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { beforeAll, describe, it } from "vitest";
 import { createTestEnvironment, TestEnvironment } from "./setup";
-import { findPda, getTokenBalance, createCourse } from "./utils/helpers";
-import { COURSE_FIXTURES } from "./utils/fixtures";
+import {
+  findPda,
+  getTokenBalance,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
 import { 
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -20,10 +29,10 @@ describe("Course Registration", () => {
     
     // Initialize program
     await env.program.methods
-      .initialize()
+      .createInstitution(env.institutionId, "Test University")
       .accounts({
         authority: env.authority.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -33,6 +42,7 @@ describe("Course Registration", () => {
     // Create test course
     sol101CoursePda = await createCourse(
       env.program,
+      env.institutionPda,
       env.authority,
       COURSE_FIXTURES.SOL101.id,
       COURSE_FIXTURES.SOL101.name,
@@ -40,8 +50,20 @@ describe("Course Registration", () => {
       COURSE_FIXTURES.SOL101.requiredCredits
     );
     
+    // Offer it in the fall term
+    await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.FALL);
+    await createOffering(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      COURSE_FIXTURES.SOL101.id,
+      TERM_FIXTURES.FALL.id,
+      env.instructor.publicKey,
+      OFFERING_CAPACITY
+    );
+    
     // Student purchases credits
-    const config = await env.program.account.programConfig.fetch(env.configPda);
+    const config = await env.program.account.institution.fetch(env.institutionPda);
     const studentCreditAccount = getAssociatedTokenAddressSync(
       config.creditMint,
       env.student1.publicKey,
@@ -50,15 +72,17 @@ describe("Course Registration", () => {
     );
     
     const [studentProfilePda] = await findPda(
-      [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
       env.program.programId
     );
     
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
     await env.program.methods
       .purchaseCredits(new anchor.BN(30))
       .accounts({
         student: env.student1.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         creditMint: config.creditMint,
         studentCreditAccount: studentCreditAccount,
@@ -74,7 +98,7 @@ describe("Course Registration", () => {
   describe("Successful Registration", () => {
     it("Student registers for SOL101 course", async () => {
       const courseId = COURSE_FIXTURES.SOL101.id;
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
@@ -83,27 +107,24 @@ describe("Course Registration", () => {
         TOKEN_2022_PROGRAM_ID
       );
       
-      const [enrollmentPda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(courseId)
-        ],
-        env.program.programId
+      const enrollmentPda = getEnrollmentPda(
+        env.program.programId,
+        env.student1.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       const creditBalanceBefore = await getTokenBalance(env.connection, studentCreditAccount);
       
       const tx = await env.program.methods
-        .registerCourse(courseId)
+        .registerCourse(courseId, TERM_FIXTURES.FALL.id)
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           course: sol101CoursePda,
           enrollment: enrollmentPda,
           studentCreditAccount: studentCreditAccount,
@@ -128,7 +149,7 @@ describe("Course Registration", () => {
       expect(enrollment.student.toString()).to.equal(env.student1.publicKey.toString());
       expect(enrollment.courseId).to.equal(courseId);
       expect(enrollment.creditsPaid.toNumber()).to.equal(COURSE_FIXTURES.SOL101.requiredCredits);
-      expect(enrollment.isCompleted).to.be.false;
+      expect(enrollment.status).to.deep.equal({ enrolled: {} });
       
       // Verify student profile updated
       const studentProfile = await env.program.account.studentProfile.fetch(studentProfilePda);
@@ -143,15 +164,25 @@ describe("Course Registration", () => {
       // Create WEB301 course
       const web301CoursePda = await createCourse(
         env.program,
+        env.institutionPda,
         env.authority,
         COURSE_FIXTURES.WEB301.id,
         COURSE_FIXTURES.WEB301.name,
         env.instructor.publicKey,
         COURSE_FIXTURES.WEB301.requiredCredits
       );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.WEB301.id,
+        TERM_FIXTURES.FALL.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
       
       const courseId = COURSE_FIXTURES.WEB301.id;
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
@@ -160,25 +191,22 @@ describe("Course Registration", () => {
         TOKEN_2022_PROGRAM_ID
       );
       
-      const [enrollmentPda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(courseId)
-        ],
-        env.program.programId
+      const enrollmentPda = getEnrollmentPda(
+        env.program.programId,
+        env.student1.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       await env.program.methods
-        .registerCourse(courseId)
+        .registerCourse(courseId, TERM_FIXTURES.FALL.id)
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           course: web301CoursePda,
           enrollment: enrollmentPda,
           studentCreditAccount: studentCreditAccount,
@@ -207,7 +235,7 @@ describe("Course Registration", () => {
       const poorStudent = anchor.web3.Keypair.generate();
       await env.airdrop(poorStudent.publicKey, 1);
       
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         poorStudent.publicKey,
@@ -215,26 +243,25 @@ describe("Course Registration", () => {
         TOKEN_2022_PROGRAM_ID
       );
       
-      const [enrollmentPda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          poorStudent.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL101.id)
-        ],
-        env.program.programId
+      const enrollmentPda = getEnrollmentPda(
+        env.program.programId,
+        poorStudent.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), poorStudent.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), poorStudent.publicKey.toBuffer()],
         env.program.programId
       );
       
       // Purchase only 2 credits (SOL101 requires 5)
+      await createStudentProfile(env.program, env.institutionPda, poorStudent);
+
       await env.program.methods
         .purchaseCredits(new anchor.BN(2))
         .accounts({
           student: poorStudent.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           creditMint: config.creditMint,
           studentCreditAccount: studentCreditAccount,
@@ -248,10 +275,10 @@ describe("Course Registration", () => {
       
       try {
         await env.program.methods
-          .registerCourse(COURSE_FIXTURES.SOL101.id)
+          .registerCourse(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
           .accounts({
             student: poorStudent.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             course: sol101CoursePda,
             enrollment: enrollmentPda,
             studentCreditAccount: studentCreditAccount,
@@ -270,7 +297,7 @@ describe("Course Registration", () => {
     });
     
     it("Fails when already enrolled", async () => {
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         env.student1.publicKey,
@@ -278,26 +305,23 @@ describe("Course Registration", () => {
         TOKEN_2022_PROGRAM_ID
       );
       
-      const [enrollmentPda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(COURSE_FIXTURES.SOL101.id)
-        ],
-        env.program.programId
+      const enrollmentPda = getEnrollmentPda(
+        env.program.programId,
+        env.student1.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       try {
         await env.program.methods
-          .registerCourse(COURSE_FIXTURES.SOL101.id)
+          .registerCourse(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id)
           .accounts({
             student: env.student1.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             course: sol101CoursePda,
             enrollment: enrollmentPda,
             studentCreditAccount: studentCreditAccount,
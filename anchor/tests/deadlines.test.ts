@@ -0,0 +1,237 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  findPda,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  sleep,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Enrollment and Grading Deadlines", () => {
+  let env: TestEnvironment;
+  const term = TERM_FIXTURES.FALL;
+  const now = () => Math.floor(Date.now() / 1000);
+
+  const registerCourse = (courseId: string) =>
+    env.program.methods
+      .registerCourse(courseId, term.id)
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([env.student1])
+      .rpc();
+
+  const enrollmentPda = (courseId: string) =>
+    getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, courseId, term.id)
+    );
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    await createTerm(env.program, env.institutionPda, env.authority, term);
+
+    for (const course of [
+      COURSE_FIXTURES.SOL101,
+      COURSE_FIXTURES.SOL102,
+      COURSE_FIXTURES.WEB301,
+      COURSE_FIXTURES.RUST201,
+    ]) {
+      await createCourse(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        course.name,
+        env.instructor.publicKey,
+        course.requiredCredits,
+        course.creditHours
+      );
+    }
+
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const [studentProfilePda] = await findPda(
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
+      env.program.programId
+    );
+
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(30))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: getAssociatedTokenAddressSync(
+          config.creditMint,
+          env.student1.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.student1])
+      .rpc();
+  });
+
+  describe("Enrollment Window", () => {
+    it("Offering deadlines default to the term dates", async () => {
+      const offeringPda = await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.WEB301.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
+
+      const offering = await env.program.account.courseOffering.fetch(offeringPda);
+      expect(offering.enrollmentOpensAt.toNumber()).to.equal(term.registrationOpensAt);
+      expect(offering.enrollmentClosesAt.toNumber()).to.equal(term.registrationClosesAt);
+      expect(offering.gradingDeadline.toNumber()).to.equal(term.endsAt);
+    });
+
+    it("Fails to register before enrollment opens", async () => {
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.SOL101.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY,
+        { enrollmentOpensAt: now() + 3600, enrollmentClosesAt: now() + 7200 }
+      );
+
+      try {
+        await registerCourse(COURSE_FIXTURES.SOL101.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Enrollment has not opened yet");
+      }
+    });
+  });
+
+  describe("Grading Deadline", () => {
+    it("Fails to expire an enrollment before the grading deadline", async () => {
+      await registerCourse(COURSE_FIXTURES.WEB301.id);
+
+      try {
+        await env.program.methods
+          .expireEnrollment(COURSE_FIXTURES.WEB301.id, term.id)
+          .accounts({ student: env.student1.publicKey, institution: env.institutionPda })
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Grading deadline has not passed yet");
+      }
+    });
+
+    it("Ungraded enrollment is expired as incomplete after the deadline", async () => {
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.SOL102.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY,
+        {
+          enrollmentOpensAt: now() - 60,
+          enrollmentClosesAt: now() + 3,
+          gradingDeadline: now() + 5,
+        }
+      );
+      await registerCourse(COURSE_FIXTURES.SOL102.id);
+
+      await sleep(7000);
+
+      try {
+        await env.program.methods
+          .completeCourse(COURSE_FIXTURES.SOL102.id, term.id, 90)
+          .accounts({
+            instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
+            student: env.student1.publicKey,
+          })
+          .signers([env.instructor])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Grading deadline has passed");
+      }
+
+      // Any wallet can crank the expiry
+      await env.program.methods
+        .expireEnrollment(COURSE_FIXTURES.SOL102.id, term.id)
+        .accounts({ student: env.student1.publicKey, institution: env.institutionPda })
+        .rpc();
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        enrollmentPda(COURSE_FIXTURES.SOL102.id)
+      );
+      expect(enrollment.status).to.deep.equal({ incomplete: {} });
+
+      console.log("✅ Enrollment expired as incomplete");
+    });
+
+    it("Fails to register after enrollment closes", async () => {
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        COURSE_FIXTURES.RUST201.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY,
+        {
+          enrollmentOpensAt: now() - 7200,
+          enrollmentClosesAt: now() - 3600,
+          gradingDeadline: now() + 3600,
+        }
+      );
+
+      try {
+        await registerCourse(COURSE_FIXTURES.RUST201.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Enrollment is closed");
+      }
+    });
+  });
+});
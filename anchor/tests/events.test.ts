@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  createTerm,
+  createOffering,
+  createStudentProfile,
+  getCpiEvents,
+  getEnrollmentPda,
+  getOfferingPda,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Program Events", () => {
+  let env: TestEnvironment;
+  const course = COURSE_FIXTURES.SOL101;
+  const term = TERM_FIXTURES.FALL;
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    await createTerm(env.program, env.institutionPda, env.authority, term);
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+  });
+
+  it("CourseCreated carries the course settings", async () => {
+    const signature = await env.program.methods
+      .createCourse(
+        course.id,
+        course.name,
+        env.instructor.publicKey,
+        new anchor.BN(course.requiredCredits),
+        course.creditHours,
+        3
+      )
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+      })
+      .signers([env.authority])
+      .rpc({ commitment: "confirmed" });
+
+    const [event] = await getCpiEvents(env.program, signature);
+    expect(event.name).to.equal("courseCreated");
+    expect(event.data.courseId).to.equal(course.id);
+    expect(event.data.instructor.toString()).to.equal(env.instructor.publicKey.toString());
+    expect(event.data.requiredCredits.toNumber()).to.equal(course.requiredCredits);
+    expect(event.data.creditHours).to.equal(course.creditHours);
+
+    await createOffering(
+      env.program,
+      env.institutionPda,
+      env.authority,
+      course.id,
+      term.id,
+      env.instructor.publicKey,
+      OFFERING_CAPACITY
+    );
+  });
+
+  it("CreditsPurchased carries the amount and cost", async () => {
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+
+    const signature = await env.program.methods
+      .purchaseCredits(new anchor.BN(10))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        creditMint: config.creditMint,
+        studentCreditAccount: getAssociatedTokenAddressSync(
+          config.creditMint,
+          env.student1.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([env.student1])
+      .rpc({ commitment: "confirmed" });
+
+    const [event] = await getCpiEvents(env.program, signature);
+    expect(event.name).to.equal("creditsPurchased");
+    expect(event.data.student.toString()).to.equal(env.student1.publicKey.toString());
+    expect(event.data.amount.toNumber()).to.equal(10);
+    expect(event.data.totalCost.toNumber()).to.equal(10 * config.creditPrice.toNumber());
+  });
+
+  it("StudentRegistered and CourseCompleted identify the enrollment", async () => {
+    const enrollmentPda = getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, course.id, term.id)
+    );
+
+    const registerSignature = await env.program.methods
+      .registerCourse(course.id, term.id)
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([env.student1])
+      .rpc({ commitment: "confirmed" });
+
+    const [registered] = await getCpiEvents(env.program, registerSignature);
+    expect(registered.name).to.equal("studentRegistered");
+    expect(registered.data.enrollment.toString()).to.equal(enrollmentPda.toString());
+    expect(registered.data.termId).to.equal(term.id);
+    expect(registered.data.creditsPaid.toNumber()).to.equal(course.requiredCredits);
+
+    const completeSignature = await env.program.methods
+      .completeCourse(course.id, term.id, 92)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        student: env.student1.publicKey,
+      })
+      .signers([env.instructor])
+      .rpc({ commitment: "confirmed" });
+
+    const [completed] = await getCpiEvents(env.program, completeSignature);
+    expect(completed.name).to.equal("courseCompleted");
+    expect(completed.data.enrollment.toString()).to.equal(enrollmentPda.toString());
+    expect(completed.data.grade).to.equal(92);
+    expect(completed.data.letterGrade).to.equal("A");
+    expect(completed.data.gpa).to.equal(400);
+
+    console.log("✅ Events decoded from inner instructions");
+  });
+});
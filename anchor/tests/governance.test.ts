@@ -0,0 +1,200 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import { getConfigGovernancePda, getConfigProposalPda } from "./utils/helpers";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+// The shortest delay a governance accepts (MIN_CONFIG_DELAY). Too long to wait
+// out here; executing after the delay is covered by the LiteSVM tests.
+const DELAY_SECONDS = 60 * 60;
+
+describe("Config Governance", () => {
+  let env: TestEnvironment;
+  let governancePda: PublicKey;
+  const admins = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+  const newTreasury = Keypair.generate().publicKey;
+
+  const change = (fields: Record<string, unknown>) => ({
+    authority: null,
+    treasury: null,
+    creditMint: null,
+    admins: null,
+    threshold: null,
+    minDelay: null,
+    ...fields,
+  });
+
+  const propose = (proposer: Keypair, proposalId: number, fields: Record<string, unknown>) =>
+    env.program.methods
+      .proposeConfigChange(change(fields) as any)
+      .accounts({
+        proposer: proposer.publicKey,
+        institution: env.institutionPda,
+        governance: governancePda,
+        proposal: getConfigProposalPda(env.program.programId, env.institutionPda, proposalId),
+      })
+      .signers([proposer])
+      .rpc();
+
+  const execute = (proposalId: number, proposer: Keypair) =>
+    env.program.methods
+      .executeConfigChange(new anchor.BN(proposalId))
+      .accounts({
+        executor: env.authority.publicKey,
+        institution: env.institutionPda,
+        proposer: proposer.publicKey,
+      })
+      .signers([env.authority])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+    for (const admin of admins) {
+      await env.airdrop(admin.publicKey, 1);
+    }
+
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    governancePda = getConfigGovernancePda(env.program.programId, env.institutionPda);
+  });
+
+  describe("Create Governance", () => {
+    it("Fails with a threshold above the admin count", async () => {
+      try {
+        await env.program.methods
+          .createConfigGovernance(admins.map((admin) => admin.publicKey), 4, new anchor.BN(DELAY_SECONDS))
+          .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+          .signers([env.authority])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid governance");
+      }
+    });
+
+    it("Fails with a delay below the floor", async () => {
+      try {
+        await env.program.methods
+          .createConfigGovernance(admins.map((admin) => admin.publicKey), 2, new anchor.BN(0))
+          .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+          .signers([env.authority])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid governance");
+      }
+    });
+
+    it("Authority puts the institution under 2-of-3 governance", async () => {
+      await env.program.methods
+        .createConfigGovernance(admins.map((admin) => admin.publicKey), 2, new anchor.BN(DELAY_SECONDS))
+        .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+        .signers([env.authority])
+        .rpc();
+
+      const governance = await env.program.account.configGovernance.fetch(governancePda);
+      expect(governance.admins).to.have.length(3);
+      expect(governance.threshold).to.equal(2);
+      const institution = await env.program.account.institution.fetch(env.institutionPda);
+      expect(institution.governed).to.be.true;
+
+      console.log("✅ Governance created");
+    });
+
+    it("Fails when the authority changes the treasury directly", async () => {
+      try {
+        await env.program.methods
+          .updateConfig(null, newTreasury, null)
+          .accounts({ authority: env.authority.publicKey, institution: env.institutionPda })
+          .signers([env.authority])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("need a config proposal");
+      }
+    });
+  });
+
+  describe("Proposals", () => {
+    it("Fails when a non-admin proposes", async () => {
+      try {
+        await propose(env.student1, 0, { treasury: newTreasury });
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("not a governance admin");
+      }
+    });
+
+    it("Admin proposes a new treasury", async () => {
+      await propose(admins[0], 0, { treasury: newTreasury });
+
+      const proposal = await env.program.account.configProposal.fetch(
+        getConfigProposalPda(env.program.programId, env.institutionPda, 0)
+      );
+      expect(proposal.approvals.map((key) => key.toString())).to.deep.equal([admins[0].publicKey.toString()]);
+      expect(proposal.executableAt.toNumber()).to.equal(proposal.createdAt.toNumber() + DELAY_SECONDS);
+    });
+
+    it("Fails to execute with one approval", async () => {
+      try {
+        await execute(0, admins[0]);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("enough approvals");
+      }
+    });
+
+    it("Fails to execute before the delay", async () => {
+      await env.program.methods
+        .approveConfigChange(new anchor.BN(0))
+        .accounts({ admin: admins[1].publicKey, institution: env.institutionPda })
+        .signers([admins[1]])
+        .rpc();
+
+      try {
+        await execute(0, admins[0]);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("delay has not elapsed");
+      }
+    });
+
+    it("Fails to propose lowering the delay below the floor", async () => {
+      try {
+        await propose(admins[0], 1, { minDelay: new anchor.BN(DELAY_SECONDS - 1) });
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid governance");
+      }
+    });
+
+    it("A single admin cancels a proposal", async () => {
+      await propose(admins[0], 1, { authority: admins[0].publicKey });
+
+      await env.program.methods
+        .cancelConfigChange(new anchor.BN(1))
+        .accounts({
+          admin: admins[2].publicKey,
+          institution: env.institutionPda,
+          proposer: admins[0].publicKey,
+        })
+        .signers([admins[2]])
+        .rpc();
+
+      const proposal = getConfigProposalPda(env.program.programId, env.institutionPda, 1);
+      expect(await env.connection.getAccountInfo(proposal)).to.be.null;
+      const institution = await env.program.account.institution.fetch(env.institutionPda);
+      expect(institution.authority.toString()).to.equal(env.authority.publicKey.toString());
+    });
+  });
+});
@@ -0,0 +1,249 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  findPda,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Letter Grades and GPA", () => {
+  let env: TestEnvironment;
+  let studentProfilePda: anchor.web3.PublicKey;
+  const courses = [COURSE_FIXTURES.SOL101, COURSE_FIXTURES.RUST201];
+  const coursePdas: Record<string, anchor.web3.PublicKey> = {};
+
+  const enrollmentPda = (courseId: string) =>
+    getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
+    );
+
+  const completeCourse = (courseId: string, grade: number) =>
+    env.program.methods
+      .completeCourse(courseId, TERM_FIXTURES.FALL.id, grade)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        course: coursePdas[courseId],
+        student: env.student1.publicKey,
+        enrollment: enrollmentPda(courseId),
+        studentProfile: studentProfilePda,
+      })
+      .signers([env.instructor])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const studentCreditAccount = getAssociatedTokenAddressSync(
+      config.creditMint,
+      env.student1.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    [studentProfilePda] = await findPda(
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
+      env.program.programId
+    );
+
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(30))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: studentCreditAccount,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.student1])
+      .rpc();
+
+    await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.FALL);
+
+    for (const course of courses) {
+      coursePdas[course.id] = await createCourse(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        course.name,
+        env.instructor.publicKey,
+        course.requiredCredits,
+        course.creditHours
+      );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        TERM_FIXTURES.FALL.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
+
+      await env.program.methods
+        .registerCourse(course.id, TERM_FIXTURES.FALL.id)
+        .accounts({
+          student: env.student1.publicKey,
+          institution: env.institutionPda,
+          course: coursePdas[course.id],
+          enrollment: enrollmentPda(course.id),
+          studentCreditAccount: studentCreditAccount,
+          creditMint: config.creditMint,
+          studentProfile: studentProfilePda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([env.student1])
+        .rpc();
+    }
+  });
+
+  describe("Grade Scale", () => {
+    it("Initializes with the default grade scale", async () => {
+      const config = await env.program.account.institution.fetch(env.institutionPda);
+
+      expect(config.gradeScale.map((entry: any) => entry.letter)).to.deep.equal(
+        ["A", "B", "C", "D", "F"]
+      );
+      expect(config.gradeScale[0].gradePoints).to.equal(400);
+    });
+
+    it("Rejects a grade scale that does not end at 0", async () => {
+      try {
+        await env.program.methods
+          .setGradeScale([
+            { minScore: 90, letter: "A", gradePoints: 400 },
+            { minScore: 50, letter: "P", gradePoints: 200 },
+          ])
+          .accounts({
+            authority: env.authority.publicKey,
+            institution: env.institutionPda,
+          })
+          .signers([env.authority])
+          .rpc();
+
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid grade scale");
+      }
+    });
+
+    it("Rejects grade scale updates from non-authority", async () => {
+      try {
+        await env.program.methods
+          .setGradeScale([{ minScore: 0, letter: "P", gradePoints: 400 }])
+          .accounts({
+            authority: env.instructor.publicKey,
+            institution: env.institutionPda,
+          })
+          .signers([env.instructor])
+          .rpc();
+
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("GPA", () => {
+    it("Completing a course records the letter grade and GPA", async () => {
+      await completeCourse(COURSE_FIXTURES.SOL101.id, 85);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        enrollmentPda(COURSE_FIXTURES.SOL101.id)
+      );
+      expect(enrollment.letterGrade).to.equal("B");
+      expect(enrollment.gradePoints).to.equal(300);
+      expect(enrollment.creditHours).to.equal(COURSE_FIXTURES.SOL101.creditHours);
+
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.gpa).to.equal(300);
+      expect(profile.creditHoursEarned).to.equal(COURSE_FIXTURES.SOL101.creditHours);
+
+      console.log("✅ GPA after first course:", profile.gpa / 100);
+    });
+
+    it("GPA is weighted by credit hours", async () => {
+      await completeCourse(COURSE_FIXTURES.RUST201.id, 95);
+
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      // (3.00 * 3 + 4.00 * 4) / 7 = 3.57
+      expect(profile.gpa).to.equal(357);
+      expect(profile.creditHoursEarned).to.equal(
+        COURSE_FIXTURES.SOL101.creditHours + COURSE_FIXTURES.RUST201.creditHours
+      );
+    });
+
+    it("Amending a grade replaces its GPA contribution", async () => {
+      await env.program.methods
+        .amendGrade(COURSE_FIXTURES.SOL101.id, TERM_FIXTURES.FALL.id, 40)
+        .accounts({
+          instructor: env.instructor.publicKey,
+          institution: env.institutionPda,
+          course: coursePdas[COURSE_FIXTURES.SOL101.id],
+          student: env.student1.publicKey,
+          enrollment: enrollmentPda(COURSE_FIXTURES.SOL101.id),
+          studentProfile: studentProfilePda,
+        })
+        .signers([env.instructor])
+        .rpc();
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        enrollmentPda(COURSE_FIXTURES.SOL101.id)
+      );
+      expect(enrollment.letterGrade).to.equal("F");
+
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      // (0.00 * 3 + 4.00 * 4) / 7 = 2.29
+      expect(profile.gpa).to.equal(229);
+      expect(profile.creditHoursAttempted).to.equal(7);
+      expect(profile.creditHoursEarned).to.equal(COURSE_FIXTURES.RUST201.creditHours);
+      expect(profile.coursesCompleted).to.equal(2);
+    });
+
+    it("Fails to complete the same course twice", async () => {
+      try {
+        await completeCourse(COURSE_FIXTURES.RUST201.id, 70);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Course already completed");
+      }
+    });
+  });
+});
@@ -1,10 +1,25 @@
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { beforeAll, describe, it } from "vitest";
 import { createTestEnvironment, TestEnvironment } from "./setup";
-import { findPda, getTokenBalance, createCourse } from "./utils/helpers";
-import { COURSE_FIXTURES, METADATA_URI } from "./utils/fixtures";
+import {
+  findPda,
+  getTokenBalance,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  getCertificateMintPda,
+  createStudentProfile,
+} from "./utils/helpers";
+import {
+  COURSE_FIXTURES,
+  TERM_FIXTURES,
+  OFFERING_CAPACITY,
+  METADATA_URI,
+  METADATA_HASH,
+} from "./utils/fixtures";
 import { 
   getAssociatedTokenAddressSync,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -27,10 +42,10 @@ describe("Graduation", () => {
     
     // Initialize program
     await env.program.methods
-      .initialize()
+      .createInstitution(env.institutionId, "Test University")
       .accounts({
         authority: env.authority.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -38,7 +53,7 @@ describe("Graduation", () => {
       .rpc();
     
     // Student purchases enough credits
-    const config = await env.program.account.programConfig.fetch(env.configPda);
+    const config = await env.program.account.institution.fetch(env.institutionPda);
     const studentCreditAccount = getAssociatedTokenAddressSync(
       config.creditMint,
       env.student1.publicKey,
@@ -47,7 +62,7 @@ describe("Graduation", () => {
     );
     
     const [studentProfilePda] = await findPda(
-      [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
       env.program.programId
     );
     
@@ -56,11 +71,13 @@ describe("Graduation", () => {
       0
     );
     
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
     await env.program.methods
       .purchaseCredits(new anchor.BN(totalCreditsNeeded + 10))
       .accounts({
         student: env.student1.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         creditMint: config.creditMint,
         studentCreditAccount: studentCreditAccount,
@@ -72,33 +89,42 @@ describe("Graduation", () => {
       .signers([env.student1])
       .rpc();
     
+    await createTerm(env.program, env.institutionPda, env.authority, TERM_FIXTURES.FALL);
+    
     // Complete all required courses
     for (const course of requiredCourses) {
       // Create course
       const coursePda = await createCourse(
         env.program,
+        env.institutionPda,
         env.authority,
         course.id,
         course.name,
         env.instructor.publicKey,
         course.requiredCredits
       );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        TERM_FIXTURES.FALL.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
       
       // Register for course
-      const [enrollmentPda] = await findPda(
-        [
-          Buffer.from("enrollment"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(course.id)
-        ],
-        env.program.programId
+      const enrollmentPda = getEnrollmentPda(
+        env.program.programId,
+        env.student1.publicKey,
+        getOfferingPda(env.program.programId, env.institutionPda, course.id, TERM_FIXTURES.FALL.id)
       );
       
       await env.program.methods
-        .registerCourse(course.id)
+        .registerCourse(course.id, TERM_FIXTURES.FALL.id)
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           course: coursePda,
           enrollment: enrollmentPda,
           studentCreditAccount: studentCreditAccount,
@@ -112,9 +138,10 @@ describe("Graduation", () => {
       
       // Complete course
       await env.program.methods
-        .completeCourse(course.id, 85)
+        .completeCourse(course.id, TERM_FIXTURES.FALL.id, 85)
         .accounts({
           instructor: env.instructor.publicKey,
+          institution: env.institutionPda,
           course: coursePda,
           enrollment: enrollmentPda,
         })
@@ -122,14 +149,7 @@ describe("Graduation", () => {
         .rpc();
       
       // Mint certificate
-      const [certificateMintPda] = await findPda(
-        [
-          Buffer.from("certificate_mint"),
-          env.student1.publicKey.toBuffer(),
-          Buffer.from(course.id)
-        ],
-        env.program.programId
-      );
+      const certificateMintPda = getCertificateMintPda(env.program.programId, enrollmentPda);
       
       const studentCertificateAccount = getAssociatedTokenAddressSync(
         certificateMintPda,
@@ -139,9 +159,10 @@ describe("Graduation", () => {
       );
       
       await env.program.methods
-        .mintCertificate(course.id, METADATA_URI)
+        .mintCertificate(course.id, TERM_FIXTURES.FALL.id, METADATA_URI, METADATA_HASH)
         .accounts({
           instructor: env.instructor.publicKey,
+          institution: env.institutionPda,
           student: env.student1.publicKey,
           course: coursePda,
           enrollment: enrollmentPda,
@@ -164,6 +185,7 @@ describe("Graduation", () => {
       const [graduationMintPda] = await findPda(
         [
           Buffer.from("graduation_mint"),
+          env.institutionPda.toBuffer(),
           env.student1.publicKey.toBuffer()
         ],
         env.program.programId
@@ -177,7 +199,7 @@ describe("Graduation", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -187,6 +209,7 @@ describe("Graduation", () => {
         .claimGraduation(requiredCourseIds)
         .accounts({
           student: env.student1.publicKey,
+          institution: env.institutionPda,
           graduationMint: graduationMintPda,
           studentGraduationAccount: studentGraduationAccount,
           studentProfile: studentProfilePda,
@@ -197,13 +220,10 @@ describe("Graduation", () => {
         .remainingAccounts(
           // Pass enrollment PDAs as remaining accounts
           requiredCourseIds.map(courseId => {
-            const [enrollmentPda] = PublicKey.findProgramAddressSync(
-              [
-                Buffer.from("enrollment"),
-                env.student1.publicKey.toBuffer(),
-                Buffer.from(courseId)
-              ],
-              env.program.programId
+            const enrollmentPda = getEnrollmentPda(
+              env.program.programId,
+              env.student1.publicKey,
+              getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
             );
             return {
               pubkey: enrollmentPda,
@@ -236,7 +256,7 @@ describe("Graduation", () => {
       const newStudent = anchor.web3.Keypair.generate();
       await env.airdrop(newStudent.publicKey, 2);
       
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const newStudentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         newStudent.publicKey,
@@ -245,16 +265,18 @@ describe("Graduation", () => {
       );
       
       const [newStudentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), newStudent.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), newStudent.publicKey.toBuffer()],
         env.program.programId
       );
       
       // Purchase credits
+      await createStudentProfile(env.program, env.institutionPda, newStudent);
+
       await env.program.methods
         .purchaseCredits(new anchor.BN(50))
         .accounts({
           student: newStudent.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           creditMint: config.creditMint,
           studentCreditAccount: newStudentCreditAccount,
@@ -270,24 +292,21 @@ describe("Graduation", () => {
       for (let i = 0; i < 3; i++) {
         const course = requiredCourses[i];
         const [coursePda] = await findPda(
-          [Buffer.from("course"), Buffer.from(course.id)],
+          [Buffer.from("course"), env.institutionPda.toBuffer(), Buffer.from(course.id)],
           env.program.programId
         );
         
-        const [enrollmentPda] = await findPda(
-          [
-            Buffer.from("enrollment"),
-            newStudent.publicKey.toBuffer(),
-            Buffer.from(course.id)
-          ],
-          env.program.programId
+        const enrollmentPda = getEnrollmentPda(
+          env.program.programId,
+          newStudent.publicKey,
+          getOfferingPda(env.program.programId, env.institutionPda, course.id, TERM_FIXTURES.FALL.id)
         );
         
         await env.program.methods
-          .registerCourse(course.id)
+          .registerCourse(course.id, TERM_FIXTURES.FALL.id)
           .accounts({
             student: newStudent.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             course: coursePda,
             enrollment: enrollmentPda,
             studentCreditAccount: newStudentCreditAccount,
@@ -300,9 +319,10 @@ describe("Graduation", () => {
           .rpc();
         
         await env.program.methods
-          .completeCourse(course.id, 75)
+          .completeCourse(course.id, TERM_FIXTURES.FALL.id, 75)
           .accounts({
             instructor: env.instructor.publicKey,
+            institution: env.institutionPda,
             course: coursePda,
             enrollment: enrollmentPda,
           })
@@ -311,7 +331,7 @@ describe("Graduation", () => {
       }
       
       const [graduationMintPda] = await findPda(
-        [Buffer.from("graduation_mint"), newStudent.publicKey.toBuffer()],
+        [Buffer.from("graduation_mint"), env.institutionPda.toBuffer(), newStudent.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -329,6 +349,7 @@ describe("Graduation", () => {
           .claimGraduation(requiredCourseIds)
           .accounts({
             student: newStudent.publicKey,
+            institution: env.institutionPda,
             graduationMint: graduationMintPda,
             studentGraduationAccount: studentGraduationAccount,
             studentProfile: newStudentProfilePda,
@@ -338,13 +359,10 @@ describe("Graduation", () => {
           })
           .remainingAccounts(
             requiredCourseIds.map(courseId => {
-              const [enrollmentPda] = PublicKey.findProgramAddressSync(
-                [
-                  Buffer.from("enrollment"),
-                  newStudent.publicKey.toBuffer(),
-                  Buffer.from(courseId)
-                ],
-                env.program.programId
+              const enrollmentPda = getEnrollmentPda(
+                env.program.programId,
+                newStudent.publicKey,
+                getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
               );
               return {
                 pubkey: enrollmentPda,
@@ -364,7 +382,7 @@ describe("Graduation", () => {
     
     it("Fails when already graduated", async () => {
       const [graduationMintPda] = await findPda(
-        [Buffer.from("graduation_mint"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("graduation_mint"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -376,7 +394,7 @@ describe("Graduation", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -387,6 +405,7 @@ describe("Graduation", () => {
           .claimGraduation(requiredCourseIds)
           .accounts({
             student: env.student1.publicKey,
+            institution: env.institutionPda,
             graduationMint: graduationMintPda,
             studentGraduationAccount: studentGraduationAccount,
             studentProfile: studentProfilePda,
@@ -396,13 +415,10 @@ describe("Graduation", () => {
           })
           .remainingAccounts(
             requiredCourseIds.map(courseId => {
-              const [enrollmentPda] = PublicKey.findProgramAddressSync(
-                [
-                  Buffer.from("enrollment"),
-                  env.student1.publicKey.toBuffer(),
-                  Buffer.from(courseId)
-                ],
-                env.program.programId
+              const enrollmentPda = getEnrollmentPda(
+                env.program.programId,
+                env.student1.publicKey,
+                getOfferingPda(env.program.programId, env.institutionPda, courseId, TERM_FIXTURES.FALL.id)
               );
               return {
                 pubkey: enrollmentPda,
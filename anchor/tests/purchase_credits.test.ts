@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { expect } from "chai";
 import { beforeAll, describe, it } from "vitest";
 import { createTestEnvironment, TestEnvironment } from "./setup";
-import { findPda, getTokenBalance } from "./utils/helpers";
+import { findPda, getTokenBalance, createStudentProfile } from "./utils/helpers";
 import { CREDIT_PRICE } from "./utils/fixtures";
 import { 
   getAssociatedTokenAddressSync,
@@ -19,10 +19,10 @@ describe("Purchase Credits", () => {
     
     // Initialize program
     await env.program.methods
-      .initialize()
+      .createInstitution(env.institutionId, "Test University")
       .accounts({
         authority: env.authority.publicKey,
-        config: env.configPda,
+        institution: env.institutionPda,
         treasury: env.treasury.publicKey,
         systemProgram: SystemProgram.programId,
       })
@@ -33,7 +33,7 @@ describe("Purchase Credits", () => {
   describe("Successful Purchase", () => {
     it("Student purchases 10 credits", async () => {
       const amount = 10;
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
@@ -44,7 +44,7 @@ describe("Purchase Credits", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -55,7 +55,7 @@ describe("Purchase Credits", () => {
         .purchaseCredits(new anchor.BN(amount))
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           creditMint: config.creditMint,
           studentCreditAccount: studentCreditAccount,
@@ -91,7 +91,7 @@ describe("Purchase Credits", () => {
     
     it("Student purchases 25 credits (bulk)", async () => {
       const amount = 25;
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
@@ -101,17 +101,19 @@ describe("Purchase Credits", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
       const balanceBefore = await getTokenBalance(env.connection, studentCreditAccount);
       
+      await createStudentProfile(env.program, env.institutionPda, env.student1);
+
       await env.program.methods
         .purchaseCredits(new anchor.BN(amount))
         .accounts({
           student: env.student1.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           creditMint: config.creditMint,
           studentCreditAccount: studentCreditAccount,
@@ -140,7 +142,7 @@ describe("Purchase Credits", () => {
       // Airdrop very small amount (not enough for purchase)
       await env.airdrop(poorStudent.publicKey, 0.01);
       
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         poorStudent.publicKey,
@@ -149,16 +151,18 @@ describe("Purchase Credits", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), poorStudent.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), poorStudent.publicKey.toBuffer()],
         env.program.programId
       );
       
       try {
+        await createStudentProfile(env.program, env.institutionPda, poorStudent);
+
         await env.program.methods
           .purchaseCredits(new anchor.BN(100))
           .accounts({
             student: poorStudent.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             treasury: env.treasury.publicKey,
             creditMint: config.creditMint,
             studentCreditAccount: studentCreditAccount,
@@ -177,7 +181,7 @@ describe("Purchase Credits", () => {
     });
     
     it("Fails with zero amount", async () => {
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       const studentCreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
         env.student1.publicKey,
@@ -186,7 +190,7 @@ describe("Purchase Credits", () => {
       );
       
       const [studentProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student1.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
         env.program.programId
       );
       
@@ -195,7 +199,7 @@ describe("Purchase Credits", () => {
           .purchaseCredits(new anchor.BN(0))
           .accounts({
             student: env.student1.publicKey,
-            config: env.configPda,
+            institution: env.institutionPda,
             treasury: env.treasury.publicKey,
             creditMint: config.creditMint,
             studentCreditAccount: studentCreditAccount,
@@ -217,7 +221,7 @@ describe("Purchase Credits", () => {
   describe("Multiple Students", () => {
     it("Student 2 purchases credits independently", async () => {
       const amount = 20;
-      const config = await env.program.account.programConfig.fetch(env.configPda);
+      const config = await env.program.account.institution.fetch(env.institutionPda);
       
       const student2CreditAccount = getAssociatedTokenAddressSync(
         config.creditMint,
@@ -227,15 +231,17 @@ describe("Purchase Credits", () => {
       );
       
       const [student2ProfilePda] = await findPda(
-        [Buffer.from("student_profile"), env.student2.publicKey.toBuffer()],
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student2.publicKey.toBuffer()],
         env.program.programId
       );
       
+      await createStudentProfile(env.program, env.institutionPda, env.student2);
+
       await env.program.methods
         .purchaseCredits(new anchor.BN(amount))
         .accounts({
           student: env.student2.publicKey,
-          config: env.configPda,
+          institution: env.institutionPda,
           treasury: env.treasury.publicKey,
           creditMint: config.creditMint,
           studentCreditAccount: student2CreditAccount,
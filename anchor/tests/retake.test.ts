@@ -0,0 +1,240 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  findPda,
+  createCourse,
+  createTerm,
+  createOffering,
+  getEnrollmentPda,
+  getOfferingPda,
+  getTokenBalance,
+  createStudentProfile,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Course Retakes", () => {
+  let env: TestEnvironment;
+  let studentProfilePda: anchor.web3.PublicKey;
+  let studentCreditAccount: anchor.web3.PublicKey;
+  const course = COURSE_FIXTURES.SOL101;
+  const passedCourse = COURSE_FIXTURES.WEB301;
+  const term = TERM_FIXTURES.FALL;
+
+  const enrollmentPda = (courseId: string) =>
+    getEnrollmentPda(
+      env.program.programId,
+      env.student1.publicKey,
+      getOfferingPda(env.program.programId, env.institutionPda, courseId, term.id)
+    );
+
+  const registerCourse = (courseId: string, termId = term.id) =>
+    env.program.methods
+      .registerCourse(courseId, termId)
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([env.student1])
+      .rpc();
+
+  const completeCourse = (courseId: string, grade: number) =>
+    env.program.methods
+      .completeCourse(courseId, term.id, grade)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        student: env.student1.publicKey,
+      })
+      .signers([env.instructor])
+      .rpc();
+
+  const retakeCourse = (courseId: string) =>
+    env.program.methods
+      .retakeCourse(courseId, term.id)
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([env.student1])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    await createTerm(env.program, env.institutionPda, env.authority, term);
+
+    // Two attempts allowed: the first try and one retake
+    for (const fixture of [course, passedCourse]) {
+      await createCourse(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        fixture.id,
+        fixture.name,
+        env.instructor.publicKey,
+        fixture.requiredCredits,
+        fixture.creditHours,
+        2
+      );
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        fixture.id,
+        term.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
+    }
+
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    studentCreditAccount = getAssociatedTokenAddressSync(
+      config.creditMint,
+      env.student1.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    [studentProfilePda] = await findPda(
+      [Buffer.from("student_profile"), env.institutionPda.toBuffer(), env.student1.publicKey.toBuffer()],
+      env.program.programId
+    );
+
+    await createStudentProfile(env.program, env.institutionPda, env.student1);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(30))
+      .accounts({
+        student: env.student1.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: studentCreditAccount,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.student1])
+      .rpc();
+
+    await registerCourse(course.id);
+    await registerCourse(passedCourse.id);
+  });
+
+  describe("Successful Retake", () => {
+    it("Failed course is retaken on the same enrollment", async () => {
+      await completeCourse(course.id, 30);
+
+      const balanceBefore = await getTokenBalance(env.connection, studentCreditAccount);
+      await retakeCourse(course.id);
+      const balanceAfter = await getTokenBalance(env.connection, studentCreditAccount);
+
+      expect(balanceBefore - balanceAfter).to.equal(course.requiredCredits);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        enrollmentPda(course.id)
+      );
+      expect(enrollment.status).to.deep.equal({ enrolled: {} });
+      expect(enrollment.creditsPaid.toNumber()).to.equal(course.requiredCredits * 2);
+      expect(enrollment.attempts.length).to.equal(1);
+      expect(enrollment.attempts[0].attempt).to.equal(1);
+      expect(enrollment.attempts[0].grade).to.equal(30);
+      expect(enrollment.attempts[0].letterGrade).to.equal("F");
+
+      // The failed grade no longer counts towards the GPA
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.coursesCompleted).to.equal(0);
+      expect(profile.creditHoursAttempted).to.equal(0);
+
+      console.log("✅ Retake registered, attempts on record:", enrollment.attempts.length);
+    });
+
+    it("Second attempt is graded normally", async () => {
+      await completeCourse(course.id, 45);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(
+        enrollmentPda(course.id)
+      );
+      expect(enrollment.status).to.deep.equal({ completed: {} });
+      expect(enrollment.grade).to.equal(45);
+      expect(enrollment.attempts.length).to.equal(1);
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Fails when the attempt limit is reached", async () => {
+      try {
+        await retakeCourse(course.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Maximum attempts for this course reached");
+      }
+    });
+
+    it("Fails to register in the next term once the attempts are used up", async () => {
+      const nextTerm = TERM_FIXTURES.SPRING;
+      await createTerm(env.program, env.institutionPda, env.authority, nextTerm);
+      await createOffering(
+        env.program,
+        env.institutionPda,
+        env.authority,
+        course.id,
+        nextTerm.id,
+        env.instructor.publicKey,
+        OFFERING_CAPACITY
+      );
+
+      try {
+        await registerCourse(course.id, nextTerm.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Maximum attempts for this course reached");
+      }
+
+      // Both 2025FA attempts are on the per-course counter
+      const [coursePda] = await findPda(
+        [Buffer.from("course"), env.institutionPda.toBuffer(), Buffer.from(course.id)],
+        env.program.programId
+      );
+      const [attemptsPda] = await findPda(
+        [Buffer.from("course_attempts"), env.student1.publicKey.toBuffer(), coursePda.toBuffer()],
+        env.program.programId
+      );
+      const attempts = await env.program.account.courseAttempts.fetch(attemptsPda);
+      expect(attempts.attempts).to.equal(2);
+    });
+
+    it("Fails to retake a passed course", async () => {
+      await completeCourse(passedCourse.id, 80);
+
+      try {
+        await retakeCourse(passedCourse.id);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Only a failed course can be retaken");
+      }
+    });
+  });
+});
@@ -0,0 +1,178 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import { getRoleAssignmentPda } from "./utils/helpers";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+// Discriminants of the `Role` enum, used in the assignment seeds
+const ROLE = { superAdmin: 0, registrar: 1, financeAdmin: 2, courseManager: 3, reviewer: 4 };
+
+describe("Roles", () => {
+  let env: TestEnvironment;
+  const superAdmin = Keypair.generate();
+  const financeAdmin = Keypair.generate();
+  const courseManager = Keypair.generate();
+
+  const assignment = (holder: PublicKey, role: number) =>
+    getRoleAssignmentPda(env.program.programId, env.institutionPda, holder, role);
+
+  const grant = (signer: Keypair, holder: PublicKey, role: object, authorityRole: PublicKey | null = null) =>
+    env.program.methods
+      .grantRole(holder, role as any)
+      .accounts({
+        authority: signer.publicKey,
+        institution: env.institutionPda,
+        authorityRole,
+      })
+      .signers([signer])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+    await env.airdrop(superAdmin.publicKey, 2);
+    await env.airdrop(courseManager.publicKey, 2);
+
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+  });
+
+  describe("Grant Role", () => {
+    it("Authority grants a super admin", async () => {
+      await grant(env.authority, superAdmin.publicKey, { superAdmin: {} });
+
+      const role = await env.program.account.roleAssignment.fetch(assignment(superAdmin.publicKey, ROLE.superAdmin));
+      expect(role.holder.toString()).to.equal(superAdmin.publicKey.toString());
+      expect(role.role).to.deep.equal({ superAdmin: {} });
+      expect(role.grantedBy.toString()).to.equal(env.authority.publicKey.toString());
+
+      console.log("✅ Super admin granted");
+    });
+
+    it("Super admin grants the other roles", async () => {
+      const superAdminRole = assignment(superAdmin.publicKey, ROLE.superAdmin);
+      await grant(superAdmin, financeAdmin.publicKey, { financeAdmin: {} }, superAdminRole);
+      await grant(superAdmin, courseManager.publicKey, { courseManager: {} }, superAdminRole);
+
+      const roles = await env.program.account.roleAssignment.all([
+        { memcmp: { offset: 8, bytes: env.institutionPda.toBase58() } },
+      ]);
+      expect(roles).to.have.length(3);
+    });
+
+    it("Fails when a super admin grants super admin", async () => {
+      try {
+        await grant(
+          superAdmin,
+          Keypair.generate().publicKey,
+          { superAdmin: {} },
+          assignment(superAdmin.publicKey, ROLE.superAdmin)
+        );
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Role Checks", () => {
+    it("Finance admin updates the credit price", async () => {
+      await env.program.methods
+        .updateConfig(null, null, new anchor.BN(3_000_000))
+        .accounts({
+          authority: financeAdmin.publicKey,
+          institution: env.institutionPda,
+          authorityRole: assignment(financeAdmin.publicKey, ROLE.financeAdmin),
+        })
+        .signers([financeAdmin])
+        .rpc();
+
+      const config = await env.program.account.institution.fetch(env.institutionPda);
+      expect(config.creditPrice.toNumber()).to.equal(3_000_000);
+    });
+
+    it("Fails when a finance admin transfers the authority", async () => {
+      try {
+        await env.program.methods
+          .updateConfig(financeAdmin.publicKey, null, null)
+          .accounts({
+            authority: financeAdmin.publicKey,
+            institution: env.institutionPda,
+            authorityRole: assignment(financeAdmin.publicKey, ROLE.financeAdmin),
+          })
+          .signers([financeAdmin])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+
+    it("Fails when the assignment is for another role", async () => {
+      try {
+        await env.program.methods
+          .updateConfig(null, null, new anchor.BN(1))
+          .accounts({
+            authority: courseManager.publicKey,
+            institution: env.institutionPda,
+            authorityRole: assignment(courseManager.publicKey, ROLE.courseManager),
+          })
+          .signers([courseManager])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+
+    it("Course manager creates a course", async () => {
+      await env.program.methods
+        .createCourse("ROLE101", "Delegated Course", env.instructor.publicKey, new anchor.BN(5), 3, 2)
+        .accounts({
+          authority: courseManager.publicKey,
+          institution: env.institutionPda,
+          authorityRole: assignment(courseManager.publicKey, ROLE.courseManager),
+        })
+        .signers([courseManager])
+        .rpc();
+    });
+  });
+
+  describe("Revoke Role", () => {
+    it("Super admin revokes a role and the holder loses access", async () => {
+      const managerRole = assignment(courseManager.publicKey, ROLE.courseManager);
+      await env.program.methods
+        .revokeRole(courseManager.publicKey, { courseManager: {} } as any)
+        .accounts({
+          authority: superAdmin.publicKey,
+          institution: env.institutionPda,
+          authorityRole: assignment(superAdmin.publicKey, ROLE.superAdmin),
+        })
+        .signers([superAdmin])
+        .rpc();
+
+      expect(await env.connection.getAccountInfo(managerRole)).to.be.null;
+
+      try {
+        await env.program.methods
+          .createCourse("ROLE102", "Revoked Course", env.instructor.publicKey, new anchor.BN(5), 3, 2)
+          .accounts({ authority: courseManager.publicKey, institution: env.institutionPda })
+          .signers([courseManager])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+
+      console.log("✅ Role revoked");
+    });
+  });
+});
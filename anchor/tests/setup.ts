@@ -28,8 +28,9 @@ export class TestEnvironment {
   
   // Program accounts
   creditMint: PublicKey;
-  configPda: PublicKey;
-  configBump: number;
+  institutionId: string;
+  institutionPda: PublicKey;
+  institutionBump: number;
   
   constructor() {
     this.provider = anchor.AnchorProvider.env();
@@ -54,9 +55,10 @@ export class TestEnvironment {
     await this.airdrop(this.student2.publicKey, 5);
     await this.airdrop(this.instructor.publicKey, 5);
     
-    // Find PDA for config
-    [this.configPda, this.configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from("config")],
+    // Each environment registers its own institution, so test files don't share a catalog
+    this.institutionId = `TEST-${Keypair.generate().publicKey.toBase58().slice(0, 8)}`;
+    [this.institutionPda, this.institutionBump] = PublicKey.findProgramAddressSync(
+      [Buffer.from("institution"), Buffer.from(this.institutionId)],
       this.program.programId
     );
     
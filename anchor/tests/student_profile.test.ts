@@ -0,0 +1,119 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import { findPda, createStudentProfile } from "./utils/helpers";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { SystemProgram } from "@solana/web3.js";
+
+describe("Student Profile", () => {
+  let env: TestEnvironment;
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+  });
+
+  describe("Onboarding", () => {
+    it("Student creates a profile with a display name and metadata", async () => {
+      const studentProfilePda = await createStudentProfile(
+        env.program,
+        env.institutionPda,
+        env.student1,
+        "Alice",
+        "https://example.com/students/alice.json"
+      );
+
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.student.toString()).to.equal(env.student1.publicKey.toString());
+      expect(profile.displayName).to.equal("Alice");
+      expect(profile.metadataUri).to.equal("https://example.com/students/alice.json");
+      expect(profile.totalCreditsPurchased.toNumber()).to.equal(0);
+
+      console.log("✅ Student profile created");
+    });
+
+    it("Display name and metadata are optional", async () => {
+      const studentProfilePda = await createStudentProfile(env.program, env.institutionPda, env.student2);
+
+      const profile = await env.program.account.studentProfile.fetch(studentProfilePda);
+      expect(profile.displayName).to.be.null;
+      expect(profile.metadataUri).to.be.null;
+    });
+  });
+
+  describe("Error Cases", () => {
+    it("Fails to create the same profile twice", async () => {
+      try {
+        await createStudentProfile(env.program, env.institutionPda, env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error).to.exist;
+      }
+    });
+
+    it("Fails with a display name that is too long", async () => {
+      const student = anchor.web3.Keypair.generate();
+      await env.airdrop(student.publicKey, 1);
+
+      try {
+        await createStudentProfile(env.program, env.institutionPda, student, "x".repeat(65));
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid display name");
+      }
+    });
+
+    it("Fails to purchase credits without a profile", async () => {
+      const student = anchor.web3.Keypair.generate();
+      await env.airdrop(student.publicKey, 1);
+
+      const config = await env.program.account.institution.fetch(env.institutionPda);
+      const [studentProfilePda] = await findPda(
+        [Buffer.from("student_profile"), env.institutionPda.toBuffer(), student.publicKey.toBuffer()],
+        env.program.programId
+      );
+
+      try {
+        await env.program.methods
+          .purchaseCredits(new anchor.BN(1))
+          .accounts({
+            student: student.publicKey,
+            institution: env.institutionPda,
+            treasury: env.treasury.publicKey,
+            creditMint: config.creditMint,
+            studentCreditAccount: getAssociatedTokenAddressSync(
+              config.creditMint,
+              student.publicKey,
+              false,
+              TOKEN_2022_PROGRAM_ID
+            ),
+            studentProfile: studentProfilePda,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([student])
+          .rpc();
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error).to.exist;
+      }
+    });
+  });
+});
@@ -0,0 +1,137 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import { createStudentProfile, getCpiEvents, getTranscriptAnchorPda } from "./utils/helpers";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { createHash } from "crypto";
+
+describe("Transcript Anchors", () => {
+  let env: TestEnvironment;
+  let studentProfilePda: anchor.web3.PublicKey;
+  const merkleRoot = Array(32).fill(7);
+
+  const transcriptAnchorPda = (root: number[]) =>
+    getTranscriptAnchorPda(env.program.programId, env.institutionPda, env.student1.publicKey, root);
+
+  const anchorTranscript = (root: number[], entryCount: number, signer = env.authority) =>
+    env.program.methods
+      .anchorTranscript(root, entryCount)
+      .accountsPartial({
+        authority: signer.publicKey,
+        institution: env.institutionPda,
+        studentProfile: studentProfilePda,
+        transcriptAnchor: transcriptAnchorPda(root),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+
+    // Initialize program
+    await env.program.methods
+      .createInstitution(env.institutionId, "Test University")
+      .accounts({
+        authority: env.authority.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([env.authority])
+      .rpc();
+
+    studentProfilePda = await createStudentProfile(env.program, env.institutionPda, env.student1);
+  });
+
+  describe("Error Cases", () => {
+    it("Fails when the signer is not the authority", async () => {
+      try {
+        await anchorTranscript(merkleRoot, 3, env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+
+    it("Fails with an empty root", async () => {
+      try {
+        await anchorTranscript(Array(32).fill(0), 3);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Invalid transcript Merkle root or entry count");
+      }
+    });
+  });
+
+  describe("Anchoring", () => {
+    it("Stores the root and emits TranscriptAnchored", async () => {
+      const signature = await anchorTranscript(merkleRoot, 3);
+
+      const transcript = await env.program.account.transcriptAnchor.fetch(transcriptAnchorPda(merkleRoot));
+      expect(transcript.institution.toString()).to.equal(env.institutionPda.toString());
+      expect(transcript.student.toString()).to.equal(env.student1.publicKey.toString());
+      expect(transcript.issuer.toString()).to.equal(env.authority.publicKey.toString());
+      expect(transcript.merkleRoot).to.deep.equal(merkleRoot);
+      expect(transcript.entryCount).to.equal(3);
+
+      const events = await getCpiEvents(env.program, signature);
+      expect(events.map((event) => event.name)).to.include("transcriptAnchored");
+
+      console.log("✅ Transcript anchored at", transcriptAnchorPda(merkleRoot).toString());
+    });
+
+    it("Cannot anchor the same root twice", async () => {
+      try {
+        await anchorTranscript(merkleRoot, 3);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.toString()).to.include("already in use");
+      }
+    });
+  });
+
+  describe("Entry Proofs", () => {
+    const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+    const entry = (courseId: string, grade: number) => ({
+      enrollment: Keypair.generate().publicKey,
+      courseId,
+      termId: "2025FA",
+      status: { completed: {} },
+      grade,
+      letterGrade: grade >= 90 ? "A" : "B",
+      creditHours: 3,
+      completionDate: new anchor.BN(1_760_000_000),
+    });
+    // Leaves are sha256(0x00 || borsh(entry)); parents hash the sorted pair after 0x01
+    const leaf = (value: ReturnType<typeof entry>) =>
+      sha256(Buffer.from([0]), env.program.coder.types.encode("transcriptEntry", value));
+    const parent = (a: Buffer, b: Buffer) =>
+      sha256(Buffer.from([1]), ...(Buffer.compare(a, b) <= 0 ? [a, b] : [b, a]));
+
+    const entries = [entry("SOL101", 92), entry("WEB301", 81)];
+    let root: number[];
+
+    const verifyEntry = (value: ReturnType<typeof entry>, proof: Buffer[]) =>
+      env.program.methods
+        .verifyTranscriptEntry(value, proof.map((node) => Array.from(node)))
+        .accounts({ transcriptAnchor: transcriptAnchorPda(root) })
+        .view();
+
+    beforeAll(async () => {
+      root = Array.from(parent(leaf(entries[0]), leaf(entries[1])));
+      await anchorTranscript(root, entries.length);
+    });
+
+    it("Accepts a proof for each anchored entry", async () => {
+      expect(await verifyEntry(entries[0], [leaf(entries[1])])).to.equal(true);
+      expect(await verifyEntry(entries[1], [leaf(entries[0])])).to.equal(true);
+    });
+
+    it("Rejects an entry with an edited grade", async () => {
+      const raised = { ...entries[1], grade: 99, letterGrade: "A" };
+      expect(await verifyEntry(raised, [leaf(entries[0])])).to.equal(false);
+    });
+  });
+});
@@ -0,0 +1,289 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { beforeAll, describe, it } from "vitest";
+import { createTestEnvironment, TestEnvironment } from "./setup";
+import {
+  createCourse,
+  createOffering,
+  createStudentProfile,
+  createTerm,
+  getCourseEquivalencyPda,
+  getCpiEvents,
+  getEnrollmentPda,
+  getOfferingPda,
+  sleep,
+} from "./utils/helpers";
+import { COURSE_FIXTURES, TERM_FIXTURES, OFFERING_CAPACITY } from "./utils/fixtures";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+
+const SOURCE_COURSE = COURSE_FIXTURES.SOL101;
+const COURSE = COURSE_FIXTURES.SOL102;
+const OTHER_COURSE = COURSE_FIXTURES.WEB301;
+const TERM = TERM_FIXTURES.FALL;
+
+describe("Course Equivalencies and Transfers", () => {
+  let env: TestEnvironment;
+  let sourceCoursePda: PublicKey;
+  let coursePda: PublicKey;
+  let offeringPda: PublicKey;
+  let equivalencyPda: PublicKey;
+  let otherCoursePda: PublicKey;
+  let otherEquivalencyPda: PublicKey;
+
+  // The receiving institution, run by its own authority
+  const partnerAuthority = Keypair.generate();
+  const partnerTreasury = Keypair.generate();
+  const partnerId = `PART-${Keypair.generate().publicKey.toBase58().slice(0, 8)}`;
+  let partnerPda: PublicKey;
+
+  const createInstitution = (authority: Keypair, institutionId: string, institution: PublicKey, treasury: PublicKey) =>
+    env.program.methods
+      .createInstitution(institutionId, "Test University")
+      .accounts({
+        authority: authority.publicKey,
+        institution,
+        treasury,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+  const createEquivalency = (sourceAuthority: Keypair, course = COURSE, receiving = coursePda, equivalency = equivalencyPda) =>
+    env.program.methods
+      .createCourseEquivalency(SOURCE_COURSE.id, course.id)
+      .accountsPartial({
+        authority: partnerAuthority.publicKey,
+        institution: partnerPda,
+        course: receiving,
+        sourceAuthority: sourceAuthority.publicKey,
+        sourceInstitution: env.institutionPda,
+        sourceCourse: sourceCoursePda,
+        courseEquivalency: equivalency,
+      })
+      .signers([partnerAuthority, sourceAuthority])
+      .rpc();
+
+  // Transferred enrollments are keyed by the receiving institution and the source enrollment
+  const transferredEnrollmentPda = (student: Keypair) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("enrollment"),
+        partnerPda.toBuffer(),
+        getEnrollmentPda(env.program.programId, student.publicKey, offeringPda).toBuffer(),
+      ],
+      env.program.programId
+    )[0];
+
+  const transfer = (student: Keypair, course = COURSE, receiving = coursePda, equivalency = equivalencyPda) =>
+    env.program.methods
+      .transferCompletion(SOURCE_COURSE.id, TERM.id, course.id)
+      .accountsPartial({
+        student: student.publicKey,
+        institution: partnerPda,
+        course: receiving,
+        sourceInstitution: env.institutionPda,
+        sourceCourse: sourceCoursePda,
+        sourceOffering: offeringPda,
+        sourceEnrollment: getEnrollmentPda(env.program.programId, student.publicKey, offeringPda),
+        courseEquivalency: equivalency,
+        enrollment: transferredEnrollmentPda(student),
+      })
+      .signers([student])
+      .rpc();
+
+  const enrollAndGrade = async (student: Keypair, grade: number) => {
+    const config = await env.program.account.institution.fetch(env.institutionPda);
+    const studentCreditAccount = getAssociatedTokenAddressSync(
+      config.creditMint,
+      student.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const studentProfilePda = await createStudentProfile(env.program, env.institutionPda, student);
+    const enrollmentPda = getEnrollmentPda(env.program.programId, student.publicKey, offeringPda);
+
+    await env.program.methods
+      .purchaseCredits(new anchor.BN(SOURCE_COURSE.requiredCredits))
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        treasury: env.treasury.publicKey,
+        creditMint: config.creditMint,
+        studentCreditAccount: studentCreditAccount,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .registerCourse(SOURCE_COURSE.id, TERM.id)
+      .accounts({
+        student: student.publicKey,
+        institution: env.institutionPda,
+        course: sourceCoursePda,
+        enrollment: enrollmentPda,
+        studentCreditAccount: studentCreditAccount,
+        creditMint: config.creditMint,
+        studentProfile: studentProfilePda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([student])
+      .rpc();
+
+    await env.program.methods
+      .completeCourse(SOURCE_COURSE.id, TERM.id, grade)
+      .accounts({
+        instructor: env.instructor.publicKey,
+        institution: env.institutionPda,
+        course: sourceCoursePda,
+        enrollment: enrollmentPda,
+      })
+      .signers([env.instructor])
+      .rpc();
+
+    // The student also needs a profile at the receiving institution
+    await createStudentProfile(env.program, partnerPda, student);
+  };
+
+  beforeAll(async () => {
+    env = await createTestEnvironment();
+    await env.airdrop(partnerAuthority.publicKey, 10);
+
+    partnerPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("institution"), Buffer.from(partnerId)],
+      env.program.programId
+    )[0];
+
+    await createInstitution(env.authority, env.institutionId, env.institutionPda, env.treasury.publicKey);
+    await createInstitution(partnerAuthority, partnerId, partnerPda, partnerTreasury.publicKey);
+
+    sourceCoursePda = await createCourse(env.program, env.institutionPda, env.authority, SOURCE_COURSE.id, SOURCE_COURSE.name, env.instructor.publicKey, SOURCE_COURSE.requiredCredits, SOURCE_COURSE.creditHours);
+    coursePda = await createCourse(env.program, partnerPda, partnerAuthority, COURSE.id, COURSE.name, env.instructor.publicKey, COURSE.requiredCredits, COURSE.creditHours);
+    otherCoursePda = await createCourse(env.program, partnerPda, partnerAuthority, OTHER_COURSE.id, OTHER_COURSE.name, env.instructor.publicKey, OTHER_COURSE.requiredCredits, OTHER_COURSE.creditHours);
+    await createTerm(env.program, env.institutionPda, env.authority, TERM);
+
+    // A short grading deadline so the completions can be transferred during the test
+    const now = Math.floor(Date.now() / 1000);
+    await createOffering(env.program, env.institutionPda, env.authority, SOURCE_COURSE.id, TERM.id, env.instructor.publicKey, OFFERING_CAPACITY, {
+      enrollmentOpensAt: now - 60,
+      enrollmentClosesAt: now + 15,
+      gradingDeadline: now + 20,
+    });
+    offeringPda = getOfferingPda(env.program.programId, env.institutionPda, SOURCE_COURSE.id, TERM.id);
+    equivalencyPda = getCourseEquivalencyPda(env.program.programId, sourceCoursePda, coursePda);
+    otherEquivalencyPda = getCourseEquivalencyPda(env.program.programId, sourceCoursePda, otherCoursePda);
+
+    await enrollAndGrade(env.student1, 88);
+    await enrollAndGrade(env.student2, 42);
+  });
+
+  describe("Equivalencies", () => {
+    it("Fails without the source institution's authority", async () => {
+      try {
+        await createEquivalency(env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Unauthorized");
+      }
+    });
+
+    it("Both authorities link the courses", async () => {
+      const signature = await createEquivalency(env.authority);
+
+      const equivalency = await env.program.account.courseEquivalency.fetch(equivalencyPda);
+      expect(equivalency.institution.toString()).to.equal(partnerPda.toString());
+      expect(equivalency.sourceInstitution.toString()).to.equal(env.institutionPda.toString());
+      expect(equivalency.courseId).to.equal(COURSE.id);
+      expect(equivalency.sourceCourseId).to.equal(SOURCE_COURSE.id);
+
+      const events = await getCpiEvents(env.program, signature);
+      expect(events.map((event) => event.name)).to.include("courseEquivalencyCreated");
+
+      await createEquivalency(env.authority, OTHER_COURSE, otherCoursePda, otherEquivalencyPda);
+
+      console.log("✅ Course equivalency created");
+    });
+  });
+
+  describe("Transfers", () => {
+    it("Fails before the source grading deadline", async () => {
+      try {
+        await transfer(env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Grading deadline has not passed yet");
+      }
+    });
+
+    it("Carries a passed completion over to the receiving institution", async () => {
+      await sleep(20000);
+      const signature = await transfer(env.student1);
+
+      const enrollment = await env.program.account.courseEnrollment.fetch(transferredEnrollmentPda(env.student1));
+      const source = await env.program.account.courseEnrollment.fetch(
+        getEnrollmentPda(env.program.programId, env.student1.publicKey, offeringPda)
+      );
+      expect(enrollment.status).to.deep.equal({ completed: {} });
+      expect(enrollment.courseId).to.equal(COURSE.id);
+      expect(enrollment.offering.toString()).to.equal(equivalencyPda.toString());
+      expect(enrollment.grade).to.equal(88);
+      expect(enrollment.creditHours).to.equal(COURSE.creditHours);
+      expect(enrollment.creditsPaid.toNumber()).to.equal(0);
+      expect(enrollment.completionDate!.toNumber()).to.equal(source.completionDate!.toNumber());
+      expect(enrollment.transferredFrom!.toString()).to.equal(
+        getEnrollmentPda(env.program.programId, env.student1.publicKey, offeringPda).toString()
+      );
+
+      const [profilePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("student_profile"), partnerPda.toBuffer(), env.student1.publicKey.toBuffer()],
+        env.program.programId
+      );
+      const profile = await env.program.account.studentProfile.fetch(profilePda);
+      expect(profile.coursesCompleted).to.equal(1);
+      expect(profile.openEnrollments).to.equal(0);
+      expect(profile.creditHoursEarned).to.equal(COURSE.creditHours);
+
+      const events = await getCpiEvents(env.program, signature);
+      const transferred = events.find((event) => event.name === "completionTransferred");
+      expect(transferred?.data.sourceTermId).to.equal(TERM.id);
+
+      console.log("✅ Completion transferred");
+    });
+
+    it("Cannot transfer the same completion twice", async () => {
+      try {
+        await transfer(env.student1);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Cannot transfer the same completion to another equivalent course", async () => {
+      try {
+        await transfer(env.student1, OTHER_COURSE, otherCoursePda, otherEquivalencyPda);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Fails for a failing grade", async () => {
+      try {
+        await transfer(env.student2);
+        expect.fail("Should have thrown error");
+      } catch (error: any) {
+        expect(error.error.errorMessage).to.include("Only a passed course can be transferred");
+      }
+    });
+  });
+});
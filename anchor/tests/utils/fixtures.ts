@@ -1,3 +1,4 @@
+import { createHash } from "crypto";
 import * as anchor from "@coral-xyz/anchor";
 
 export const COURSE_FIXTURES = {
@@ -5,29 +6,58 @@ export const COURSE_FIXTURES = {
     id: "SOL101",
     name: "Solana Blockchain Fundamentals",
     requiredCredits: 5,
+    creditHours: 3,
   },
   SOL102: {
     id: "SOL102",
     name: "Advanced Solana Development",
     requiredCredits: 8,
+    creditHours: 4,
   },
   WEB301: {
     id: "WEB301",
     name: "Web3 Frontend Development",
     requiredCredits: 6,
+    creditHours: 3,
   },
   RUST201: {
     id: "RUST201",
     name: "Rust Programming for Blockchain",
     requiredCredits: 7,
+    creditHours: 4,
   },
   DEFI101: {
     id: "DEFI101",
     name: "DeFi Fundamentals",
     requiredCredits: 5,
+    creditHours: 2,
   },
 };
 
+const DAY = 24 * 60 * 60;
+const now = Math.floor(Date.now() / 1000);
+
+export const TERM_FIXTURES = {
+  FALL: {
+    id: "2025FA",
+    name: "Fall 2025",
+    startsAt: now - 7 * DAY,
+    endsAt: now + 120 * DAY,
+    registrationOpensAt: now - 30 * DAY,
+    registrationClosesAt: now + 14 * DAY,
+  },
+  SPRING: {
+    id: "2026SP",
+    name: "Spring 2026",
+    startsAt: now + 150 * DAY,
+    endsAt: now + 270 * DAY,
+    registrationOpensAt: now - DAY,
+    registrationClosesAt: now + 160 * DAY,
+  },
+};
+
+export const OFFERING_CAPACITY = 50;
+
 export const STUDENT_FIXTURES = {
   student1: {
     name: "Nguyễn Văn An",
@@ -42,3 +72,5 @@ export const STUDENT_FIXTURES = {
 export const CREDIT_PRICE = 0.005 * anchor.web3.LAMPORTS_PER_SOL; // 0.005 SOL per credit
 
 export const METADATA_URI = "https://ipfs.io/ipfs/QmTestMetadata";
+// SHA-256 of the JSON at METADATA_URI, recorded on the enrollment
+export const METADATA_HASH = Array.from(createHash("sha256").update("QmTestMetadata").digest());
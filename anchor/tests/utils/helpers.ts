@@ -28,23 +28,33 @@ export async function getTokenBalance(
 
 export async function createCourse(
   program: Program<AcademicChain>,
+  institution: PublicKey,
   authority: Keypair,
   courseId: string,
   courseName: string,
   instructor: PublicKey,
-  requiredCredits: number
+  requiredCredits: number,
+  creditHours: number = 3,
+  maxAttempts: number = 3
 ) {
   const [coursePda, courseBump] = await findPda(
-    [Buffer.from("course"), Buffer.from(courseId)],
+    [Buffer.from("course"), institution.toBuffer(), Buffer.from(courseId)],
     program.programId
   );
   
   await program.methods
-    .createCourse(courseId, courseName, requiredCredits)
+    .createCourse(
+      courseId,
+      courseName,
+      instructor,
+      new anchor.BN(requiredCredits),
+      creditHours,
+      maxAttempts
+    )
     .accounts({
       authority: authority.publicKey,
+      institution,
       course: coursePda,
-      instructor: instructor,
       systemProgram: anchor.web3.SystemProgram.programId,
     })
     .signers([authority])
@@ -53,6 +63,243 @@ export async function createCourse(
   return coursePda;
 }
 
+export async function createStudentProfile(
+  program: Program<AcademicChain>,
+  institution: PublicKey,
+  student: Keypair,
+  displayName: string | null = null,
+  metadataUri: string | null = null
+) {
+  const [studentProfilePda] = await findPda(
+    [Buffer.from("student_profile"), institution.toBuffer(), student.publicKey.toBuffer()],
+    program.programId
+  );
+
+  await program.methods
+    .createStudentProfile(displayName, metadataUri)
+    .accounts({
+      student: student.publicKey,
+      institution,
+    })
+    .signers([student])
+    .rpc();
+
+  return studentProfilePda;
+}
+
+export function getOfferingPda(
+  programId: PublicKey,
+  institution: PublicKey,
+  courseId: string,
+  termId: string
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("offering"), institution.toBuffer(), Buffer.from(courseId), Buffer.from(termId)],
+    programId
+  )[0];
+}
+
+export function getEnrollmentPda(
+  programId: PublicKey,
+  student: PublicKey,
+  offering: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("enrollment"), student.toBuffer(), offering.toBuffer()],
+    programId
+  )[0];
+}
+
+export function getCertificateMintPda(
+  programId: PublicKey,
+  enrollment: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate_mint"), enrollment.toBuffer()],
+    programId
+  )[0];
+}
+
+export function getTranscriptAnchorPda(
+  programId: PublicKey,
+  institution: PublicKey,
+  student: PublicKey,
+  merkleRoot: number[]
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("transcript"), institution.toBuffer(), student.toBuffer(), Buffer.from(merkleRoot)],
+    programId
+  )[0];
+}
+
+export function getCertificateBatchPda(
+  programId: PublicKey,
+  offering: PublicKey,
+  merkleRoot: number[]
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate_batch"), offering.toBuffer(), Buffer.from(merkleRoot)],
+    programId
+  )[0];
+}
+
+export function getCertificateTreePda(
+  programId: PublicKey,
+  merkleTree: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("certificate_tree"), merkleTree.toBuffer()],
+    programId
+  )[0];
+}
+
+export function getCourseEquivalencyPda(
+  programId: PublicKey,
+  sourceCourse: PublicKey,
+  course: PublicKey
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("equivalency"), sourceCourse.toBuffer(), course.toBuffer()],
+    programId
+  )[0];
+}
+
+export function getRoleAssignmentPda(
+  programId: PublicKey,
+  institution: PublicKey,
+  holder: PublicKey,
+  role: number
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("role"), institution.toBuffer(), holder.toBuffer(), Buffer.from([role])],
+    programId
+  )[0];
+}
+
+export function getConfigGovernancePda(programId: PublicKey, institution: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance"), institution.toBuffer()],
+    programId
+  )[0];
+}
+
+export function getConfigProposalPda(
+  programId: PublicKey,
+  institution: PublicKey,
+  proposalId: number
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("config_proposal"), institution.toBuffer(), new anchor.BN(proposalId).toArrayLike(Buffer, "le", 8)],
+    programId
+  )[0];
+}
+
+export async function createTerm(
+  program: Program<AcademicChain>,
+  institution: PublicKey,
+  authority: Keypair,
+  term: {
+    id: string;
+    name: string;
+    startsAt: number;
+    endsAt: number;
+    registrationOpensAt: number;
+    registrationClosesAt: number;
+  }
+) {
+  const [termPda] = await findPda(
+    [Buffer.from("term"), institution.toBuffer(), Buffer.from(term.id)],
+    program.programId
+  );
+
+  await program.methods
+    .createTerm(
+      term.id,
+      term.name,
+      new anchor.BN(term.startsAt),
+      new anchor.BN(term.endsAt),
+      new anchor.BN(term.registrationOpensAt),
+      new anchor.BN(term.registrationClosesAt)
+    )
+    .accounts({
+      authority: authority.publicKey,
+      institution,
+      term: termPda,
+    })
+    .signers([authority])
+    .rpc();
+
+  return termPda;
+}
+
+export async function createOffering(
+  program: Program<AcademicChain>,
+  institution: PublicKey,
+  authority: Keypair,
+  courseId: string,
+  termId: string,
+  instructor: PublicKey,
+  capacity: number,
+  deadlines: {
+    enrollmentOpensAt?: number;
+    enrollmentClosesAt?: number;
+    gradingDeadline?: number;
+  } = {}
+) {
+  const offering = getOfferingPda(program.programId, institution, courseId, termId);
+  const toBN = (value?: number) => (value === undefined ? null : new anchor.BN(value));
+
+  await program.methods
+    .createOffering(
+      courseId,
+      termId,
+      instructor,
+      capacity,
+      toBN(deadlines.enrollmentOpensAt),
+      toBN(deadlines.enrollmentClosesAt),
+      toBN(deadlines.gradingDeadline)
+    )
+    .accounts({
+      authority: authority.publicKey,
+      institution,
+      offering,
+    })
+    .signers([authority])
+    .rpc();
+
+  return offering;
+}
+
+// Decode the events a transaction emitted through emit_cpi!
+export async function getCpiEvents(
+  program: Program<AcademicChain>,
+  signature: string
+): Promise<anchor.Event[]> {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx?.transaction.message.getAccountKeys();
+
+  const events: anchor.Event[] = [];
+  for (const inner of tx?.meta?.innerInstructions ?? []) {
+    for (const ix of inner.instructions) {
+      if (!accountKeys?.get(ix.programIdIndex)?.equals(program.programId)) {
+        continue;
+      }
+      // Instruction data: 8-byte event tag, then the event discriminator and payload
+      const data = anchor.utils.bytes.bs58.decode(ix.data);
+      const event = program.coder.events.decode(
+        anchor.utils.bytes.base64.encode(Buffer.from(data.subarray(8)))
+      );
+      if (event) {
+        events.push(event);
+      }
+    }
+  }
+  return events;
+}
+
 export async function sleep(ms: number) {
   return new Promise(resolve => setTimeout(resolve, ms));
 }
@@ -0,0 +1,104 @@
+//! Standalone HTML page for people following a printed or shared link.
+
+use chrono::DateTime;
+
+use crate::view::CertificateView;
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn row(label: &str, value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            escape(label),
+            escape(value)
+        ),
+        None => String::new(),
+    }
+}
+
+pub fn render(view: &CertificateView) -> String {
+    let (status_class, headline) = if view.valid {
+        ("valid", "✅ Verified certificate".to_string())
+    } else {
+        (
+            "invalid",
+            format!("❌ Not verified: {}", view.reason.as_deref().unwrap_or("unknown reason")),
+        )
+    };
+
+    let course = match (&view.course_name, &view.course_id) {
+        (Some(name), Some(id)) => Some(format!("{name} ({id})")),
+        (None, Some(id)) => Some(id.clone()),
+        _ => None,
+    };
+    let grade = view.grade.map(|grade| match &view.letter_grade {
+        Some(letter) => format!("{grade} ({letter})"),
+        None => grade.to_string(),
+    });
+    let completed = view
+        .completed_at
+        .as_deref()
+        .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+        .map(|date| date.format("%B %-d, %Y").to_string());
+
+    let rows = [
+        row("Student", view.student.as_deref()),
+        row("Course", course.as_deref()),
+        row("Term", view.term_id.as_deref()),
+        row("Instructor", view.instructor.as_deref()),
+        row("Grade", grade.as_deref()),
+        row("Completed", completed.as_deref()),
+        row("Certificate mint", Some(&view.mint)),
+        row("Metadata SHA-256", view.metadata_hash.as_deref()),
+        row("Issuing program", Some(&view.program_id)),
+    ]
+    .concat();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Certificate verification</title>
+<style>
+body {{ font-family: system-ui, sans-serif; max-width: 42rem; margin: 2rem auto; padding: 0 1rem; color: #1f2937; }}
+h1 {{ font-size: 1.5rem; }}
+.valid {{ color: #047857; }}
+.invalid {{ color: #b91c1c; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ text-align: left; padding: .5rem; border-bottom: 1px solid #e5e7eb; vertical-align: top; }}
+th {{ width: 10rem; color: #6b7280; font-weight: 500; }}
+td {{ word-break: break-all; }}
+footer {{ margin-top: 1.5rem; font-size: .875rem; color: #6b7280; word-break: break-all; }}
+</style>
+</head>
+<body>
+<h1 class="{status_class}">{headline}</h1>
+<table>
+{rows}</table>
+<footer>
+<p>Checked live against Solana: the mint must be the certificate PDA of a completed enrollment with a passing grade.</p>
+<p>Share: <a href="{short_url}">{short_url}</a></p>
+</footer>
+</body>
+</html>
+"#,
+        headline = escape(&headline),
+        short_url = escape(&view.short_url),
+    )
+}
@@ -0,0 +1,121 @@
+//! Public certificate verification over HTTP.
+//!
+//! `GET /verify/{mint}` checks a course certificate live against the program
+//! and answers with JSON, or with an HTML page when the client asks for
+//! `text/html` (or passes `?format=html`). `GET /V/{code}` is the short form
+//! printed as a QR code on diplomas. It redirects to the full URL.
+
+pub mod html;
+pub mod shortlink;
+pub mod view;
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use academic_chain_client::verify::{verify_certificate, VerificationFailure};
+use anchor_lang::prelude::Pubkey;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Deserialize;
+use solana_rpc_client::rpc_client::RpcClient;
+
+use crate::view::CertificateView;
+
+#[derive(Clone)]
+pub struct AppState {
+    rpc: Arc<RpcClient>,
+    /// Base URL links are built from, e.g. `https://verify.example.edu`
+    public_url: Arc<str>,
+}
+
+impl AppState {
+    pub fn new(rpc: RpcClient, public_url: &str) -> Self {
+        Self {
+            rpc: Arc::new(rpc),
+            public_url: public_url.trim_end_matches('/').into(),
+        }
+    }
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/verify/{mint}", get(verify))
+        .route("/V/{code}", get(short))
+        .route("/v/{code}", get(short))
+        .with_state(state)
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Json,
+    Html,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct VerifyQuery {
+    pub format: Option<Format>,
+}
+
+/// `?format=` wins; otherwise browsers asking for HTML get the page
+fn negotiate(query: &VerifyQuery, headers: &HeaderMap) -> Format {
+    query.format.unwrap_or_else(|| {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .unwrap_or_default();
+        if accept.contains("text/html") {
+            Format::Html
+        } else {
+            Format::Json
+        }
+    })
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+async fn verify(
+    State(state): State<AppState>,
+    Path(mint): Path<String>,
+    Query(query): Query<VerifyQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let Ok(mint) = Pubkey::from_str(&mint) else {
+        return error(StatusCode::BAD_REQUEST, format!("invalid mint address `{mint}`"));
+    };
+
+    let rpc = state.rpc.clone();
+    let verification = match tokio::task::spawn_blocking(move || verify_certificate(&rpc, &mint)).await {
+        Ok(Ok(verification)) => verification,
+        Ok(Err(err)) => {
+            log::error!("verifying {mint}: {err}");
+            return error(StatusCode::BAD_GATEWAY, "failed to read program accounts".to_string());
+        }
+        Err(err) => return error(StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    };
+
+    let view = CertificateView::new(&verification, &state.public_url);
+    // Invalid certificates still answer 200 with the reason; unknown mints are 404
+    let status = if verification.failure == Some(VerificationFailure::NotFound) {
+        StatusCode::NOT_FOUND
+    } else {
+        StatusCode::OK
+    };
+
+    match negotiate(&query, &headers) {
+        Format::Json => (status, Json(view)).into_response(),
+        Format::Html => (status, Html(html::render(&view))).into_response(),
+    }
+}
+
+async fn short(State(state): State<AppState>, Path(code): Path<String>) -> Response {
+    match shortlink::decode(&code) {
+        Some(mint) => Redirect::permanent(&shortlink::verify_url(&state.public_url, &mint)).into_response(),
+        None => error(StatusCode::BAD_REQUEST, format!("invalid certificate code `{code}`")),
+    }
+}
@@ -0,0 +1,44 @@
+use std::net::SocketAddr;
+
+use academic_verify::{router, AppState};
+use anyhow::{Context as _, Result};
+use clap::Parser;
+use solana_commitment_config::CommitmentConfig;
+use solana_rpc_client::rpc_client::RpcClient;
+
+#[derive(Parser)]
+#[command(name = "academic-verify", version, about = "Public certificate verification for academic_chain")]
+struct Args {
+    /// RPC endpoint certificates are checked against
+    #[arg(short = 'u', long, default_value = "http://127.0.0.1:8899", env = "ACADEMIC_RPC_URL")]
+    rpc_url: String,
+
+    /// Address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:8081", env = "ACADEMIC_VERIFY_BIND")]
+    bind: SocketAddr,
+
+    /// Public base URL used in shared and short links; defaults to the bind address
+    #[arg(long, env = "ACADEMIC_VERIFY_URL")]
+    public_url: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let args = Args::parse();
+
+    let public_url = args.public_url.unwrap_or_else(|| format!("http://{}", args.bind));
+    // Finalized so a certificate is never shown from a fork that may be dropped
+    let rpc = RpcClient::new_with_commitment(args.rpc_url, CommitmentConfig::finalized());
+    let listener = tokio::net::TcpListener::bind(args.bind)
+        .await
+        .with_context(|| format!("failed to bind {}", args.bind))?;
+    log::info!("listening on http://{} (links use {public_url})", args.bind);
+
+    axum::serve(listener, router(AppState::new(rpc, &public_url)))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Short verification links for printing as QR codes.
+//!
+//! The mint is written in unpadded RFC 4648 base32 and the whole URL is
+//! uppercased. Every character is then in the QR alphanumeric set, which
+//! packs 5.5 bits per character instead of 8 and yields a smaller code than a
+//! base58 link.
+
+use anchor_lang::prelude::Pubkey;
+use data_encoding::BASE32_NOPAD;
+
+/// Path prefix of short links; `/v/` is accepted too
+pub const SHORT_PREFIX: &str = "/V/";
+
+pub fn encode(mint: &Pubkey) -> String {
+    BASE32_NOPAD.encode(mint.as_ref())
+}
+
+/// Decode a short code, case-insensitively
+pub fn decode(code: &str) -> Option<Pubkey> {
+    let bytes = BASE32_NOPAD.decode(code.to_ascii_uppercase().as_bytes()).ok()?;
+    Pubkey::try_from(bytes.as_slice()).ok()
+}
+
+/// `public_url` must be case-insensitive (scheme and host only)
+pub fn short_url(public_url: &str, mint: &Pubkey) -> String {
+    format!("{}{SHORT_PREFIX}{}", public_url.trim_end_matches('/').to_ascii_uppercase(), encode(mint))
+}
+
+pub fn verify_url(public_url: &str, mint: &Pubkey) -> String {
+    format!("{}/verify/{mint}", public_url.trim_end_matches('/'))
+}
@@ -0,0 +1,73 @@
+//! What a verification response shows, shared by the JSON and HTML forms.
+
+use academic_chain::ID;
+use academic_chain_client::metadata::hash_to_hex;
+use academic_chain_client::verify::Verification;
+use chrono::{DateTime, SecondsFormat};
+use serde::Serialize;
+
+use crate::shortlink::{short_url, verify_url};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateView {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub mint: String,
+    pub program_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub student: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrollment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub course_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grade: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub letter_grade: Option<String>,
+    /// RFC 3339, UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    /// Hex SHA-256 the hosted metadata JSON must match
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_hash: Option<String>,
+    pub verify_url: String,
+    pub short_url: String,
+}
+
+impl CertificateView {
+    pub fn new(verification: &Verification, public_url: &str) -> Self {
+        let enrollment = verification.enrollment.as_ref();
+        Self {
+            valid: verification.is_valid(),
+            reason: verification.failure.map(|failure| failure.to_string()),
+            mint: verification.mint.to_string(),
+            program_id: ID.to_string(),
+            student: enrollment.map(|(_, enrollment)| enrollment.student.to_string()),
+            enrollment: enrollment.map(|(address, _)| address.to_string()),
+            course_id: enrollment.map(|(_, enrollment)| enrollment.course_id.clone()),
+            course_name: verification.course.as_ref().map(|course| course.course_name.clone()),
+            term_id: verification.offering.as_ref().map(|offering| offering.term_id.clone()),
+            // The offering's instructor graded the course
+            instructor: verification.offering.as_ref().map(|offering| offering.instructor.to_string()),
+            grade: enrollment.map(|(_, enrollment)| enrollment.grade),
+            letter_grade: enrollment.map(|(_, enrollment)| enrollment.letter_grade.clone()),
+            completed_at: enrollment
+                .and_then(|(_, enrollment)| enrollment.completion_date)
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+                .map(|date| date.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            metadata_hash: enrollment
+                .and_then(|(_, enrollment)| enrollment.metadata_hash)
+                .map(|hash| hash_to_hex(&hash)),
+            verify_url: verify_url(public_url, &verification.mint),
+            short_url: short_url(public_url, &verification.mint),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use academic_chain::state::{Course, CourseEnrollment, CourseOffering, EnrollmentStatus};
+use academic_chain_client::pda::certificate_mint_pda;
+use academic_chain_client::verify::{Verification, VerificationFailure};
+use academic_verify::view::CertificateView;
+use academic_verify::{html, router, shortlink, AppState};
+use anchor_lang::prelude::Pubkey;
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use http_body_util::BodyExt;
+use solana_rpc_client::rpc_client::RpcClient;
+use tower::ServiceExt;
+
+const PUBLIC_URL: &str = "https://verify.example.edu/";
+
+fn verification(grade: u8, failure: Option<VerificationFailure>) -> Verification {
+    let address = Pubkey::new_unique();
+    let mint = certificate_mint_pda(&address).0;
+    let instructor = Pubkey::new_unique();
+    Verification {
+        mint,
+        enrollment: Some((
+            address,
+            CourseEnrollment {
+                student: Pubkey::new_unique(),
+                course_id: "SOL101".to_string(),
+                offering: Pubkey::new_unique(),
+                credits_paid: 5,
+                enrollment_date: 1_760_000_000,
+                completion_date: Some(1_760_745_600),
+                status: EnrollmentStatus::Completed,
+                grade,
+                letter_grade: "B".to_string(),
+                grade_points: 300,
+                credit_hours: 3,
+                certificate_mint: Some(mint),
+                metadata_hash: Some([0x42; 32]),
+                certificate_batch: None,
+                compressed_certificate: None,
+                transferred_from: None,
+                attempts: Vec::new(),
+                bump: 255,
+            },
+        )),
+        offering: Some(CourseOffering {
+            institution: Pubkey::default(),
+            course_id: "SOL101".to_string(),
+            term_id: "2025FA".to_string(),
+            instructor,
+            capacity: 30,
+            enrollment_count: 1,
+            enrollment_opens_at: 0,
+            enrollment_closes_at: 0,
+            grading_deadline: 0,
+            created_at: 0,
+            bump: 255,
+        }),
+        course: Some(Course {
+            institution: Pubkey::default(),
+            course_id: "SOL101".to_string(),
+            course_name: "Solana <Fundamentals>".to_string(),
+            instructor,
+            required_credits: 5,
+            credit_hours: 3,
+            max_attempts: 3,
+            is_active: true,
+            created_at: 0,
+            bump: 255,
+        }),
+        failure,
+    }
+}
+
+#[test]
+fn short_codes_round_trip_in_qr_alphanumeric_form() {
+    let mint = Pubkey::new_unique();
+    let url = shortlink::short_url(PUBLIC_URL, &mint);
+
+    assert!(url.starts_with("HTTPS://VERIFY.EXAMPLE.EDU/V/"));
+    assert!(url
+        .chars()
+        .all(|ch| ch.is_ascii_digit() || ch.is_ascii_uppercase() || " $%*+-./:".contains(ch)));
+
+    let code = url.rsplit('/').next().unwrap();
+    assert_eq!(shortlink::decode(code), Some(mint));
+    assert_eq!(shortlink::decode(&code.to_lowercase()), Some(mint));
+    assert_eq!(shortlink::decode("NOT-A-CODE"), None);
+}
+
+#[test]
+fn view_lists_who_earned_what() {
+    let verification = verification(85, None);
+    let view = CertificateView::new(&verification, PUBLIC_URL);
+
+    assert!(view.valid);
+    assert_eq!(view.reason, None);
+    assert_eq!(view.course_name.as_deref(), Some("Solana <Fundamentals>"));
+    assert_eq!(view.term_id.as_deref(), Some("2025FA"));
+    assert_eq!(view.grade, Some(85));
+    assert_eq!(view.completed_at.as_deref(), Some("2025-10-18T00:00:00Z"));
+    assert_eq!(view.program_id, academic_chain::ID.to_string());
+    assert_eq!(view.verify_url, format!("https://verify.example.edu/verify/{}", verification.mint));
+
+    let json = serde_json::to_value(&view).unwrap();
+    assert_eq!(json["letterGrade"], "B");
+    assert_eq!(json["metadataHash"], "42".repeat(32));
+    assert_eq!(json["instructor"], verification.offering.as_ref().unwrap().instructor.to_string());
+}
+
+#[test]
+fn html_page_escapes_and_reports_revocation() {
+    let page = html::render(&CertificateView::new(&verification(85, None), PUBLIC_URL));
+    assert!(page.contains("Verified certificate"));
+    assert!(page.contains("Solana &lt;Fundamentals&gt; (SOL101)"));
+    assert!(page.contains("October 18, 2025"));
+    assert!(page.contains("85 (B)"));
+
+    let revoked = verification(40, Some(VerificationFailure::Revoked));
+    let page = html::render(&CertificateView::new(&revoked, PUBLIC_URL));
+    assert!(page.contains("Not verified: grade is below passing"));
+}
+
+fn app() -> axum::Router {
+    // Nothing listens here; only routes that reach RPC notice
+    router(AppState::new(RpcClient::new("http://127.0.0.1:1".to_string()), PUBLIC_URL))
+}
+
+async fn get(uri: &str, accept: &str) -> axum::response::Response {
+    app()
+        .oneshot(Request::get(uri).header(header::ACCEPT, accept).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn short_links_redirect_to_the_verify_page() {
+    let mint = Pubkey::new_unique();
+    for prefix in ["/V/", "/v/"] {
+        let response = get(&format!("{prefix}{}", shortlink::encode(&mint)), "*/*").await;
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(
+            response.headers()[header::LOCATION],
+            format!("https://verify.example.edu/verify/{mint}").as_str()
+        );
+    }
+
+    assert_eq!(get("/V/BAD", "*/*").await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn verify_rejects_bad_addresses_and_reports_rpc_failures() {
+    let response = get("/verify/not-a-mint", "application/json").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(String::from_utf8_lossy(&body).contains("invalid mint address"));
+
+    let response = get(&format!("/verify/{}?format=html", Pubkey::new_unique()), "*/*").await;
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+}
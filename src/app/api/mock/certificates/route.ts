@@ -1,6 +1,6 @@
-import { NextResponse } from 'next/server';
+import { fromAcademicApi } from '@/lib/academic-api';
 import { mockCertificates } from '@/lib/mockData';
 
-export async function GET() {
-  return NextResponse.json(mockCertificates);
+export async function GET(request: Request) {
+  return fromAcademicApi(request, '/certificates', mockCertificates);
 }
@@ -10,8 +10,9 @@ import { Label } from '@/components/ui/label'
 import { toast } from 'sonner'
 import {
   getPurchaseCreditsInstructionAsync,
-  fetchProgramConfig,
+  fetchInstitution,
 } from '../../../anchor/src/client/js/academic-chain'
+import { getInstitutionAddress } from '@/lib/academic-chain-client'
 import { address, createSolanaRpc, createSolanaRpcSubscriptions, sendAndConfirmTransactionFactory } from 'gill'
 
 const ACADEMIC_CHAIN_PROGRAM_ID = '9HuNte7WjS8GVHBKpE42y1QXq4C7e6uNvtjmDRM1G99F'
@@ -22,18 +23,15 @@ export function PurchaseCreditsComponent() {
   const { transactionSigner } = useWalletUiGill()
   const [amount, setAmount] = useState('10')
 
-  // Fetch program config to get credit price and treasury info
+  // Fetch the institution to get credit price and treasury info
   const { data: config, isLoading: configLoading } = useQuery({
-    queryKey: ['academic-chain-config'],
+    queryKey: ['academic-chain-institution'],
     queryFn: async () => {
       const rpc = createSolanaRpc(DEVNET_RPC_URL)
-      const configPda = await getProgramDerivedAddress({
-        programAddress: address(ACADEMIC_CHAIN_PROGRAM_ID),
-        seeds: [new TextEncoder().encode('config')],
-      })
+      const institutionPda = await getInstitutionAddress()
       
-      const configAccount = await fetchProgramConfig(rpc, configPda)
-      return configAccount
+      const institutionAccount = await fetchInstitution(rpc, institutionPda)
+      return institutionAccount
     },
     enabled: connected,
     refetchInterval: 30000, // Refetch every 30s
@@ -52,8 +50,10 @@ export function PurchaseCreditsComponent() {
       // Get the purchase credits instruction
       const instruction = await getPurchaseCreditsInstructionAsync({
         student: transactionSigner,
+        institution: config.address,
         treasury: config.data.treasury,
         creditMint: config.data.creditMint,
+        program: address(ACADEMIC_CHAIN_PROGRAM_ID),
         amount: BigInt(creditAmount),
       })
 
@@ -151,7 +151,7 @@ export function PurchaseCreditsComponent() {
 
       {configLoading ? (
         <div className="py-8 text-center">
-          <p className="text-muted-foreground">Loading program config...</p>
+          <p className="text-muted-foreground">Loading institution config...</p>
         </div>
       ) : config ? (
         <div className="space-y-4">
@@ -198,28 +198,9 @@ export function PurchaseCreditsComponent() {
         </div>
       ) : (
         <div className="py-8 text-center">
-          <p className="text-destructive">Failed to load program config</p>
+          <p className="text-destructive">Failed to load institution config</p>
         </div>
       )}
     </div>
   )
 }
-
-// Helper function to derive PDA (if not exported from client)
-async function getProgramDerivedAddress(params: {
-  programAddress: ReturnType<typeof address>
-  seeds: Uint8Array[]
-}) {
-  const { programAddress, seeds } = params
-  
-  // Use the gill library's getProgramDerivedAddress
-  const { getProgramDerivedAddress: getPda } = await import('gill')
-  
-  return getPda({
-    programAddress,
-    seeds: seeds.map(seed => ({
-      kind: 'const' as const,
-      value: seed,
-    })),
-  })
-}
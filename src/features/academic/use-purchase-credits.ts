@@ -7,7 +7,7 @@ import { useWalletUi, useWalletUiSigner, type UiWalletAccount } from '@wallet-ui
 import { useSolana } from '@/components/solana/use-solana'
 import {
   purchaseCredits,
-  getProgramConfiguration,
+  getInstitutionConfiguration,
   getStudentProfile,
   lamportsToSol,
   getExplorerUrl,
@@ -39,16 +39,16 @@ export function usePurchaseCredits(options: UsePurchaseCreditsOptions) {
     hasSigner: !!transactionSigner,
   })
 
-  // Fetch program config
+  // Fetch institution config
   const {
     data: config,
     isLoading: configLoading,
     error: configError,
   } = useQuery({
-    queryKey: ['academic-chain-config', rpcUrl],
+    queryKey: ['academic-chain-institution', rpcUrl],
     queryFn: async () => {
       try {
-        return await getProgramConfiguration(rpcUrl)
+        return await getInstitutionConfiguration(rpcUrl)
       } catch (error) {
         console.error('❌ Failed to fetch program configuration:', error)
         throw error
@@ -230,8 +230,8 @@ export function useStudentStats(studentAddress?: string, rpcUrl?: string) {
  */
 export function useAcademicChainProgram(rpcUrl?: string) {
   const { data: config, ...queryState } = useQuery({
-    queryKey: ['academic-chain-config', rpcUrl],
-    queryFn: () => getProgramConfiguration(rpcUrl),
+    queryKey: ['academic-chain-institution', rpcUrl],
+    queryFn: () => getInstitutionConfiguration(rpcUrl),
     staleTime: 300000, // Cache for 5 minutes
   })
 
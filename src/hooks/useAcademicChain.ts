@@ -18,18 +18,18 @@ import {
 import {
   getProgram,
   getConnection,
-  getConfigPda,
+  getInstitutionPda,
   getCoursePda,
   getEnrollmentPda,
-  getStudentProfilePda,
   getStudentCreditAccount,
-  fetchConfig,
+  fetchInstitution,
   fetchCourse,
   fetchEnrollment,
   fetchStudentProfile,
   getCreditBalance,
   parseAnchorError,
   confirmTransaction,
+  TERM_ID,
   type AnchorWallet,
 } from '@/lib/academic-chain';
 import { DEVNET_RPC_URL } from '@/lib/academic-chain-client';
@@ -69,17 +69,17 @@ function useAnchorWallet(): AnchorWallet | null {
 }
 
 /**
- * Hook to fetch program config
+ * Hook to fetch the institution account
  */
-export function useConfig() {
+export function useInstitution() {
   const wallet = useAnchorWallet();
 
   return useQuery({
-    queryKey: ['academic-chain', 'config'],
+    queryKey: ['academic-chain', 'institution'],
     queryFn: async () => {
       if (!wallet) throw new Error('Wallet not connected');
       const program = getProgram(wallet);
-      return await fetchConfig(program);
+      return await fetchInstitution(program);
     },
     enabled: !!wallet,
     staleTime: 5 * 60 * 1000, // 5 minutes
@@ -107,15 +107,15 @@ export function useCourse(courseId: string | null) {
 /**
  * Hook to check enrollment status
  */
-export function useEnrollment(courseId: string | null) {
+export function useEnrollment(courseId: string | null, termId: string = TERM_ID) {
   const wallet = useAnchorWallet();
 
   return useQuery({
-    queryKey: ['academic-chain', 'enrollment', wallet?.publicKey?.toString(), courseId],
+    queryKey: ['academic-chain', 'enrollment', wallet?.publicKey?.toString(), courseId, termId],
     queryFn: async () => {
       if (!wallet || !courseId) return null;
       const program = getProgram(wallet);
-      return await fetchEnrollment(program, wallet.publicKey, courseId);
+      return await fetchEnrollment(program, wallet.publicKey, courseId, termId);
     },
     enabled: !!wallet && !!courseId,
   });
@@ -143,40 +143,40 @@ export function useStudentProfile() {
  */
 export function useCreditBalance() {
   const wallet = useAnchorWallet();
-  const { data: config, isLoading: configLoading, error: configError } = useConfig();
+  const { data: institution, isLoading: institutionLoading, error: institutionError } = useInstitution();
 
   const query = useQuery({
     queryKey: ['academic-chain', 'credit-balance', wallet?.publicKey?.toString()],
     queryFn: async () => {
       console.log('🔍 useCreditBalance queryFn called:', {
         hasWallet: !!wallet,
-        hasConfig: !!config,
+        hasInstitution: !!institution,
         walletPublicKey: wallet?.publicKey?.toString(),
-        creditMint: config?.creditMint?.toString(),
+        creditMint: institution?.creditMint?.toString(),
       });
 
-      if (!wallet || !config) {
-        console.log('⚠️ Missing wallet or config:', { wallet: !!wallet, config: !!config });
+      if (!wallet || !institution) {
+        console.log('⚠️ Missing wallet or institution:', { wallet: !!wallet, institution: !!institution });
         return 0;
       }
 
       const connection = getConnection();
-      const balance = await getCreditBalance(connection, config.creditMint, wallet.publicKey);
+      const balance = await getCreditBalance(connection, institution.creditMint, wallet.publicKey);
       
       console.log('✅ Credit balance result:', balance);
       
       return balance;
     },
-    enabled: !!wallet && !!config,
+    enabled: !!wallet && !!institution,
     staleTime: 30000, // 30 seconds
     refetchInterval: 60000, // Refetch every minute
   });
 
   console.log('📊 useCreditBalance state:', {
     hasWallet: !!wallet,
-    hasConfig: !!config,
-    configLoading,
-    configError: !!configError,
+    hasInstitution: !!institution,
+    institutionLoading,
+    institutionError: !!institutionError,
     isEnabled: query.isEnabled,
     isLoading: query.isLoading,
     data: query.data,
@@ -203,7 +203,7 @@ export function useCourseRegistration() {
   const [isProcessing, setIsProcessing] = useState(false);
 
   const registerCourse = useCallback(
-    async (courseId: string) => {
+    async (courseId: string, termId: string = TERM_ID) => {
       if (!wallet || !account || !transactionSigner || !connected || !shouldUseSigner) {
         throw new Error('Wallet not connected');
       }
@@ -215,21 +215,20 @@ export function useCourseRegistration() {
         const connection = getConnection();
 
         // Find PDAs
-        getConfigPda(program.programId);
-        const [coursePda] = getCoursePda(courseId, program.programId);
-        const [enrollmentPda] = getEnrollmentPda(wallet.publicKey, courseId, program.programId);
-        getStudentProfilePda(wallet.publicKey, program.programId);
-
-        // Fetch config to get credit mint
-        const config = await fetchConfig(program);
-        if (!config) {
-          throw new Error('Program config not found. Is the program initialized?');
+        const [institutionPda] = getInstitutionPda(undefined, program.programId);
+        const [coursePda] = getCoursePda(courseId, undefined, program.programId);
+        const [enrollmentPda] = getEnrollmentPda(wallet.publicKey, courseId, termId, undefined, program.programId);
+
+        // Fetch institution to get credit mint
+        const institution = await fetchInstitution(program);
+        if (!institution) {
+          throw new Error('Institution not found. Has it been created with scripts/init-simple.ts?');
         }
 
         // Get student credit account
-        const studentCreditAccount = getStudentCreditAccount(config.creditMint, wallet.publicKey);
+        const studentCreditAccount = getStudentCreditAccount(institution.creditMint, wallet.publicKey);
 
-        console.log('🔄 Registering for course:', courseId);
+        console.log('🔄 Registering for course:', courseId, termId);
         console.log('Course PDA:', coursePda.toString());
         console.log('Enrollment PDA:', enrollmentPda.toString());
         console.log('Student Credit Account:', studentCreditAccount.toString());
@@ -237,10 +236,11 @@ export function useCourseRegistration() {
 
         console.log('🏗️  Building Anchor instruction...');
         const instruction = await program.methods
-          .registerCourse(courseId)
+          .registerCourse(courseId, termId)
           .accounts({
             student: wallet.publicKey,
-            creditMint: config.creditMint,
+            institution: institutionPda,
+            creditMint: institution.creditMint,
           })
           .instruction();
 
@@ -300,10 +300,11 @@ export function useCourseRegistration() {
         console.log('🧪 Simulating transaction first...');
         try {
           const tx = await program.methods
-            .registerCourse(courseId)
+            .registerCourse(courseId, termId)
             .accounts({
               student: wallet.publicKey,
-              creditMint: config.creditMint,
+              institution: institutionPda,
+              creditMint: institution.creditMint,
             })
             .transaction();
           
@@ -428,18 +429,14 @@ export function usePurchaseCredits() {
       const connection = getConnection();
 
       // Find PDAs
-      getConfigPda(program.programId);
-      getStudentProfilePda(wallet.publicKey, program.programId);
+      const [institutionPda] = getInstitutionPda(undefined, program.programId);
 
-      // Fetch config
-      const config = await fetchConfig(program);
-      if (!config) {
-        throw new Error('Program config not found');
+      // Fetch institution
+      const institution = await fetchInstitution(program);
+      if (!institution) {
+        throw new Error('Institution not found');
       }
 
-      // Get student credit account
-      getStudentCreditAccount(config.creditMint, wallet.publicKey);
-
       console.log('🔄 Purchasing credits:', amount);
 
       // Build and send transaction
@@ -447,8 +444,9 @@ export function usePurchaseCredits() {
         .purchaseCredits(new BN(amount))
         .accounts({
           student: wallet.publicKey,
-          treasury: config.treasury,
-          creditMint: config.creditMint,
+          institution: institutionPda,
+          treasury: institution.treasury,
+          creditMint: institution.creditMint,
         })
         .rpc();
 
@@ -486,18 +484,18 @@ export function useCanAffordCourse(requiredCredits: number) {
 /**
  * Hook to check if student is enrolled in a course
  */
-export function useIsEnrolled(courseId: string | null) {
-  const { data: enrollment } = useEnrollment(courseId);
+export function useIsEnrolled(courseId: string | null, termId: string = TERM_ID) {
+  const { data: enrollment } = useEnrollment(courseId, termId);
   return !!enrollment;
 }
 
 /**
  * Combined hook for course registration page
  */
-export function useCourseRegistrationPage(courseId: string) {
+export function useCourseRegistrationPage(courseId: string, termId: string = TERM_ID) {
   const wallet = useAnchorWallet();
   const { data: course, isLoading: courseLoading } = useCourse(courseId);
-  const { data: enrollment, isLoading: enrollmentLoading } = useEnrollment(courseId);
+  const { data: enrollment, isLoading: enrollmentLoading } = useEnrollment(courseId, termId);
   const { data: creditBalance = 0, isLoading: balanceLoading } = useCreditBalance();
   const { registerCourse, isProcessing } = useCourseRegistration();
 
@@ -561,6 +559,8 @@ export function useCreateCourse() {
       courseName: string;
       instructor: string;
       requiredCredits: number;
+      creditHours?: number;
+      maxAttempts?: number;
     }) => {
       if (!wallet || !account || !transactionSigner || !connected) {
         throw new Error('Wallet not connected');
@@ -575,7 +575,8 @@ export function useCreateCourse() {
         console.log('🔄 Creating course:', params.courseId);
 
         // Derive course PDA using helper to avoid Anchor auto-generating a new keypair signer
-        const [coursePda] = getCoursePda(params.courseId, program.programId);
+        const [institutionPda] = getInstitutionPda(undefined, program.programId);
+        const [coursePda] = getCoursePda(params.courseId, undefined, program.programId);
 
         // Build instruction using Anchor
         const instruction = await program.methods
@@ -583,10 +584,14 @@ export function useCreateCourse() {
             params.courseId,
             params.courseName,
             new PublicKey(params.instructor),
-            new BN(params.requiredCredits)
+            new BN(params.requiredCredits),
+            params.creditHours ?? 3,
+            params.maxAttempts ?? 3
           )
           .accounts({
             authority: wallet.publicKey,
+            institution: institutionPda,
+            authorityRole: null,
             course: coursePda,
             systemProgram: SystemProgram.programId,
           })
@@ -0,0 +1,19 @@
+import { NextResponse } from 'next/server';
+
+// Base URL of the academic-api service; the mock data is served when unset
+const ACADEMIC_API_URL = process.env.ACADEMIC_API_URL;
+
+export async function fromAcademicApi<T>(request: Request, path: string, mock: T) {
+  if (!ACADEMIC_API_URL) {
+    return NextResponse.json(mock);
+  }
+
+  const { search } = new URL(request.url);
+  const response = await fetch(`${ACADEMIC_API_URL.replace(/\/$/, '')}${path}${search}`, { cache: 'no-store' });
+  const headers = new Headers();
+  const total = response.headers.get('x-total-count');
+  if (total) {
+    headers.set('x-total-count', total);
+  }
+  return NextResponse.json(await response.json(), { status: response.status, headers });
+}
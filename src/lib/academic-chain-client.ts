@@ -5,10 +5,11 @@
 
 import {
   getPurchaseCreditsInstructionAsync,
-  fetchProgramConfig,
+  fetchInstitution,
   fetchStudentProfile,
   ACADEMIC_CHAIN_PROGRAM_ADDRESS,
 } from '../../anchor/src/client/js/academic-chain'
+import { INSTITUTION_ID } from './academic-chain'
 import {
   address,
   createSolanaRpc,
@@ -31,6 +32,7 @@ export const DEVNET_WS_URL = 'wss://api.devnet.solana.com'
 export interface PurchaseCreditsParams {
   student: TransactionSigner
   amount: number
+  institutionId?: string
   rpcUrl?: string
   rpc?: ReturnType<typeof createSolanaRpc> // Optional: pass RPC client directly
 }
@@ -63,26 +65,23 @@ export async function purchaseCredits(
       throw new Error('Invalid wallet signer. Please reconnect your wallet.')
     }
 
-    // Fetch program config
-    console.log('📋 Fetching program configuration...')
-    const [configPda] = await getProgramDerivedAddress({
-      programAddress: address(ACADEMIC_CHAIN_PROGRAM_ADDRESS),
-      seeds: [new TextEncoder().encode('config')],
-    })
+    // Fetch institution
+    console.log('📋 Fetching institution...')
+    const institutionPda = await getInstitutionAddress(params.institutionId)
     
-    console.log('📍 Config PDA:', configPda)
+    console.log('📍 Institution PDA:', institutionPda)
 
     let config
     try {
-      config = await fetchProgramConfig(rpc, configPda)
+      config = await fetchInstitution(rpc, institutionPda)
     } catch (configError) {
-      console.error('❌ Failed to fetch program config:', configError)
+      console.error('❌ Failed to fetch institution:', configError)
       throw new Error(
-        'Program not initialized. The academic chain program needs to be initialized first. ' +
+        'Institution not found. The institution needs to be created with scripts/init-simple.ts first. ' +
         'Please contact the administrator or check if you are connected to the correct network (devnet/mainnet).'
       )
     }
-    console.log('✅ Config fetched:', {
+    console.log('✅ Institution fetched:', {
       creditPrice: config.data.creditPrice.toString(),
       creditPriceSOL: lamportsToSol(config.data.creditPrice),
       treasury: config.data.treasury,
@@ -162,10 +161,12 @@ export async function purchaseCredits(
     console.log('📝 Creating purchase instruction...')
     const instruction = await getPurchaseCreditsInstructionAsync({
       student: params.student,
+      institution: institutionPda,
       treasury: config.data.treasury,
       creditMint: config.data.creditMint,
       studentCreditAccount,
       tokenProgram: tokenProgramAddress,
+      program: ACADEMIC_CHAIN_PROGRAM_ADDRESS,
       amount: BigInt(params.amount),
     })
     instructions.push(instruction)
@@ -293,14 +294,17 @@ export async function purchaseCredits(
  */
 export async function getStudentProfile(
   studentAddress: Address,
-  rpcUrl?: string
+  rpcUrl?: string,
+  institutionId?: string
 ) {
   const rpc = createSolanaRpc(rpcUrl || DEVNET_RPC_URL)
 
+  const institutionPda = await getInstitutionAddress(institutionId)
   const [studentProfilePda] = await getProgramDerivedAddress({
     programAddress: address(ACADEMIC_CHAIN_PROGRAM_ADDRESS),
     seeds: [
       new TextEncoder().encode('student_profile'),
+      new Uint8Array(getAddressEncoder().encode(institutionPda)),
       new Uint8Array(getAddressEncoder().encode(studentAddress)),
     ],
   })
@@ -328,20 +332,31 @@ export async function getStudentProfile(
 }
 
 /**
- * Get program configuration
+ * Get institution configuration (credit price, treasury, credit mint)
  */
-export async function getProgramConfiguration(rpcUrl?: string) {
+export async function getInstitutionConfiguration(rpcUrl?: string, institutionId?: string) {
   const rpc = createSolanaRpc(rpcUrl || DEVNET_RPC_URL)
 
-  const [configPda] = await getProgramDerivedAddress({
-    programAddress: address(ACADEMIC_CHAIN_PROGRAM_ADDRESS),
-    seeds: [new TextEncoder().encode('config')],
-  })
+  const institutionPda = await getInstitutionAddress(institutionId)
 
-  const config = await fetchProgramConfig(rpc, configPda)
+  const config = await fetchInstitution(rpc, institutionPda)
   return config
 }
 
+/**
+ * Derive the institution PDA: ["institution", institution_id]
+ */
+export async function getInstitutionAddress(institutionId: string = INSTITUTION_ID) {
+  const [institutionPda] = await getProgramDerivedAddress({
+    programAddress: address(ACADEMIC_CHAIN_PROGRAM_ADDRESS),
+    seeds: [
+      new TextEncoder().encode('institution'),
+      new TextEncoder().encode(institutionId),
+    ],
+  })
+  return institutionPda
+}
+
 /**
  * Helper to derive program derived addresses
  */
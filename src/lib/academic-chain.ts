@@ -27,6 +27,12 @@ export const RPC_ENDPOINT =
   process.env.NEXT_PUBLIC_SOLANA_RPC_URL ||
   'https://api.devnet.solana.com';
 
+// Institution the app operates on, created with scripts/init-simple.ts
+export const INSTITUTION_ID = process.env.NEXT_PUBLIC_INSTITUTION_ID || 'EXU';
+
+// Term whose course offerings students register for
+export const TERM_ID = process.env.NEXT_PUBLIC_TERM_ID || '2025FA';
+
 /**
  * Get connection to Solana
  */
@@ -50,10 +56,13 @@ export function getProgram(wallet: AnchorWallet): Program<AcademicChain> {
  * PDA Helper Functions
  */
 
-// Get config PDA
-export function getConfigPda(programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID): [PublicKey, number] {
+// Get institution PDA
+export function getInstitutionPda(
+  institutionId: string = INSTITUTION_ID,
+  programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
+): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('config')],
+    [Buffer.from('institution'), Buffer.from(institutionId)],
     programId
   );
 }
@@ -61,10 +70,31 @@ export function getConfigPda(programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID):
 // Get course PDA
 export function getCoursePda(
   courseId: string,
+  institutionId: string = INSTITUTION_ID,
+  programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
+): [PublicKey, number] {
+  const [institutionPda] = getInstitutionPda(institutionId, programId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('course'), institutionPda.toBuffer(), Buffer.from(courseId)],
+    programId
+  );
+}
+
+// Get course offering PDA
+export function getOfferingPda(
+  courseId: string,
+  termId: string = TERM_ID,
+  institutionId: string = INSTITUTION_ID,
   programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
 ): [PublicKey, number] {
+  const [institutionPda] = getInstitutionPda(institutionId, programId);
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('course'), Buffer.from(courseId)],
+    [
+      Buffer.from('offering'),
+      institutionPda.toBuffer(),
+      Buffer.from(courseId),
+      Buffer.from(termId)
+    ],
     programId
   );
 }
@@ -73,13 +103,16 @@ export function getCoursePda(
 export function getEnrollmentPda(
   studentPubkey: PublicKey,
   courseId: string,
+  termId: string = TERM_ID,
+  institutionId: string = INSTITUTION_ID,
   programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
 ): [PublicKey, number] {
+  const [offeringPda] = getOfferingPda(courseId, termId, institutionId, programId);
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from('enrollment'),
       studentPubkey.toBuffer(),
-      Buffer.from(courseId)
+      offeringPda.toBuffer()
     ],
     programId
   );
@@ -88,26 +121,27 @@ export function getEnrollmentPda(
 // Get student profile PDA
 export function getStudentProfilePda(
   studentPubkey: PublicKey,
+  institutionId: string = INSTITUTION_ID,
   programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
 ): [PublicKey, number] {
+  const [institutionPda] = getInstitutionPda(institutionId, programId);
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('student_profile'), studentPubkey.toBuffer()],
+    [
+      Buffer.from('student_profile'),
+      institutionPda.toBuffer(),
+      studentPubkey.toBuffer()
+    ],
     programId
   );
 }
 
 // Get certificate mint PDA
 export function getCertificateMintPda(
-  studentPubkey: PublicKey,
-  courseId: string,
+  enrollmentPda: PublicKey,
   programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from('certificate_mint'),
-      studentPubkey.toBuffer(),
-      Buffer.from(courseId)
-    ],
+    [Buffer.from('certificate_mint'), enrollmentPda.toBuffer()],
     programId
   );
 }
@@ -115,10 +149,16 @@ export function getCertificateMintPda(
 // Get graduation mint PDA
 export function getGraduationMintPda(
   studentPubkey: PublicKey,
+  institutionId: string = INSTITUTION_ID,
   programId: PublicKey = ACADEMIC_CHAIN_PROGRAM_ID
 ): [PublicKey, number] {
+  const [institutionPda] = getInstitutionPda(institutionId, programId);
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('graduation_mint'), studentPubkey.toBuffer()],
+    [
+      Buffer.from('graduation_mint'),
+      institutionPda.toBuffer(),
+      studentPubkey.toBuffer()
+    ],
     programId
   );
 }
@@ -127,31 +167,39 @@ export function getGraduationMintPda(
  * Account fetching helpers
  */
 
-// Fetch config account
-export async function fetchConfig(program: Program<AcademicChain>) {
-  const [configPda] = getConfigPda(program.programId);
+// Fetch institution account
+export async function fetchInstitution(
+  program: Program<AcademicChain>,
+  institutionId: string = INSTITUTION_ID
+) {
+  const [institutionPda] = getInstitutionPda(institutionId, program.programId);
   
-  console.log('🔍 Fetching program config from PDA:', configPda.toString());
+  console.log('🔍 Fetching institution from PDA:', institutionPda.toString());
   
   try {
-    const config = await program.account.programConfig.fetch(configPda);
+    const institution = await program.account.institution.fetch(institutionPda);
     
-    console.log('✅ Program config fetched:', {
-      creditMint: config.creditMint.toString(),
-      treasury: config.treasury.toString(),
-      authority: config.authority.toString(),
+    console.log('✅ Institution fetched:', {
+      institutionId: institution.institutionId,
+      creditMint: institution.creditMint.toString(),
+      treasury: institution.treasury.toString(),
+      authority: institution.authority.toString(),
     });
     
-    return config;
+    return institution;
   } catch (error) {
-    console.error('❌ Failed to fetch program config:', error);
+    console.error('❌ Failed to fetch institution:', error);
     throw error;
   }
 }
 
 // Fetch course account
-export async function fetchCourse(program: Program<AcademicChain>, courseId: string) {
-  const [coursePda] = getCoursePda(courseId, program.programId);
+export async function fetchCourse(
+  program: Program<AcademicChain>,
+  courseId: string,
+  institutionId: string = INSTITUTION_ID
+) {
+  const [coursePda] = getCoursePda(courseId, institutionId, program.programId);
   try {
     return await program.account.course.fetch(coursePda);
   } catch {
@@ -164,9 +212,17 @@ export async function fetchCourse(program: Program<AcademicChain>, courseId: str
 export async function fetchEnrollment(
   program: Program<AcademicChain>,
   studentPubkey: PublicKey,
-  courseId: string
+  courseId: string,
+  termId: string = TERM_ID,
+  institutionId: string = INSTITUTION_ID
 ) {
-  const [enrollmentPda] = getEnrollmentPda(studentPubkey, courseId, program.programId);
+  const [enrollmentPda] = getEnrollmentPda(
+    studentPubkey,
+    courseId,
+    termId,
+    institutionId,
+    program.programId
+  );
   try {
     return await program.account.courseEnrollment.fetch(enrollmentPda);
   } catch {
@@ -178,9 +234,14 @@ export async function fetchEnrollment(
 // Fetch student profile
 export async function fetchStudentProfile(
   program: Program<AcademicChain>,
-  studentPubkey: PublicKey
+  studentPubkey: PublicKey,
+  institutionId: string = INSTITUTION_ID
 ) {
-  const [studentProfilePda] = getStudentProfilePda(studentPubkey, program.programId);
+  const [studentProfilePda] = getStudentProfilePda(
+    studentPubkey,
+    institutionId,
+    program.programId
+  );
   try {
     return await program.account.studentProfile.fetch(studentProfilePda);
   } catch {
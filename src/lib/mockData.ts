@@ -312,6 +312,13 @@ export const mockDashboardMetrics: DashboardMetrics = {
   totalCourses: 24,
   certificatesIssued: 1653,
   totalCreditsInCirculation: 45670,
+  creditsInCirculation: [
+    {
+      institution: 'AcademicChainInstitutionXXXXXXXXXXXXXXXXXX',
+      creditMint: 'AcademicChainCreditMintXXXXXXXXXXXXXXXXXXX',
+      credits: 45670
+    }
+  ],
   monthlyActiveUsers: 1245,
   averageGrade: 83.7,
   completionRate: 78.5,
@@ -73,11 +73,19 @@ export interface Transaction {
   details: Record<string, unknown>;
 }
 
+export interface CreditsInCirculation {
+  institution: string;
+  creditMint: string;
+  credits: number;
+}
+
 export interface DashboardMetrics {
   totalStudents: number;
   totalCourses: number;
   certificatesIssued: number;
-  totalCreditsInCirculation: number;
+  // Only set when the metrics are scoped to one institution
+  totalCreditsInCirculation?: number;
+  creditsInCirculation: CreditsInCirculation[];
   monthlyActiveUsers: number;
   averageGrade: number;
   completionRate: number;